@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use libpulse_binding::{callbacks::ListResult, context::Context};
+
+use crate::{
+    backend::{
+        conversion::card::from_card,
+        types::{CardStore, EventSender},
+    },
+    events::AudioEvent,
+    types::card::CardInfo,
+};
+
+pub(crate) fn trigger_discovery(context: &Context, cards: &CardStore, events_tx: &EventSender) {
+    let introspect = context.introspect();
+
+    let cards_clone = Arc::clone(cards);
+    let events_tx_clone = events_tx.clone();
+
+    introspect.get_card_info_list(move |card_list| {
+        if let ListResult::Item(card) = card_list {
+            process_card_update(from_card(card), &cards_clone, &events_tx_clone);
+        }
+    });
+}
+
+pub(crate) fn trigger_refresh(
+    context: &Context,
+    cards: &CardStore,
+    events_tx: &EventSender,
+    index: u32,
+) {
+    let introspect = context.introspect();
+
+    let cards_clone = Arc::clone(cards);
+    let events_tx_clone = events_tx.clone();
+
+    introspect.get_card_info_by_index(index, move |card_list| {
+        if let ListResult::Item(card) = card_list {
+            process_card_update(from_card(card), &cards_clone, &events_tx_clone);
+        }
+    });
+}
+
+pub(crate) fn process_card_update(card: CardInfo, cards: &CardStore, events_tx: &EventSender) {
+    let Ok(mut cards_guard) = cards.write() else {
+        return;
+    };
+
+    let is_new = !cards_guard.contains_key(&card.index);
+    cards_guard.insert(card.index, card.clone());
+
+    let event = if is_new {
+        AudioEvent::CardAdded(card)
+    } else {
+        AudioEvent::CardChanged(card)
+    };
+
+    let _ = events_tx.send(event);
+}
+
+pub(crate) fn set_card_profile(context: &Context, card_index: u32, profile: String) {
+    let mut introspect = context.introspect();
+    introspect.set_card_profile_by_index(card_index, &profile, None);
+}
@@ -1,3 +1,4 @@
+pub(crate) mod card;
 pub(crate) mod device;
 pub(crate) mod server;
 pub(crate) mod stream;
@@ -7,6 +8,7 @@ use tokio::sync::oneshot;
 use crate::{
     error::Error,
     types::{
+        card::CardInfo,
         device::{Device, DeviceKey},
         stream::{StreamInfo, StreamKey},
     },
@@ -61,4 +63,13 @@ pub(crate) enum Command {
         port: String,
         responder: oneshot::Sender<Result<(), Error>>,
     },
+    GetCard {
+        index: u32,
+        responder: oneshot::Sender<Result<CardInfo, Error>>,
+    },
+    SetCardProfile {
+        card_index: u32,
+        profile: String,
+        responder: oneshot::Sender<Result<(), Error>>,
+    },
 }
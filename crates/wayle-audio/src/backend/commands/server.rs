@@ -9,6 +9,11 @@ use crate::{
     types::device::{Device, DeviceKey},
 };
 
+/// Re-reads the server's default sink and source.
+///
+/// Each default is published as a single `Default*Changed` event, so after
+/// the default device is unplugged the service moves straight to the
+/// server's fallback. `None` is only sent when the server has no default.
 pub(crate) fn trigger_info_query(
     context: &Context,
     devices: &DeviceStore,
@@ -49,6 +54,11 @@ pub(crate) fn trigger_info_query(
                         devices_guard.keys().collect::<Vec<_>>());
                 }
             }
+        } else {
+            if let Ok(mut guard) = default_output_clone.write() {
+                *guard = None;
+            }
+            let _ = events_tx_clone.send(AudioEvent::DefaultOutputChanged(None));
         }
 
         if let Some(source_name) = server_info.default_source_name.as_ref() {
@@ -76,6 +86,11 @@ pub(crate) fn trigger_info_query(
                         devices_guard.keys().collect::<Vec<_>>());
                 }
             }
+        } else {
+            if let Ok(mut guard) = default_input_clone.write() {
+                *guard = None;
+            }
+            let _ = events_tx_clone.send(AudioEvent::DefaultInputChanged(None));
         }
     });
 }
@@ -0,0 +1,91 @@
+use libpulse_binding::context::introspect::{
+    CardInfo as PulseCardInfo, CardProfileInfo2 as PulseCardProfile,
+};
+
+use super::pulse::{collect_proplist, cow_to_string};
+use crate::types::card::{CardInfo, CardProfile};
+
+pub(crate) fn from_card(card: &PulseCardInfo) -> CardInfo {
+    let properties = collect_proplist(&card.proplist);
+    let name = cow_to_string(card.name.as_ref());
+    let description = properties
+        .get("device.description")
+        .cloned()
+        .unwrap_or_else(|| name.clone());
+
+    CardInfo {
+        index: card.index,
+        name,
+        description,
+        owner_module: card.owner_module,
+        driver: cow_to_string(card.driver.as_ref()),
+        properties,
+        profiles: card.profiles.iter().map(convert_profile).collect(),
+        active_profile: card
+            .active_profile
+            .as_ref()
+            .and_then(|profile| profile.name.as_ref().map(|name| name.to_string())),
+    }
+}
+
+fn convert_profile(profile: &PulseCardProfile) -> CardProfile {
+    CardProfile {
+        name: cow_to_string(profile.name.as_ref()),
+        description: cow_to_string(profile.description.as_ref()),
+        priority: profile.priority,
+        available: profile.available,
+        n_sinks: profile.n_sinks,
+        n_sources: profile.n_sources,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libpulse_binding::proplist::Proplist;
+
+    use super::*;
+
+    #[test]
+    fn card_keeps_profile_availability_and_active_profile() {
+        let card = create_headset_card();
+        let info = from_card(&card);
+
+        assert_eq!(info.active_profile.as_deref(), Some("a2dp-sink"));
+        assert_eq!(info.profiles.len(), 2);
+        assert!(info.profiles[0].available);
+        assert!(!info.profiles[1].available);
+    }
+
+    #[test]
+    fn card_description_falls_back_to_name() {
+        let card = create_headset_card();
+        assert_eq!(from_card(&card).description, "bluez_card.00_11_22");
+    }
+
+    fn create_profile(name: &'static str, available: bool) -> PulseCardProfile<'static> {
+        PulseCardProfile {
+            name: Some(name.into()),
+            description: Some(name.into()),
+            n_sinks: 1,
+            n_sources: 0,
+            priority: 0,
+            available,
+        }
+    }
+
+    fn create_headset_card() -> PulseCardInfo<'static> {
+        PulseCardInfo {
+            index: 0,
+            name: Some("bluez_card.00_11_22".into()),
+            owner_module: None,
+            driver: Some("module-bluez5-device.c".into()),
+            proplist: Proplist::new().unwrap(),
+            ports: vec![],
+            profiles: vec![
+                create_profile("a2dp-sink", true),
+                create_profile("headset-head-unit", false),
+            ],
+            active_profile: Some(Box::new(create_profile("a2dp-sink", true))),
+        }
+    }
+}
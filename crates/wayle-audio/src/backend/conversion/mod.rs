@@ -1,5 +1,6 @@
 mod pulse;
 
+pub(crate) mod card;
 pub(crate) mod device;
 pub(crate) mod format;
 pub(crate) mod stream;
@@ -1,9 +1,10 @@
 use libpulse_binding::context::Context;
 
 use super::{
-    commands::{device, server, stream},
+    commands::{card, device, server, stream},
     types::{
-        DefaultDevice, DeviceStore, EventSender, ExternalCommand, InternalRefresh, StreamStore,
+        CardStore, DefaultDevice, DeviceStore, EventSender, ExternalCommand, InternalRefresh,
+        StreamStore,
     },
 };
 
@@ -13,6 +14,7 @@ pub(super) fn handle_internal_command(
     command: InternalRefresh,
     devices: &DeviceStore,
     streams: &StreamStore,
+    cards: &CardStore,
     events_tx: &EventSender,
     default_input: &DefaultDevice,
     default_output: &DefaultDevice,
@@ -24,6 +26,9 @@ pub(super) fn handle_internal_command(
         InternalRefresh::Streams => {
             stream::trigger_discovery(context, streams, events_tx);
         }
+        InternalRefresh::Cards => {
+            card::trigger_discovery(context, cards, events_tx);
+        }
         InternalRefresh::ServerInfo => {
             server::trigger_info_query(context, devices, events_tx, default_input, default_output);
         }
@@ -39,6 +44,9 @@ pub(super) fn handle_internal_command(
         } => {
             stream::trigger_refresh(context, streams, events_tx, stream_key, facility);
         }
+        InternalRefresh::Card { index } => {
+            card::trigger_refresh(context, cards, events_tx, index);
+        }
     }
 }
 
@@ -76,5 +84,11 @@ pub(super) fn handle_external_command(
         ExternalCommand::SetPort { device_key, port } => {
             device::set_device_port(context, device_key, port, devices);
         }
+        ExternalCommand::SetCardProfile {
+            card_index,
+            profile,
+        } => {
+            card::set_card_profile(context, card_index, profile);
+        }
     }
 }
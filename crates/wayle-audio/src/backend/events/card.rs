@@ -0,0 +1,31 @@
+use libpulse_binding::context::subscribe::Operation;
+
+use crate::{
+    backend::types::{CardStore, EventSender, InternalCommandSender, InternalRefresh},
+    events::AudioEvent,
+};
+
+pub(crate) async fn handle_change(
+    operation: Operation,
+    index: u32,
+    cards: &CardStore,
+    events_tx: &EventSender,
+    command_tx: &InternalCommandSender,
+) {
+    match operation {
+        Operation::Removed => {
+            let removed_card = if let Ok(mut cards_guard) = cards.write() {
+                cards_guard.remove(&index)
+            } else {
+                None
+            };
+
+            if removed_card.is_some() {
+                let _ = events_tx.send(AudioEvent::CardRemoved(index));
+            }
+        }
+        Operation::New | Operation::Changed => {
+            let _ = command_tx.send(InternalRefresh::Card { index });
+        }
+    }
+}
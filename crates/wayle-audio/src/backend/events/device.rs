@@ -31,6 +31,10 @@ pub(crate) async fn handle_change(
 
             if removed_device.is_some() {
                 let _ = events_tx.send(AudioEvent::DeviceRemoved(device_key));
+                // If this was the default, the server has already fallen back
+                // to another device. Re-read it now rather than waiting on the
+                // server change event, so the default doesn't stay stale.
+                let _ = command_tx.send(InternalRefresh::ServerInfo);
             }
         }
         Operation::New | Operation::Changed => {
@@ -41,3 +45,106 @@ pub(crate) async fn handle_change(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, RwLock},
+    };
+
+    use libpulse_binding::time::MicroSeconds;
+    use tokio::sync::{broadcast, mpsc};
+
+    use super::*;
+    use crate::{
+        types::{
+            device::{Device, DeviceInfo, DeviceState, SinkInfo},
+            format::{ChannelMap, SampleFormat, SampleSpec},
+        },
+        volume::types::Volume,
+    };
+
+    fn sink(index: u32) -> Device {
+        Device::Sink(SinkInfo {
+            device: DeviceInfo {
+                index,
+                name: format!("sink-{index}"),
+                description: format!("Sink {index}"),
+                card_index: None,
+                owner_module: None,
+                driver: String::from("test"),
+                state: DeviceState::Running,
+                volume: Volume::mono(1.0),
+                base_volume: Volume::mono(1.0),
+                n_volume_steps: 0,
+                muted: false,
+                properties: HashMap::new(),
+                ports: vec![],
+                active_port: None,
+                formats: vec![],
+                sample_spec: SampleSpec {
+                    format: SampleFormat::S16LE,
+                    rate: 44100,
+                    channels: 2,
+                },
+                channel_map: ChannelMap {
+                    channels: 2,
+                    positions: vec![],
+                },
+                latency: MicroSeconds(0),
+                configured_latency: MicroSeconds(0),
+                flags: 0,
+            },
+            monitor_source: 0,
+            monitor_source_name: String::new(),
+        })
+    }
+
+    #[tokio::test]
+    async fn removing_tracked_sink_rereads_server_defaults() {
+        let key = DeviceKey::new(1, DeviceType::Output);
+        let devices: DeviceStore = Arc::new(RwLock::new(HashMap::from([(key, sink(1))])));
+        let (events_tx, mut events_rx) = broadcast::channel(8);
+        let (command_tx, mut command_rx) = mpsc::unbounded_channel();
+
+        handle_change(
+            Facility::Sink,
+            Operation::Removed,
+            1,
+            &devices,
+            &events_tx,
+            &command_tx,
+        )
+        .await;
+
+        assert!(matches!(
+            events_rx.try_recv(),
+            Ok(AudioEvent::DeviceRemoved(removed)) if removed == key
+        ));
+        assert!(matches!(
+            command_rx.try_recv(),
+            Ok(InternalRefresh::ServerInfo)
+        ));
+    }
+
+    #[tokio::test]
+    async fn removing_unknown_sink_does_nothing() {
+        let devices: DeviceStore = Arc::new(RwLock::new(HashMap::new()));
+        let (events_tx, mut events_rx) = broadcast::channel(8);
+        let (command_tx, mut command_rx) = mpsc::unbounded_channel();
+
+        handle_change(
+            Facility::Sink,
+            Operation::Removed,
+            1,
+            &devices,
+            &events_tx,
+            &command_tx,
+        )
+        .await;
+
+        assert!(events_rx.try_recv().is_err());
+        assert!(command_rx.try_recv().is_err());
+    }
+}
@@ -7,10 +7,11 @@ use tokio_util::sync::CancellationToken;
 use tracing::info;
 
 use super::types::{
-    ChangeNotification, DeviceStore, EventSender, InternalCommandSender, StreamStore,
+    CardStore, ChangeNotification, DeviceStore, EventSender, InternalCommandSender, StreamStore,
 };
 use crate::error::Error;
 
+pub(crate) mod card;
 pub(crate) mod device;
 pub(crate) mod server;
 pub(crate) mod stream;
@@ -21,6 +22,7 @@ pub(crate) fn start_event_processor(
     context: &mut Context,
     devices: DeviceStore,
     streams: StreamStore,
+    cards: CardStore,
     events_tx: EventSender,
     internal_command_tx: InternalCommandSender,
     cancellation_token: CancellationToken,
@@ -41,6 +43,7 @@ pub(crate) fn start_event_processor(
                         notification,
                         &devices,
                         &streams,
+                        &cards,
                         &events_tx,
                         &internal_command_tx
                     )
@@ -65,6 +68,7 @@ fn setup_subscription(
         | InterestMaskSet::SOURCE
         | InterestMaskSet::SINK_INPUT
         | InterestMaskSet::SOURCE_OUTPUT
+        | InterestMaskSet::CARD
         | InterestMaskSet::SERVER;
 
     let subscription_callback: SubscriptionCallback =
@@ -84,6 +88,7 @@ fn setup_subscription(
                     operation,
                     index,
                 },
+                Facility::Card => ChangeNotification::Card { operation, index },
                 Facility::Server => ChangeNotification::Server { operation },
                 _ => return,
             };
@@ -103,6 +108,7 @@ async fn process_change_notification(
     notification: ChangeNotification,
     devices: &DeviceStore,
     streams: &StreamStore,
+    cards: &CardStore,
     events_tx: &EventSender,
     command_tx: &InternalCommandSender,
 ) {
@@ -121,6 +127,9 @@ async fn process_change_notification(
         } => {
             stream::handle_change(facility, operation, index, streams, events_tx, command_tx).await;
         }
+        ChangeNotification::Card { operation, index } => {
+            card::handle_change(operation, index, cards, events_tx, command_tx).await;
+        }
         ChangeNotification::Server { operation } => {
             server::handle_change(operation, command_tx).await;
         }
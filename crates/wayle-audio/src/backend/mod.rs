@@ -22,8 +22,8 @@ use tokio::{
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 use types::{
-    CommandReceiver, DefaultDevice, DeviceStore, EventSender, ExternalCommand, InternalRefresh,
-    StreamStore,
+    CardStore, CommandReceiver, DefaultDevice, DeviceStore, EventSender, ExternalCommand,
+    InternalRefresh, StreamStore,
 };
 
 use crate::{Error, tokio_mainloop::TokioMain};
@@ -31,6 +31,7 @@ use crate::{Error, tokio_mainloop::TokioMain};
 struct BackendState {
     devices: DeviceStore,
     streams: StreamStore,
+    cards: CardStore,
     default_input: DefaultDevice,
     default_output: DefaultDevice,
 }
@@ -40,6 +41,7 @@ impl BackendState {
         Self {
             devices: Arc::new(RwLock::new(HashMap::new())),
             streams: Arc::new(RwLock::new(HashMap::new())),
+            cards: Arc::new(RwLock::new(HashMap::new())),
             default_input: Arc::new(RwLock::new(None)),
             default_output: Arc::new(RwLock::new(None)),
         }
@@ -119,14 +121,16 @@ impl PulseBackend {
             &mut self.context,
             self.state.devices.clone(),
             self.state.streams.clone(),
+            self.state.cards.clone(),
             event_tx,
             internal_command_tx.clone(),
             cancellation_token,
         )?;
 
-        info!("Triggering initial device and stream discovery");
+        info!("Triggering initial device, stream and card discovery");
         let _ = internal_command_tx.send(InternalRefresh::Devices);
         let _ = internal_command_tx.send(InternalRefresh::Streams);
+        let _ = internal_command_tx.send(InternalRefresh::Cards);
         let _ = internal_command_tx.send(InternalRefresh::ServerInfo);
 
         Ok((internal_command_tx, internal_command_rx))
@@ -140,6 +144,7 @@ impl PulseBackend {
     ) -> JoinHandle<()> {
         let devices = self.state.devices.clone();
         let streams = self.state.streams.clone();
+        let cards = self.state.cards.clone();
 
         spawn(async move {
             loop {
@@ -154,7 +159,7 @@ impl PulseBackend {
                             return;
                         };
 
-                        Self::handle_command(command, &devices, &streams, &external_tx);
+                        Self::handle_command(command, &devices, &streams, &cards, &external_tx);
                     }
                 }
             }
@@ -166,6 +171,7 @@ impl PulseBackend {
         command: Command,
         devices: &DeviceStore,
         streams: &StreamStore,
+        cards: &CardStore,
         external_tx: &mpsc::UnboundedSender<ExternalCommand>,
     ) {
         match command {
@@ -278,6 +284,28 @@ impl PulseBackend {
                 let _ = external_tx.send(ExternalCommand::SetPort { device_key, port });
                 let _ = responder.send(Ok(()));
             }
+            Command::GetCard { index, responder } => {
+                let result = if let Ok(cards_guard) = cards.read() {
+                    cards_guard
+                        .get(&index)
+                        .cloned()
+                        .ok_or(Error::CardNotFound { index })
+                } else {
+                    Err(Error::LockPoisoned)
+                };
+                let _ = responder.send(result);
+            }
+            Command::SetCardProfile {
+                card_index,
+                profile,
+                responder,
+            } => {
+                let _ = external_tx.send(ExternalCommand::SetCardProfile {
+                    card_index,
+                    profile,
+                });
+                let _ = responder.send(Ok(()));
+            }
         }
     }
 
@@ -322,6 +350,7 @@ impl PulseBackend {
                         cmd,
                         &self.state.devices,
                         &self.state.streams,
+                        &self.state.cards,
                         &event_tx,
                         &self.state.default_input,
                         &self.state.default_output,
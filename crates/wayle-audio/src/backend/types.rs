@@ -13,6 +13,7 @@ use super::commands::Command;
 use crate::{
     events::AudioEvent,
     types::{
+        card::CardInfo,
         device::{Device, DeviceKey},
         stream::{StreamInfo, StreamKey},
     },
@@ -22,6 +23,8 @@ pub(crate) type DeviceStore = Arc<RwLock<HashMap<DeviceKey, Device>>>;
 
 pub(crate) type StreamStore = Arc<RwLock<HashMap<StreamKey, StreamInfo>>>;
 
+pub(crate) type CardStore = Arc<RwLock<HashMap<u32, CardInfo>>>;
+
 pub(crate) type DefaultDevice = Arc<RwLock<Option<Device>>>;
 
 pub(crate) type EventSender = broadcast::Sender<AudioEvent>;
@@ -44,6 +47,10 @@ pub(crate) enum ChangeNotification {
         operation: Operation,
         index: u32,
     },
+    Card {
+        operation: Operation,
+        index: u32,
+    },
     Server {
         operation: Operation,
     },
@@ -53,6 +60,7 @@ pub(crate) enum ChangeNotification {
 pub(crate) enum InternalRefresh {
     Devices,
     Streams,
+    Cards,
     ServerInfo,
     Device {
         device_key: DeviceKey,
@@ -62,6 +70,9 @@ pub(crate) enum InternalRefresh {
         stream_key: StreamKey,
         facility: Facility,
     },
+    Card {
+        index: u32,
+    },
 }
 
 #[derive(Debug)]
@@ -96,4 +107,8 @@ pub(crate) enum ExternalCommand {
         device_key: DeviceKey,
         port: String,
     },
+    SetCardProfile {
+        card_index: u32,
+        profile: String,
+    },
 }
@@ -13,15 +13,26 @@ use crate::{
     backend::PulseBackend,
     dbus::{AudioDaemon, SERVICE_NAME, SERVICE_PATH},
     error::Error,
+    output_policy::OutputPolicy,
     service::AudioService,
 };
 
+/// Volume ceiling for stepping when none is configured, so hotkeys never
+/// boost past 100% unless asked to.
+const DEFAULT_MAX_VOLUME_PERCENT: f64 = 100.0;
+
+/// Highest ceiling accepted by [`AudioServiceBuilder::max_volume_percent`],
+/// matching the limit of [`Volume`](crate::volume::types::Volume).
+const MAX_VOLUME_PERCENT_LIMIT: f64 = 400.0;
+
 /// Builder for configuring and creating an AudioService instance.
 ///
 /// Allows optional D-Bus daemon registration for external control.
 #[derive(Default)]
 pub struct AudioServiceBuilder {
     register_daemon: bool,
+    output_policy: OutputPolicy,
+    max_volume_percent: Option<f64>,
 }
 
 impl AudioServiceBuilder {
@@ -40,6 +51,36 @@ impl AudioServiceBuilder {
         self
     }
 
+    /// Remembers each output's volume when it disappears and restores it
+    /// when the same device comes back, e.g. a Bluetooth headset
+    /// reconnecting. Disabled by default.
+    pub fn restore_per_device_volume(mut self, enabled: bool) -> Self {
+        self.output_policy.restore_per_device_volume = enabled;
+        self
+    }
+
+    /// Mutes the built-in speakers when they become the output because the
+    /// previous one was unplugged, so audio doesn't suddenly play out loud.
+    /// Covers both a separate sink going away and a headphone jack port
+    /// switching back to speakers. Disabled by default.
+    pub fn mute_speakers_on_unplug(mut self, enabled: bool) -> Self {
+        self.output_policy.mute_speakers_on_unplug = enabled;
+        self
+    }
+
+    /// Caps how far [`OutputDevice::adjust_volume`] and
+    /// [`InputDevice::adjust_volume`] raise the volume, in percent. Values
+    /// above 100 allow boosting, e.g. `150.0`. Defaults to 100%.
+    ///
+    /// The D-Bus daemon clamps set and adjust requests to the same ceiling.
+    ///
+    /// [`OutputDevice::adjust_volume`]: crate::core::device::output::OutputDevice::adjust_volume
+    /// [`InputDevice::adjust_volume`]: crate::core::device::input::InputDevice::adjust_volume
+    pub fn max_volume_percent(mut self, percent: f64) -> Self {
+        self.max_volume_percent = Some(percent.clamp(0.0, MAX_VOLUME_PERCENT_LIMIT));
+        self
+    }
+
     /// Builds and initializes the AudioService.
     ///
     /// This will establish a PulseAudio connection and start monitoring
@@ -59,6 +100,7 @@ impl AudioServiceBuilder {
         let default_input = Property::new(None);
         let playback_streams = Property::new(Vec::new());
         let recording_streams = Property::new(Vec::new());
+        let cards = Property::new(Vec::new());
 
         let backend_handle = PulseBackend::start(
             command_rx,
@@ -81,12 +123,17 @@ impl AudioServiceBuilder {
             cancellation_token,
             backend_handle: Some(backend_handle),
             _connection: connection.clone(),
+            output_policy: self.output_policy,
+            max_volume_percent: self
+                .max_volume_percent
+                .unwrap_or(DEFAULT_MAX_VOLUME_PERCENT),
             output_devices,
             input_devices,
             default_output,
             default_input,
             playback_streams,
             recording_streams,
+            cards,
         });
 
         service.start_monitoring().await?;
@@ -0,0 +1,30 @@
+use tokio::sync::oneshot;
+use tracing::instrument;
+
+use crate::{
+    backend::{commands::Command, types::CommandSender},
+    error::Error,
+};
+
+pub(crate) struct CardController;
+
+impl CardController {
+    #[instrument(skip(command_tx), fields(card = card_index, profile = %profile), err)]
+    pub async fn set_profile(
+        command_tx: &CommandSender,
+        card_index: u32,
+        profile: String,
+    ) -> Result<(), Error> {
+        let (tx, rx) = oneshot::channel();
+
+        command_tx
+            .send(Command::SetCardProfile {
+                card_index,
+                profile,
+                responder: tx,
+            })
+            .map_err(|_| Error::CommandChannelDisconnected)?;
+
+        rx.await.map_err(|_| Error::CommandChannelDisconnected)?
+    }
+}
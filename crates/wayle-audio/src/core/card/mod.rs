@@ -0,0 +1,166 @@
+pub(crate) mod controls;
+pub(crate) mod monitoring;
+pub(crate) mod types;
+
+use std::{collections::HashMap, sync::Arc};
+
+use controls::CardController;
+use derive_more::Debug;
+use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
+pub(crate) use types::{CardParams, LiveCardParams};
+use wayle_core::Property;
+use wayle_traits::{ModelMonitoring, Reactive};
+
+use crate::{
+    backend::{
+        commands::Command,
+        types::{CommandSender, EventSender},
+    },
+    error::Error,
+    types::card::{CardInfo, CardProfile},
+};
+
+/// PulseAudio sound card with its profiles.
+///
+/// A profile decides which sinks and sources the card exposes, e.g. HDMI vs
+/// analog output, or A2DP playback vs a headset profile with a microphone on
+/// Bluetooth. Devices point back to their card through `card_index`.
+///
+/// Instances from [`AudioService::cards`] are live: `active_profile` and each
+/// profile's `available` flag update when PulseAudio state changes.
+/// Instances from [`AudioService::card`] are snapshots that don't update.
+///
+/// [`AudioService::cards`]: crate::AudioService::cards
+/// [`AudioService::card`]: crate::AudioService::card
+#[derive(Clone, Debug)]
+pub struct Card {
+    /// Command sender for backend operations
+    #[debug(skip)]
+    command_tx: CommandSender,
+
+    /// Event sender for monitoring (only for live instances)
+    #[debug(skip)]
+    event_tx: Option<EventSender>,
+
+    /// Cancellation token for monitoring (only for live instances)
+    #[debug(skip)]
+    pub(crate) cancellation_token: Option<CancellationToken>,
+
+    /// Card index
+    pub index: u32,
+
+    /// Card name (internal identifier)
+    pub name: Property<String>,
+
+    /// Human-readable description
+    pub description: Property<String>,
+
+    /// Index of the owning module
+    pub owner_module: Property<Option<u32>>,
+
+    /// Driver name
+    pub driver: Property<String>,
+
+    /// Card properties from PulseAudio
+    pub properties: Property<HashMap<String, String>>,
+
+    /// Available profiles
+    pub profiles: Property<Vec<CardProfile>>,
+
+    /// Currently active profile
+    pub active_profile: Property<Option<String>>,
+}
+
+impl PartialEq for Card {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Reactive for Card {
+    type Context<'a> = CardParams<'a>;
+    type LiveContext<'a> = LiveCardParams<'a>;
+    type Error = Error;
+
+    async fn get(params: Self::Context<'_>) -> Result<Self, Self::Error> {
+        let info = fetch_card(params.command_tx, params.index).await?;
+
+        Ok(Self::from_info(
+            &info,
+            params.command_tx.clone(),
+            None,
+            None,
+        ))
+    }
+
+    async fn get_live(params: Self::LiveContext<'_>) -> Result<Arc<Self>, Self::Error> {
+        let info = fetch_card(params.command_tx, params.index).await?;
+
+        let card = Arc::new(Self::from_info(
+            &info,
+            params.command_tx.clone(),
+            Some(params.event_tx.clone()),
+            Some(params.cancellation_token.child_token()),
+        ));
+
+        card.clone().start_monitoring().await?;
+
+        Ok(card)
+    }
+}
+
+async fn fetch_card(command_tx: &CommandSender, index: u32) -> Result<CardInfo, Error> {
+    let (tx, rx) = oneshot::channel();
+    command_tx
+        .send(Command::GetCard {
+            index,
+            responder: tx,
+        })
+        .map_err(|_| Error::CommandChannelDisconnected)?;
+
+    rx.await.map_err(|_| Error::CommandChannelDisconnected)?
+}
+
+impl Card {
+    pub(crate) fn from_info(
+        info: &CardInfo,
+        command_tx: CommandSender,
+        event_tx: Option<EventSender>,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Self {
+        Self {
+            command_tx,
+            event_tx,
+            cancellation_token,
+            index: info.index,
+            name: Property::new(info.name.clone()),
+            description: Property::new(info.description.clone()),
+            owner_module: Property::new(info.owner_module),
+            driver: Property::new(info.driver.clone()),
+            properties: Property::new(info.properties.clone()),
+            profiles: Property::new(info.profiles.clone()),
+            active_profile: Property::new(info.active_profile.clone()),
+        }
+    }
+
+    pub(crate) fn update_from_info(&self, info: &CardInfo) {
+        self.name.set(info.name.clone());
+        self.description.set(info.description.clone());
+        self.owner_module.set(info.owner_module);
+        self.driver.set(info.driver.clone());
+        self.properties.set(info.properties.clone());
+        self.profiles.set(info.profiles.clone());
+        self.active_profile.set(info.active_profile.clone());
+    }
+
+    /// Switch this card to `profile`, one of the names in `profiles`.
+    ///
+    /// `active_profile` updates once PulseAudio applies the switch.
+    ///
+    /// # Errors
+    /// Returns error if backend communication fails or card operation fails.
+    pub async fn set_profile(&self, profile: &str) -> Result<(), Error> {
+        CardController::set_profile(&self.command_tx, self.index, profile.to_owned()).await
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use tracing::debug;
+use wayle_traits::ModelMonitoring;
+
+use crate::{
+    core::card::Card,
+    error::{Error, MissingMonitoringComponent},
+    events::AudioEvent,
+};
+
+impl ModelMonitoring for Card {
+    type Error = Error;
+
+    async fn start_monitoring(self: Arc<Self>) -> Result<(), Self::Error> {
+        let Some(ref cancellation_token) = self.cancellation_token else {
+            return Err(Error::MonitoringNotInitialized(
+                MissingMonitoringComponent::CancellationToken,
+            ));
+        };
+
+        let Some(ref event_tx) = self.event_tx else {
+            return Err(Error::MonitoringNotInitialized(
+                MissingMonitoringComponent::EventSender,
+            ));
+        };
+
+        let weak_card = Arc::downgrade(&self);
+        let index = self.index;
+        let cancellation_token = cancellation_token.clone();
+        let mut event_rx = event_tx.subscribe();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => {
+                        debug!("Card monitor cancelled for {}", index);
+                        return;
+                    }
+                    Ok(event) = event_rx.recv() => {
+                        let Some(card) = weak_card.upgrade() else {
+                            return;
+                        };
+
+                        match event {
+                            AudioEvent::CardChanged(info) if info.index == index => {
+                                card.update_from_info(&info);
+                            }
+                            AudioEvent::CardRemoved(removed) if removed == index => {
+                                break;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::backend::types::{CommandSender, EventSender};
+
+#[doc(hidden)]
+#[allow(private_interfaces)]
+pub struct CardParams<'a> {
+    pub command_tx: &'a CommandSender,
+    pub index: u32,
+}
+
+#[doc(hidden)]
+#[allow(private_interfaces)]
+pub struct LiveCardParams<'a> {
+    pub command_tx: &'a CommandSender,
+    pub event_tx: &'a EventSender,
+    pub index: u32,
+    pub cancellation_token: &'a CancellationToken,
+}
@@ -35,6 +35,8 @@ use crate::{
 ///
 /// - [`set_volume`](Self::set_volume) - Adjust input gain
 /// - [`set_mute`](Self::set_mute) - Mute or unmute
+/// - [`adjust_volume`](Self::adjust_volume) - Step the volume up or down, e.g. from hotkeys
+/// - [`toggle_mute`](Self::toggle_mute) - Flip the mute state
 /// - [`set_port`](Self::set_port) - Switch input port
 /// - [`set_as_default`](Self::set_as_default) - Make this the default input
 ///
@@ -54,6 +56,9 @@ pub struct InputDevice {
     #[debug(skip)]
     cancellation_token: Option<CancellationToken>,
 
+    /// Ceiling in percent for [`adjust_volume`](Self::adjust_volume)
+    max_volume: f64,
+
     /// Device key for identification
     pub key: DeviceKey,
 
@@ -153,6 +158,7 @@ impl Reactive for InputDevice {
                 params.command_tx.clone(),
                 None,
                 None,
+                params.max_volume,
             )),
             Device::Sink(_) => Err(Error::DeviceNotFound {
                 index: params.device_key.index,
@@ -179,6 +185,7 @@ impl Reactive for InputDevice {
                 params.command_tx.clone(),
                 Some(params.event_tx.clone()),
                 Some(params.cancellation_token.child_token()),
+                params.max_volume,
             )),
             Device::Sink(_) => {
                 return Err(Error::DeviceNotFound {
@@ -200,11 +207,13 @@ impl InputDevice {
         command_tx: CommandSender,
         event_tx: Option<EventSender>,
         cancellation_token: Option<CancellationToken>,
+        max_volume: f64,
     ) -> Self {
         Self {
             command_tx,
             event_tx,
             cancellation_token,
+            max_volume,
             key: source.key(),
             name: Property::new(source.device.name.clone()),
             description: Property::new(source.device.description.clone()),
@@ -274,6 +283,33 @@ impl InputDevice {
         InputDeviceController::set_mute(&self.command_tx, self.key, muted).await
     }
 
+    /// Raises or lowers the volume by `delta` percentage points, keeping the
+    /// balance between channels.
+    ///
+    /// Raising stops at the service's maximum volume (see
+    /// [`AudioServiceBuilder::max_volume_percent`]) and lowering stops at 0%.
+    /// Returns the volume that was applied.
+    ///
+    /// # Errors
+    /// Returns error if backend communication fails or device operation fails.
+    ///
+    /// [`AudioServiceBuilder::max_volume_percent`]: crate::AudioServiceBuilder::max_volume_percent
+    pub async fn adjust_volume(&self, delta: f64) -> Result<Volume, Error> {
+        let volume = self.volume.get().stepped(delta, self.max_volume);
+        self.set_volume(volume.clone()).await?;
+        Ok(volume)
+    }
+
+    /// Flips the mute state of this input device. Returns the new state.
+    ///
+    /// # Errors
+    /// Returns error if backend communication fails or device operation fails.
+    pub async fn toggle_mute(&self) -> Result<bool, Error> {
+        let muted = !self.muted.get();
+        self.set_mute(muted).await?;
+        Ok(muted)
+    }
+
     /// Set the active port for this input device.
     ///
     /// # Errors
@@ -10,6 +10,7 @@ use crate::{
 pub struct OutputDeviceParams<'a> {
     pub command_tx: &'a CommandSender,
     pub device_key: DeviceKey,
+    pub max_volume: f64,
 }
 
 #[doc(hidden)]
@@ -19,4 +20,5 @@ pub struct LiveOutputDeviceParams<'a> {
     pub event_tx: &'a EventSender,
     pub device_key: DeviceKey,
     pub cancellation_token: &'a CancellationToken,
+    pub max_volume: f64,
 }
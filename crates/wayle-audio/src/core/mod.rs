@@ -1,3 +1,5 @@
+/// Sound card implementations
+pub mod card;
 /// Audio device implementations
 pub mod device;
 /// Audio stream implementations
@@ -20,8 +20,9 @@ pub(crate) struct AudioDaemon {
 impl AudioDaemon {
     /// Sets the volume for the default output device.
     ///
-    /// Volume is specified as a percentage (0.0 to 100.0 for normal range,
-    /// up to 400.0 for amplification). Returns the volume that was set.
+    /// Volume is specified as a percentage and clamped to the service's
+    /// maximum volume (100% unless configured otherwise). Returns the volume
+    /// that was set.
     #[instrument(skip(self), fields(volume = %volume))]
     pub async fn set_output_volume(&self, volume: f64) -> fdo::Result<f64> {
         let device = self
@@ -30,7 +31,7 @@ impl AudioDaemon {
             .get()
             .ok_or_else(|| fdo::Error::Failed("No default output device".to_string()))?;
 
-        let clamped = volume.clamp(0.0, 100.0);
+        let clamped = volume.clamp(0.0, self.service.max_volume_percent);
         let channels = device.volume.get().channels();
         let vol = Volume::from_percentage(clamped, channels);
 
@@ -45,7 +46,8 @@ impl AudioDaemon {
     /// Adjusts the output volume by a relative delta.
     ///
     /// Delta is specified as percentage points (e.g., +5.0 or -10.0).
-    /// Result is clamped to 0-100% range. Returns the new volume.
+    /// Raising stops at the service's maximum volume (100% unless
+    /// configured otherwise). Returns the new volume.
     #[instrument(skip(self), fields(delta = %delta))]
     pub async fn adjust_output_volume(&self, delta: f64) -> fdo::Result<f64> {
         let device = self
@@ -54,18 +56,12 @@ impl AudioDaemon {
             .get()
             .ok_or_else(|| fdo::Error::Failed("No default output device".to_string()))?;
 
-        let current = device.volume.get();
-        let current_pct = current.average() * 100.0;
-        let new_pct = (current_pct + delta).clamp(0.0, 100.0);
-
-        let vol = Volume::from_percentage(new_pct, current.channels());
-
-        device
-            .set_volume(vol)
+        let volume = device
+            .adjust_volume(delta)
             .await
             .map_err(|e| fdo::Error::Failed(e.to_string()))?;
 
-        Ok(new_pct)
+        Ok(volume.average_percentage())
     }
 
     /// Sets the mute state for the default output device.
@@ -92,13 +88,10 @@ impl AudioDaemon {
             .get()
             .ok_or_else(|| fdo::Error::Failed("No default output device".to_string()))?;
 
-        let new_state = !device.muted.get();
         device
-            .set_mute(new_state)
+            .toggle_mute()
             .await
-            .map_err(|e| fdo::Error::Failed(e.to_string()))?;
-
-        Ok(new_state)
+            .map_err(|e| fdo::Error::Failed(e.to_string()))
     }
 
     /// Sets the default output device (sink) by index.
@@ -133,7 +126,9 @@ impl AudioDaemon {
 
     /// Sets the volume for the default input device.
     ///
-    /// Volume is specified as a percentage (0.0 to 100.0). Returns the volume that was set.
+    /// Volume is specified as a percentage and clamped to the service's
+    /// maximum volume (100% unless configured otherwise). Returns the volume
+    /// that was set.
     #[instrument(skip(self), fields(volume = %volume))]
     pub async fn set_input_volume(&self, volume: f64) -> fdo::Result<f64> {
         let device = self
@@ -142,7 +137,7 @@ impl AudioDaemon {
             .get()
             .ok_or_else(|| fdo::Error::Failed("No default input device".to_string()))?;
 
-        let clamped = volume.clamp(0.0, 100.0);
+        let clamped = volume.clamp(0.0, self.service.max_volume_percent);
         let channels = device.volume.get().channels();
         let vol = Volume::from_percentage(clamped, channels);
 
@@ -157,7 +152,8 @@ impl AudioDaemon {
     /// Adjusts the input volume by a relative delta.
     ///
     /// Delta is specified as percentage points (e.g., +5.0 or -10.0).
-    /// Result is clamped to 0-100% range. Returns the new volume.
+    /// Raising stops at the service's maximum volume (100% unless
+    /// configured otherwise). Returns the new volume.
     #[instrument(skip(self), fields(delta = %delta))]
     pub async fn adjust_input_volume(&self, delta: f64) -> fdo::Result<f64> {
         let device = self
@@ -166,18 +162,12 @@ impl AudioDaemon {
             .get()
             .ok_or_else(|| fdo::Error::Failed("No default input device".to_string()))?;
 
-        let current = device.volume.get();
-        let current_pct = current.average() * 100.0;
-        let new_pct = (current_pct + delta).clamp(0.0, 100.0);
-
-        let vol = Volume::from_percentage(new_pct, current.channels());
-
-        device
-            .set_volume(vol)
+        let volume = device
+            .adjust_volume(delta)
             .await
             .map_err(|e| fdo::Error::Failed(e.to_string()))?;
 
-        Ok(new_pct)
+        Ok(volume.average_percentage())
     }
 
     /// Sets the mute state for the default input device.
@@ -204,13 +194,10 @@ impl AudioDaemon {
             .get()
             .ok_or_else(|| fdo::Error::Failed("No default input device".to_string()))?;
 
-        let new_state = !device.muted.get();
         device
-            .set_mute(new_state)
+            .toggle_mute()
             .await
-            .map_err(|e| fdo::Error::Failed(e.to_string()))?;
-
-        Ok(new_state)
+            .map_err(|e| fdo::Error::Failed(e.to_string()))
     }
 
     /// Lists all output devices (sinks).
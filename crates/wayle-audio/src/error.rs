@@ -53,6 +53,13 @@ pub enum Error {
         stream_type: StreamType,
     },
 
+    /// Sound card not found.
+    #[error("card {index} not found")]
+    CardNotFound {
+        /// Card index that was not found.
+        index: u32,
+    },
+
     /// Command channel disconnected.
     #[error("command channel disconnected")]
     CommandChannelDisconnected,
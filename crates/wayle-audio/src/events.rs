@@ -1,4 +1,5 @@
 use super::types::{
+    card::CardInfo,
     device::{Device, DeviceKey},
     stream::{StreamInfo, StreamKey},
 };
@@ -11,6 +12,9 @@ pub(crate) enum AudioEvent {
     StreamAdded(StreamInfo),
     StreamChanged(StreamInfo),
     StreamRemoved(StreamKey),
+    CardAdded(CardInfo),
+    CardChanged(CardInfo),
+    CardRemoved(u32),
     DefaultInputChanged(Option<Device>),
     DefaultOutputChanged(Option<Device>),
 }
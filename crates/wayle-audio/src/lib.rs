@@ -8,8 +8,8 @@
 //!
 //! # Reactive Properties
 //!
-//! Every field on [`AudioService`], [`OutputDevice`], [`InputDevice`], and
-//! [`AudioStream`] is a [`Property<T>`] with two access patterns:
+//! Every field on [`AudioService`], [`OutputDevice`], [`InputDevice`],
+//! [`AudioStream`], and [`Card`] is a [`Property<T>`] with two access patterns:
 //!
 //! - **Snapshot**: Call `.get()` for the current value
 //! - **Stream**: Call `.watch()` for a `Stream<Item = T>` that yields on changes
@@ -84,6 +84,10 @@
 //!     // Set volume (0.0 to 1.0 per channel)
 //!     device.set_volume(Volume::stereo(0.5, 0.5)).await?;
 //!
+//!     // Step by percentage points, e.g. from a volume hotkey
+//!     device.adjust_volume(5.0).await?;
+//!     device.toggle_mute().await?;
+//!
 //!     // Make this device the default
 //!     device.set_as_default().await?;
 //! }
@@ -91,11 +95,53 @@
 //! # }
 //! ```
 //!
+//! `set_as_default()` only routes streams started afterwards. To also move
+//! audio that is already playing, go through the service:
+//!
+//! ```rust,no_run
+//! # use wayle_audio::AudioService;
+//! # async fn example() -> Result<(), wayle_audio::Error> {
+//! # let audio = AudioService::new().await?;
+//! if let Some(headphones) = audio.output_devices.get().last() {
+//!     audio.set_default_output(headphones, true).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Switching Card Profiles
+//!
+//! A [`Card`] profile decides which devices the card exposes. Bluetooth
+//! headsets only offer a microphone in their headset profile, at the cost of
+//! playback quality, so switch to it while recording and back afterwards:
+//!
+//! ```rust,no_run
+//! # use wayle_audio::AudioService;
+//! # async fn example() -> Result<(), wayle_audio::Error> {
+//! # let audio = AudioService::new().await?;
+//! for card in audio.cards.get() {
+//!     let headset = card
+//!         .profiles
+//!         .get()
+//!         .into_iter()
+//!         .find(|profile| profile.available && profile.name.starts_with("headset"));
+//!
+//!     if let Some(profile) = headset {
+//!         card.set_profile(&profile.name).await?;
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Configuration
 //!
 //! | Method | Effect |
 //! |--------|--------|
 //! | `with_daemon()` | Control audio from scripts or other processes |
+//! | `restore_per_device_volume(bool)` | Give each output back its last volume when it reconnects |
+//! | `mute_speakers_on_unplug(bool)` | Mute built-in speakers when headphones are unplugged |
+//! | `max_volume_percent(f64)` | Ceiling for `adjust_volume()` steps, e.g. `150.0` to allow boost |
 //!
 //! ```rust,no_run
 //! use wayle_audio::AudioService;
@@ -131,6 +177,7 @@
 //! | [`default_input`] | `Option<Arc<InputDevice>>` | Current default source |
 //! | [`playback_streams`] | `Vec<Arc<AudioStream>>` | Active playback (apps playing audio) |
 //! | [`recording_streams`] | `Vec<Arc<AudioStream>>` | Active recording (apps capturing audio) |
+//! | [`cards`] | `Vec<Arc<Card>>` | Sound cards and their profiles |
 //!
 //! [`output_devices`]: AudioService::output_devices
 //! [`input_devices`]: AudioService::input_devices
@@ -138,11 +185,13 @@
 //! [`default_input`]: AudioService::default_input
 //! [`playback_streams`]: AudioService::playback_streams
 //! [`recording_streams`]: AudioService::recording_streams
+//! [`cards`]: AudioService::cards
 //! [`Property`]: wayle_core::Property
 //! [`Property<T>`]: wayle_core::Property
 //! [`OutputDevice`]: core::device::output::OutputDevice
 //! [`InputDevice`]: core::device::input::InputDevice
 //! [`AudioStream`]: core::stream::AudioStream
+//! [`Card`]: core::card::Card
 
 #![cfg_attr(test, allow(clippy::panic))]
 
@@ -155,6 +204,7 @@ pub mod dbus;
 mod error;
 mod events;
 mod monitoring;
+mod output_policy;
 mod service;
 mod tokio_mainloop;
 /// Types for the audio service
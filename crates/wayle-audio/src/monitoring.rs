@@ -1,16 +1,22 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Instant};
 
 use tracing::info;
 use wayle_core::Property;
 use wayle_traits::{ModelMonitoring, ServiceMonitoring};
 
 use crate::{
+    backend::types::CommandSender,
     core::{
-        device::{input::InputDevice, output::OutputDevice},
+        card::Card,
+        device::{
+            input::InputDevice,
+            output::{OutputDevice, controls::OutputDeviceController},
+        },
         stream::AudioStream,
     },
     error::Error,
     events::AudioEvent,
+    output_policy::{OutputAction, OutputTracker, is_speaker},
     service::AudioService,
     types::{
         device::{Device, DeviceKey},
@@ -27,6 +33,8 @@ impl ServiceMonitoring for AudioService {
         let mut output_devs: HashMap<DeviceKey, Arc<OutputDevice>> = HashMap::new();
         let mut input_devs: HashMap<DeviceKey, Arc<InputDevice>> = HashMap::new();
         let mut streams: HashMap<StreamKey, Arc<AudioStream>> = HashMap::new();
+        let mut card_map: HashMap<u32, Arc<Card>> = HashMap::new();
+        let mut outputs = OutputTracker::new(self.output_policy);
 
         let command_tx = self.command_tx.clone();
         let event_tx = self.event_tx.clone();
@@ -34,9 +42,11 @@ impl ServiceMonitoring for AudioService {
         let input_devices = self.input_devices.clone();
         let playback_streams = self.playback_streams.clone();
         let recording_streams = self.recording_streams.clone();
+        let cards = self.cards.clone();
         let default_input = self.default_input.clone();
         let default_output = self.default_output.clone();
         let cancellation_token = self.cancellation_token.clone();
+        let max_volume = self.max_volume_percent;
 
         tokio::spawn(async move {
             loop {
@@ -56,10 +66,15 @@ impl ServiceMonitoring for AudioService {
                                             command_tx.clone(),
                                             Some(event_tx.clone()),
                                             Some(cancellation_token.child_token()),
+                                            max_volume,
                                         ));
                                         output.clone().start_monitoring().await.ok();
                                         output_devs.insert(key, output);
                                         output_devices.set(output_devs.values().cloned().collect());
+
+                                        let action =
+                                            outputs.added(key, &sink.device.name, &sink.device.volume);
+                                        apply_output_action(action, &command_tx);
                                     }
                                     Device::Source(source) => {
                                         let key = source.key();
@@ -68,6 +83,7 @@ impl ServiceMonitoring for AudioService {
                                             command_tx.clone(),
                                             Some(event_tx.clone()),
                                             Some(cancellation_token.child_token()),
+                                            max_volume,
                                         ));
                                         input.clone().start_monitoring().await.ok();
                                         input_devs.insert(key, input);
@@ -81,17 +97,36 @@ impl ServiceMonitoring for AudioService {
                                     Device::Sink(sink) => {
                                         let key = sink.key();
                                         if let Some(existing) = output_devs.get(&key) {
+                                            let port_changed =
+                                                existing.active_port.get() != sink.device.active_port;
                                             existing.update_from_sink(&sink);
+
+                                            if port_changed {
+                                                let speaker = is_speaker(
+                                                    sink.device.active_port.as_deref(),
+                                                    &sink.device.properties,
+                                                );
+                                                let action = outputs.port_changed(key, speaker);
+                                                apply_output_action(action, &command_tx);
+                                            }
                                         } else {
                                             let output = Arc::new(OutputDevice::from_sink(
                                                 &sink,
                                                 command_tx.clone(),
                                                 Some(event_tx.clone()),
                                                 Some(cancellation_token.child_token()),
+                                                max_volume,
                                             ));
                                             output.clone().start_monitoring().await.ok();
                                             output_devs.insert(key, output);
                                             output_devices.set(output_devs.values().cloned().collect());
+
+                                            let action = outputs.added(
+                                                key,
+                                                &sink.device.name,
+                                                &sink.device.volume,
+                                            );
+                                            apply_output_action(action, &command_tx);
                                         }
                                     }
                                     Device::Source(source) => {
@@ -104,6 +139,7 @@ impl ServiceMonitoring for AudioService {
                                                 command_tx.clone(),
                                                 Some(event_tx.clone()),
                                                 Some(cancellation_token.child_token()),
+                                                max_volume,
                                             ));
                                             input.clone().start_monitoring().await.ok();
                                             input_devs.insert(key, input);
@@ -120,6 +156,14 @@ impl ServiceMonitoring for AudioService {
                                     };
 
                                     output_devices.set(output_devs.values().cloned().collect());
+
+                                    let action = outputs.removed(
+                                        key,
+                                        &device.name.get(),
+                                        device.volume.get(),
+                                        Instant::now(),
+                                    );
+                                    apply_output_action(action, &command_tx);
                                 }
                                 if input_devs.remove(&key).is_some() {
                                     input_devices.set(input_devs.values().cloned().collect());
@@ -165,6 +209,32 @@ impl ServiceMonitoring for AudioService {
                                 update_stream_properties(&streams, &playback_streams, &recording_streams);
                             }
 
+                            AudioEvent::CardAdded(info) | AudioEvent::CardChanged(info) => {
+                                if let Some(existing) = card_map.get(&info.index) {
+                                    existing.update_from_info(&info);
+                                } else {
+                                    let card = Arc::new(Card::from_info(
+                                        &info,
+                                        command_tx.clone(),
+                                        Some(event_tx.clone()),
+                                        Some(cancellation_token.child_token()),
+                                    ));
+                                    card.clone().start_monitoring().await.ok();
+                                    card_map.insert(info.index, card);
+                                    cards.set(card_map.values().cloned().collect());
+                                }
+                            }
+
+                            AudioEvent::CardRemoved(index) => {
+                                if let Some(cancel_token) = card_map
+                                    .remove(&index)
+                                    .and_then(|card| card.cancellation_token.clone())
+                                {
+                                    cancel_token.cancel();
+                                }
+                                cards.set(card_map.values().cloned().collect());
+                            }
+
                             AudioEvent::DefaultInputChanged(maybe_device) => {
                                 let device = maybe_device.and_then(|dev| {
                                     match dev {
@@ -179,6 +249,17 @@ impl ServiceMonitoring for AudioService {
                             }
 
                             AudioEvent::DefaultOutputChanged(maybe_device) => {
+                                let default = match &maybe_device {
+                                    Some(Device::Sink(sink)) => Some((
+                                        sink.key(),
+                                        is_speaker(
+                                            sink.device.active_port.as_deref(),
+                                            &sink.device.properties,
+                                        ),
+                                    )),
+                                    _ => None,
+                                };
+
                                 let device = maybe_device.and_then(|dev| {
                                     match dev {
                                         Device::Sink(sink) => {
@@ -189,6 +270,9 @@ impl ServiceMonitoring for AudioService {
                                     }
                                 });
                                 default_output.set(device);
+
+                                let action = outputs.default_changed(default, Instant::now());
+                                apply_output_action(action, &command_tx);
                             }
                         }
                     }
@@ -200,6 +284,26 @@ impl ServiceMonitoring for AudioService {
     }
 }
 
+/// Sends the change through the backend without waiting on it. The
+/// resulting sink events keep the device properties in sync.
+fn apply_output_action(action: Option<OutputAction>, command_tx: &CommandSender) {
+    let Some(action) = action else {
+        return;
+    };
+
+    let command_tx = command_tx.clone();
+    tokio::spawn(async move {
+        let _ = match action {
+            OutputAction::Mute(key) => {
+                OutputDeviceController::set_mute(&command_tx, key, true).await
+            }
+            OutputAction::SetVolume(key, volume) => {
+                OutputDeviceController::set_volume(&command_tx, key, volume).await
+            }
+        };
+    });
+}
+
 fn update_stream_properties(
     streams: &HashMap<StreamKey, Arc<AudioStream>>,
     playback_streams: &Property<Vec<Arc<AudioStream>>>,
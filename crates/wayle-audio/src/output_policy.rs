@@ -0,0 +1,272 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::{types::device::DeviceKey, volume::types::Volume};
+
+/// How long after the default output switches away from a device its
+/// removal still counts as that device being unplugged. PulseAudio may
+/// announce the new default before the old sink is gone.
+const UNPLUG_WINDOW: Duration = Duration::from_secs(2);
+
+const SPEAKER_FORM_FACTORS: [&str; 2] = ["internal", "speaker"];
+
+/// Opt-in reactions to output devices coming and going. Both are off by
+/// default.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct OutputPolicy {
+    /// Remember each sink's volume when it disappears and restore it when a
+    /// sink with the same name comes back.
+    pub restore_per_device_volume: bool,
+    /// Mute the new default output when it is a built-in speaker that took
+    /// over from an unplugged device.
+    pub mute_speakers_on_unplug: bool,
+}
+
+/// Change the monitor should apply to a sink.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum OutputAction {
+    Mute(DeviceKey),
+    SetVolume(DeviceKey, Volume),
+}
+
+/// Follows the default output and sink lifetimes to decide when
+/// [`OutputPolicy`] applies.
+#[derive(Debug, Default)]
+pub(crate) struct OutputTracker {
+    policy: OutputPolicy,
+    volumes: HashMap<String, Volume>,
+    default: Option<DeviceKey>,
+    default_is_speaker: bool,
+    default_unplugged: bool,
+    replaced_default: Option<(DeviceKey, Instant)>,
+}
+
+impl OutputTracker {
+    pub fn new(policy: OutputPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    /// A sink appeared. Returns the volume to restore if it was seen before.
+    pub fn added(&mut self, key: DeviceKey, name: &str, volume: &Volume) -> Option<OutputAction> {
+        if !self.policy.restore_per_device_volume {
+            return None;
+        }
+
+        let saved = self.volumes.get(name)?;
+        if saved == volume || saved.channels() != volume.channels() {
+            return None;
+        }
+
+        Some(OutputAction::SetVolume(key, saved.clone()))
+    }
+
+    /// A sink went away with `volume` as its last known volume.
+    pub fn removed(
+        &mut self,
+        key: DeviceKey,
+        name: &str,
+        volume: Volume,
+        now: Instant,
+    ) -> Option<OutputAction> {
+        if self.policy.restore_per_device_volume {
+            self.volumes.insert(name.to_owned(), volume);
+        }
+
+        if self.default == Some(key) {
+            self.default_unplugged = true;
+            return None;
+        }
+
+        let (replaced, at) = self.replaced_default.take()?;
+        if replaced != key || now.duration_since(at) > UNPLUG_WINDOW {
+            return None;
+        }
+
+        self.mute_default_speaker()
+    }
+
+    /// The default output changed to `default`, with whether it is a speaker.
+    pub fn default_changed(
+        &mut self,
+        default: Option<(DeviceKey, bool)>,
+        now: Instant,
+    ) -> Option<OutputAction> {
+        let previous = self.default.take();
+        self.default = default.map(|(key, _)| key);
+        self.default_is_speaker = default.is_some_and(|(_, speaker)| speaker);
+
+        if self.default == previous {
+            return None;
+        }
+
+        if std::mem::take(&mut self.default_unplugged) {
+            self.replaced_default = None;
+            return self.mute_default_speaker();
+        }
+
+        self.replaced_default = previous.map(|key| (key, now));
+        None
+    }
+
+    /// The sink `key` switched ports, e.g. from the headphone jack to the
+    /// speakers when headphones are pulled out.
+    pub fn port_changed(&mut self, key: DeviceKey, speaker: bool) -> Option<OutputAction> {
+        if self.default != Some(key) {
+            return None;
+        }
+
+        let was_speaker = std::mem::replace(&mut self.default_is_speaker, speaker);
+        if was_speaker || !speaker {
+            return None;
+        }
+
+        self.mute_default_speaker()
+    }
+
+    fn mute_default_speaker(&self) -> Option<OutputAction> {
+        if !self.policy.mute_speakers_on_unplug || !self.default_is_speaker {
+            return None;
+        }
+
+        self.default.map(OutputAction::Mute)
+    }
+}
+
+/// Whether a sink plays through built-in speakers, judged by its active
+/// port. The form factor describes the whole card, so a laptop's internal
+/// sink is still "internal" with headphones plugged in; it's only used
+/// when the sink has no port.
+pub(crate) fn is_speaker(active_port: Option<&str>, properties: &HashMap<String, String>) -> bool {
+    if let Some(port) = active_port {
+        return port.to_ascii_lowercase().contains("speaker");
+    }
+
+    properties
+        .get("device.form_factor")
+        .is_some_and(|form_factor| SPEAKER_FORM_FACTORS.contains(&form_factor.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::device::DeviceType;
+
+    const BOTH: OutputPolicy = OutputPolicy {
+        restore_per_device_volume: true,
+        mute_speakers_on_unplug: true,
+    };
+
+    fn key(index: u32) -> DeviceKey {
+        DeviceKey::new(index, DeviceType::Output)
+    }
+
+    #[test]
+    fn restores_volume_when_device_returns() {
+        let mut tracker = OutputTracker::new(BOTH);
+        let now = Instant::now();
+
+        tracker.removed(key(5), "bluez_sink.headset", Volume::stereo(0.3, 0.3), now);
+        let action = tracker.added(key(9), "bluez_sink.headset", &Volume::stereo(1.0, 1.0));
+
+        assert_eq!(
+            action,
+            Some(OutputAction::SetVolume(key(9), Volume::stereo(0.3, 0.3)))
+        );
+        assert_eq!(
+            tracker.added(key(10), "other", &Volume::stereo(1.0, 1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn does_not_restore_when_disabled() {
+        let mut tracker = OutputTracker::new(OutputPolicy::default());
+
+        tracker.removed(key(5), "headset", Volume::mono(0.3), Instant::now());
+
+        assert_eq!(tracker.added(key(9), "headset", &Volume::mono(1.0)), None);
+    }
+
+    #[test]
+    fn mutes_speaker_when_default_is_removed_first() {
+        let mut tracker = OutputTracker::new(BOTH);
+        let now = Instant::now();
+        tracker.default_changed(Some((key(2), false)), now);
+
+        assert_eq!(
+            tracker.removed(key(2), "headset", Volume::mono(0.5), now),
+            None
+        );
+        assert_eq!(
+            tracker.default_changed(Some((key(1), true)), now),
+            Some(OutputAction::Mute(key(1)))
+        );
+    }
+
+    #[test]
+    fn mutes_speaker_when_default_switches_first() {
+        let mut tracker = OutputTracker::new(BOTH);
+        let now = Instant::now();
+        tracker.default_changed(Some((key(2), false)), now);
+
+        assert_eq!(tracker.default_changed(Some((key(1), true)), now), None);
+        assert_eq!(
+            tracker.removed(key(2), "headset", Volume::mono(0.5), now),
+            Some(OutputAction::Mute(key(1)))
+        );
+    }
+
+    #[test]
+    fn manual_switch_does_not_mute() {
+        let mut tracker = OutputTracker::new(BOTH);
+        let now = Instant::now();
+        tracker.default_changed(Some((key(2), false)), now);
+        tracker.default_changed(Some((key(1), true)), now);
+
+        let later = now + UNPLUG_WINDOW + Duration::from_secs(1);
+
+        assert_eq!(
+            tracker.removed(key(2), "headset", Volume::mono(0.5), later),
+            None
+        );
+    }
+
+    #[test]
+    fn mutes_when_port_switches_to_speaker() {
+        let mut tracker = OutputTracker::new(BOTH);
+        tracker.default_changed(Some((key(1), false)), Instant::now());
+
+        assert_eq!(
+            tracker.port_changed(key(1), true),
+            Some(OutputAction::Mute(key(1)))
+        );
+        assert_eq!(tracker.port_changed(key(1), true), None);
+        assert_eq!(tracker.port_changed(key(3), true), None);
+    }
+
+    #[test]
+    fn speaker_detection_uses_port_and_form_factor() {
+        let none = HashMap::new();
+        let internal =
+            HashMap::from([(String::from("device.form_factor"), String::from("internal"))]);
+
+        assert!(is_speaker(Some("analog-output-speaker"), &none));
+        assert!(is_speaker(None, &internal));
+        assert!(!is_speaker(Some("analog-output-headphones"), &none));
+        assert!(!is_speaker(None, &none));
+    }
+
+    #[test]
+    fn internal_sink_on_headphones_port_is_not_speaker() {
+        let internal =
+            HashMap::from([(String::from("device.form_factor"), String::from("internal"))]);
+
+        assert!(!is_speaker(Some("analog-output-headphones"), &internal));
+        assert!(is_speaker(Some("analog-output-speaker"), &internal));
+    }
+}
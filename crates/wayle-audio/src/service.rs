@@ -3,12 +3,13 @@ use std::sync::Arc;
 use derive_more::Debug;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
-use tracing::{error, instrument};
+use tracing::{error, instrument, warn};
 use wayle_core::Property;
 use wayle_traits::Reactive;
 use zbus::Connection;
 
 use super::core::{
+    card::{CardParams, LiveCardParams},
     device::{
         input::{InputDeviceParams, LiveInputDeviceParams},
         output::{LiveOutputDeviceParams, OutputDeviceParams},
@@ -19,10 +20,12 @@ use crate::{
     backend::types::{CommandSender, EventSender},
     builder::AudioServiceBuilder,
     core::{
+        card::Card,
         device::{input::InputDevice, output::OutputDevice},
         stream::AudioStream,
     },
     error::Error,
+    output_policy::OutputPolicy,
     types::{device::DeviceKey, stream::StreamKey},
 };
 
@@ -39,6 +42,8 @@ pub struct AudioService {
     pub(crate) backend_handle: Option<JoinHandle<Result<(), Error>>>,
     #[debug(skip)]
     pub(crate) _connection: Option<Connection>,
+    pub(crate) output_policy: OutputPolicy,
+    pub(crate) max_volume_percent: f64,
 
     /// All PulseAudio sinks: speakers, headphones, Bluetooth outputs, virtual sinks.
     pub output_devices: Property<Vec<Arc<OutputDevice>>>,
@@ -47,9 +52,14 @@ pub struct AudioService {
     pub input_devices: Property<Vec<Arc<InputDevice>>>,
 
     /// Current default sink, or `None` if unset.
+    ///
+    /// Unplugging the default sink moves this straight to the server's
+    /// fallback in a single update; it only becomes `None` when no sink is
+    /// left to fall back to.
     pub default_output: Property<Option<Arc<OutputDevice>>>,
 
-    /// Current default source, or `None` if unset.
+    /// Current default source, or `None` if unset. Follows the server's
+    /// fallback on unplug the same way as `default_output`.
     pub default_input: Property<Option<Arc<InputDevice>>>,
 
     /// Applications currently playing audio.
@@ -57,6 +67,9 @@ pub struct AudioService {
 
     /// Applications currently recording audio.
     pub recording_streams: Property<Vec<Arc<AudioStream>>>,
+
+    /// Sound cards and their profiles: built-in audio, HDMI, Bluetooth headsets.
+    pub cards: Property<Vec<Arc<Card>>>,
 }
 
 impl AudioService {
@@ -76,6 +89,49 @@ impl AudioService {
         AudioServiceBuilder::new()
     }
 
+    /// Makes `device` the default output.
+    ///
+    /// Setting the default only affects streams started afterwards. With
+    /// `move_streams`, every current playback stream is also moved onto
+    /// `device`, so audio that is already playing follows the switch.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if backend communication fails. When moving streams, every
+    /// stream is attempted and the first failure is returned.
+    #[instrument(skip(self, device), fields(device_key = ?device.key), err)]
+    pub async fn set_default_output(
+        &self,
+        device: &OutputDevice,
+        move_streams: bool,
+    ) -> Result<(), Error> {
+        device.set_as_default().await?;
+
+        if !move_streams {
+            return Ok(());
+        }
+
+        let mut result = Ok(());
+        for stream in self.playback_streams.get() {
+            if stream.device_index.get() == device.key.index {
+                continue;
+            }
+
+            if let Err(err) = stream.move_to_device(device.key).await {
+                warn!(
+                    stream_key = ?stream.key,
+                    error = %err,
+                    "cannot move stream to new default output"
+                );
+                if result.is_ok() {
+                    result = Err(err);
+                }
+            }
+        }
+
+        result
+    }
+
     /// Returns a snapshot of the output device's current state.
     ///
     /// The returned [`OutputDevice`] properties will not update after this call.
@@ -89,6 +145,7 @@ impl AudioService {
         OutputDevice::get(OutputDeviceParams {
             command_tx: &self.command_tx,
             device_key: key,
+            max_volume: self.max_volume_percent,
         })
         .await
     }
@@ -111,6 +168,7 @@ impl AudioService {
             event_tx: &self.event_tx,
             device_key: key,
             cancellation_token: &self.cancellation_token,
+            max_volume: self.max_volume_percent,
         })
         .await
     }
@@ -125,6 +183,7 @@ impl AudioService {
         InputDevice::get(InputDeviceParams {
             command_tx: &self.command_tx,
             device_key: key,
+            max_volume: self.max_volume_percent,
         })
         .await
     }
@@ -141,6 +200,7 @@ impl AudioService {
             event_tx: &self.event_tx,
             device_key: key,
             cancellation_token: &self.cancellation_token,
+            max_volume: self.max_volume_percent,
         })
         .await
     }
@@ -174,6 +234,36 @@ impl AudioService {
         })
         .await
     }
+
+    /// Returns a snapshot of the sound card's current state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CardNotFound`] if no card exists with this index.
+    #[instrument(skip(self), err)]
+    pub async fn card(&self, index: u32) -> Result<Card, Error> {
+        Card::get(CardParams {
+            command_tx: &self.command_tx,
+            index,
+        })
+        .await
+    }
+
+    /// Returns a live-updating sound card instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CardNotFound`] if no card exists with this index.
+    #[instrument(skip(self), err)]
+    pub async fn card_monitored(&self, index: u32) -> Result<Arc<Card>, Error> {
+        Card::get_live(LiveCardParams {
+            command_tx: &self.command_tx,
+            event_tx: &self.event_tx,
+            index,
+            cancellation_token: &self.cancellation_token,
+        })
+        .await
+    }
 }
 
 impl Drop for AudioService {
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+/// Card profile information
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardProfile {
+    /// Profile name (e.g. `a2dp-sink`, `output:hdmi-stereo`)
+    pub name: String,
+    /// Human-readable description
+    pub description: String,
+    /// Profile priority
+    pub priority: u32,
+    /// Whether the profile can currently be activated
+    pub available: bool,
+    /// Number of sinks the profile creates
+    pub n_sinks: u32,
+    /// Number of sources the profile creates
+    pub n_sources: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CardInfo {
+    pub index: u32,
+    pub name: String,
+    pub description: String,
+    pub owner_module: Option<u32>,
+    pub driver: String,
+    pub properties: HashMap<String, String>,
+    pub profiles: Vec<CardProfile>,
+    pub active_profile: Option<String>,
+}
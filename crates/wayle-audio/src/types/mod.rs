@@ -1,3 +1,5 @@
+/// Sound card type definitions
+pub mod card;
 /// Audio device type definitions
 pub mod device;
 /// Audio format type definitions
@@ -153,6 +153,28 @@ impl Volume {
         self.average() * 100.0
     }
 
+    /// Steps the average volume by `delta` percentage points, keeping the
+    /// balance between channels.
+    ///
+    /// Raising stops at `max_percentage`, and a volume already above it is
+    /// left alone rather than pulled down. Lowering stops at 0%.
+    pub fn stepped(&self, delta: f64, max_percentage: f64) -> Self {
+        let current = self.average();
+        let target = current + delta / 100.0;
+        let target = if delta > 0.0 {
+            target.min(current.max(max_percentage / 100.0))
+        } else {
+            target.max(0.0)
+        };
+
+        if current <= 0.0 {
+            return Self::new(vec![target; self.volumes.len()]);
+        }
+
+        let scale = target / current;
+        Self::new(self.volumes.iter().map(|&v| v * scale).collect())
+    }
+
     /// Check if volume is muted (all channels at 0.0)
     pub fn is_muted(&self) -> bool {
         self.volumes.iter().all(|&v| v == 0.0)
@@ -359,4 +381,48 @@ mod tests {
 
         assert!((avg_pct - 75.0).abs() < 0.01);
     }
+
+    #[test]
+    fn stepped_raises_all_channels() {
+        let volume = Volume::stereo(0.5, 0.5).stepped(5.0, 100.0);
+
+        assert!((volume.average_percentage() - 55.0).abs() < 0.01);
+        assert_eq!(volume.channels(), 2);
+    }
+
+    #[test]
+    fn stepped_stops_at_max() {
+        let volume = Volume::stereo(1.45, 1.45).stepped(10.0, 150.0);
+
+        assert!((volume.average_percentage() - 150.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn stepped_up_leaves_volume_above_max_alone() {
+        let volume = Volume::mono(1.2).stepped(5.0, 100.0);
+
+        assert_eq!(volume.channel(0), Some(1.2));
+    }
+
+    #[test]
+    fn stepped_down_stops_at_zero() {
+        let volume = Volume::mono(0.03).stepped(-5.0, 100.0);
+
+        assert!(volume.is_muted());
+    }
+
+    #[test]
+    fn stepped_keeps_channel_balance() {
+        let volume = Volume::stereo(0.4, 0.8).stepped(30.0, 150.0);
+
+        assert!((volume.channel(0).unwrap_or_default() - 0.6).abs() < 0.01);
+        assert!((volume.channel(1).unwrap_or_default() - 1.2).abs() < 0.01);
+    }
+
+    #[test]
+    fn stepped_from_silence_sets_every_channel() {
+        let volume = Volume::muted(2).stepped(10.0, 100.0);
+
+        assert_eq!(volume.as_slice(), &[0.1, 0.1]);
+    }
 }
@@ -1,23 +1,34 @@
+use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
 use tracing::instrument;
-use wayle_traits::Reactive;
+use wayle_core::Property;
+use wayle_traits::{Reactive, ServiceMonitoring};
 use zbus::{Connection, zvariant::OwnedObjectPath};
 
 use crate::{
     core::device::{Device, types::LiveDeviceParams},
     error::Error,
+    monitoring::{enumerate_devices, select_primary_device},
+    proxy::upower::UPowerProxy,
     service::BatteryService,
+    types::DeviceSelector,
 };
 
+const DEVICE_EVENT_CHANNEL_CAPACITY: usize = 16;
+
 /// Builder for configuring a BatteryService.
 pub struct BatteryServiceBuilder {
     device_path: Option<OwnedObjectPath>,
+    primary_device: Option<DeviceSelector>,
 }
 
 impl BatteryServiceBuilder {
     /// Creates a new builder with default configuration.
     pub fn new() -> Self {
-        Self { device_path: None }
+        Self {
+            device_path: None,
+            primary_device: None,
+        }
     }
 
     /// Sets a specific UPower device path.
@@ -31,6 +42,14 @@ impl BatteryServiceBuilder {
         self
     }
 
+    /// Sets which device [`BatteryService::primary_device`] follows.
+    ///
+    /// If not set, defaults to the battery that powers the system.
+    pub fn primary_device(mut self, selector: DeviceSelector) -> Self {
+        self.primary_device = Some(selector);
+        self
+    }
+
     /// Builds the BatteryService.
     ///
     /// Uses the DisplayDevice if no specific device path was set.
@@ -60,10 +79,25 @@ impl BatteryServiceBuilder {
         })
         .await?;
 
-        Ok(BatteryService {
+        let devices = enumerate_devices(&connection, &cancellation_token).await?;
+        let primary_device = select_primary_device(&devices, self.primary_device.as_ref());
+        let on_battery = UPowerProxy::new(&connection).await?.on_battery().await?;
+        let (device_events_tx, _) = broadcast::channel(DEVICE_EVENT_CHANNEL_CAPACITY);
+
+        let service = BatteryService {
             device,
+            devices: Property::new(devices),
+            primary_device: Property::new(primary_device),
+            primary_selector: Property::new(self.primary_device),
+            device_events_tx,
+            on_battery: Property::new(on_battery),
+            zbus_connection: connection,
             cancellation_token,
-        })
+        };
+
+        service.start_monitoring().await?;
+
+        Ok(service)
     }
 }
 
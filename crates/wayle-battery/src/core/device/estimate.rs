@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use crate::types::{DeviceState, TimeRemaining, TimeRemainingSource};
+
+/// Weight of the newest energy rate sample in the smoothed rate.
+const ENERGY_RATE_SMOOTHING: f64 = 0.3;
+/// Below this rate a fallback estimate would be wildly long, so none is made.
+const MIN_ESTIMATE_RATE_WATTS: f64 = 0.1;
+/// Fallback estimates beyond this are treated as noise.
+const MAX_ESTIMATE: Duration = Duration::from_secs(48 * 60 * 60);
+
+/// Smoothed energy rate behind the fallback time remaining estimate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct RateTracker {
+    state: DeviceState,
+    watts: f64,
+}
+
+impl RateTracker {
+    pub(super) fn new(state: DeviceState, rate: f64) -> Self {
+        Self {
+            state,
+            watts: rate.abs(),
+        }
+    }
+
+    pub(super) fn record_rate(&mut self, rate: f64) {
+        let rate = rate.abs();
+        self.watts = if self.watts > 0.0 {
+            self.watts + ENERGY_RATE_SMOOTHING * (rate - self.watts)
+        } else {
+            rate
+        };
+    }
+
+    /// Charging and discharging rates differ, so smoothing restarts from
+    /// `current_rate` whenever the state changes.
+    pub(super) fn record_state(&mut self, state: DeviceState, current_rate: f64) {
+        if self.state != state {
+            self.watts = current_rate.abs();
+        }
+        self.state = state;
+    }
+
+    /// Uses UPower's `reported` seconds when non-zero, otherwise estimates
+    /// from `energy_left` (Wh) at the smoothed rate.
+    pub(super) fn time_remaining(&self, reported: i64, energy_left: f64) -> Option<TimeRemaining> {
+        if reported > 0 {
+            return Some(TimeRemaining {
+                duration: Duration::from_secs(reported as u64),
+                source: TimeRemainingSource::UPower,
+            });
+        }
+
+        if self.watts < MIN_ESTIMATE_RATE_WATTS || energy_left <= 0.0 {
+            return None;
+        }
+
+        let duration = Duration::from_secs_f64(energy_left / self.watts * 3600.0);
+        (duration <= MAX_ESTIMATE).then_some(TimeRemaining {
+            duration: Duration::from_secs(duration.as_secs()),
+            source: TimeRemainingSource::Estimated,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimated(secs: u64) -> Option<TimeRemaining> {
+        Some(TimeRemaining {
+            duration: Duration::from_secs(secs),
+            source: TimeRemainingSource::Estimated,
+        })
+    }
+
+    #[test]
+    fn steady_discharge_estimates_from_energy_left() {
+        let mut tracker = RateTracker::new(DeviceState::Discharging, -10.0);
+        for _ in 0..5 {
+            tracker.record_rate(-10.0);
+        }
+
+        assert_eq!(tracker.time_remaining(0, 25.0), estimated(9000));
+    }
+
+    #[test]
+    fn reported_time_wins_over_estimate() {
+        let tracker = RateTracker::new(DeviceState::Discharging, 10.0);
+
+        assert_eq!(
+            tracker.time_remaining(600, 25.0),
+            Some(TimeRemaining {
+                duration: Duration::from_secs(600),
+                source: TimeRemainingSource::UPower,
+            })
+        );
+    }
+
+    #[test]
+    fn state_flip_restarts_smoothing_from_current_rate() {
+        let mut tracker = RateTracker::new(DeviceState::Discharging, 10.0);
+        tracker.record_rate(10.0);
+
+        tracker.record_state(DeviceState::Charging, 40.0);
+        assert_eq!(tracker.time_remaining(0, 20.0), estimated(1800));
+
+        tracker.record_state(DeviceState::Charging, 5.0);
+        assert_eq!(tracker.time_remaining(0, 20.0), estimated(1800));
+    }
+
+    #[test]
+    fn zero_rate_gives_no_estimate() {
+        let mut tracker = RateTracker::new(DeviceState::Discharging, 0.0);
+        assert_eq!(tracker.time_remaining(0, 25.0), None);
+
+        tracker.record_rate(0.0);
+        assert_eq!(tracker.time_remaining(0, 25.0), None);
+    }
+}
@@ -1,4 +1,5 @@
 mod controls;
+mod estimate;
 mod monitoring;
 /// Type definitions for battery device.
 pub mod types;
@@ -7,16 +8,20 @@ use std::sync::Arc;
 
 use controls::DeviceController;
 use derive_more::Debug;
+use estimate::RateTracker;
 use tokio_util::sync::CancellationToken;
 use types::{DeviceParams, DeviceProps, LiveDeviceParams};
-use wayle_core::{Property, unwrap_dbus, unwrap_dbus_or};
-use wayle_traits::{ModelMonitoring, Reactive};
+use wayle_core::{ComputedProperty, Property, unwrap_dbus, unwrap_dbus_or};
+use wayle_traits::{ModelMonitoring, Reactive, Refresh};
 use zbus::{Connection, zvariant::OwnedObjectPath};
 
 use crate::{
     Error,
     proxy::device::DeviceProxy,
-    types::{BatteryLevel, BatteryTechnology, DeviceState, DeviceType, WarningLevel},
+    types::{
+        BatteryLevel, BatteryTechnology, DeviceState, DeviceType, PowerFlow, TimeRemaining,
+        WarningLevel,
+    },
 };
 
 /// UPower battery device with reactive properties.
@@ -26,14 +31,20 @@ use crate::{
 ///
 /// # Control Methods
 ///
-/// - [`refresh`](Self::refresh) - Force hardware data refresh
+/// - [`refresh`](Refresh::refresh) - Force hardware data refresh
 /// - [`get_history`](Self::get_history) - Historical charge/rate data
 /// - [`get_statistics`](Self::get_statistics) - Charge prediction statistics
 /// - [`enable_charge_threshold`](Self::enable_charge_threshold) - Battery charge limiting
+///
+/// [`power_flow`](Self::power_flow) and [`power_flow_label`](Self::power_flow_label)
+/// give the signed charge/discharge rate for power-usage displays.
+///
+/// [`time_remaining`](Self::time_remaining) falls back to an estimate of its
+/// own when UPower reports zero time to empty or full.
 #[derive(Debug, Clone)]
 pub struct Device {
     #[debug(skip)]
-    cancellation_token: Option<CancellationToken>,
+    pub(crate) cancellation_token: Option<CancellationToken>,
     #[debug(skip)]
     pub(crate) zbus_connection: Connection,
     #[debug(skip)]
@@ -115,6 +126,17 @@ pub struct Device {
     pub voltage_max_design: Property<f64>,
     /// Coarse representation of battery capacity.
     pub capacity_level: Property<String>,
+
+    #[debug(skip)]
+    rate_tracker: Property<RateTracker>,
+    #[debug(skip)]
+    remaining: Property<Option<TimeRemaining>>,
+}
+
+impl PartialEq for Device {
+    fn eq(&self, other: &Self) -> bool {
+        self.device_path == other.device_path
+    }
 }
 
 impl Reactive for Device {
@@ -148,15 +170,23 @@ impl Reactive for Device {
     }
 }
 
-impl Device {
-    /// Refreshes the data collected from the power source.
+impl Refresh for Device {
+    type Error = Error;
+
+    /// Asks UPower to refresh the data collected from the power source, then
+    /// re-reads every property.
     ///
     /// # Errors
-    /// Returns error if refresh operation fails.
-    pub async fn refresh(&self) -> Result<(), Error> {
-        DeviceController::refresh(&self.zbus_connection, &self.device_path).await
+    /// Returns error if the refresh call or reading the properties fails.
+    async fn refresh(&self) -> Result<(), Self::Error> {
+        DeviceController::refresh(&self.zbus_connection, &self.device_path).await?;
+        let props = Self::from_connection(&self.zbus_connection, &self.device_path).await?;
+        self.apply_props(props);
+        Ok(())
     }
+}
 
+impl Device {
     /// Gets history for the power device that is persistent across reboots.
     ///
     /// # Arguments
@@ -204,6 +234,32 @@ impl Device {
         DeviceController::get_statistics(&self.zbus_connection, &self.device_path, stat_type).await
     }
 
+    /// Current power flow, combining [`state`](Self::state) with the
+    /// unsigned [`energy_rate`](Self::energy_rate).
+    pub fn power_flow(&self) -> PowerFlow {
+        PowerFlow::new(self.state.get(), self.energy_rate.get())
+    }
+
+    /// Power flow as display text (e.g. "Discharging at 8.2 W"), updated
+    /// whenever the state or energy rate changes.
+    pub fn power_flow_label(&self) -> ComputedProperty<String> {
+        self.state.combine(&self.energy_rate, |state, rate| {
+            PowerFlow::new(*state, *rate).to_string()
+        })
+    }
+
+    /// Time until empty while discharging, or until full while charging.
+    ///
+    /// Uses UPower's `TimeToEmpty`/`TimeToFull` when nonzero. Some hardware
+    /// reports zero there even while discharging, so otherwise the time is
+    /// estimated from [`energy`](Self::energy), the energy bounds and a
+    /// smoothed [`energy_rate`](Self::energy_rate), and marked
+    /// [`TimeRemainingSource::Estimated`]. `None` when the battery is
+    /// neither charging nor discharging, or no estimate can be made.
+    pub fn time_remaining(&self) -> ComputedProperty<Option<TimeRemaining>> {
+        self.remaining.clone().into()
+    }
+
     /// Limiting the battery charge to the configured thresholds.
     ///
     /// If it is true, the battery charge will be limited to ChargeEndThreshold and start to charge
@@ -351,7 +407,7 @@ impl Device {
         device_path: OwnedObjectPath,
         cancellation_token: Option<CancellationToken>,
     ) -> Self {
-        Self {
+        let device = Self {
             zbus_connection: connection.clone(),
             device_path,
             cancellation_token,
@@ -395,6 +451,105 @@ impl Device {
             voltage_min_design: Property::new(props.voltage_min_design),
             voltage_max_design: Property::new(props.voltage_max_design),
             capacity_level: Property::new(props.capacity_level),
-        }
+            rate_tracker: Property::new(RateTracker::new(
+                DeviceState::from(props.state),
+                props.energy_rate,
+            )),
+            remaining: Property::new(None),
+        };
+
+        device.update_time_remaining();
+        device
+    }
+
+    fn apply_props(&self, props: DeviceProps) {
+        self.native_path.set(props.native_path);
+        self.vendor.set(props.vendor);
+        self.model.set(props.model);
+        self.serial.set(props.serial);
+        self.update_time.set(props.update_time);
+        self.device_type.set(DeviceType::from(props.device_type));
+        self.power_supply.set(props.power_supply);
+        self.has_history.set(props.has_history);
+        self.has_statistics.set(props.has_statistics);
+        self.online.set(props.online);
+        self.energy.set(props.energy);
+        self.energy_empty.set(props.energy_empty);
+        self.energy_full.set(props.energy_full);
+        self.energy_full_design.set(props.energy_full_design);
+        self.record_energy_rate(props.energy_rate);
+        self.voltage.set(props.voltage);
+        self.charge_cycles.set(props.charge_cycles);
+        self.luminosity.set(props.luminosity);
+        self.time_to_empty.set(props.time_to_empty);
+        self.time_to_full.set(props.time_to_full);
+        self.percentage.set(props.percentage);
+        self.temperature.set(props.temperature);
+        self.is_present.set(props.is_present);
+        self.record_state(DeviceState::from(props.state));
+        self.is_rechargeable.set(props.is_rechargeable);
+        self.capacity.set(props.capacity);
+        self.technology
+            .set(BatteryTechnology::from(props.technology));
+        self.warning_level
+            .set(WarningLevel::from(props.warning_level));
+        self.battery_level
+            .set(BatteryLevel::from(props.battery_level));
+        self.icon_name.set(props.icon_name);
+        self.charge_start_threshold
+            .set(props.charge_start_threshold);
+        self.charge_end_threshold.set(props.charge_end_threshold);
+        self.charge_threshold_enabled
+            .set(props.charge_threshold_enabled);
+        self.charge_threshold_supported
+            .set(props.charge_threshold_supported);
+        self.charge_threshold_settings_supported
+            .set(props.charge_threshold_settings_supported);
+        self.voltage_min_design.set(props.voltage_min_design);
+        self.voltage_max_design.set(props.voltage_max_design);
+        self.capacity_level.set(props.capacity_level);
+        self.update_time_remaining();
+    }
+
+    fn record_energy_rate(&self, rate: f64) {
+        self.energy_rate.set(rate);
+
+        let mut tracker = self.rate_tracker.get();
+        tracker.record_rate(rate);
+        self.rate_tracker.set(tracker);
+    }
+
+    fn record_state(&self, state: DeviceState) {
+        let mut tracker = self.rate_tracker.get();
+        tracker.record_state(state, self.energy_rate.get());
+        self.rate_tracker.set(tracker);
+
+        self.state.set(state);
+    }
+
+    fn update_time_remaining(&self) {
+        self.remaining.set(self.compute_time_remaining());
+    }
+
+    fn compute_time_remaining(&self) -> Option<TimeRemaining> {
+        let (reported, energy_left) = match self.state.get() {
+            DeviceState::Discharging => (
+                self.time_to_empty.get(),
+                self.energy.get() - self.energy_empty.get(),
+            ),
+            DeviceState::Charging => (
+                self.time_to_full.get(),
+                self.energy_full.get() - self.energy.get(),
+            ),
+            DeviceState::Unknown
+            | DeviceState::Empty
+            | DeviceState::FullyCharged
+            | DeviceState::PendingCharge
+            | DeviceState::PendingDischarge => return None,
+        };
+
+        self.rate_tracker
+            .get()
+            .time_remaining(reported, energy_left)
     }
 }
@@ -141,16 +141,19 @@ async fn monitor_device(
                 Some(change) = energy_changed.next() => {
                     if let Ok(val) = change.get().await {
                         device.energy.set(val);
+                        device.update_time_remaining();
                     }
                 }
                 Some(change) = energy_empty_changed.next() => {
                     if let Ok(val) = change.get().await {
                         device.energy_empty.set(val);
+                        device.update_time_remaining();
                     }
                 }
                 Some(change) = energy_full_changed.next() => {
                     if let Ok(val) = change.get().await {
                         device.energy_full.set(val);
+                        device.update_time_remaining();
                     }
                 }
                 Some(change) = energy_full_design_changed.next() => {
@@ -160,7 +163,8 @@ async fn monitor_device(
                 }
                 Some(change) = energy_rate_changed.next() => {
                     if let Ok(val) = change.get().await {
-                        device.energy_rate.set(val);
+                        device.record_energy_rate(val);
+                        device.update_time_remaining();
                     }
                 }
                 Some(change) = voltage_changed.next() => {
@@ -181,11 +185,13 @@ async fn monitor_device(
                 Some(change) = time_to_empty_changed.next() => {
                     if let Ok(val) = change.get().await {
                         device.time_to_empty.set(val);
+                        device.update_time_remaining();
                     }
                 }
                 Some(change) = time_to_full_changed.next() => {
                     if let Ok(val) = change.get().await {
                         device.time_to_full.set(val);
+                        device.update_time_remaining();
                     }
                 }
                 Some(change) = percentage_changed.next() => {
@@ -205,7 +211,8 @@ async fn monitor_device(
                 }
                 Some(change) = state_changed.next() => {
                     if let Ok(val) = change.get().await {
-                        device.state.set(DeviceState::from(val));
+                        device.record_state(DeviceState::from(val));
+                        device.update_time_remaining();
                     }
                 }
                 Some(change) = is_rechargeable_changed.next() => {
@@ -56,6 +56,93 @@
 //! # }
 //! ```
 //!
+//! # Choosing Among Several Devices
+//!
+//! [`BatteryService::devices`] lists every UPower device, including mice,
+//! keyboards and UPSes. [`BatteryService::primary_device`] follows one of
+//! them, the battery powering the system unless
+//! [`set_primary_device`](BatteryService::set_primary_device) picks another:
+//!
+//! ```rust,no_run
+//! # use wayle_battery::BatteryService;
+//! use wayle_battery::types::{DeviceSelector, DeviceType};
+//!
+//! # async fn example() -> Result<(), wayle_battery::Error> {
+//! let service = BatteryService::new().await?;
+//! service.set_primary_device(Some(DeviceSelector::Type(DeviceType::Mouse)));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Devices Coming and Going
+//!
+//! `devices` follows UPower's `DeviceAdded` and `DeviceRemoved` signals, so
+//! a Bluetooth mouse shows up when it connects. [`BatteryService::device_events`]
+//! yields each change, with the device type for filtering:
+//!
+//! ```rust,no_run
+//! # use wayle_battery::BatteryService;
+//! use futures::StreamExt;
+//! use wayle_battery::types::{DeviceEvent, DeviceType};
+//!
+//! # async fn example() -> Result<(), wayle_battery::Error> {
+//! let service = BatteryService::new().await?;
+//! let mut events = service.device_events();
+//! while let Some(event) = events.next().await {
+//!     if !matches!(event.device_type(), DeviceType::Mouse | DeviceType::Keyboard) {
+//!         continue;
+//!     }
+//!     match event {
+//!         DeviceEvent::Added(device) => println!("{} connected", device.model.get()),
+//!         DeviceEvent::Removed(device) => println!("{} disconnected", device.model.get()),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Critical Battery
+//!
+//! [`BatteryService::on_battery`] tracks whether the system runs on battery,
+//! and [`critical_action`](BatteryService::critical_action) says what
+//! happens when it runs out:
+//!
+//! ```rust,no_run
+//! # use wayle_battery::BatteryService;
+//! use wayle_battery::types::CriticalAction;
+//!
+//! # async fn example() -> Result<(), wayle_battery::Error> {
+//! let service = BatteryService::new().await?;
+//! if service.on_battery.get() && service.critical_action().await? == CriticalAction::PowerOff {
+//!     println!("The system will power off at critical battery");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Time Remaining
+//!
+//! [`Device::time_remaining`](core::device::Device::time_remaining) prefers
+//! UPower's estimate. Some hardware reports zero even while discharging, in
+//! which case Wayle estimates the time from the stored energy and a smoothed
+//! energy rate, and marks it as
+//! [`TimeRemainingSource::Estimated`](types::TimeRemainingSource::Estimated):
+//!
+//! ```rust,no_run
+//! # use wayle_battery::BatteryService;
+//! use wayle_battery::types::TimeRemainingSource;
+//!
+//! # async fn example() -> Result<(), wayle_battery::Error> {
+//! let service = BatteryService::new().await?;
+//! if let Some(remaining) = service.device.time_remaining().get() {
+//!     let minutes = remaining.duration.as_secs() / 60;
+//!     let approx = if remaining.source == TimeRemainingSource::Estimated { "~" } else { "" };
+//!     println!("{approx}{minutes} min left");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Control Methods
 //!
 //! The [`Device`](core::device::Device) type exposes UPower operations:
@@ -68,6 +155,7 @@ mod builder;
 /// Core battery device functionality.
 pub mod core;
 mod error;
+mod monitoring;
 mod proxy;
 mod service;
 /// Type definitions for battery service domain models and enums.
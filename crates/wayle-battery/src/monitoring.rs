@@ -0,0 +1,279 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+use wayle_core::Property;
+use wayle_traits::{Reactive, ServiceMonitoring};
+use zbus::{Connection, zvariant::OwnedObjectPath};
+
+use crate::{
+    core::device::{Device, types::LiveDeviceParams},
+    error::Error,
+    proxy::upower::UPowerProxy,
+    service::BatteryService,
+    types::{DeviceEvent, DeviceSelector, DeviceType},
+};
+
+impl ServiceMonitoring for BatteryService {
+    type Error = Error;
+
+    async fn start_monitoring(&self) -> Result<(), Self::Error> {
+        monitor_devices(
+            &self.zbus_connection,
+            self.cancellation_token.child_token(),
+            &self.devices,
+            &self.device_events_tx,
+        )
+        .await?;
+        monitor_primary_device(
+            &self.primary_device,
+            &self.devices,
+            &self.primary_selector,
+            self.cancellation_token.clone(),
+        );
+        monitor_on_battery(
+            &self.zbus_connection,
+            self.cancellation_token.child_token(),
+            &self.on_battery,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Loads every device UPower currently reports.
+pub(crate) async fn enumerate_devices(
+    connection: &Connection,
+    cancellation_token: &CancellationToken,
+) -> Result<Vec<Arc<Device>>, Error> {
+    let upower = UPowerProxy::new(connection).await?;
+    let paths = upower.enumerate_devices().await?;
+
+    let mut devices = Vec::with_capacity(paths.len());
+    for path in paths {
+        match load_device(connection, cancellation_token, &path).await {
+            Ok(device) => devices.push(device),
+            Err(error) => warn!(error = %error, path = %path, "cannot load power device"),
+        }
+    }
+
+    Ok(devices)
+}
+
+async fn load_device(
+    connection: &Connection,
+    cancellation_token: &CancellationToken,
+    path: &OwnedObjectPath,
+) -> Result<Arc<Device>, Error> {
+    Device::get_live(LiveDeviceParams {
+        connection,
+        device_path: path,
+        cancellation_token,
+    })
+    .await
+}
+
+async fn monitor_devices(
+    connection: &Connection,
+    cancellation_token: CancellationToken,
+    devices: &Property<Vec<Arc<Device>>>,
+    events: &broadcast::Sender<DeviceEvent>,
+) -> Result<(), Error> {
+    let upower = UPowerProxy::new(connection).await?;
+    let mut device_added = upower.receive_device_added().await?;
+    let mut device_removed = upower.receive_device_removed().await?;
+
+    let devices_prop = devices.clone();
+    let connection = connection.clone();
+    let events = events.clone();
+
+    // Devices that came or went between the initial enumeration and the
+    // signal subscriptions above would otherwise be missed until restart.
+    match upower.enumerate_devices().await {
+        Ok(paths) => {
+            for path in &paths {
+                add_device(
+                    &connection,
+                    &cancellation_token,
+                    &devices_prop,
+                    &events,
+                    path,
+                )
+                .await;
+            }
+            let stale: Vec<OwnedObjectPath> = devices_prop
+                .get()
+                .iter()
+                .map(|device| device.device_path.clone())
+                .filter(|path| !paths.contains(path))
+                .collect();
+            for path in &stale {
+                remove_device(&devices_prop, &events, path);
+            }
+        }
+        Err(error) => warn!(error = %error, "cannot re-enumerate power devices"),
+    }
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    debug!("Battery 'devices' monitoring cancelled");
+                    return;
+                }
+                Some(added) = device_added.next() => {
+                    let Ok(args) = added.args() else {
+                        continue;
+                    };
+                    add_device(
+                        &connection,
+                        &cancellation_token,
+                        &devices_prop,
+                        &events,
+                        &args.device,
+                    )
+                    .await;
+                }
+                Some(removed) = device_removed.next() => {
+                    let Ok(args) = removed.args() else {
+                        continue;
+                    };
+                    remove_device(&devices_prop, &events, &args.device);
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+async fn monitor_on_battery(
+    connection: &Connection,
+    cancellation_token: CancellationToken,
+    on_battery: &Property<bool>,
+) -> Result<(), Error> {
+    let upower = UPowerProxy::new(connection).await?;
+    let mut on_battery_changed = upower.receive_on_battery_changed().await;
+    let on_battery = on_battery.clone();
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    debug!("Battery 'on_battery' monitoring cancelled");
+                    return;
+                }
+                Some(change) = on_battery_changed.next() => {
+                    if let Ok(value) = change.get().await {
+                        on_battery.set(value);
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+async fn add_device(
+    connection: &Connection,
+    cancellation_token: &CancellationToken,
+    devices: &Property<Vec<Arc<Device>>>,
+    events: &broadcast::Sender<DeviceEvent>,
+    path: &OwnedObjectPath,
+) {
+    if devices
+        .get()
+        .iter()
+        .any(|device| device.device_path == *path)
+    {
+        return;
+    }
+
+    match load_device(connection, cancellation_token, path).await {
+        Ok(device) => {
+            debug!(path = %path, device_type = %device.device_type.get(), "Power device added");
+            let mut list = devices.get();
+            list.push(Arc::clone(&device));
+            devices.set(list);
+            let _ = events.send(DeviceEvent::Added(device));
+        }
+        Err(error) => {
+            warn!(error = %error, path = %path, "cannot load power device");
+        }
+    }
+}
+
+fn remove_device(
+    devices: &Property<Vec<Arc<Device>>>,
+    events: &broadcast::Sender<DeviceEvent>,
+    path: &OwnedObjectPath,
+) {
+    let mut list = devices.get();
+    let Some(index) = list.iter().position(|device| device.device_path == *path) else {
+        return;
+    };
+
+    let device = list.remove(index);
+    if let Some(token) = device.cancellation_token.as_ref() {
+        token.cancel();
+    }
+    devices.set(list);
+
+    debug!(path = %path, device_type = %device.device_type.get(), "Power device removed");
+    let _ = events.send(DeviceEvent::Removed(device));
+}
+
+fn monitor_primary_device(
+    primary_device: &Property<Option<Arc<Device>>>,
+    devices: &Property<Vec<Arc<Device>>>,
+    selector: &Property<Option<DeviceSelector>>,
+    cancellation_token: CancellationToken,
+) {
+    let primary_prop = primary_device.clone();
+    let devices_prop = devices.clone();
+    let selector_prop = selector.clone();
+
+    tokio::spawn(async move {
+        let mut devices_stream = devices_prop.watch();
+        let mut selector_stream = selector_prop.watch();
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    debug!("Battery 'primary_device' monitoring cancelled");
+                    return;
+                }
+                Some(_) = devices_stream.next() => {}
+                Some(_) = selector_stream.next() => {}
+            }
+
+            let primary = select_primary_device(&devices_prop.get(), selector_prop.get().as_ref());
+            primary_prop.set(primary);
+        }
+    });
+}
+
+/// Picks the device `selector` names, or the battery that powers the
+/// system when there is no selector.
+pub(crate) fn select_primary_device(
+    devices: &[Arc<Device>],
+    selector: Option<&DeviceSelector>,
+) -> Option<Arc<Device>> {
+    let found = match selector {
+        Some(DeviceSelector::NativePath(path)) => devices
+            .iter()
+            .find(|device| device.native_path.get() == *path),
+        Some(DeviceSelector::Type(device_type)) => devices
+            .iter()
+            .find(|device| device.device_type.get() == *device_type),
+        None => devices.iter().find(|device| {
+            device.device_type.get() == DeviceType::Battery && device.power_supply.get()
+        }),
+    };
+
+    found.cloned()
+}
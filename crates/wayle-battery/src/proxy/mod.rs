@@ -1 +1,2 @@
 pub(crate) mod device;
+pub(crate) mod upower;
@@ -1,9 +1,21 @@
 use std::sync::Arc;
 
 use derive_more::Debug;
+use futures::Stream;
+use tokio::sync::broadcast;
+use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 use tokio_util::sync::CancellationToken;
+use tracing::debug;
+use wayle_core::Property;
+use zbus::Connection;
 
-use crate::{builder::BatteryServiceBuilder, core::device::Device, error::Error};
+use crate::{
+    builder::BatteryServiceBuilder,
+    core::device::Device,
+    error::Error,
+    proxy::upower::UPowerProxy,
+    types::{CriticalAction, DeviceEvent, DeviceSelector},
+};
 
 /// Battery service for monitoring power devices via UPower.
 ///
@@ -14,9 +26,26 @@ use crate::{builder::BatteryServiceBuilder, core::device::Device, error::Error};
 pub struct BatteryService {
     #[debug(skip)]
     pub(crate) cancellation_token: CancellationToken,
+    #[debug(skip)]
+    pub(crate) zbus_connection: Connection,
+    pub(crate) primary_selector: Property<Option<DeviceSelector>>,
+    #[debug(skip)]
+    pub(crate) device_events_tx: broadcast::Sender<DeviceEvent>,
 
     /// The UPower battery device proxy for power metrics and charging state.
     pub device: Arc<Device>,
+
+    /// Every power device UPower knows about: batteries, AC adapters, UPSes
+    /// and peripherals such as mice and keyboards.
+    pub devices: Property<Vec<Arc<Device>>>,
+
+    /// The device picked from [`devices`](Self::devices) by
+    /// [`set_primary_device`](Self::set_primary_device). Defaults to the
+    /// battery that powers the system.
+    pub primary_device: Property<Option<Arc<Device>>>,
+
+    /// Whether the system is running on battery power.
+    pub on_battery: Property<bool>,
 }
 
 impl BatteryService {
@@ -41,6 +70,38 @@ impl BatteryService {
     pub fn builder() -> BatteryServiceBuilder {
         BatteryServiceBuilder::new()
     }
+
+    /// Chooses which device [`primary_device`](Self::primary_device) follows.
+    ///
+    /// `None` restores the default, the battery that powers the system. A
+    /// selector that matches no device leaves `primary_device` empty until a
+    /// matching device appears.
+    pub fn set_primary_device(&self, selector: Option<DeviceSelector>) {
+        debug!(?selector, "Updating primary battery device");
+        self.primary_selector.set(selector);
+    }
+
+    /// Returns a stream of devices added to or removed from
+    /// [`devices`](Self::devices) while the service runs.
+    ///
+    /// Only changes that happen after subscribing are yielded; read
+    /// `devices` for the ones already present.
+    pub fn device_events(&self) -> impl Stream<Item = DeviceEvent> + Send + 'static {
+        BroadcastStream::new(self.device_events_tx.subscribe()).filter_map(|result| result.ok())
+    }
+
+    /// Returns what the system does when the battery reaches the critical
+    /// level, so a low-battery warning can name the consequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Dbus` if the UPower call fails.
+    pub async fn critical_action(&self) -> Result<CriticalAction, Error> {
+        let upower = UPowerProxy::new(&self.zbus_connection).await?;
+        let action = upower.get_critical_action().await?;
+
+        Ok(CriticalAction::from(action.as_str()))
+    }
 }
 
 impl Drop for BatteryService {
@@ -1,4 +1,11 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::core::device::Device;
 
 /// Type of power source as defined by UPower
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -135,6 +142,41 @@ impl Display for DeviceType {
     }
 }
 
+/// Which UPower device [`BatteryService::primary_device`](crate::BatteryService::primary_device)
+/// follows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceSelector {
+    /// The device with this OS native path (e.g. "BAT1" or "hidpp_battery_0").
+    NativePath(String),
+    /// The first device of this type.
+    Type(DeviceType),
+}
+
+/// A power device appearing in or leaving
+/// [`BatteryService::devices`](crate::BatteryService::devices), e.g. a
+/// Bluetooth mouse connecting or disconnecting.
+#[derive(Debug, Clone)]
+pub enum DeviceEvent {
+    /// UPower added the device.
+    Added(Arc<Device>),
+    /// UPower removed the device. Its properties keep their last values.
+    Removed(Arc<Device>),
+}
+
+impl DeviceEvent {
+    /// The device that was added or removed.
+    pub fn device(&self) -> &Arc<Device> {
+        match self {
+            Self::Added(device) | Self::Removed(device) => device,
+        }
+    }
+
+    /// Type of the device, for showing only e.g. mice and keyboards.
+    pub fn device_type(&self) -> DeviceType {
+        self.device().device_type.get()
+    }
+}
+
 /// The battery power state as defined by UPower
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DeviceState {
@@ -182,6 +224,77 @@ impl Display for DeviceState {
     }
 }
 
+/// Which way power is flowing through the battery, with the rate in watts.
+///
+/// UPower reports `EnergyRate` as a magnitude, so the direction comes from
+/// the device state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PowerFlow {
+    /// Battery is charging at the given rate.
+    Charging(f64),
+    /// Battery is supplying power at the given rate.
+    Discharging(f64),
+    /// No power is flowing (full, waiting to charge, or state unknown).
+    Idle,
+}
+
+impl PowerFlow {
+    /// Combines a device state with its reported energy rate.
+    pub fn new(state: DeviceState, energy_rate: f64) -> Self {
+        let watts = energy_rate.abs();
+
+        match state {
+            DeviceState::Charging => Self::Charging(watts),
+            DeviceState::Discharging => Self::Discharging(watts),
+            DeviceState::Unknown
+            | DeviceState::Empty
+            | DeviceState::FullyCharged
+            | DeviceState::PendingCharge
+            | DeviceState::PendingDischarge => Self::Idle,
+        }
+    }
+}
+
+impl Display for PowerFlow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let loader = wayle_i18n::loader();
+        let text = match self {
+            Self::Charging(watts) => {
+                loader.get_args("battery-power-flow-charging", watts_arg(*watts))
+            }
+            Self::Discharging(watts) => {
+                loader.get_args("battery-power-flow-discharging", watts_arg(*watts))
+            }
+            Self::Idle => loader.get("battery-power-flow-idle"),
+        };
+
+        f.write_str(&text)
+    }
+}
+
+fn watts_arg(watts: f64) -> HashMap<&'static str, String> {
+    HashMap::from([("watts", format!("{watts:.1}"))])
+}
+
+/// Time until the battery is empty (discharging) or full (charging).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRemaining {
+    /// Time left until the battery is empty or full.
+    pub duration: Duration,
+    /// Where the value came from.
+    pub source: TimeRemainingSource,
+}
+
+/// Origin of a [`TimeRemaining`] value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeRemainingSource {
+    /// UPower's own `TimeToEmpty` or `TimeToFull`.
+    UPower,
+    /// Estimated by Wayle from the stored energy and the smoothed energy
+    /// rate, because UPower reported zero.
+    Estimated,
+}
+
 /// Technology used in the battery
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BatteryTechnology {
@@ -318,3 +431,61 @@ impl Display for BatteryLevel {
         }
     }
 }
+
+/// What the system does when the battery reaches the critical level, as
+/// configured in UPower's `CriticalPowerAction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CriticalAction {
+    /// Unknown or unrecognized action
+    Unknown,
+    /// Power off the system
+    PowerOff,
+    /// Hibernate to disk
+    Hibernate,
+    /// Suspend to RAM and hibernate to disk
+    HybridSleep,
+    /// Suspend to RAM
+    Suspend,
+    /// Take no action
+    Ignore,
+}
+
+impl From<&str> for CriticalAction {
+    fn from(value: &str) -> Self {
+        match value {
+            "PowerOff" => Self::PowerOff,
+            "Hibernate" => Self::Hibernate,
+            "HybridSleep" => Self::HybridSleep,
+            "Suspend" => Self::Suspend,
+            "Ignore" => Self::Ignore,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for CriticalAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Unknown => write!(f, "Unknown"),
+            Self::PowerOff => write!(f, "Power Off"),
+            Self::Hibernate => write!(f, "Hibernate"),
+            Self::HybridSleep => write!(f, "Hybrid Sleep"),
+            Self::Suspend => write!(f, "Suspend"),
+            Self::Ignore => write!(f, "Ignore"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_flow_label_is_localized() {
+        assert_eq!(PowerFlow::Idle.to_string(), "Idle");
+
+        let label = PowerFlow::new(DeviceState::Discharging, -8.24).to_string();
+        assert!(label.starts_with("Discharging at"));
+        assert!(label.contains("8.2"));
+    }
+}
@@ -1,4 +1,6 @@
-use tracing::instrument;
+use std::time::Duration;
+
+use tracing::{debug, instrument};
 use zbus::{Connection, zvariant::OwnedObjectPath};
 
 use crate::{
@@ -19,6 +21,35 @@ impl DeviceControls {
         Ok(proxy.connect().await?)
     }
 
+    /// Connects unless `timeout` elapses or `cancelled` resolves first. In
+    /// either case a Disconnect is sent to abort the pending Connect.
+    #[instrument(skip(connection, cancelled), fields(device = %device_path), err)]
+    pub(super) async fn connect_until(
+        connection: &Connection,
+        device_path: &OwnedObjectPath,
+        timeout: Duration,
+        cancelled: impl Future<Output = ()>,
+    ) -> Result<(), Error> {
+        let proxy = Device1Proxy::new(connection, device_path).await?;
+
+        let err = tokio::select! {
+            result = proxy.connect() => return Ok(result?),
+            () = tokio::time::sleep(timeout) => Error::Timeout {
+                operation: "connect device",
+                timeout,
+            },
+            () = cancelled => Error::Cancelled {
+                operation: "connect device",
+            },
+        };
+
+        if let Err(abort_err) = proxy.disconnect().await {
+            debug!(error = %abort_err, "cannot abort pending connect");
+        }
+
+        Err(err)
+    }
+
     #[instrument(skip(connection), fields(device = %device_path), err)]
     pub(super) async fn disconnect(
         connection: &Connection,
@@ -2,7 +2,7 @@ pub(crate) mod controls;
 pub(crate) mod monitoring;
 pub(crate) mod types;
 
-use std::sync::Arc;
+use std::{future, sync::Arc, time::Duration};
 
 use controls::DeviceControls;
 use derive_more::Debug;
@@ -12,16 +12,17 @@ use tokio_util::sync::CancellationToken;
 use types::{AdvertisingData, DeviceProperties, DeviceSet, ManufacturerData, ServiceData};
 pub use types::{DeviceParams, DisconnectedEvent, LiveDeviceParams};
 use wayle_core::{Property, unwrap_dbus};
-use wayle_traits::{ModelMonitoring, Reactive};
+use wayle_traits::{ModelMonitoring, Reactive, Refresh};
 use zbus::{Connection, zvariant::OwnedObjectPath};
 
 use crate::{
+    core::media_player::MediaPlayer,
     error::Error,
     proxy::{battery::Battery1Proxy, device::Device1Proxy},
     types::{
         ServiceNotification, UUID,
         adapter::AddressType,
-        device::{DisconnectReason, PreferredBearer},
+        device::{BluetoothCodec, DisconnectReason, PreferredBearer},
     },
 };
 
@@ -34,6 +35,8 @@ use crate::{
 /// # Control Methods
 ///
 /// - [`connect()`](Self::connect) / [`disconnect()`](Self::disconnect) - Manage connection
+/// - [`connect_timeout()`](Self::connect_timeout) /
+///   [`connect_cancellable()`](Self::connect_cancellable) - Bounded connect
 /// - [`pair()`](Self::pair) / [`cancel_pairing()`](Self::cancel_pairing) - Pairing flow
 /// - [`connect_profile()`](Self::connect_profile) /
 ///   [`disconnect_profile()`](Self::disconnect_profile) - Profile-specific
@@ -41,6 +44,7 @@ use crate::{
 ///   Trust and block settings
 /// - [`set_alias()`](Self::set_alias) - Custom display name
 /// - [`forget()`](Self::forget) - Remove from adapter and clear bonding
+/// - [`refresh()`](Refresh::refresh) - Re-read all properties from BlueZ
 #[derive(Debug, Clone)]
 pub struct Device {
     #[debug(skip)]
@@ -194,6 +198,24 @@ pub struct Device {
     ///
     /// (BlueZ experimental)
     pub preferred_bearer: Property<Option<PreferredBearer>>,
+
+    /// Media player the device exposes over AVRCP, e.g. a phone's music app.
+    ///
+    /// `None` unless the device exposes `org.bluez.MediaPlayer1`. Only kept
+    /// up to date on devices from service fields.
+    pub media_player: Property<Option<Arc<MediaPlayer>>>,
+
+    /// Codec of the audio this host is streaming to the device over A2DP.
+    ///
+    /// `None` unless the device is an audio sink with an active
+    /// `org.bluez.MediaTransport1`, so it clears while playback is idle.
+    /// Read-only: picking a codec is left to the audio server. Only kept up
+    /// to date on devices from service fields.
+    pub active_codec: Property<Option<BluetoothCodec>>,
+
+    /// Transport that `active_codec` is read from.
+    #[debug(skip)]
+    pub(crate) transport_path: Property<Option<OwnedObjectPath>>,
 }
 
 impl PartialEq for Device {
@@ -239,6 +261,18 @@ impl Reactive for Device {
     }
 }
 
+impl Refresh for Device {
+    type Error = Error;
+
+    async fn refresh(&self) -> Result<(), Self::Error> {
+        let device_proxy = Device1Proxy::new(&self.zbus_connection, &self.object_path).await?;
+        let battery_proxy = Battery1Proxy::new(&self.zbus_connection, &self.object_path).await?;
+        let props = Self::fetch_properties(&device_proxy, &battery_proxy).await?;
+        self.apply_properties(props);
+        Ok(())
+    }
+}
+
 impl Device {
     /// Connects all profiles the remote device supports that can be connected to and
     /// have been flagged as auto-connectable. If only subset of profiles is already
@@ -270,6 +304,53 @@ impl Device {
         DeviceControls::connect(&self.zbus_connection, &self.object_path).await
     }
 
+    /// Like [`connect()`](Self::connect), but gives up after `timeout`.
+    ///
+    /// A live device also stops waiting once it is removed or the service
+    /// shuts down. When the connect is abandoned, a Disconnect is sent to
+    /// cancel it on the BlueZ side; failures there are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - `Timeout` - Connect did not finish within `timeout`
+    /// - `Cancelled` - Device was removed while connecting
+    /// - Any error [`connect()`](Self::connect) returns
+    pub async fn connect_timeout(&self, timeout: Duration) -> Result<(), Error> {
+        self.connect_cancellable(timeout, &CancellationToken::new())
+            .await
+    }
+
+    /// Like [`connect_timeout()`](Self::connect_timeout), but also aborts
+    /// when `cancel` is cancelled, e.g. because the UI that started the
+    /// connect was closed.
+    ///
+    /// # Errors
+    ///
+    /// - `Cancelled` - `cancel` fired, or the device was removed, while connecting
+    /// - Any error [`connect_timeout()`](Self::connect_timeout) returns
+    pub async fn connect_cancellable(
+        &self,
+        timeout: Duration,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let device_removed = async {
+            match &self.cancellation_token {
+                Some(token) => token.cancelled().await,
+                None => future::pending().await,
+            }
+        };
+
+        let cancelled = async {
+            tokio::select! {
+                () = cancel.cancelled() => {}
+                () = device_removed => {}
+            }
+        };
+
+        DeviceControls::connect_until(&self.zbus_connection, &self.object_path, timeout, cancelled)
+            .await
+    }
+
     /// Disconnects all connected profiles and terminates the low-level ACL connection.
     ///
     /// ACL connection terminates even if some profiles fail to disconnect properly
@@ -617,6 +698,45 @@ impl Device {
                     .preferred_bearer
                     .map(|s| PreferredBearer::from(s.as_str())),
             ),
+            media_player: Property::new(None),
+            active_codec: Property::new(None),
+            transport_path: Property::new(None),
         }
     }
+
+    fn apply_properties(&self, props: DeviceProperties) {
+        self.address.set(props.address);
+        self.address_type
+            .set(AddressType::from(props.address_type.as_str()));
+        self.name.set(props.name);
+        self.icon.set(props.icon);
+        self.battery_percentage.set(props.battery_percentage);
+        self.class.set(props.class);
+        self.appearance.set(props.appearance);
+        self.uuids.set(props.uuids);
+        self.paired.set(props.paired);
+        self.bonded.set(props.bonded);
+        self.connected.set(props.connected);
+        self.trusted.set(props.trusted);
+        self.blocked.set(props.blocked);
+        self.wake_allowed.set(props.wake_allowed);
+        self.alias.set(props.alias);
+        self.adapter.set(props.adapter);
+        self.legacy_pairing.set(props.legacy_pairing);
+        self.cable_pairing.set(props.cable_pairing);
+        self.modalias.set(props.modalias);
+        self.rssi.set(props.rssi);
+        self.tx_power.set(props.tx_power);
+        self.manufacturer_data.set(props.manufacturer_data);
+        self.service_data.set(props.service_data);
+        self.services_resolved.set(props.services_resolved);
+        self.advertising_flags.set(props.advertising_flags);
+        self.advertising_data.set(props.advertising_data);
+        self.sets.replace(props.sets);
+        self.preferred_bearer.set(
+            props
+                .preferred_bearer
+                .map(|s| PreferredBearer::from(s.as_str())),
+        );
+    }
 }
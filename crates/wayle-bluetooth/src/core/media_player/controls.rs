@@ -0,0 +1,53 @@
+use tracing::instrument;
+use zbus::{Connection, zvariant::OwnedObjectPath};
+
+use crate::{error::Error, proxy::media_player::MediaPlayer1Proxy};
+
+pub(super) struct MediaPlayerControls;
+
+impl MediaPlayerControls {
+    #[instrument(skip(connection), fields(player = %player_path), err)]
+    pub(super) async fn play(
+        connection: &Connection,
+        player_path: &OwnedObjectPath,
+    ) -> Result<(), Error> {
+        let proxy = MediaPlayer1Proxy::new(connection, player_path).await?;
+        Ok(proxy.play().await?)
+    }
+
+    #[instrument(skip(connection), fields(player = %player_path), err)]
+    pub(super) async fn pause(
+        connection: &Connection,
+        player_path: &OwnedObjectPath,
+    ) -> Result<(), Error> {
+        let proxy = MediaPlayer1Proxy::new(connection, player_path).await?;
+        Ok(proxy.pause().await?)
+    }
+
+    #[instrument(skip(connection), fields(player = %player_path), err)]
+    pub(super) async fn stop(
+        connection: &Connection,
+        player_path: &OwnedObjectPath,
+    ) -> Result<(), Error> {
+        let proxy = MediaPlayer1Proxy::new(connection, player_path).await?;
+        Ok(proxy.stop().await?)
+    }
+
+    #[instrument(skip(connection), fields(player = %player_path), err)]
+    pub(super) async fn next(
+        connection: &Connection,
+        player_path: &OwnedObjectPath,
+    ) -> Result<(), Error> {
+        let proxy = MediaPlayer1Proxy::new(connection, player_path).await?;
+        Ok(proxy.next().await?)
+    }
+
+    #[instrument(skip(connection), fields(player = %player_path), err)]
+    pub(super) async fn previous(
+        connection: &Connection,
+        player_path: &OwnedObjectPath,
+    ) -> Result<(), Error> {
+        let proxy = MediaPlayer1Proxy::new(connection, player_path).await?;
+        Ok(proxy.previous().await?)
+    }
+}
@@ -0,0 +1,186 @@
+pub(crate) mod controls;
+pub(crate) mod monitoring;
+pub(crate) mod types;
+
+use std::{sync::Arc, time::Duration};
+
+use controls::MediaPlayerControls;
+use derive_more::Debug;
+use tokio_util::sync::CancellationToken;
+use types::MediaPlayerProperties;
+pub use types::{LiveMediaPlayerParams, MediaPlayerParams};
+use wayle_core::{Property, unwrap_dbus};
+use wayle_traits::{ModelMonitoring, Reactive};
+use zbus::{Connection, zvariant::OwnedObjectPath};
+
+use crate::{
+    error::Error,
+    proxy::media_player::MediaPlayer1Proxy,
+    types::media_player::{PlaybackStatus, TrackMetadata},
+};
+
+/// Media player a connected device exposes over AVRCP, e.g. a phone
+/// playing music through the headset profile.
+///
+/// Reached through [`Device::media_player`](crate::core::device::Device::media_player),
+/// which is only `Some` while the device exposes `org.bluez.MediaPlayer1`.
+///
+/// # Control Methods
+///
+/// - [`play()`](Self::play) / [`pause()`](Self::pause) / [`stop()`](Self::stop) - Playback
+/// - [`next()`](Self::next) / [`previous()`](Self::previous) - Track navigation
+#[derive(Debug, Clone)]
+pub struct MediaPlayer {
+    #[debug(skip)]
+    pub(crate) zbus_connection: Connection,
+    #[debug(skip)]
+    pub(crate) cancellation_token: Option<CancellationToken>,
+
+    /// D-Bus object path for this player.
+    pub object_path: OwnedObjectPath,
+
+    /// Object path of the device this player belongs to.
+    pub device: OwnedObjectPath,
+
+    /// Player name, e.g. the app playing on the phone.
+    pub name: Property<Option<String>>,
+
+    /// Current playback state.
+    pub status: Property<PlaybackStatus>,
+
+    /// Playback position within the current track.
+    pub position: Property<Duration>,
+
+    /// Metadata of the current track.
+    pub track: Property<TrackMetadata>,
+}
+
+impl PartialEq for MediaPlayer {
+    fn eq(&self, other: &Self) -> bool {
+        self.object_path == other.object_path
+    }
+}
+
+impl Reactive for MediaPlayer {
+    type Error = Error;
+    type Context<'a> = MediaPlayerParams<'a>;
+    type LiveContext<'a> = LiveMediaPlayerParams<'a>;
+
+    async fn get(context: Self::Context<'_>) -> Result<Self, Self::Error> {
+        let proxy = MediaPlayer1Proxy::new(context.connection, &context.path).await?;
+        let props = Self::fetch_properties(&proxy).await?;
+        Ok(Self::from_properties(
+            props,
+            context.connection,
+            context.path,
+            None,
+        ))
+    }
+
+    async fn get_live(context: Self::LiveContext<'_>) -> Result<Arc<Self>, Self::Error> {
+        let proxy = MediaPlayer1Proxy::new(context.connection, &context.path).await?;
+        let props = Self::fetch_properties(&proxy).await?;
+        let player = Arc::new(Self::from_properties(
+            props,
+            context.connection,
+            context.path.clone(),
+            Some(context.cancellation_token.child_token()),
+        ));
+
+        player.clone().start_monitoring().await?;
+
+        Ok(player)
+    }
+}
+
+impl MediaPlayer {
+    /// Resumes or starts playback.
+    ///
+    /// # Errors
+    ///
+    /// - `NotSupported` - Player does not support this command
+    /// - `Failed` - Operation failed
+    pub async fn play(&self) -> Result<(), Error> {
+        MediaPlayerControls::play(&self.zbus_connection, &self.object_path).await
+    }
+
+    /// Pauses playback.
+    ///
+    /// # Errors
+    ///
+    /// - `NotSupported` - Player does not support this command
+    /// - `Failed` - Operation failed
+    pub async fn pause(&self) -> Result<(), Error> {
+        MediaPlayerControls::pause(&self.zbus_connection, &self.object_path).await
+    }
+
+    /// Stops playback.
+    ///
+    /// # Errors
+    ///
+    /// - `NotSupported` - Player does not support this command
+    /// - `Failed` - Operation failed
+    pub async fn stop(&self) -> Result<(), Error> {
+        MediaPlayerControls::stop(&self.zbus_connection, &self.object_path).await
+    }
+
+    /// Skips to the next track.
+    ///
+    /// # Errors
+    ///
+    /// - `NotSupported` - Player does not support this command
+    /// - `Failed` - Operation failed
+    pub async fn next(&self) -> Result<(), Error> {
+        MediaPlayerControls::next(&self.zbus_connection, &self.object_path).await
+    }
+
+    /// Goes back to the previous track.
+    ///
+    /// # Errors
+    ///
+    /// - `NotSupported` - Player does not support this command
+    /// - `Failed` - Operation failed
+    pub async fn previous(&self) -> Result<(), Error> {
+        MediaPlayerControls::previous(&self.zbus_connection, &self.object_path).await
+    }
+
+    async fn fetch_properties(
+        proxy: &MediaPlayer1Proxy<'_>,
+    ) -> Result<MediaPlayerProperties, Error> {
+        let (device, name, status, position, track) = tokio::join!(
+            proxy.device(),
+            proxy.name(),
+            proxy.status(),
+            proxy.position(),
+            proxy.track(),
+        );
+
+        Ok(MediaPlayerProperties {
+            device: unwrap_dbus!(device),
+            name: name.ok(),
+            status: unwrap_dbus!(status),
+            position: position.unwrap_or_default(),
+            track: track
+                .map(|track| TrackMetadata::from_dbus(&track))
+                .unwrap_or_default(),
+        })
+    }
+
+    fn from_properties(
+        props: MediaPlayerProperties,
+        connection: &Connection,
+        object_path: OwnedObjectPath,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Self {
+        Self {
+            zbus_connection: connection.clone(),
+            cancellation_token,
+            object_path,
+            device: props.device,
+            name: Property::new(props.name),
+            status: Property::new(PlaybackStatus::from(props.status.as_str())),
+            position: Property::new(Duration::from_millis(u64::from(props.position))),
+            track: Property::new(props.track),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use futures::StreamExt;
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+use wayle_traits::ModelMonitoring;
+
+use super::MediaPlayer;
+use crate::{
+    Error,
+    proxy::media_player::MediaPlayer1Proxy,
+    types::media_player::{PlaybackStatus, TrackMetadata},
+};
+
+impl ModelMonitoring for MediaPlayer {
+    type Error = Error;
+
+    async fn start_monitoring(self: Arc<Self>) -> Result<(), Self::Error> {
+        let proxy = MediaPlayer1Proxy::new(&self.zbus_connection, self.object_path.clone())
+            .await
+            .map_err(Error::Dbus)?;
+
+        let Some(ref cancellation_token) = self.cancellation_token else {
+            return Err(Error::NoCancellationToken);
+        };
+
+        let cancel_token = cancellation_token.clone();
+        let weak_self = Arc::downgrade(&self);
+
+        tokio::spawn(async move {
+            monitor(weak_self, proxy, cancel_token).await;
+        });
+
+        Ok(())
+    }
+}
+
+async fn monitor(
+    weak_player: Weak<MediaPlayer>,
+    proxy: MediaPlayer1Proxy<'static>,
+    cancellation_token: CancellationToken,
+) {
+    let mut name_changed = proxy.receive_name_changed().await;
+    let mut status_changed = proxy.receive_status_changed().await;
+    let mut position_changed = proxy.receive_position_changed().await;
+    let mut track_changed = proxy.receive_track_changed().await;
+
+    loop {
+        let Some(player) = weak_player.upgrade() else {
+            return;
+        };
+        tokio::select! {
+            _ = cancellation_token.cancelled() => {
+                debug!("Media player monitoring cancelled for {}", player.object_path);
+                return;
+            }
+            Some(change) = name_changed.next() => {
+                player.name.set(change.get().await.ok());
+            }
+            Some(change) = status_changed.next() => {
+                if let Ok(value) = change.get().await {
+                    player.status.set(PlaybackStatus::from(value.as_str()));
+                }
+            }
+            Some(change) = position_changed.next() => {
+                if let Ok(value) = change.get().await {
+                    player.position.set(Duration::from_millis(u64::from(value)));
+                }
+            }
+            Some(change) = track_changed.next() => {
+                if let Ok(value) = change.get().await {
+                    player.track.set(TrackMetadata::from_dbus(&value));
+                }
+            }
+            else => {
+                debug!("All property streams ended for media player {}", player.object_path);
+                break;
+            }
+        }
+    }
+}
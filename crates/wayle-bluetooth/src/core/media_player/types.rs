@@ -0,0 +1,25 @@
+use tokio_util::sync::CancellationToken;
+use zbus::{Connection, zvariant::OwnedObjectPath};
+
+use crate::types::media_player::TrackMetadata;
+
+#[doc(hidden)]
+pub struct MediaPlayerParams<'a> {
+    pub connection: &'a Connection,
+    pub path: OwnedObjectPath,
+}
+
+#[doc(hidden)]
+pub struct LiveMediaPlayerParams<'a> {
+    pub connection: &'a Connection,
+    pub path: OwnedObjectPath,
+    pub cancellation_token: &'a CancellationToken,
+}
+
+pub(crate) struct MediaPlayerProperties {
+    pub device: OwnedObjectPath,
+    pub name: Option<String>,
+    pub status: String,
+    pub position: u32,
+    pub track: TrackMetadata,
+}
@@ -2,3 +2,5 @@
 pub mod adapter;
 /// Bluetooth device implementation
 pub mod device;
+/// Bluetooth media player implementation
+pub mod media_player;
@@ -18,7 +18,11 @@ use super::{
         device::{Device, LiveDeviceParams},
     },
     error::Error,
-    types::{ADAPTER_INTERFACE, BLUEZ_SERVICE, DEVICE_INTERFACE, ServiceNotification},
+    monitoring::{handle_media_player_added, handle_media_transport_added},
+    types::{
+        ADAPTER_INTERFACE, BLUEZ_SERVICE, DEVICE_INTERFACE, MEDIA_PLAYER_INTERFACE,
+        MEDIA_TRANSPORT_INTERFACE, ServiceNotification,
+    },
 };
 
 pub(crate) struct BluetoothDiscovery {
@@ -44,8 +48,17 @@ impl BluetoothDiscovery {
 
         let mut adapters = Vec::new();
         let mut devices = Vec::new();
+        let mut player_paths = Vec::new();
+        let mut transport_paths = Vec::new();
 
         for (object_path, interfaces) in managed_objects {
+            if interfaces.contains_key(MEDIA_PLAYER_INTERFACE) {
+                player_paths.push(object_path.clone());
+            }
+            if interfaces.contains_key(MEDIA_TRANSPORT_INTERFACE) {
+                transport_paths.push(object_path.clone());
+            }
+
             Self::extract_adapter(
                 &mut adapters,
                 connection,
@@ -65,6 +78,14 @@ impl BluetoothDiscovery {
             .await;
         }
 
+        for player_path in player_paths {
+            handle_media_player_added(connection, &devices, player_path).await;
+        }
+
+        for transport_path in transport_paths {
+            handle_media_transport_added(connection, &devices, transport_path).await;
+        }
+
         let primary_adapter = adapters
             .iter()
             .find(|adapter| adapter.powered.get())
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, time::Duration};
 
 #[derive(Debug)]
 pub(crate) struct ResponderDropped;
@@ -74,4 +74,19 @@ pub enum Error {
         #[source]
         source: Box<dyn std::error::Error + Send + Sync>,
     },
+    /// Operation did not finish in time and was abandoned.
+    #[error("cannot {operation}: timed out after {timeout:?}")]
+    Timeout {
+        /// The operation that timed out.
+        operation: &'static str,
+        /// How long the operation was allowed to run.
+        timeout: Duration,
+    },
+
+    /// Operation was cancelled before it finished.
+    #[error("cannot {operation}: cancelled")]
+    Cancelled {
+        /// The operation that was cancelled.
+        operation: &'static str,
+    },
 }
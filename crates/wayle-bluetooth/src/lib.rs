@@ -59,6 +59,72 @@
 //! # }
 //! ```
 //!
+//! # Pairing Agent
+//!
+//! The service registers itself as the default BlueZ agent, so
+//! [`Device::pair()`](core::device::Device::pair) prompts arrive as
+//! [`PairingRequest`](types::agent::PairingRequest)s on `pairing_request`.
+//! Answer each with the matching `provide_*` method, or reject it with
+//! [`cancel_pending_request()`](BluetoothService::cancel_pending_request).
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use wayle_bluetooth::{BluetoothService, types::agent::PairingRequest};
+//!
+//! # async fn example() -> Result<(), wayle_bluetooth::Error> {
+//! # let bt = BluetoothService::new().await?;
+//! let mut requests = bt.pairing_request.watch();
+//! while let Some(request) = requests.next().await {
+//!     match request {
+//!         Some(PairingRequest::DisplayPasskey { passkey, .. }) => {
+//!             println!("Type {passkey:06} on the device");
+//!         }
+//!         Some(PairingRequest::RequestConfirmation { passkey, .. }) => {
+//!             println!("Confirming {passkey:06}");
+//!             bt.provide_confirmation(true).await?;
+//!         }
+//!         Some(PairingRequest::RequestPinCode { .. }) => {
+//!             bt.provide_pin(String::from("0000")).await?;
+//!         }
+//!         Some(_) => bt.cancel_pending_request().await,
+//!         None => {}
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Phone Media Control
+//!
+//! A connected phone that exposes its music app over AVRCP shows up as
+//! [`Device::media_player`](core::device::Device::media_player). It stays
+//! `None` for devices without the `org.bluez.MediaPlayer1` interface.
+//!
+//! ```rust,no_run
+//! # use wayle_bluetooth::BluetoothService;
+//! # async fn example() -> Result<(), wayle_bluetooth::Error> {
+//! # let bt = BluetoothService::new().await?;
+//! for device in bt.devices.get().iter() {
+//!     let Some(player) = device.media_player.get() else {
+//!         continue;
+//!     };
+//!
+//!     let track = player.track.get();
+//!     println!("{:?} - {:?}", track.artist, track.title);
+//!     player.next().await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Audio Codec
+//!
+//! While audio is streaming to headphones or speakers over A2DP,
+//! [`Device::active_codec`](core::device::Device::active_codec) holds the
+//! negotiated codec (SBC, AAC, aptX, LDAC, ...). It is `None` when nothing
+//! is playing. The codec can only be observed here; choosing one is up to
+//! the audio server (PipeWire or PulseAudio).
+//!
 //! # Reactive Properties
 //!
 //! All fields are [`Property<T>`](wayle_core::Property):
@@ -82,8 +148,13 @@
 //! - [`enable()`](BluetoothService::enable) / [`disable()`](BluetoothService::disable) - Power adapter
 //! - [`start_discovery()`](BluetoothService::start_discovery) / [`stop_discovery()`](BluetoothService::stop_discovery) - Scan
 //! - [`start_timed_discovery()`](BluetoothService::start_timed_discovery) - Scan with timeout
+//! - [`provide_pin()`](BluetoothService::provide_pin) / [`provide_passkey()`](BluetoothService::provide_passkey) - Answer input requests
+//! - [`provide_confirmation()`](BluetoothService::provide_confirmation) / [`provide_authorization()`](BluetoothService::provide_authorization) / [`provide_service_authorization()`](BluetoothService::provide_service_authorization) - Accept or reject
+//! - [`cancel_pending_request()`](BluetoothService::cancel_pending_request) - Reject whatever is pending
+//!
+//! Device-level: `connect()`, `connect_timeout()`, `disconnect()`, `pair()`, `forget()`
 //!
-//! Device-level: `connect()`, `disconnect()`, `pair()`, `forget()`
+//! Media player: `play()`, `pause()`, `stop()`, `next()`, `previous()`
 
 mod agent;
 /// Bluetooth domain models for adapters and devices.
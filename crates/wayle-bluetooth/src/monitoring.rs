@@ -1,9 +1,9 @@
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 use futures::StreamExt;
 use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
-use tracing::debug;
+use tracing::{debug, warn};
 use wayle_core::{Property, PropertyStream, ROOT_PATH, remove_and_cancel};
 use wayle_traits::{Reactive, ServiceMonitoring};
 use zbus::{Connection, fdo::ObjectManagerProxy, zvariant::OwnedObjectPath};
@@ -12,14 +12,19 @@ use super::{
     core::{
         adapter::{Adapter, LiveAdapterParams},
         device::{Device, LiveDeviceParams},
+        media_player::{LiveMediaPlayerParams, MediaPlayer},
     },
     error::Error,
     service::BluetoothService,
     types::{
-        ADAPTER_INTERFACE, BATTERY_INTERFACE, BLUEZ_SERVICE, DEVICE_INTERFACE, ServiceNotification,
+        A2DP_SOURCE_UUID, ADAPTER_INTERFACE, BATTERY_INTERFACE, BLUEZ_SERVICE, DEVICE_INTERFACE,
+        MEDIA_PLAYER_INTERFACE, MEDIA_TRANSPORT_INTERFACE, ServiceNotification,
+        device::BluetoothCodec,
     },
 };
-use crate::proxy::battery::Battery1Proxy;
+use crate::proxy::{
+    battery::Battery1Proxy, media_player::MediaPlayer1Proxy, media_transport::MediaTransport1Proxy,
+};
 
 impl ServiceMonitoring for BluetoothService {
     type Error = Error;
@@ -99,7 +104,9 @@ async fn monitor_devices(
                     let interfaces = &args.interfaces_and_properties;
                     let device_added = interfaces.contains_key(DEVICE_INTERFACE);
                     let battery_added = interfaces.contains_key(BATTERY_INTERFACE);
-                    if !device_added && !battery_added {
+                    let player_added = interfaces.contains_key(MEDIA_PLAYER_INTERFACE);
+                    let transport_added = interfaces.contains_key(MEDIA_TRANSPORT_INTERFACE);
+                    if !device_added && !battery_added && !player_added && !transport_added {
                         continue;
                     }
 
@@ -116,7 +123,20 @@ async fn monitor_devices(
                         .await;
                     }
                     if battery_added {
-                        handle_device_battery_added(&connection, &devices_prop, object_path).await;
+                        handle_device_battery_added(&connection, &devices_prop, object_path.clone())
+                            .await;
+                    }
+                    if player_added {
+                        handle_media_player_added(
+                            &connection,
+                            &devices_prop.get(),
+                            object_path.clone(),
+                        )
+                        .await;
+                    }
+                    if transport_added {
+                        handle_media_transport_added(&connection, &devices_prop.get(), object_path)
+                            .await;
                     }
                 }
                 Some(removed) = interfaces_removed.next() => {
@@ -126,7 +146,11 @@ async fn monitor_devices(
                     let device_removed = args.interfaces.iter().any(|i| i.as_str() == DEVICE_INTERFACE);
                     let battery_removed =
                         args.interfaces.iter().any(|i| i.as_str() == BATTERY_INTERFACE);
-                    if !device_removed && !battery_removed {
+                    let player_removed =
+                        args.interfaces.iter().any(|i| i.as_str() == MEDIA_PLAYER_INTERFACE);
+                    let transport_removed =
+                        args.interfaces.iter().any(|i| i.as_str() == MEDIA_TRANSPORT_INTERFACE);
+                    if !device_removed && !battery_removed && !player_removed && !transport_removed {
                         continue;
                     }
                     let object_path: OwnedObjectPath = args.object_path.into();
@@ -135,6 +159,10 @@ async fn monitor_devices(
                         remove_and_cancel!(devices_prop, object_path);
                     } else if battery_removed {
                         handle_device_battery_removed(&devices_prop, object_path);
+                    } else if player_removed {
+                        handle_media_player_removed(&devices_prop, &object_path);
+                    } else if transport_removed {
+                        handle_media_transport_removed(&devices_prop, &object_path);
                     }
                 }
             }
@@ -418,6 +446,171 @@ fn handle_device_battery_removed(
     }
 }
 
+/// Loads the player at `object_path` and attaches it to the device it
+/// belongs to. The player stops monitoring when that device is removed.
+pub(crate) async fn handle_media_player_added(
+    connection: &Connection,
+    devices: &[Arc<Device>],
+    object_path: OwnedObjectPath,
+) {
+    let Ok(proxy) = MediaPlayer1Proxy::new(connection, &object_path).await else {
+        return;
+    };
+    let Ok(device_path) = proxy.device().await else {
+        return;
+    };
+    let Some(device) = devices
+        .iter()
+        .find(|device| device.object_path == device_path)
+    else {
+        return;
+    };
+    let Some(device_token) = device.cancellation_token.as_ref() else {
+        return;
+    };
+
+    match MediaPlayer::get_live(LiveMediaPlayerParams {
+        connection,
+        path: object_path.clone(),
+        cancellation_token: device_token,
+    })
+    .await
+    {
+        Ok(player) => device.media_player.set(Some(player)),
+        Err(error) => {
+            warn!(error = %error, path = %object_path, "cannot create media player");
+        }
+    }
+}
+
+fn handle_media_player_removed(
+    devices: &Property<Vec<Arc<Device>>>,
+    object_path: &OwnedObjectPath,
+) {
+    for device in devices.get() {
+        let Some(player) = device.media_player.get() else {
+            continue;
+        };
+        if player.object_path != *object_path {
+            continue;
+        }
+
+        if let Some(token) = player.cancellation_token.as_ref() {
+            token.cancel();
+        }
+        device.media_player.set(None);
+    }
+}
+
+/// Follows the A2DP transport at `object_path` and keeps the codec of the
+/// device it streams to up to date. Transports for other profiles, or for
+/// audio the device sends to this host, are ignored.
+pub(crate) async fn handle_media_transport_added(
+    connection: &Connection,
+    devices: &[Arc<Device>],
+    object_path: OwnedObjectPath,
+) {
+    let Ok(proxy) = MediaTransport1Proxy::new(connection, object_path.clone()).await else {
+        return;
+    };
+    if !proxy
+        .uuid()
+        .await
+        .is_ok_and(|uuid| uuid.eq_ignore_ascii_case(A2DP_SOURCE_UUID))
+    {
+        return;
+    }
+    let Ok(device_path) = proxy.device().await else {
+        return;
+    };
+    let Some(device) = devices
+        .iter()
+        .find(|device| device.object_path == device_path)
+    else {
+        return;
+    };
+    let Some(device_token) = device.cancellation_token.clone() else {
+        return;
+    };
+
+    device.transport_path.set(Some(object_path.clone()));
+    device.active_codec.set(active_codec(&proxy).await);
+
+    tokio::spawn(monitor_media_transport(
+        Arc::downgrade(device),
+        device.transport_path.clone(),
+        proxy,
+        object_path,
+        device_token,
+    ));
+}
+
+async fn monitor_media_transport(
+    weak_device: Weak<Device>,
+    transport_path: Property<Option<OwnedObjectPath>>,
+    proxy: MediaTransport1Proxy<'static>,
+    object_path: OwnedObjectPath,
+    cancellation_token: CancellationToken,
+) {
+    let mut transport_changed = transport_path.watch();
+    let mut state_changed = proxy.receive_state_changed().await;
+    let mut codec_changed = proxy.receive_codec_changed().await;
+    let mut configuration_changed = proxy.receive_configuration_changed().await;
+
+    loop {
+        tokio::select! {
+            _ = cancellation_token.cancelled() => {
+                debug!("Media transport monitoring cancelled for {object_path}");
+                return;
+            }
+            Some(path) = transport_changed.next() => {
+                if path.as_ref() != Some(&object_path) {
+                    return;
+                }
+                continue;
+            }
+            Some(_) = state_changed.next() => {}
+            Some(_) = codec_changed.next() => {}
+            Some(_) = configuration_changed.next() => {}
+            else => {
+                debug!("All property streams ended for media transport {object_path}");
+                return;
+            }
+        }
+
+        let Some(device) = weak_device.upgrade() else {
+            return;
+        };
+        device.active_codec.set(active_codec(&proxy).await);
+    }
+}
+
+/// Codec of the transport, or `None` while nothing is being streamed.
+async fn active_codec(proxy: &MediaTransport1Proxy<'_>) -> Option<BluetoothCodec> {
+    if proxy.state().await.ok()? != "active" {
+        return None;
+    }
+
+    let codec = proxy.codec().await.ok()?;
+    let configuration = proxy.configuration().await.unwrap_or_default();
+
+    Some(BluetoothCodec::from_a2dp(codec, &configuration))
+}
+
+fn handle_media_transport_removed(
+    devices: &Property<Vec<Arc<Device>>>,
+    object_path: &OwnedObjectPath,
+) {
+    for device in devices.get() {
+        if device.transport_path.get().as_ref() != Some(object_path) {
+            continue;
+        }
+
+        device.transport_path.set(None);
+        device.active_codec.set(None);
+    }
+}
+
 async fn handle_adapter_added(
     connection: &Connection,
     cancellation_token: CancellationToken,
@@ -0,0 +1,35 @@
+#![allow(missing_docs)]
+use std::collections::HashMap;
+
+use zbus::{
+    Result, proxy,
+    zvariant::{OwnedObjectPath, OwnedValue},
+};
+
+#[proxy(interface = "org.bluez.MediaPlayer1", default_service = "org.bluez")]
+pub(crate) trait MediaPlayer1 {
+    async fn play(&self) -> Result<()>;
+
+    async fn pause(&self) -> Result<()>;
+
+    async fn stop(&self) -> Result<()>;
+
+    async fn next(&self) -> Result<()>;
+
+    async fn previous(&self) -> Result<()>;
+
+    #[zbus(property)]
+    fn status(&self) -> Result<String>;
+
+    #[zbus(property)]
+    fn position(&self) -> Result<u32>;
+
+    #[zbus(property)]
+    fn track(&self) -> Result<HashMap<String, OwnedValue>>;
+
+    #[zbus(property)]
+    fn device(&self) -> Result<OwnedObjectPath>;
+
+    #[zbus(property)]
+    fn name(&self) -> Result<String>;
+}
@@ -0,0 +1,20 @@
+#![allow(missing_docs)]
+use zbus::{Result, proxy, zvariant::OwnedObjectPath};
+
+#[proxy(interface = "org.bluez.MediaTransport1", default_service = "org.bluez")]
+pub(crate) trait MediaTransport1 {
+    #[zbus(property)]
+    fn device(&self) -> Result<OwnedObjectPath>;
+
+    #[zbus(property, name = "UUID")]
+    fn uuid(&self) -> Result<String>;
+
+    #[zbus(property)]
+    fn codec(&self) -> Result<u8>;
+
+    #[zbus(property)]
+    fn configuration(&self) -> Result<Vec<u8>>;
+
+    #[zbus(property)]
+    fn state(&self) -> Result<String>;
+}
@@ -8,3 +8,7 @@ pub mod agent_manager;
 pub mod battery;
 /// Bluetooth device proxy
 pub mod device;
+/// Bluetooth media player proxy
+pub mod media_player;
+/// Bluetooth media transport proxy
+pub mod media_transport;
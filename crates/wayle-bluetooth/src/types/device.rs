@@ -85,6 +85,83 @@ impl Display for DisconnectReason {
     }
 }
 
+/// Audio codec in use on a device's A2DP stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BluetoothCodec {
+    /// Low Complexity Subband Coding, supported by every A2DP device
+    Sbc,
+    /// MPEG-1,2 Audio (MP3)
+    Mpeg12,
+    /// MPEG-2,4 Advanced Audio Coding
+    Aac,
+    /// Qualcomm aptX
+    Aptx,
+    /// Qualcomm aptX HD
+    AptxHd,
+    /// Sony LDAC
+    Ldac,
+    /// Any other standard or vendor codec
+    Unknown,
+}
+
+const A2DP_CODEC_SBC: u8 = 0x00;
+const A2DP_CODEC_MPEG12: u8 = 0x01;
+const A2DP_CODEC_AAC: u8 = 0x02;
+const A2DP_CODEC_VENDOR: u8 = 0xFF;
+
+const APTX_VENDOR: (u32, u16) = (0x0000_004F, 0x0001);
+const APTX_HD_VENDOR: (u32, u16) = (0x0000_00D7, 0x0024);
+const LDAC_VENDOR: (u32, u16) = (0x0000_012D, 0x00AA);
+
+impl BluetoothCodec {
+    /// Maps an A2DP codec id and its `Configuration` blob, as exposed on
+    /// `org.bluez.MediaTransport1`, to a codec.
+    ///
+    /// Vendor codecs (id `0xFF`) are told apart by the vendor and codec ids
+    /// at the start of the configuration.
+    pub fn from_a2dp(codec: u8, configuration: &[u8]) -> Self {
+        match codec {
+            A2DP_CODEC_SBC => Self::Sbc,
+            A2DP_CODEC_MPEG12 => Self::Mpeg12,
+            A2DP_CODEC_AAC => Self::Aac,
+            A2DP_CODEC_VENDOR => match vendor_codec(configuration) {
+                Some(APTX_VENDOR) => Self::Aptx,
+                Some(APTX_HD_VENDOR) => Self::AptxHd,
+                Some(LDAC_VENDOR) => Self::Ldac,
+                _ => Self::Unknown,
+            },
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Little-endian vendor id and codec id heading a vendor codec's
+/// configuration.
+fn vendor_codec(configuration: &[u8]) -> Option<(u32, u16)> {
+    let [v0, v1, v2, v3, c0, c1, ..] = *configuration else {
+        return None;
+    };
+
+    Some((
+        u32::from_le_bytes([v0, v1, v2, v3]),
+        u16::from_le_bytes([c0, c1]),
+    ))
+}
+
+impl Display for BluetoothCodec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Sbc => write!(f, "SBC"),
+            Self::Mpeg12 => write!(f, "MP3"),
+            Self::Aac => write!(f, "AAC"),
+            Self::Aptx => write!(f, "aptX"),
+            Self::AptxHd => write!(f, "aptX HD"),
+            Self::Ldac => write!(f, "LDAC"),
+            Self::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +215,43 @@ mod tests {
         assert_eq!(DisconnectReason::from("unknown"), DisconnectReason::Unknown);
         assert_eq!(DisconnectReason::from(""), DisconnectReason::Unknown);
     }
+
+    #[test]
+    fn bluetooth_codec_from_a2dp_maps_standard_codecs() {
+        assert_eq!(BluetoothCodec::from_a2dp(0x00, &[]), BluetoothCodec::Sbc);
+        assert_eq!(BluetoothCodec::from_a2dp(0x01, &[]), BluetoothCodec::Mpeg12);
+        assert_eq!(BluetoothCodec::from_a2dp(0x02, &[]), BluetoothCodec::Aac);
+        assert_eq!(
+            BluetoothCodec::from_a2dp(0x04, &[]),
+            BluetoothCodec::Unknown
+        );
+    }
+
+    #[test]
+    fn bluetooth_codec_from_a2dp_reads_vendor_ids() {
+        assert_eq!(
+            BluetoothCodec::from_a2dp(0xFF, &[0x4F, 0x00, 0x00, 0x00, 0x01, 0x00, 0x22]),
+            BluetoothCodec::Aptx
+        );
+        assert_eq!(
+            BluetoothCodec::from_a2dp(0xFF, &[0xD7, 0x00, 0x00, 0x00, 0x24, 0x00, 0x22]),
+            BluetoothCodec::AptxHd
+        );
+        assert_eq!(
+            BluetoothCodec::from_a2dp(0xFF, &[0x2D, 0x01, 0x00, 0x00, 0xAA, 0x00, 0x34, 0x07]),
+            BluetoothCodec::Ldac
+        );
+    }
+
+    #[test]
+    fn bluetooth_codec_from_a2dp_defaults_to_unknown_vendor() {
+        assert_eq!(
+            BluetoothCodec::from_a2dp(0xFF, &[0x4F, 0x00, 0x00, 0x00, 0x02, 0x00]),
+            BluetoothCodec::Unknown
+        );
+        assert_eq!(
+            BluetoothCodec::from_a2dp(0xFF, &[0x4F, 0x00]),
+            BluetoothCodec::Unknown
+        );
+    }
 }
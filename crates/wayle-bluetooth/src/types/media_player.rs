@@ -0,0 +1,119 @@
+use std::{collections::HashMap, time::Duration};
+
+use zbus::zvariant::OwnedValue;
+
+/// Playback state reported by a remote media player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackStatus {
+    /// Playing
+    Playing,
+    /// Stopped
+    #[default]
+    Stopped,
+    /// Paused
+    Paused,
+    /// Seeking forward
+    ForwardSeek,
+    /// Seeking backward
+    ReverseSeek,
+    /// Player reported an error
+    Error,
+}
+
+impl From<&str> for PlaybackStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "playing" => Self::Playing,
+            "paused" => Self::Paused,
+            "forward-seek" => Self::ForwardSeek,
+            "reverse-seek" => Self::ReverseSeek,
+            "error" => Self::Error,
+            _ => Self::Stopped,
+        }
+    }
+}
+
+/// Metadata of the track a remote media player is on.
+///
+/// Phones fill in what they know; any field may be missing.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TrackMetadata {
+    /// Track title
+    pub title: Option<String>,
+    /// Track artist
+    pub artist: Option<String>,
+    /// Track album
+    pub album: Option<String>,
+    /// Track genre
+    pub genre: Option<String>,
+    /// Number of tracks in the album
+    pub number_of_tracks: Option<u32>,
+    /// Position of the track in the album
+    pub track_number: Option<u32>,
+    /// Track length
+    pub duration: Option<Duration>,
+}
+
+impl TrackMetadata {
+    pub(crate) fn from_dbus(track: &HashMap<String, OwnedValue>) -> Self {
+        let string = |key: &str| {
+            track
+                .get(key)
+                .and_then(|value| String::try_from(value.clone()).ok())
+                .filter(|value| !value.is_empty())
+        };
+        let number = |key: &str| track.get(key).and_then(|value| u32::try_from(value).ok());
+
+        Self {
+            title: string("Title"),
+            artist: string("Artist"),
+            album: string("Album"),
+            genre: string("Genre"),
+            number_of_tracks: number("NumberOfTracks"),
+            track_number: number("TrackNumber"),
+            duration: number("Duration").map(|ms| Duration::from_millis(u64::from(ms))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zbus::zvariant::Str;
+
+    use super::*;
+
+    #[test]
+    fn playback_status_from_str_handles_all_variants() {
+        assert_eq!(PlaybackStatus::from("playing"), PlaybackStatus::Playing);
+        assert_eq!(PlaybackStatus::from("paused"), PlaybackStatus::Paused);
+        assert_eq!(PlaybackStatus::from("stopped"), PlaybackStatus::Stopped);
+        assert_eq!(
+            PlaybackStatus::from("forward-seek"),
+            PlaybackStatus::ForwardSeek
+        );
+        assert_eq!(
+            PlaybackStatus::from("reverse-seek"),
+            PlaybackStatus::ReverseSeek
+        );
+        assert_eq!(PlaybackStatus::from("error"), PlaybackStatus::Error);
+        assert_eq!(PlaybackStatus::from(""), PlaybackStatus::Stopped);
+    }
+
+    #[test]
+    fn track_metadata_reads_known_keys() {
+        let track = HashMap::from([
+            (String::from("Title"), OwnedValue::from(Str::from("Song"))),
+            (String::from("Artist"), OwnedValue::from(Str::from(""))),
+            (String::from("TrackNumber"), OwnedValue::from(3u32)),
+            (String::from("Duration"), OwnedValue::from(185_000u32)),
+        ]);
+
+        let metadata = TrackMetadata::from_dbus(&track);
+
+        assert_eq!(metadata.title.as_deref(), Some("Song"));
+        assert_eq!(metadata.artist, None);
+        assert_eq!(metadata.album, None);
+        assert_eq!(metadata.track_number, Some(3));
+        assert_eq!(metadata.duration, Some(Duration::from_secs(185)));
+    }
+}
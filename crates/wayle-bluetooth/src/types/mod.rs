@@ -4,12 +4,20 @@ pub mod adapter;
 pub mod agent;
 /// Bluetooth device type definitions
 pub mod device;
+/// Bluetooth media player type definitions
+pub mod media_player;
 
 pub(crate) const ADAPTER_INTERFACE: &str = "org.bluez.Adapter1";
 pub(crate) const DEVICE_INTERFACE: &str = "org.bluez.Device1";
 pub(crate) const BATTERY_INTERFACE: &str = "org.bluez.Battery1";
+pub(crate) const MEDIA_PLAYER_INTERFACE: &str = "org.bluez.MediaPlayer1";
+pub(crate) const MEDIA_TRANSPORT_INTERFACE: &str = "org.bluez.MediaTransport1";
 pub(crate) const BLUEZ_SERVICE: &str = "org.bluez";
 
+/// Profile of a transport streaming from this host to a remote audio sink,
+/// such as headphones or speakers.
+pub(crate) const A2DP_SOURCE_UUID: &str = "0000110a-0000-1000-8000-00805f9b34fb";
+
 /// Bluetooth UUID represented as a string.
 #[allow(clippy::upper_case_acronyms)]
 pub type UUID = String;
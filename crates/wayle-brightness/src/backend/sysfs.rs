@@ -30,7 +30,14 @@ pub(crate) fn enumerate() -> Vec<BacklightInfo> {
     dir.filter_map(|entry| {
         let entry = entry.ok()?;
         let name = entry.file_name().to_str()?.to_owned();
-        read_device(&name).ok()
+
+        match read_device(&name) {
+            Ok(info) => Some(info),
+            Err(err) => {
+                warn!(error = %err, device = name, "cannot read backlight device, skipping");
+                None
+            }
+        }
     })
     .collect()
 }
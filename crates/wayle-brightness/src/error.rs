@@ -1,4 +1,4 @@
-use std::io;
+use std::io::{self, ErrorKind};
 
 use zbus::Error as ZbusError;
 
@@ -51,3 +51,45 @@ pub enum Error {
     #[error("no backlight devices found")]
     NoDevices,
 }
+
+impl Error {
+    /// Whether the failure came from missing sysfs permissions.
+    ///
+    /// Writes fall back to sysfs when logind is unavailable, which needs
+    /// `video` group membership. Callers can use this to stop offering
+    /// brightness control instead of retrying.
+    pub fn is_permission_denied(&self) -> bool {
+        match self {
+            Self::SysfsRead { source, .. }
+            | Self::SysfsWrite { source, .. }
+            | Self::WatchFailed { source, .. } => source.kind() == ErrorKind::PermissionDenied,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permission_denied_write_is_detected() {
+        let err = Error::SysfsWrite {
+            path: String::from("/sys/class/backlight/intel_backlight/brightness"),
+            source: io::Error::from(ErrorKind::PermissionDenied),
+        };
+
+        assert!(err.is_permission_denied());
+    }
+
+    #[test]
+    fn other_failures_are_not_permission_errors() {
+        let err = Error::SysfsWrite {
+            path: String::from("/sys/class/backlight/intel_backlight/brightness"),
+            source: io::Error::from(ErrorKind::NotFound),
+        };
+
+        assert!(!err.is_permission_denied());
+        assert!(!Error::CommandChannelDisconnected.is_permission_denied());
+    }
+}
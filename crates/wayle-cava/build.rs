@@ -13,6 +13,7 @@ const REQUIRED_VERSION: &str = "0.10.6";
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=wrapper.h");
+    println!("cargo:rustc-check-cfg=cfg(cava_pulse)");
 
     #[cfg(feature = "vendored")]
     let include_paths = build_vendored();
@@ -68,6 +69,7 @@ fn build_vendored() -> Vec<PathBuf> {
     }
 
     if let Some(ref pulse_lib) = pulse {
+        println!("cargo:rustc-cfg=cava_pulse");
         build.define("PULSE", None);
         for path in &pulse_lib.include_paths {
             build.include(path);
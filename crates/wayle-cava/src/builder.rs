@@ -120,6 +120,8 @@ impl CavaServiceBuilder {
     /// Sets the audio input method/backend.
     ///
     /// Determines which audio system to use for capturing audio (PipeWire, PulseAudio, ALSA, etc.).
+    /// Defaults to PipeWire. Backends that read from a path or device take it from
+    /// [`source`](Self::source).
     pub fn input(mut self, input: InputMethod) -> Self {
         self.input = input;
         self
@@ -171,7 +173,9 @@ impl CavaServiceBuilder {
     /// - `samplerate` is not greater than 2 * `high_cutoff` (Nyquist theorem)
     /// - `noise_reduction` is not in range 0.0-1.0
     /// - `monstercat` is negative
-    /// - Audio initialization fails or input method is unavailable
+    /// - `input` isn't built into libcava ([`Error::UnsupportedInput`])
+    /// - `input` is FIFO and `source` isn't an existing path
+    /// - Audio initialization fails
     #[instrument(skip(self))]
     pub async fn build(self) -> Result<CavaService, Error> {
         if self.low_cutoff == 0 {
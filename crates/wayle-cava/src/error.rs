@@ -2,6 +2,8 @@ use std::ffi::NulError;
 
 use thiserror::Error;
 
+use crate::InputMethod;
+
 /// Errors that can occur when working with the CAVA audio visualizer.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -51,12 +53,13 @@ pub enum Error {
     #[error("Condition variable initialization failed with error code: {0}")]
     CondInit(i32),
 
-    /// No audio input function was returned by CAVA.
+    /// The linked libcava was built without the selected input backend.
     ///
     /// # Errors
-    /// Indicates the selected input method is not supported or could not be loaded.
-    #[error("No audio input function returned")]
-    NoInputFunction,
+    /// Returned at build or restart time instead of starting a capture that
+    /// would only ever produce zeros.
+    #[error("input method {0:?} is not supported by this libcava build")]
+    UnsupportedInput(InputMethod),
 
     /// String parameter contains an interior null byte.
     ///
@@ -84,14 +84,13 @@ impl AudioInput {
     /// thread function. Must be called before [`AudioOutput::init`] to match
     /// the order expected by libcava.
     ///
-    /// # Errors
-    ///
-    /// Returns error if `get_input` returns null (unsupported input method).
-    pub fn setup_input(&mut self, config: &mut Config) -> Result<InputFn> {
+    /// Returns `None` if `get_input` returns null (input method not compiled
+    /// into libcava).
+    pub fn setup_input(&mut self, config: &mut Config) -> Option<InputFn> {
         // SAFETY: Both pointers are valid and point to initialized structs.
         // get_input allocates `cava_in` and `source` buffers via malloc,
         // sets audio format/rate/channels, and returns a function pointer.
-        unsafe { get_input(self.as_ptr(), config.as_ptr()) }.ok_or(Error::NoInputFunction)
+        unsafe { get_input(self.as_ptr(), config.as_ptr()) }
     }
 
     /// Spawns the audio input thread using the function from [`setup_input`].
@@ -65,6 +65,18 @@
 //! # }
 //! ```
 //!
+//! # Input Backends
+//!
+//! Without configuration the service captures with [`InputMethod::PipeWire`]
+//! from source `"auto"`, the monitor of the default output. Nothing falls
+//! back automatically: on PulseAudio-only systems select
+//! [`InputMethod::Pulse`], and for other backends set `source` to what they
+//! expect (the pipe path for FIFO, the device for ALSA).
+//!
+//! Building or restarting with a backend the linked libcava lacks returns
+//! [`Error::UnsupportedInput`] instead of a visualizer stuck at zero. See
+//! [`InputMethod::is_available`].
+//!
 //! # Service Fields
 //!
 //! | Field | Type | Description |
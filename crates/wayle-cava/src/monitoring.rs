@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{path::Path, time::Duration};
 
 use tracing::{debug, error, warn};
 use wayle_traits::ServiceMonitoring;
@@ -6,7 +6,8 @@ use wayle_traits::ServiceMonitoring;
 use crate::{
     Error,
     ffi::{AudioInput, AudioOutput, Config},
-    service::CavaService,
+    service::{CavaService, DEFAULT_SOURCE},
+    types::InputMethod,
 };
 
 impl ServiceMonitoring for CavaService {
@@ -31,6 +32,7 @@ impl ServiceMonitoring for CavaService {
         let framerate = self.framerate.get().value();
         let input = self.input.get();
         let source = self.source.get();
+        check_input(input, &source)?;
         let low_cutoff = self.low_cutoff.get();
         let high_cutoff = self.high_cutoff.get();
         let samplerate = self.samplerate.get();
@@ -55,7 +57,9 @@ impl ServiceMonitoring for CavaService {
             &source,
         )?;
 
-        let input_fn = audio_input.setup_input(&mut config)?;
+        let input_fn = audio_input
+            .setup_input(&mut config)
+            .ok_or(Error::UnsupportedInput(input))?;
 
         let mut audio_output = AudioOutput::new(bars);
         let plan = audio_output.init(&mut audio_input, &mut config)?;
@@ -108,6 +112,22 @@ impl ServiceMonitoring for CavaService {
     }
 }
 
+/// Rejects backends the linked libcava lacks, and FIFO input without a
+/// readable pipe, before any capture thread starts.
+fn check_input(input: InputMethod, source: &str) -> Result<(), Error> {
+    if !input.is_available() {
+        return Err(Error::UnsupportedInput(input));
+    }
+
+    if input == InputMethod::Fifo && (source == DEFAULT_SOURCE || !Path::new(source).exists()) {
+        return Err(Error::InvalidParameter(format!(
+            "fifo input needs source set to an existing FIFO path, got '{source}'"
+        )));
+    }
+
+    Ok(())
+}
+
 fn calculate_cava_buffer_size(sample_rate: u32, channels: u32) -> usize {
     const BASE_FFT_SIZE: usize = 512;
     const BASS_BUFFER_MULTIPLIER: usize = 2;
@@ -276,4 +296,21 @@ mod tests {
             BASE_FFT_SIZE * MULTIPLIER_TIER_7 * BASS_BUFFER_MULTIPLIER * channels as usize;
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn fifo_input_requires_existing_path() {
+        assert!(matches!(
+            check_input(InputMethod::Fifo, DEFAULT_SOURCE),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            check_input(InputMethod::Fifo, "/nonexistent/wayle-cava.fifo"),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn other_inputs_accept_auto_source() {
+        assert!(check_input(InputMethod::PipeWire, DEFAULT_SOURCE).is_ok());
+    }
 }
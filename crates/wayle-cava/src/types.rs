@@ -121,6 +121,10 @@ impl From<i32> for Framerate {
 /// Audio input method for capturing system audio.
 ///
 /// Specifies which audio backend CAVA should use to capture audio data for visualization.
+/// The backend-specific target goes in the service's `source`: the pipe path for
+/// [`Fifo`](Self::Fifo), the device (e.g. `hw:Loopback,1`) for [`Alsa`](Self::Alsa),
+/// and a sink monitor or `"auto"` for [`PipeWire`](Self::PipeWire) and
+/// [`Pulse`](Self::Pulse).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputMethod {
     /// Read audio from a named pipe (FIFO).
@@ -154,6 +158,28 @@ pub enum InputMethod {
     Winscap,
 }
 
+impl InputMethod {
+    /// Whether the linked libcava can capture with this backend.
+    ///
+    /// Exact for the `vendored` build, which always includes FIFO, shared
+    /// memory and PipeWire, plus PulseAudio when libpulse was found. A system
+    /// libcava doesn't report its backends, so every method is assumed
+    /// available and a missing one is caught when capture starts.
+    pub fn is_available(self) -> bool {
+        if !cfg!(feature = "vendored") {
+            return true;
+        }
+
+        match self {
+            Self::Fifo | Self::Shmem | Self::PipeWire => true,
+            Self::Pulse => cfg!(cava_pulse),
+            Self::PortAudio | Self::Alsa | Self::Sndio | Self::Oss | Self::Jack | Self::Winscap => {
+                false
+            }
+        }
+    }
+}
+
 impl From<InputMethod> for ffi::InputMethod {
     fn from(method: InputMethod) -> Self {
         match method {
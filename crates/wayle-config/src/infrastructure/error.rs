@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
+use crate::schemas::styling::InvalidHexColor;
+
 /// Error types for the Wayle configuration infrastructure.
 #[derive(Error, Debug)]
 pub enum Error {
@@ -125,6 +127,18 @@ pub enum Error {
         source: toml::de::Error,
     },
 
+    /// Theme file has a color that isn't a valid hex color.
+    #[error("invalid color '{key}' in theme file '{}'", path.display())]
+    ThemeInvalidColor {
+        /// Path of the theme file.
+        path: PathBuf,
+        /// Palette key holding the invalid color.
+        key: &'static str,
+        /// Why the color was rejected.
+        #[source]
+        source: InvalidHexColor,
+    },
+
     /// File watcher initialization failed.
     #[error("cannot initialize file watcher")]
     WatcherInit {
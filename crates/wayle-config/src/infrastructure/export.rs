@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use toml_edit::{DocumentMut, Item, Table};
+use tracing::warn;
+
+use super::error::Error;
+use crate::{CollectValueSources, Config, ValueSource};
+
+const HEADER: &str = "\
+# Effective Wayle configuration (defaults + config.toml + imports + runtime.toml).
+# Values that don't come from the defaults are marked with their source file.
+";
+
+/// Serializes `config` to TOML, commenting every value that was set in
+/// `config.toml`, one of its imports or `runtime.toml` with its source file.
+///
+/// `key_files` maps key paths to the file that sets them (see
+/// [`Config::key_files`]). Config-layer values missing from it are
+/// attributed to `config.toml`.
+pub(crate) fn effective_toml(
+    config: &Config,
+    key_files: &HashMap<String, String>,
+) -> Result<String, Error> {
+    let plain = toml::to_string_pretty(config).map_err(|source| Error::Serialization {
+        content_type: "config",
+        source,
+    })?;
+
+    let mut document = match plain.parse::<DocumentMut>() {
+        Ok(document) => document,
+        Err(e) => {
+            warn!(error = %e, "cannot annotate config dump, writing it without sources");
+            return Ok(format!("{HEADER}\n{plain}"));
+        }
+    };
+
+    let mut sources = Vec::new();
+    config.collect_value_sources("", &mut sources);
+
+    for (path, source) in sources {
+        let file = key_files.get(&path).map_or("config.toml", String::as_str);
+        if let Some(label) = source_label(source, file) {
+            annotate(document.as_table_mut(), &path, &label);
+        }
+    }
+
+    Ok(format!("{HEADER}\n{document}"))
+}
+
+/// Comment for a value from `source`, where `file` is the config file that
+/// sets it, if any.
+fn source_label(source: ValueSource, file: &str) -> Option<String> {
+    match source {
+        ValueSource::Default => None,
+        ValueSource::Config => Some(file.to_owned()),
+        ValueSource::Custom => Some(String::from("runtime.toml")),
+        ValueSource::Override => Some(format!("runtime.toml, overrides {file}")),
+    }
+}
+
+/// Attaches `# label` to the key at `path`.
+///
+/// Keys inside inline tables are left alone since TOML doesn't allow
+/// comments there.
+fn annotate(root: &mut Table, path: &str, label: &str) {
+    let (parent_path, key) = path.rsplit_once('.').unwrap_or(("", path));
+
+    let mut parent = root;
+    for segment in parent_path.split('.').filter(|segment| !segment.is_empty()) {
+        let Some(table) = parent.get_mut(segment).and_then(Item::as_table_mut) else {
+            return;
+        };
+        parent = table;
+    }
+
+    match parent.get_mut(key) {
+        Some(Item::Value(value)) => value.decor_mut().set_suffix(format!(" # {label}")),
+        Some(Item::Table(table)) => table.decor_mut().set_prefix(format!("\n# {label}\n")),
+        Some(Item::ArrayOfTables(tables)) => {
+            if let Some(first) = tables.get_mut(0) {
+                first.decor_mut().set_prefix(format!("\n# {label}\n"));
+            }
+        }
+        Some(Item::None) | None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ApplyConfigLayer, ApplyRuntimeLayer};
+
+    #[test]
+    fn defaults_are_not_annotated() -> Result<(), Error> {
+        let output = effective_toml(&Config::default(), &HashMap::new())?;
+
+        assert!(output.starts_with(HEADER));
+        assert!(!output.contains("# config.toml"));
+        assert!(!output.contains("# runtime.toml"));
+        Ok(())
+    }
+
+    #[test]
+    fn values_are_annotated_with_their_layer() -> Result<(), Box<dyn std::error::Error>> {
+        let config = Config::default();
+        config.apply_config_layer(&toml::from_str("[bar]\nscale = 1.5\n")?, "");
+        config.apply_runtime_layer(&toml::from_str("[general]\nfont-sans = \"Inter\"\n")?, "")?;
+
+        let output = effective_toml(&config, &HashMap::new())?;
+
+        assert!(output.contains("scale = 1.5 # config.toml\n"));
+        assert!(output.contains("font-sans = \"Inter\" # runtime.toml\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn runtime_override_of_config_value_is_marked() -> Result<(), Box<dyn std::error::Error>> {
+        let config = Config::default();
+        config.apply_config_layer(&toml::from_str("[bar]\nscale = 1.5\n")?, "");
+        config.apply_runtime_layer(&toml::from_str("[bar]\nscale = 2.0\n")?, "")?;
+
+        let output = effective_toml(&config, &HashMap::new())?;
+
+        assert!(output.contains("scale = 2.0 # runtime.toml, overrides config.toml\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn imported_values_name_their_file() -> Result<(), Box<dyn std::error::Error>> {
+        let config = Config::default();
+        config.apply_config_layer(&toml::from_str("[bar]\nscale = 1.5\n")?, "");
+        config.apply_config_layer(&toml::from_str("[general]\nfont-sans = \"Inter\"\n")?, "");
+        config.apply_runtime_layer(&toml::from_str("[general]\nfont-sans = \"Noto\"\n")?, "")?;
+        let key_files = HashMap::from([
+            (
+                String::from("bar.scale"),
+                String::from("themes/colors.toml"),
+            ),
+            (
+                String::from("general.font-sans"),
+                String::from("fonts.toml"),
+            ),
+        ]);
+
+        let output = effective_toml(&config, &key_files)?;
+
+        assert!(output.contains("scale = 1.5 # themes/colors.toml\n"));
+        assert!(output.contains("font-sans = \"Noto\" # runtime.toml, overrides fonts.toml\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn annotated_output_parses_back() -> Result<(), Box<dyn std::error::Error>> {
+        let config = Config::default();
+        config.apply_config_layer(&toml::from_str("[bar]\nscale = 1.5\n")?, "");
+
+        let output = effective_toml(&config, &HashMap::new())?;
+        let parsed: toml::Value = toml::from_str(&output)?;
+
+        assert_eq!(parsed["bar"]["scale"].as_float(), Some(1.5));
+        Ok(())
+    }
+}
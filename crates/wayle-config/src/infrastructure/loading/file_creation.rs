@@ -1,5 +1,6 @@
 use std::{fs, path::Path};
 
+use super::migration::CURRENT_VERSION;
 use crate::infrastructure::error::{Error, IoOperation};
 
 pub(super) fn create_default_config_file(path: &Path) -> Result<(), Error> {
@@ -11,7 +12,8 @@ pub(super) fn create_default_config_file(path: &Path) -> Result<(), Error> {
         })?;
     }
 
-    fs::write(path, "# Wayle configuration file\n").map_err(|source| Error::Io {
+    let contents = format!("# Wayle configuration file\nversion = {CURRENT_VERSION}\n");
+    fs::write(path, contents).map_err(|source| Error::Io {
         operation: IoOperation::WriteFile,
         path: path.to_path_buf(),
         source,
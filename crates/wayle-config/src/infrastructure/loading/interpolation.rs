@@ -0,0 +1,172 @@
+//! Environment variable and home directory expansion in config strings.
+//!
+//! Runs on every string value of the merged config, so paths like
+//! `"~/Pictures/walls"` or `"${XDG_PICTURES_DIR}/walls"` work anywhere:
+//!
+//! - `${VAR}` is replaced with the value of `VAR`
+//! - `~` alone or followed by `/` at the start of a value is the home directory
+//! - `$$` is a literal `$`
+//!
+//! A bare `$VAR` is left alone so secret references still reach
+//! [`secrets::resolve`](crate::infrastructure::secrets::resolve). Unset
+//! variables stay in the value as written and are logged.
+
+use toml::Value;
+use tracing::warn;
+
+/// Expands `${VAR}`, `~` and `$$` in every string within `value`.
+pub(super) fn expand_strings(value: &mut Value) {
+    let lookup = |name: &str| std::env::var(name).ok();
+
+    match value {
+        Value::String(text) => {
+            if let Some(expanded) = expand(text, lookup) {
+                *text = expanded;
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(expand_strings),
+        Value::Table(table) => table
+            .iter_mut()
+            .for_each(|(_, value)| expand_strings(value)),
+        _ => {}
+    }
+}
+
+/// Expands `text` with variables from `lookup`, or returns `None` when it
+/// has no `$` or leading `~` to look at.
+fn expand(text: &str, lookup: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let (home, rest) = match text.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => match lookup("HOME") {
+            Some(home) => (Some(home), rest),
+            None => {
+                warn!(value = %text, "HOME not set, leaving '~' unexpanded");
+                (None, text)
+            }
+        },
+        _ => (None, text),
+    };
+
+    if home.is_none() && !rest.contains('$') {
+        return None;
+    }
+
+    let mut output = home.unwrap_or_default();
+    let mut rest = rest;
+
+    while let Some(index) = rest.find('$') {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        if let Some(after) = rest.strip_prefix("$$") {
+            output.push('$');
+            rest = after;
+            continue;
+        }
+
+        let Some((name, after)) = rest
+            .strip_prefix("${")
+            .and_then(|inner| inner.split_once('}'))
+        else {
+            output.push('$');
+            rest = &rest[1..];
+            continue;
+        };
+
+        match lookup(name) {
+            Some(resolved) => output.push_str(&resolved),
+            None => {
+                warn!(var = %name, "Environment variable not set, leaving it unexpanded");
+                output.push_str(&rest[..name.len() + 3]);
+            }
+        }
+        rest = after;
+    }
+
+    output.push_str(rest);
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some(String::from("/home/ana")),
+            "XDG_PICTURES_DIR" => Some(String::from("/home/ana/Pictures")),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expands_leading_tilde() {
+        assert_eq!(
+            expand("~/Pictures/walls", env),
+            Some(String::from("/home/ana/Pictures/walls"))
+        );
+        assert_eq!(expand("~", env), Some(String::from("/home/ana")));
+    }
+
+    #[test]
+    fn tilde_elsewhere_is_literal() {
+        assert_eq!(expand("~user/walls", env), None);
+        assert_eq!(expand("a~/b", env), None);
+    }
+
+    #[test]
+    fn expands_braced_variables() {
+        assert_eq!(
+            expand("${XDG_PICTURES_DIR}/walls", env),
+            Some(String::from("/home/ana/Pictures/walls"))
+        );
+        assert_eq!(
+            expand("${HOME}:${HOME}", env),
+            Some(String::from("/home/ana:/home/ana"))
+        );
+    }
+
+    #[test]
+    fn double_dollar_is_literal_dollar() {
+        assert_eq!(
+            expand("$${HOME} costs $$5", env),
+            Some(String::from("${HOME} costs $5"))
+        );
+    }
+
+    #[test]
+    fn undefined_variable_is_left_as_written() {
+        assert_eq!(
+            expand("${NOPE}/walls", env),
+            Some(String::from("${NOPE}/walls"))
+        );
+    }
+
+    #[test]
+    fn bare_and_unterminated_references_are_untouched() {
+        assert_eq!(
+            expand("$WEATHER_API_KEY", env),
+            Some(String::from("$WEATHER_API_KEY"))
+        );
+        assert_eq!(expand("${HOME", env), Some(String::from("${HOME")));
+        assert_eq!(expand("plain text", env), None);
+    }
+
+    #[test]
+    fn walks_nested_tables_and_arrays() -> Result<(), toml::de::Error> {
+        let mut value: Value = toml::from_str(
+            r#"
+            [wallpaper]
+            cycling-directory = "$${HOME}"
+            monitors = ["$$", "$$$$"]
+            "#,
+        )?;
+
+        expand_strings(&mut value);
+
+        let wallpaper = &value["wallpaper"];
+        assert_eq!(wallpaper["cycling-directory"].as_str(), Some("${HOME}"));
+        assert_eq!(wallpaper["monitors"][0].as_str(), Some("$"));
+        assert_eq!(wallpaper["monitors"][1].as_str(), Some("$$"));
+        Ok(())
+    }
+}
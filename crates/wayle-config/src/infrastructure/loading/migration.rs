@@ -0,0 +1,170 @@
+use std::{fs, path::Path};
+
+use toml_edit::{DocumentMut, Item, Table, value};
+use tracing::info;
+
+use crate::infrastructure::{
+    error::{Error, IoOperation},
+    persistence,
+};
+
+/// Schema version written to new and migrated config files.
+pub(super) const CURRENT_VERSION: u32 = 1;
+
+/// Changes that bring a file up to `version`.
+pub(super) struct Migration {
+    pub version: u32,
+    /// Rewrites the user's TOML in place, keeping comments and formatting.
+    pub migrate: fn(&mut Table),
+}
+
+/// Ordered by version. Version 1 introduces the `version` key itself and
+/// changes nothing else.
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    migrate: |_| {},
+}];
+
+/// Upgrades the config file at `path` in place if its `version` is older
+/// than [`CURRENT_VERSION`] and a newer migration changes its contents.
+///
+/// Comments and formatting are preserved. The original is kept next to it
+/// as `<name>.v<old>.bak`. Files that don't parse, or that no migration
+/// changes, are left alone.
+///
+/// # Errors
+///
+/// Returns error if the file cannot be read, or the backup or upgraded
+/// file cannot be written.
+pub(super) fn migrate_file(path: &Path) -> Result<(), Error> {
+    let content = fs::read_to_string(path).map_err(|source| Error::Io {
+        operation: IoOperation::ReadFile,
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let Ok(mut document) = content.parse::<DocumentMut>() else {
+        return Ok(());
+    };
+
+    let from_version = document_version(&document);
+    if !apply_migrations(&mut document, MIGRATIONS, CURRENT_VERSION) {
+        return Ok(());
+    }
+
+    let backup = path.with_extension(format!("toml.v{from_version}.bak"));
+    fs::copy(path, &backup).map_err(|source| Error::Io {
+        operation: IoOperation::WriteFile,
+        path: backup.clone(),
+        source,
+    })?;
+
+    persistence::write_atomic(path, &document.to_string())?;
+
+    info!(
+        from = from_version,
+        to = CURRENT_VERSION,
+        backup = %backup.display(),
+        "Migrated config file"
+    );
+
+    Ok(())
+}
+
+/// The file's `version`, or 0 if it has none or it isn't a valid version.
+fn document_version(document: &DocumentMut) -> u32 {
+    document
+        .get("version")
+        .and_then(Item::as_integer)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+/// Runs every migration newer than the document's version, then stamps
+/// `target` if any of them changed the document. Returns whether the
+/// document changed.
+fn apply_migrations(document: &mut DocumentMut, migrations: &[Migration], target: u32) -> bool {
+    let from_version = document_version(document);
+    if from_version >= target {
+        return false;
+    }
+
+    let original = document.to_string();
+    for migration in migrations
+        .iter()
+        .filter(|migration| migration.version > from_version && migration.version <= target)
+    {
+        (migration.migrate)(document.as_table_mut());
+    }
+
+    if document.to_string() == original {
+        return false;
+    }
+
+    document["version"] = value(i64::from(target));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename_a_to_b(root: &mut Table) {
+        if let Some(item) = root.remove("a") {
+            root.insert("b", item);
+        }
+    }
+
+    const RENAME: &[Migration] = &[Migration {
+        version: 2,
+        migrate: rename_a_to_b,
+    }];
+
+    #[test]
+    fn unversioned_file_without_changes_is_not_stamped() {
+        let mut document: DocumentMut = "# mine\n[bar]\nscale = 1\n".parse().unwrap();
+
+        assert!(!apply_migrations(
+            &mut document,
+            MIGRATIONS,
+            CURRENT_VERSION
+        ));
+        assert!(document.get("version").is_none());
+    }
+
+    #[test]
+    fn changed_file_is_stamped_and_keeps_comments() {
+        let mut document: DocumentMut = "a = 1\n# mine\n[bar]\nscale = 1\n".parse().unwrap();
+
+        assert!(apply_migrations(&mut document, RENAME, 2));
+        assert_eq!(document_version(&document), 2);
+        assert_eq!(document["b"].as_integer(), Some(1));
+        assert!(document.to_string().contains("# mine"));
+    }
+
+    #[test]
+    fn current_file_is_untouched() {
+        let mut document: DocumentMut = format!("version = {CURRENT_VERSION}\n").parse().unwrap();
+
+        assert!(!apply_migrations(
+            &mut document,
+            MIGRATIONS,
+            CURRENT_VERSION
+        ));
+    }
+
+    #[test]
+    fn skips_migrations_already_applied() {
+        let mut document: DocumentMut = "version = 2\na = 1\n".parse().unwrap();
+
+        assert!(!apply_migrations(&mut document, RENAME, 2));
+        assert!(document.get("a").is_some());
+    }
+
+    #[test]
+    fn invalid_version_counts_as_unversioned() {
+        let document: DocumentMut = "version = -3\n".parse().unwrap();
+
+        assert_eq!(document_version(&document), 0);
+    }
+}
@@ -1,9 +1,12 @@
 mod circular_detection;
 mod file_creation;
+mod interpolation;
 mod merging;
+mod migration;
 
 use std::{
-    fs,
+    collections::HashMap,
+    fs, io,
     path::{Path, PathBuf},
 };
 
@@ -11,9 +14,17 @@ use circular_detection::CircularDetector;
 use file_creation::create_default_config_file;
 use merging::merge_toml_configs;
 use toml::Value;
+use tracing::warn;
 
-use super::error::{Error, IoOperation};
-use crate::Config;
+use super::{
+    error::{Error, IoOperation},
+    paths::ConfigPaths,
+};
+use crate::{CollectUnknownKeys, Config, Diagnostic};
+
+/// Import prefix that resolves the rest of the path from the config
+/// directory instead of the importing file's directory.
+const CONFIG_ROOT_PREFIX: &str = "config:";
 
 impl Config {
     /// Loads and deserializes configuration with imports resolved.
@@ -31,6 +42,13 @@ impl Config {
 
     /// Loads and merges configuration TOML with imports resolved.
     ///
+    /// A main config file older than the current schema `version` is
+    /// migrated and rewritten first. Imported files are not migrated.
+    ///
+    /// String values in the merged result have `${VAR}` and a leading `~`
+    /// expanded, with `$$` as an escaped `$`. Unset variables are left as
+    /// written. A bare `$VAR` is kept for [`secrets`](super::secrets).
+    ///
     /// # Errors
     ///
     /// Returns error on read failures, invalid TOML, import failures,
@@ -46,8 +64,87 @@ impl Config {
             source,
         })?;
 
+        if let Err(err) = migration::migrate_file(&canonical_path) {
+            warn!(error = %err, "cannot migrate config file, loading it as is");
+        }
+
+        let mut detector = CircularDetector::new();
+        let mut merged = Self::load_merged_toml(&canonical_path, &mut detector)?;
+        interpolation::expand_strings(&mut merged);
+        Ok(merged)
+    }
+
+    /// Maps the dot-separated path of every key in the merged config to the
+    /// file that sets it, such as `bar.scale` to `colors.toml`.
+    ///
+    /// Files are visited in the same order as
+    /// [`load_toml_with_imports`](Self::load_toml_with_imports), so a key set
+    /// in several files maps to the one whose value wins. Files are named
+    /// relative to the main config's directory when they're inside it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error on read failures, invalid TOML, import failures,
+    /// or circular imports.
+    pub(crate) fn key_files(path: &Path) -> Result<HashMap<String, String>, Error> {
+        let canonical_path = path.canonicalize().map_err(|source| Error::Io {
+            operation: IoOperation::ResolvePath,
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let root = canonical_path
+            .parent()
+            .unwrap_or(Path::new("/"))
+            .to_path_buf();
+
+        let mut files = HashMap::new();
         let mut detector = CircularDetector::new();
-        Self::load_merged_toml(&canonical_path, &mut detector)
+        Self::collect_key_files(&canonical_path, &root, &mut detector, &mut files)?;
+        Ok(files)
+    }
+
+    fn collect_key_files(
+        path: &Path,
+        root: &Path,
+        detector: &mut CircularDetector,
+        files: &mut HashMap<String, String>,
+    ) -> Result<(), Error> {
+        detector.detect_circular_import(path)?;
+        detector.push_to_chain(path);
+
+        let content = fs::read_to_string(path)?;
+        for import_path in Self::extract_import_paths(&content)? {
+            let resolved_path =
+                Self::resolve_import_path(path, &import_path, ConfigPaths::config_dir)?;
+            let canonical_import = resolved_path.canonicalize().map_err(|source| Error::Io {
+                operation: IoOperation::ResolvePath,
+                path: resolved_path.clone(),
+                source,
+            })?;
+            Self::collect_key_files(&canonical_import, root, detector, files)?;
+        }
+
+        let value: Value = toml::from_str(&content).map_err(|source| Error::TomlParse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let name = path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string();
+        record_key_files(&value, "", &name, files);
+
+        detector.pop_from_chain();
+        Ok(())
+    }
+
+    /// Dot-separated paths of the keys in `value` that no config field
+    /// reads, such as a misspelled `modules.clok`.
+    pub fn unknown_keys(&self, value: &Value) -> Vec<String> {
+        let mut unknown = Vec::new();
+        self.collect_unknown_keys(value, "", &mut unknown);
+        unknown
     }
 
     fn load_merged_toml(path: &Path, detector: &mut CircularDetector) -> Result<Value, Error> {
@@ -76,7 +173,8 @@ impl Config {
         import_paths
             .iter()
             .map(|import_path| {
-                let resolved_path = Self::resolve_import_path(base_path, import_path)?;
+                let resolved_path =
+                    Self::resolve_import_path(base_path, import_path, ConfigPaths::config_dir)?;
                 let canonical_import =
                     resolved_path
                         .canonicalize()
@@ -150,17 +248,188 @@ impl Config {
         Ok(import_paths)
     }
 
-    fn resolve_import_path(base_path: &Path, import_path: &str) -> Result<PathBuf, Error> {
-        let parent_dir = base_path.parent().ok_or_else(|| Error::ImportNoParent {
-            path: base_path.to_path_buf(),
-        })?;
+    /// Resolves `import_path` as written in `base_path`.
+    ///
+    /// Paths starting with `config:` resolve from `config_dir()`, so
+    /// `config:colors.toml` names the same file from any importing file.
+    /// Other relative paths resolve from the importing file's directory.
+    fn resolve_import_path(
+        base_path: &Path,
+        import_path: &str,
+        config_dir: impl FnOnce() -> io::Result<PathBuf>,
+    ) -> Result<PathBuf, Error> {
+        let (root, relative_path) = match import_path.strip_prefix(CONFIG_ROOT_PREFIX) {
+            Some(relative_path) => {
+                let root = config_dir().map_err(|source| Error::Io {
+                    operation: IoOperation::AccessConfigDir,
+                    path: PathBuf::from(import_path),
+                    source,
+                })?;
+                (root, relative_path.trim_start_matches('/'))
+            }
+            None => {
+                let parent_dir = base_path.parent().ok_or_else(|| Error::ImportNoParent {
+                    path: base_path.to_path_buf(),
+                })?;
+                (parent_dir.to_path_buf(), import_path)
+            }
+        };
 
-        let mut import_path_buf = PathBuf::from(import_path);
+        let mut import_path_buf = PathBuf::from(relative_path);
         if import_path_buf.extension().is_none() {
             import_path_buf.set_extension("toml");
         }
 
-        let resolved_path = parent_dir.join(import_path_buf);
-        Ok(resolved_path)
+        Ok(root.join(import_path_buf))
+    }
+}
+
+/// Points every key path in `value`, tables included, at `file`.
+fn record_key_files(value: &Value, prefix: &str, file: &str, files: &mut HashMap<String, String>) {
+    let Value::Table(table) = value else {
+        return;
+    };
+
+    for (key, value) in table {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        record_key_files(value, &path, file, files);
+        files.insert(path, file.to_owned());
+    }
+}
+
+/// Warns about every key in `value` that no config field reads.
+///
+/// `source` names the file the layer came from, for the message.
+pub(crate) fn warn_unknown_keys(config: &Config, value: &Value, source: &str) {
+    for key in config.unknown_keys(value) {
+        let diag = Diagnostic::warning("unknown config key ignored")
+            .field("Key", key)
+            .field("File", source)
+            .hint("check the spelling, or run `wayle config check`");
+        diag.emit();
+        warn!("{}", diag.to_plain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_misspelled_keys_with_their_path() -> Result<(), toml::de::Error> {
+        let value: Value = toml::from_str(
+            r#"
+            version = 2
+            imports = ["colors.toml"]
+
+            [bar]
+            scael = 1.5
+
+            [modules.clok]
+            format = "%H:%M"
+            "#,
+        )?;
+
+        let unknown = Config::default().unknown_keys(&value);
+
+        assert_eq!(unknown, vec!["bar.scael", "modules.clok"]);
+        Ok(())
+    }
+
+    #[test]
+    fn known_keys_are_not_reported() -> Result<(), toml::de::Error> {
+        let value: Value = toml::from_str(
+            r#"
+            [bar]
+            scale = 1.5
+
+            [modules.clock]
+            format = "%H:%M"
+            "#,
+        )?;
+
+        assert!(Config::default().unknown_keys(&value).is_empty());
+        Ok(())
+    }
+
+    fn config_dir() -> io::Result<PathBuf> {
+        Ok(PathBuf::from("/home/user/.config/wayle"))
+    }
+
+    #[test]
+    fn imports_resolve_from_the_importing_file() -> Result<(), Error> {
+        let resolved = Config::resolve_import_path(
+            Path::new("/home/user/.config/wayle/modules/bar.toml"),
+            "clock",
+            config_dir,
+        )?;
+
+        assert_eq!(
+            resolved,
+            PathBuf::from("/home/user/.config/wayle/modules/clock.toml")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn config_prefixed_imports_resolve_from_the_config_dir() -> Result<(), Error> {
+        let base = Path::new("/home/user/.config/wayle/modules/bar.toml");
+
+        assert_eq!(
+            Config::resolve_import_path(base, "config:themes/nord", config_dir)?,
+            PathBuf::from("/home/user/.config/wayle/themes/nord.toml")
+        );
+        assert_eq!(
+            Config::resolve_import_path(base, "config:/colors.toml", config_dir)?,
+            PathBuf::from("/home/user/.config/wayle/colors.toml")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn config_dir_is_only_looked_up_for_prefixed_imports() -> Result<(), Error> {
+        let missing_dir = || Err(io::Error::from(io::ErrorKind::NotFound));
+        let base = Path::new("/tmp/wayle/config.toml");
+
+        assert_eq!(
+            Config::resolve_import_path(base, "colors.toml", missing_dir)?,
+            PathBuf::from("/tmp/wayle/colors.toml")
+        );
+        assert!(matches!(
+            Config::resolve_import_path(base, "config:colors.toml", missing_dir),
+            Err(Error::Io {
+                operation: IoOperation::AccessConfigDir,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn later_files_own_the_keys_they_set() -> Result<(), toml::de::Error> {
+        let import: Value = toml::from_str("[bar]\nscale = 1.5\nlocation = \"top\"\n")?;
+        let main: Value = toml::from_str("[bar]\nscale = 2.0\n")?;
+
+        let mut files = HashMap::new();
+        record_key_files(&import, "", "colors.toml", &mut files);
+        record_key_files(&main, "", "config.toml", &mut files);
+
+        assert_eq!(files["bar.location"], "colors.toml");
+        assert_eq!(files["bar.scale"], "config.toml");
+        assert_eq!(files["bar"], "config.toml");
+        Ok(())
+    }
+
+    #[test]
+    fn default_config_has_no_unknown_keys() -> Result<(), toml::ser::Error> {
+        let config = Config::default();
+        let value = Value::try_from(&config)?;
+
+        assert_eq!(config.unknown_keys(&value), Vec::<String>::new());
+        Ok(())
     }
 }
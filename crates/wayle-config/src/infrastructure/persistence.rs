@@ -1,7 +1,12 @@
-use std::sync::Arc;
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use tokio::sync::mpsc;
-use tracing::{debug, error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
 
 use super::{error::Error, service::ConfigService};
 use crate::SubscribeChanges;
@@ -40,3 +45,139 @@ impl PersistenceWatcher {
         Ok(Self)
     }
 }
+
+/// Writes `contents` to `path` so a crash never leaves it half-written.
+///
+/// The data goes to a synced temp file that is renamed over `path`, then
+/// copied to a `.bak` sibling that [`load_with_backup`] falls back to. The
+/// write has already landed by the time the backup is made, so a failed
+/// backup is only logged.
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> Result<(), Error> {
+    let temp_path = path.with_extension("tmp");
+    let persistence_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| Error::Persistence { path, source }
+    };
+
+    let mut file = File::create(&temp_path).map_err(persistence_error(&temp_path))?;
+    file.write_all(contents.as_bytes())
+        .and_then(|()| file.sync_all())
+        .map_err(persistence_error(&temp_path))?;
+    drop(file);
+
+    fs::rename(&temp_path, path).map_err(persistence_error(path))?;
+
+    let backup = backup_path(path);
+    if let Err(e) = fs::copy(path, &backup) {
+        warn!(path = %backup.display(), error = %e, "cannot update backup");
+    }
+
+    Ok(())
+}
+
+/// Loads a TOML file written by [`write_atomic`].
+///
+/// If the file exists but can't be parsed, the `.bak` copy of the last
+/// good write is used instead.
+pub(crate) fn load_with_backup(path: &Path) -> Result<toml::Value, Error> {
+    let err = match ConfigService::load_toml_file(path) {
+        Ok(value) => return Ok(value),
+        Err(err @ Error::TomlParse { .. }) => err,
+        Err(err) => return Err(err),
+    };
+
+    let backup = backup_path(path);
+    match ConfigService::load_toml_file(&backup) {
+        Ok(value) => {
+            warn!(
+                path = %path.display(),
+                error = %err,
+                "file is corrupt, restored last good write from backup"
+            );
+            Ok(value)
+        }
+        Err(_) => Err(err),
+    }
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+    PathBuf::from(backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    fn scratch_dir(name: &str) -> std::io::Result<PathBuf> {
+        let dir = std::env::temp_dir().join(format!(
+            "wayle-config-persistence-{}-{name}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn write_atomic_round_trips_and_keeps_backup() -> TestResult {
+        let dir = scratch_dir("round-trip")?;
+        let path = dir.join("runtime.toml");
+
+        write_atomic(&path, "[bar]\nscale = 1.5\n")?;
+
+        let value = load_with_backup(&path)?;
+        assert_eq!(value["bar"]["scale"].as_float(), Some(1.5));
+        assert!(backup_path(&path).exists());
+        assert!(!path.with_extension("tmp").exists());
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn failed_backup_still_reports_success() -> TestResult {
+        let dir = scratch_dir("failed-backup")?;
+        let path = dir.join("runtime.toml");
+        fs::create_dir(backup_path(&path))?;
+
+        write_atomic(&path, "[bar]\nscale = 1.5\n")?;
+
+        assert_eq!(fs::read_to_string(&path)?, "[bar]\nscale = 1.5\n");
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn partial_write_recovers_from_backup() -> TestResult {
+        let dir = scratch_dir("partial-write")?;
+        let path = dir.join("runtime.toml");
+
+        write_atomic(&path, "[bar]\nscale = 1.5\n")?;
+        fs::write(&path, "[bar]\nscale = ")?;
+
+        let value = load_with_backup(&path)?;
+        assert_eq!(value["bar"]["scale"].as_float(), Some(1.5));
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn corrupt_file_without_backup_reports_parse_error() -> TestResult {
+        let dir = scratch_dir("no-backup")?;
+        let path = dir.join("runtime.toml");
+
+        fs::write(&path, "[bar]\nscale = ")?;
+
+        let result = load_with_backup(&path);
+        assert!(matches!(result, Err(Error::TomlParse { .. })));
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -1,12 +1,16 @@
-use std::sync::{Arc, RwLock};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
-use tokio::fs;
 use tracing::{info, instrument, warn};
 
 use super::{
     error::{Error, InvalidFieldReason, IoOperation},
+    export,
+    loading::warn_unknown_keys,
     paths::ConfigPaths,
-    secrets, toml_path,
+    persistence, secrets, toml_path,
     watcher::FileWatcher,
 };
 use crate::{
@@ -50,18 +54,22 @@ impl ConfigService {
                 .map_err(|source| Error::TaskJoin { source })?;
 
         match config_result {
-            Ok(config_toml) => config.apply_config_layer(&config_toml, ""),
+            Ok(config_toml) => {
+                config.apply_config_layer(&config_toml, "");
+                warn_unknown_keys(&config, &config_toml, "config.toml");
+            }
             Err(e) => warn!("using defaults, config.toml failed:\n{e}"),
         }
 
         let runtime_path = ConfigPaths::runtime_config();
         let runtime_result =
-            tokio::task::spawn_blocking(move || Self::load_toml_file(&runtime_path))
+            tokio::task::spawn_blocking(move || persistence::load_with_backup(&runtime_path))
                 .await
                 .map_err(|source| Error::TaskJoin { source })?;
 
         match runtime_result {
             Ok(runtime_toml) => {
+                warn_unknown_keys(&config, &runtime_toml, "runtime.toml");
                 if let Err(e) = config.apply_runtime_layer(&runtime_toml, "") {
                     warn!("invalid runtime.toml value:\n{e}");
                 }
@@ -93,6 +101,29 @@ impl ConfigService {
         &self.config
     }
 
+    /// Serializes the fully merged config to TOML.
+    ///
+    /// Every value is the effective one after defaults, `config.toml` (with
+    /// imports) and `runtime.toml` are layered. Values that don't come from
+    /// the defaults carry a trailing comment naming their source file.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the config cannot be serialized.
+    pub fn export_effective(&self) -> Result<String, Error> {
+        let config_path = ConfigPaths::main_config();
+        let key_files = if config_path.exists() {
+            Config::key_files(&config_path).unwrap_or_else(|e| {
+                warn!(error = %e, "cannot trace config values to their files");
+                HashMap::new()
+            })
+        } else {
+            HashMap::new()
+        };
+
+        export::effective_toml(&self.config, &key_files)
+    }
+
     /// Subscribes to secrets reload events.
     ///
     /// Returns a receiver that fires whenever `.env` files are reloaded.
@@ -107,7 +138,8 @@ impl ConfigService {
 
     /// Persists runtime layer values to `runtime.toml`.
     ///
-    /// Only values with runtime overrides are written.
+    /// Only values with runtime overrides are written. The write is atomic,
+    /// and the previous good file is kept as `runtime.toml.bak`.
     ///
     /// # Errors
     ///
@@ -120,7 +152,6 @@ impl ConfigService {
             .unwrap_or_else(|| toml::Value::Table(toml::Table::new()));
 
         let runtime_path = ConfigPaths::runtime_config();
-        let temp_path = runtime_path.with_extension("tmp");
 
         let toml_str =
             toml::to_string_pretty(&runtime_value).map_err(|source| Error::Serialization {
@@ -128,19 +159,9 @@ impl ConfigService {
                 source,
             })?;
 
-        fs::write(&temp_path, toml_str)
-            .await
-            .map_err(|source| Error::Persistence {
-                path: temp_path.clone(),
-                source,
-            })?;
-
-        fs::rename(&temp_path, &runtime_path)
+        tokio::task::spawn_blocking(move || persistence::write_atomic(&runtime_path, &toml_str))
             .await
-            .map_err(|source| Error::Persistence {
-                path: runtime_path.clone(),
-                source,
-            })?;
+            .map_err(|source| Error::TaskJoin { source })??;
 
         info!("Configuration saved to runtime.toml");
 
@@ -176,7 +197,8 @@ pub trait ConfigServiceCli {
     ///
     /// # Errors
     ///
-    /// Returns error if path is invalid.
+    /// Returns error if path doesn't name a config field or the value
+    /// doesn't match the field's type.
     fn set_by_path(&self, path: &str, value: toml::Value) -> Result<(), Error>;
 
     /// Clears the runtime override at a dot-separated path.
@@ -213,11 +235,7 @@ impl ConfigServiceCli for ConfigService {
     }
 
     fn set_by_path(&self, path: &str, value: toml::Value) -> Result<(), Error> {
-        let mut root = toml::Value::Table(toml::Table::new());
-        toml_path::insert(&mut root, path, value)?;
-        self.config
-            .apply_runtime_layer(&root, "")
-            .map_err(Error::InvalidValue)
+        set_runtime_by_path(&self.config, path, value)
     }
 
     fn reset_by_path(&self, path: &str) -> Result<bool, Error> {
@@ -226,3 +244,66 @@ impl ConfigServiceCli for ConfigService {
             .map_err(Error::InvalidValue)
     }
 }
+
+fn set_runtime_by_path(config: &Config, path: &str, value: toml::Value) -> Result<(), Error> {
+    let mut root = toml::Value::Table(toml::Table::new());
+    toml_path::insert(&mut root, path, value)?;
+
+    // Unknown keys are skipped when the layer is applied, so check the path
+    // against the schema up front rather than reporting success for a typo.
+    // The serialized config can't be used for this: it omits unset options.
+    if let Some(unknown) = config.unknown_keys(&root).into_iter().next() {
+        let field = unknown.rsplit('.').next().unwrap_or(&unknown).to_string();
+        return Err(Error::InvalidConfigField {
+            field,
+            component: path.to_string(),
+            reason: InvalidFieldReason::NotFound,
+        });
+    }
+
+    config
+        .apply_runtime_layer(&root, "")
+        .map_err(Error::InvalidValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_runtime_by_path_accepts_unset_option_field() {
+        let config = Config::default();
+        assert_eq!(config.modules.weather.visual_crossing_key.get(), None);
+
+        let result = set_runtime_by_path(
+            &config,
+            "modules.weather.visual-crossing-key",
+            toml::Value::String(String::from("abc")),
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(
+            config.modules.weather.visual_crossing_key.get().as_deref(),
+            Some("abc")
+        );
+    }
+
+    #[test]
+    fn set_runtime_by_path_rejects_unknown_field() {
+        let config = Config::default();
+
+        let result = set_runtime_by_path(
+            &config,
+            "modules.weather.no-such-key",
+            toml::Value::Boolean(true),
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigField {
+                reason: InvalidFieldReason::NotFound,
+                ..
+            })
+        ));
+    }
+}
@@ -6,7 +6,7 @@ pub mod palettes;
 /// Theme discovery utilities.
 pub(crate) mod utils;
 
-/// Ten-color palette for CSS generation.
+/// Ten-color palette for CSS generation, plus optional semantic slots.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 pub struct Palette {
     /// Base background color (darkest).
@@ -29,4 +29,67 @@ pub struct Palette {
     pub green: String,
     /// Blue palette color.
     pub blue: String,
+    /// Warning color. Falls back to `yellow` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    /// Informational color. Falls back to `blue` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    /// Secondary accent color. Falls back to `primary` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_2: Option<String>,
+}
+
+impl Palette {
+    /// Warning color, or `yellow` if the palette doesn't define one.
+    pub fn warning(&self) -> &str {
+        self.warning.as_deref().unwrap_or(&self.yellow)
+    }
+
+    /// Informational color, or `blue` if the palette doesn't define one.
+    pub fn info(&self) -> &str {
+        self.info.as_deref().unwrap_or(&self.blue)
+    }
+
+    /// Secondary accent color, or `primary` if the palette doesn't define one.
+    pub fn accent_2(&self) -> &str {
+        self.accent_2.as_deref().unwrap_or(&self.primary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_THEME: &str = r##"
+        bg = "#000000"
+        surface = "#111111"
+        elevated = "#222222"
+        fg = "#ffffff"
+        fg_muted = "#cccccc"
+        primary = "#ff00ff"
+        red = "#ff0000"
+        yellow = "#ffff00"
+        green = "#00ff00"
+        blue = "#0000ff"
+    "##;
+
+    #[test]
+    fn optional_slots_fall_back_when_absent() {
+        let palette: Palette = toml::from_str(LEGACY_THEME).unwrap();
+
+        assert_eq!(palette.warning(), "#ffff00");
+        assert_eq!(palette.info(), "#0000ff");
+        assert_eq!(palette.accent_2(), "#ff00ff");
+    }
+
+    #[test]
+    fn optional_slots_override_fallbacks() {
+        let source = format!("{LEGACY_THEME}\nwarning = \"#ff8800\"\naccent_2 = \"#00ffff\"");
+        let palette: Palette = toml::from_str(&source).unwrap();
+
+        assert_eq!(palette.warning(), "#ff8800");
+        assert_eq!(palette.info(), "#0000ff");
+        assert_eq!(palette.accent_2(), "#00ffff");
+    }
 }
@@ -81,6 +81,9 @@ pub fn wayle() -> Palette {
         yellow: YELLOW.to_owned(),
         green: GREEN.to_owned(),
         blue: BLUE.to_owned(),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -96,6 +99,9 @@ fn catppuccin() -> Palette {
         yellow: String::from("#f9e2af"),
         green: String::from("#a6e3a1"),
         blue: String::from("#74c7ec"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -111,6 +117,9 @@ fn catppuccin_latte() -> Palette {
         yellow: String::from("#df8e1d"),
         green: String::from("#40a02b"),
         blue: String::from("#1e66f5"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -126,6 +135,9 @@ fn gruvbox() -> Palette {
         yellow: String::from("#fabd2f"),
         green: String::from("#b8bb26"),
         blue: String::from("#8ec07c"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -141,6 +153,9 @@ fn tokyo_night() -> Palette {
         yellow: String::from("#e0af68"),
         green: String::from("#9ece6a"),
         blue: String::from("#7dcfff"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -156,6 +171,9 @@ fn rose_pine() -> Palette {
         yellow: String::from("#f6c177"),
         green: String::from("#31748f"),
         blue: String::from("#9ccfd8"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -171,6 +189,9 @@ fn dracula() -> Palette {
         yellow: String::from("#f1fa8c"),
         green: String::from("#50fa7b"),
         blue: String::from("#8be9fd"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -186,6 +207,9 @@ fn nord() -> Palette {
         yellow: String::from("#ebcb8b"),
         green: String::from("#a3be8c"),
         blue: String::from("#81a1c1"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
 
@@ -201,5 +225,8 @@ fn everforest() -> Palette {
         yellow: String::from("#dbbc7f"),
         green: String::from("#a7c080"),
         blue: String::from("#83c092"),
+        warning: None,
+        info: None,
+        accent_2: None,
     }
 }
@@ -1,13 +1,19 @@
 use std::{fs, path::Path};
 
-use tracing::{error, info};
+use tracing::{debug, info, warn};
 
 use crate::{
     Config, Error,
     infrastructure::themes::{Palette, palettes::builtins},
-    schemas::styling::ThemeEntry,
+    schemas::styling::{HexColor, InvalidHexColor, ThemeEntry},
 };
 
+/// Publishes the built-in themes plus every valid `*.toml` theme in
+/// `themes_dir` to `styling.available`.
+///
+/// Invalid theme files are skipped with a warning, so one broken file never
+/// hides the others. User themes are sorted by name to keep the list stable
+/// across reloads.
 pub(crate) fn load_themes(config: &Config, themes_dir: &Path) {
     let mut all_themes: Vec<ThemeEntry> = builtins();
 
@@ -17,27 +23,45 @@ pub(crate) fn load_themes(config: &Config, themes_dir: &Path) {
         return;
     };
 
+    let mut user_themes: Vec<ThemeEntry> = Vec::new();
+
     for entry in entries {
         let Ok(entry) = entry else {
             continue;
         };
 
-        let path = entry.path();
+        if let Some(theme) = read_user_theme(&entry.path(), &all_themes) {
+            user_themes.push(theme);
+        }
+    }
 
-        let Ok(theme) = get_theme_from_file(&path) else {
-            error!(path = %path.display(), "cannot load theme");
-            continue;
-        };
+    user_themes.sort_by(|a, b| a.name.cmp(&b.name));
+    all_themes.extend(user_themes);
 
-        if all_themes.iter().any(|t| t.name == theme.name) {
-            error!(theme = %theme.name, "theme already exists, skipping");
-            continue;
+    config.styling.available.set(all_themes);
+}
+
+/// Loads one file from the themes directory, logging why it is skipped if
+/// it can't be used.
+fn read_user_theme(path: &Path, builtin_themes: &[ThemeEntry]) -> Option<ThemeEntry> {
+    let theme = match get_theme_from_file(path) {
+        Ok(theme) => theme,
+        Err(Error::ThemeNotToml { .. }) => {
+            debug!(path = %path.display(), "ignoring non-toml file in themes directory");
+            return None;
         }
+        Err(err) => {
+            warn!(error = %err, "skipping invalid theme");
+            return None;
+        }
+    };
 
-        all_themes.push(theme);
+    if builtin_themes.iter().any(|t| t.name == theme.name) {
+        warn!(theme = %theme.name, "theme name is taken by a built-in theme, skipping");
+        return None;
     }
 
-    config.styling.available.set(all_themes);
+    Some(theme)
 }
 
 fn get_theme_from_file(path: &Path) -> Result<ThemeEntry, Error> {
@@ -61,9 +85,128 @@ fn get_theme_from_file(path: &Path) -> Result<ThemeEntry, Error> {
         source,
     })?;
 
+    validate_palette(&palette).map_err(|(key, source)| Error::ThemeInvalidColor {
+        path: path.into(),
+        key,
+        source,
+    })?;
+
     Ok(ThemeEntry {
         name,
         palette,
         builtin: false,
     })
 }
+
+/// Checks every color is a hex color the palette config accepts. Missing
+/// required keys are already rejected when parsing.
+fn validate_palette(palette: &Palette) -> Result<(), (&'static str, InvalidHexColor)> {
+    let required = [
+        ("bg", &palette.bg),
+        ("surface", &palette.surface),
+        ("elevated", &palette.elevated),
+        ("fg", &palette.fg),
+        ("fg_muted", &palette.fg_muted),
+        ("primary", &palette.primary),
+        ("red", &palette.red),
+        ("yellow", &palette.yellow),
+        ("green", &palette.green),
+        ("blue", &palette.blue),
+    ];
+    let optional = [
+        ("warning", &palette.warning),
+        ("info", &palette.info),
+        ("accent_2", &palette.accent_2),
+    ];
+
+    let colors = required.into_iter().chain(
+        optional
+            .into_iter()
+            .filter_map(|(key, color)| color.as_ref().map(|color| (key, color))),
+    );
+
+    for (key, color) in colors {
+        HexColor::new(color.as_str()).map_err(|err| (key, err))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    const THEME: &str = r##"
+        bg = "#000000"
+        surface = "#111111"
+        elevated = "#222222"
+        fg = "#ffffff"
+        fg_muted = "#cccccc"
+        primary = "#ff00ff"
+        red = "#ff0000"
+        yellow = "#ffff00"
+        green = "#00ff00"
+        blue = "#0000ff"
+    "##;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("wayle-config-themes-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn user_theme_names(config: &Config) -> Vec<String> {
+        config
+            .styling
+            .available
+            .get()
+            .into_iter()
+            .filter(|theme| !theme.builtin)
+            .map(|theme| theme.name)
+            .collect()
+    }
+
+    #[test]
+    fn discovers_user_themes_sorted_by_name() {
+        let dir = scratch_dir("discover");
+        fs::write(dir.join("zenburn.toml"), THEME).unwrap();
+        fs::write(dir.join("autumn.toml"), THEME).unwrap();
+
+        let config = Config::default();
+        load_themes(&config, &dir);
+
+        assert_eq!(user_theme_names(&config), ["autumn", "zenburn"]);
+    }
+
+    #[test]
+    fn skips_invalid_and_non_toml_files() {
+        let dir = scratch_dir("invalid");
+        fs::write(dir.join("good.toml"), THEME).unwrap();
+        fs::write(dir.join("missing-keys.toml"), "bg = \"#000000\"\n").unwrap();
+        fs::write(
+            dir.join("bad-color.toml"),
+            THEME.replace("\"#ff0000\"", "\"red\""),
+        )
+        .unwrap();
+        fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+        let config = Config::default();
+        load_themes(&config, &dir);
+
+        assert_eq!(user_theme_names(&config), ["good"]);
+    }
+
+    #[test]
+    fn reports_which_color_is_invalid() {
+        let mut palette: Palette = toml::from_str(THEME).unwrap();
+        palette.info = Some(String::from("#12"));
+
+        let (key, _) = validate_palette(&palette).unwrap_err();
+
+        assert_eq!(key, "info");
+    }
+}
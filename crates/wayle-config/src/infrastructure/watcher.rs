@@ -6,7 +6,10 @@ use notify::{
 use tokio::sync::{mpsc, watch};
 use tracing::{debug, error, info, instrument};
 
-use super::{error::Error, paths::ConfigPaths, secrets, service::ConfigService};
+use super::{
+    error::Error, loading::warn_unknown_keys, paths::ConfigPaths, persistence, secrets,
+    service::ConfigService,
+};
 use crate::{
     ApplyConfigLayer, ApplyRuntimeLayer, CommitConfigReload, Config, ResetConfigLayer,
     ResetRuntimeLayer, infrastructure::themes::utils::load_themes,
@@ -114,11 +117,12 @@ impl FileWatcher {
 
             config.reset_config_layer();
             config.apply_config_layer(&toml_value, "");
+            warn_unknown_keys(config, &toml_value, "config.toml");
 
             config.reset_runtime_layer();
             let runtime_path = ConfigPaths::runtime_config();
             let runtime_result =
-                tokio::task::spawn_blocking(move || ConfigService::load_toml_file(&runtime_path))
+                tokio::task::spawn_blocking(move || persistence::load_with_backup(&runtime_path))
                     .await
                     .map_err(|source| Error::TaskJoin { source })?;
 
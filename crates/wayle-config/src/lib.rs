@@ -15,8 +15,9 @@ pub mod docs;
 pub use click_action::ClickAction;
 pub use diagnostic::Diagnostic;
 pub use property::{
-    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CommitConfigReload, ConfigProperty,
-    ExtractRuntimeValues, ResetConfigLayer, ResetRuntimeLayer, SubscribeChanges, ValueSource,
+    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CollectUnknownKeys,
+    CollectValueSources, CommitConfigReload, ConfigProperty, ExtractRuntimeValues,
+    ResetConfigLayer, ResetRuntimeLayer, SubscribeChanges, ValueSource,
 };
 
 /// Configuration schema definitions.
@@ -39,6 +40,8 @@ pub mod schemas {
 pub mod infrastructure {
     /// Configuration error types
     pub mod error;
+    /// Effective configuration export
+    pub mod export;
     /// Configuration loading
     pub mod loading;
     /// Configuration paths
@@ -82,13 +85,23 @@ use crate::schemas::general::GeneralConfig;
 /// from TOML files. All fields have sensible defaults.
 #[wayle_config]
 pub struct Config {
+    /// Schema version of the config file.
+    ///
+    /// Managed by Wayle: older files are migrated on load and rewritten
+    /// with the current version. Files without it are treated as version 0.
+    #[wayle(skip)]
+    #[serde(default)]
+    pub version: u32,
+
     /// TOML files to import and merge before this config.
     ///
-    /// Paths are relative to the config file.
-    /// Imported values are overridden by values in this file.
+    /// Paths are relative to the config file. A `config:` prefix resolves
+    /// the path from the config directory (`$XDG_CONFIG_HOME/wayle`)
+    /// instead, so the same import works from any file. Absolute paths are
+    /// used as is. Imported values are overridden by values in this file.
     ///
     /// ```toml
-    /// imports = ["themes.toml", "modules/clock.toml"]
+    /// imports = ["themes.toml", "config:modules/clock.toml"]
     /// ```
     #[wayle(skip)]
     #[serde(default)]
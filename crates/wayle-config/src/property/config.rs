@@ -7,8 +7,9 @@ use tokio::sync::mpsc;
 use wayle_core::Property;
 
 use super::{
-    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CommitConfigReload,
-    ExtractRuntimeValues, ResetConfigLayer, ResetRuntimeLayer, SubscribeChanges,
+    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CollectUnknownKeys,
+    CollectValueSources, CommitConfigReload, ExtractRuntimeValues, ResetConfigLayer,
+    ResetRuntimeLayer, SubscribeChanges,
 };
 use crate::diagnostic::Diagnostic;
 
@@ -332,6 +333,30 @@ impl<T: Clone + Send + Sync + PartialEq + 'static> ClearRuntimeByPath for Config
     }
 }
 
+impl<T: Clone + Send + Sync + PartialEq + 'static> CollectValueSources for ConfigProperty<T> {
+    fn collect_value_sources(&self, path: &str, sources: &mut Vec<(String, ValueSource)>) {
+        sources.push((path.to_string(), self.source()));
+    }
+}
+
+/// Leaf values are handed to `T`'s deserializer whole, so there are no
+/// field keys to check here.
+impl<T: Clone + Send + Sync + PartialEq + 'static> CollectUnknownKeys for ConfigProperty<T> {
+    fn collect_unknown_keys(&self, _value: &toml::Value, _path: &str, _unknown: &mut Vec<String>) {}
+
+    fn accepts_key(&self, _key: &str) -> bool {
+        false
+    }
+
+    fn collect_nested_unknown_keys(
+        &self,
+        _table: &toml::Table,
+        _path: &str,
+        _unknown: &mut Vec<String>,
+    ) {
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
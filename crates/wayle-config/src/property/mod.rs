@@ -8,6 +8,7 @@ mod traits;
 
 pub use config::{ConfigProperty, ValueSource};
 pub use traits::{
-    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CommitConfigReload,
-    ExtractRuntimeValues, ResetConfigLayer, ResetRuntimeLayer, SubscribeChanges,
+    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CollectUnknownKeys,
+    CollectValueSources, CommitConfigReload, ExtractRuntimeValues, ResetConfigLayer,
+    ResetRuntimeLayer, SubscribeChanges,
 };
@@ -1,5 +1,7 @@
 use tokio::sync::mpsc;
 
+use super::ValueSource;
+
 /// Applies TOML values to the config layer of ConfigProperty fields.
 ///
 /// Used when loading or hot-reloading config.toml. The config layer sits
@@ -45,6 +47,46 @@ pub trait ExtractRuntimeValues {
     fn extract_runtime_values(&self) -> Option<toml::Value>;
 }
 
+/// Collects which layer each config value comes from.
+///
+/// Used by config dumps to annotate the effective config with the source
+/// of each value.
+pub trait CollectValueSources {
+    /// Appends the dot-separated path and [`ValueSource`] of every leaf field
+    /// to `sources`.
+    ///
+    /// The `path` parameter is the key path of this value (e.g., "bar.scale"),
+    /// or an empty string for the root.
+    fn collect_value_sources(&self, path: &str, sources: &mut Vec<(String, ValueSource)>);
+}
+
+/// Finds keys in a TOML layer that no config field reads.
+///
+/// Layers are applied by looking up the keys each struct knows, so anything
+/// else, such as a misspelled `modules.clok`, would be dropped silently.
+pub trait CollectUnknownKeys {
+    /// Appends the dot-separated path of every key in `value`, at any depth,
+    /// that doesn't match a config field.
+    ///
+    /// The `path` parameter is the key path of `value` (e.g., "modules"),
+    /// or an empty string for the root.
+    fn collect_unknown_keys(&self, value: &toml::Value, path: &str, unknown: &mut Vec<String>);
+
+    /// Whether `key` names a field of this struct, including the fields of
+    /// flattened structs.
+    fn accepts_key(&self, key: &str) -> bool;
+
+    /// Checks the values of known fields in `table` without reporting the
+    /// table's own keys. Used for flattened fields, whose keys are checked
+    /// by the parent.
+    fn collect_nested_unknown_keys(
+        &self,
+        table: &toml::Table,
+        path: &str,
+        unknown: &mut Vec<String>,
+    );
+}
+
 /// Trait for subscribing to changes in config structures.
 ///
 /// Enables automatic persistence by watching all fields for changes.
@@ -53,11 +53,36 @@ pub use shadow::ShadowPreset;
 /// [[bar.layout]]
 /// monitor = "HDMI-2"
 /// show = false
+///
+/// # Match by monitor description instead of connector
+/// [[bar.layout]]
+/// monitor = "desc:Dell Inc. DELL U2720Q"
+/// extends = "*"
+/// center = ["media", "clock"]
 /// ```
+///
+/// ## Precedence
+///
+/// Each bar uses the first layout that matches its monitor:
+///
+/// 1. `monitor` equal to the connector (e.g., `"eDP-1"`).
+/// 2. `monitor` of the form `"desc:<text>"`, where the monitor's description
+///    (make, model and serial) starts with `<text>`. This keeps a layout on
+///    the same physical screen when its connector changes between docks.
+/// 3. `monitor = "*"`.
+///
+/// Monitors with no match get no bar. A matched layout only inherits
+/// sections from another layout through `extends`; sections left empty
+/// without `extends` stay empty.
+///
+/// Modules that filter by monitor, such as `monitor-specific` in
+/// `hyprland-workspaces`, always use the connector of the monitor the bar
+/// is on, whichever layout entry matched.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(default)]
 pub struct BarLayout {
-    /// Monitor connector name (e.g., `"DP-1"`) or `"*"` for all monitors.
+    /// Monitor connector name (e.g., `"DP-1"`), `"desc:"` followed by the
+    /// start of the monitor description, or `"*"` for all monitors.
     pub monitor: String,
     /// Inherit from another layout by its monitor value (e.g., `"*"`).
     pub extends: Option<String>,
@@ -168,6 +193,8 @@ pub enum BarModule {
     Battery,
     /// Bluetooth connection status and devices.
     Bluetooth,
+    /// Display backlight brightness.
+    Brightness,
     /// Audio frequency visualizer.
     Cava,
     /// Current time display.
@@ -247,6 +274,7 @@ impl BarModule {
         match self {
             Self::Battery => "battery",
             Self::Bluetooth => "bluetooth",
+            Self::Brightness => "brightness",
             Self::Cava => "cava",
             Self::Clock => "clock",
             Self::Cpu => "cpu",
@@ -279,6 +307,7 @@ impl BarModule {
         let module = match s {
             "battery" => Self::Battery,
             "bluetooth" => Self::Bluetooth,
+            "brightness" => Self::Brightness,
             "cava" => Self::Cava,
             "clock" => Self::Clock,
             "cpu" => Self::Cpu,
@@ -362,6 +391,7 @@ impl fmt::Display for BarModule {
 const BUILTIN_MODULES: &[&str] = &[
     "battery",
     "bluetooth",
+    "brightness",
     "cava",
     "clock",
     "cpu",
@@ -1,19 +1,27 @@
 use wayle_derive::wayle_config;
 
-use crate::ConfigProperty;
+use crate::{ConfigProperty, schemas::styling::FontFamily};
+
+fn font(name: &str) -> FontFamily {
+    FontFamily::new(name).unwrap_or_default()
+}
 
 /// General Wayle configuration.
 #[wayle_config]
 pub struct GeneralConfig {
     /// Sans-serif font family for UI text and labels.
+    ///
+    /// Must not be empty or contain `"`, `\` or line breaks.
     #[serde(rename = "font-sans")]
-    #[default(String::from("Inter"))]
-    pub font_sans: ConfigProperty<String>,
+    #[default(font("Inter"))]
+    pub font_sans: ConfigProperty<FontFamily>,
 
     /// Monospace font family for code and technical content.
+    ///
+    /// Must not be empty or contain `"`, `\` or line breaks.
     #[serde(rename = "font-mono")]
-    #[default(String::from("JetBrains Mono"))]
-    pub font_mono: ConfigProperty<String>,
+    #[default(font("JetBrains Mono"))]
+    pub font_mono: ConfigProperty<FontFamily>,
 
     /// Demote overlay surfaces to allow compositor screen tearing.
     ///
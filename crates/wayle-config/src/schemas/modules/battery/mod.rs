@@ -4,7 +4,10 @@ use wayle_derive::wayle_config;
 use crate::{
     ClickAction, ConfigProperty,
     docs::{ModuleInfo, ModuleInfoProvider},
-    schemas::styling::{ColorValue, CssToken},
+    schemas::{
+        modules::HideWhen,
+        styling::{ColorValue, CssToken},
+    },
 };
 
 /// Battery module configuration.
@@ -37,6 +40,14 @@ pub struct BatteryConfig {
     #[default(String::from("md-battery_android_alert-symbolic"))]
     pub alert_icon: ConfigProperty<String>,
 
+    /// When to hide the module.
+    ///
+    /// `empty` hides the module when no battery is present, such as on
+    /// desktops. `on-battery` hides it while running on battery power.
+    #[serde(rename = "hide-when")]
+    #[default(HideWhen::Never)]
+    pub hide_when: ConfigProperty<HideWhen>,
+
     /// Display border around button.
     #[serde(rename = "border-show")]
     #[default(false)]
@@ -246,6 +246,14 @@ pub struct CustomModuleDefinition {
     #[serde(rename = "hide-if-empty", default)]
     pub hide_if_empty: bool,
 
+    /// Render the label as Pango markup.
+    ///
+    /// Lets commands style their output with tags like `<b>` or
+    /// `<span color="red">`. The label must then be valid markup, so escape
+    /// literal `&` and `<` as `&amp;` and `&lt;`.
+    #[serde(default)]
+    pub markup: bool,
+
     /// Static symbolic icon name.
     ///
     /// Used when `icon-names` and `icon-map` don't provide a match.
@@ -71,13 +71,15 @@ pub struct DashboardConfig {
     pub dropdown_logout_command: ConfigProperty<String>,
 
     /// Shell command for the reboot button in the dashboard dropdown.
+    /// Empty reboots through logind.
     #[serde(rename = "dropdown-reboot-command")]
-    #[default(String::from("systemctl reboot"))]
+    #[default(String::new())]
     pub dropdown_reboot_command: ConfigProperty<String>,
 
     /// Shell command for the power-off button in the dashboard dropdown.
+    /// Empty powers off through logind.
     #[serde(rename = "dropdown-poweroff-command")]
-    #[default(String::from("systemctl poweroff"))]
+    #[default(String::new())]
     pub dropdown_poweroff_command: ConfigProperty<String>,
 
     /// Hidden: icon always shown.
@@ -35,6 +35,10 @@ pub enum Numbering {
     /// If monitor has workspaces 4, 5, 6 assigned, they display as 1, 2, 3.
     /// Useful when keybinds use per-monitor numbering (Shift+1 for ws 4, etc.).
     Relative,
+    /// Show user-assigned workspace names ("web", "code", ...).
+    ///
+    /// Workspaces without a custom name show their ID.
+    Name,
 }
 
 /// Where the urgent pulse animation is applied.
@@ -180,7 +184,8 @@ pub struct HyprlandWorkspacesConfig {
 
     /// What identifies each workspace button.
     ///
-    /// - `label`: Shows workspace number (or name if `label-use-name` is true)
+    /// - `label`: Shows workspace number (or name if `label-use-name` is true or
+    ///   `numbering = "name"`)
     /// - `icon`: Shows icon from `workspace-map` (falls back to label if unmapped)
     /// - `none`: Shows nothing - only app icons visible
     #[serde(rename = "display-mode")]
@@ -201,6 +206,8 @@ pub struct HyprlandWorkspacesConfig {
     /// - `relative`: Show numbers relative to monitor's starting workspace.
     ///   If a monitor has workspaces 4, 5, 6 assigned, they display as 1, 2, 3.
     ///   Useful when keybinds use per-monitor numbering.
+    /// - `name`: Show user-assigned workspace names, falling back to the ID
+    ///   for workspaces that only have the default numeric name.
     #[serde(rename = "numbering")]
     #[default(Numbering::Absolute)]
     pub numbering: ConfigProperty<Numbering>,
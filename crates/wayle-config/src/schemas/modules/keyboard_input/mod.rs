@@ -76,6 +76,18 @@ pub struct KeyboardInputConfig {
     #[default(ColorValue::Token(CssToken::BgSurfaceElevated))]
     pub button_bg_color: ConfigProperty<ColorValue>,
 
+    /// Cycle keyboard layouts on click.
+    ///
+    /// When enabled, left click switches to the next layout and right click
+    /// to the previous one, wrapping around at either end. Only applies to
+    /// a click whose `left-click` or `right-click` action is unset.
+    ///
+    /// Any click or scroll action can also be set to `:next-layout` or
+    /// `:prev-layout` to cycle layouts.
+    #[serde(rename = "cycle-on-click")]
+    #[default(true)]
+    pub cycle_on_click: ConfigProperty<bool>,
+
     /// Action on left click.
     #[serde(rename = "left-click")]
     #[default(ClickAction::None)]
@@ -10,7 +10,10 @@ pub use self::icons::BUILTIN_MAPPINGS;
 use crate::{
     ClickAction, ConfigProperty,
     docs::{ModuleInfo, ModuleInfoProvider},
-    schemas::styling::{ColorValue, CssToken},
+    schemas::{
+        modules::HideWhen,
+        styling::{ColorValue, CssToken},
+    },
 };
 
 /// Media player module configuration.
@@ -73,6 +76,14 @@ pub struct MediaConfig {
     #[default(String::from("ld-disc-3-symbolic"))]
     pub spinning_disc_icon: ConfigProperty<String>,
 
+    /// When to hide the module.
+    ///
+    /// `empty` hides the module while no media player is active.
+    /// `on-battery` hides it while running on battery power.
+    #[serde(rename = "hide-when")]
+    #[default(HideWhen::Never)]
+    pub hide_when: ConfigProperty<HideWhen>,
+
     /// Display border around button.
     #[serde(rename = "border-show")]
     #[default(false)]
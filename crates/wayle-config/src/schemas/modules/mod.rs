@@ -2,6 +2,7 @@ mod types;
 
 mod battery;
 mod bluetooth;
+mod brightness;
 mod cava;
 mod clock;
 mod cpu;
@@ -30,6 +31,7 @@ mod world_clock;
 
 pub use battery::BatteryConfig;
 pub use bluetooth::BluetoothConfig;
+pub use brightness::BrightnessConfig;
 pub use cava::{
     BarCount as CavaBarCount, CavaConfig, CavaDirection, CavaInput, CavaStyle,
     Framerate as CavaFramerate, FrequencyHz,
@@ -57,8 +59,8 @@ pub use power::PowerConfig;
 pub use ram::RamConfig;
 pub use separator::SeparatorConfig;
 pub use storage::StorageConfig;
-pub use systray::{SystrayConfig, TrayItemOverride};
-pub use types::TimeFormat;
+pub use systray::{SystrayConfig, TrayItemOverride, TraySort};
+pub use types::{HideWhen, TimeFormat};
 pub use volume::{AppIconSource, VolumeConfig};
 use wayle_derive::wayle_config;
 pub use weather::{TemperatureUnit, WeatherConfig, WeatherProvider};
@@ -74,6 +76,8 @@ pub struct ModulesConfig {
     pub battery: BatteryConfig,
     /// Bluetooth connection module.
     pub bluetooth: BluetoothConfig,
+    /// Display brightness module.
+    pub brightness: BrightnessConfig,
     /// Cava audio visualizer module.
     pub cava: CavaConfig,
     /// Clock display module.
@@ -110,6 +110,14 @@ pub struct NotificationConfig {
     #[default(Vec::new())]
     pub blocklist: ConfigProperty<Vec<String>>,
 
+    /// Merge notifications that share an app name and summary.
+    ///
+    /// Only applies when the app doesn't name a notification to replace.
+    /// Keeps apps that resend progress updates from filling the list.
+    #[serde(rename = "coalesce")]
+    #[default(false)]
+    pub coalesce: ConfigProperty<bool>,
+
     /// How notification icons are resolved.
     ///
     /// | Mode | Per-notification image | No image provided |
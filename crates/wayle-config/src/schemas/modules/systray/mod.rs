@@ -5,7 +5,10 @@ use wayle_derive::wayle_config;
 use crate::{
     ConfigProperty,
     docs::{ModuleInfo, ModuleInfoProvider},
-    schemas::styling::{ColorValue, CssToken, ScaleFactor, Spacing},
+    schemas::{
+        modules::HideWhen,
+        styling::{ColorValue, CssToken, ScaleFactor, Spacing},
+    },
 };
 
 /// Systray module configuration.
@@ -49,6 +52,28 @@ pub struct SystrayConfig {
     #[default(Vec::new())]
     pub overrides: ConfigProperty<Vec<TrayItemOverride>>,
 
+    /// Order of tray items.
+    ///
+    /// `registration` keeps the order apps registered in, which can change
+    /// between sessions. The other modes give a stable order.
+    #[default(TraySort::Registration)]
+    pub sort: ConfigProperty<TraySort>,
+
+    /// Item IDs in display order, used when `sort` is `manual`.
+    ///
+    /// Items not listed follow the listed ones.
+    /// Example: `["nm-applet", "blueman", "discord"]`
+    #[default(Vec::new())]
+    pub order: ConfigProperty<Vec<String>>,
+
+    /// When to hide the module.
+    ///
+    /// `empty` hides the module while there are no tray items to show.
+    /// `on-battery` hides it while running on battery power.
+    #[serde(rename = "hide-when")]
+    #[default(HideWhen::Never)]
+    pub hide_when: ConfigProperty<HideWhen>,
+
     /// Display border around container.
     #[serde(rename = "border-show")]
     #[default(false)]
@@ -77,6 +102,22 @@ impl ModuleInfoProvider for SystrayConfig {
     }
 }
 
+/// Order of items in the systray module.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TraySort {
+    /// Order in which apps registered their tray items.
+    #[default]
+    Registration,
+    /// Alphabetical by item title.
+    Alphabetical,
+    /// Grouped by item category (application, communications, system,
+    /// hardware), alphabetical within each group.
+    Category,
+    /// Order given by `order`.
+    Manual,
+}
+
 /// Custom icon and color override for tray items matching a pattern.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct TrayItemOverride {
@@ -13,3 +13,17 @@ pub enum TimeFormat {
     #[serde(rename = "24h")]
     TwentyFourHour,
 }
+
+/// When a bar module hides itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum HideWhen {
+    /// Always shown.
+    #[default]
+    Never,
+    /// Hidden while the module has nothing to show, such as no tray items,
+    /// no media player, or no battery.
+    Empty,
+    /// Hidden while the system runs on battery power.
+    OnBattery,
+}
@@ -3,10 +3,11 @@ mod types;
 
 pub use palette::PaletteConfig;
 pub use types::{
-    ColorValue, CssToken, FontWeightClass, GapClass, HexColor, IconSizeClass, InvalidCssToken,
-    InvalidHexColor, MatugenScheme, NormalizedF64, PaddingClass, Percentage, PywalContrast,
-    RadiusClass, RoundingLevel, ScaleFactor, SignedNormalizedF64, Spacing, TextSizeClass,
-    ThemeEntry, ThemeProvider, WallustBackend, WallustColorspace, WallustPalette,
+    ColorScheme, ColorValue, CssToken, FontFamily, FontWeightClass, GapClass, HexColor,
+    IconSizeClass, InvalidCssToken, InvalidFontFamily, InvalidHexColor, MatugenScheme,
+    NormalizedF64, PaddingClass, Percentage, PywalContrast, RadiusClass, RoundingLevel,
+    ScaleFactor, SignedNormalizedF64, Spacing, TextSizeClass, ThemeEntry, ThemeMode, ThemeProvider,
+    WallustBackend, WallustColorspace, WallustPalette,
 };
 use wayle_derive::wayle_config;
 
@@ -28,6 +29,17 @@ pub struct StylingConfig {
     #[default(ThemeProvider::default())]
     pub theme_provider: ConfigProperty<ThemeProvider>,
 
+    /// Light or dark variant. Auto follows the system color-scheme preference.
+    #[serde(rename = "theme-mode")]
+    #[default(ThemeMode::default())]
+    pub theme_mode: ConfigProperty<ThemeMode>,
+
+    /// Theme whose palette is the light variant. Empty uses `palette` in
+    /// both modes.
+    #[serde(rename = "light-theme")]
+    #[default(String::new())]
+    pub light_theme: ConfigProperty<String>,
+
     /// Monitor whose wallpaper drives color extraction. Empty uses the first available.
     #[serde(rename = "theming-monitor")]
     #[default(String::new())]
@@ -128,6 +140,9 @@ impl StylingConfig {
             yellow: self.palette.yellow.get().to_string(),
             green: self.palette.green.get().to_string(),
             blue: self.palette.blue.get().to_string(),
+            warning: self.palette.warning.get().map(|color| color.to_string()),
+            info: self.palette.info.get().map(|color| color.to_string()),
+            accent_2: self.palette.accent_2.get().map(|color| color.to_string()),
         }
     }
 }
@@ -50,4 +50,17 @@ pub struct PaletteConfig {
     /// Blue semantic color.
     #[default(hex(wayle_theme::BLUE))]
     pub blue: ConfigProperty<HexColor>,
+
+    /// Warning semantic color. Falls back to `yellow` when unset.
+    #[default(None)]
+    pub warning: ConfigProperty<Option<HexColor>>,
+
+    /// Informational semantic color. Falls back to `blue` when unset.
+    #[default(None)]
+    pub info: ConfigProperty<Option<HexColor>>,
+
+    /// Secondary accent color. Falls back to `primary` when unset.
+    #[serde(rename = "accent-2")]
+    #[default(None)]
+    pub accent_2: ConfigProperty<Option<HexColor>>,
 }
@@ -49,6 +49,9 @@ pub enum CssToken {
     AccentSubtle,
     /// `--accent-hover` - Accent hover state.
     AccentHover,
+    /// `--accent-2` - Secondary accent color.
+    #[serde(rename = "accent-2")]
+    Accent2,
 
     /// `--status-error` - Error state color.
     StatusError,
@@ -111,6 +114,7 @@ impl CssToken {
             Self::Accent => "var(--accent)",
             Self::AccentSubtle => "var(--accent-subtle)",
             Self::AccentHover => "var(--accent-hover)",
+            Self::Accent2 => "var(--accent-2)",
 
             Self::StatusError => "var(--status-error)",
             Self::StatusWarning => "var(--status-warning)",
@@ -155,6 +159,7 @@ impl CssToken {
             Self::Accent => "--accent",
             Self::AccentSubtle => "--accent-subtle",
             Self::AccentHover => "--accent-hover",
+            Self::Accent2 => "--accent-2",
 
             Self::StatusError => "--status-error",
             Self::StatusWarning => "--status-warning",
@@ -213,6 +218,7 @@ impl FromStr for CssToken {
             "accent" => Ok(Self::Accent),
             "accent-subtle" => Ok(Self::AccentSubtle),
             "accent-hover" => Ok(Self::AccentHover),
+            "accent-2" => Ok(Self::Accent2),
 
             "status-error" => Ok(Self::StatusError),
             "status-warning" => Ok(Self::StatusWarning),
@@ -1,5 +1,6 @@
 mod color;
 mod extractor;
+mod mode;
 mod rounding;
 mod sizing;
 mod theme;
@@ -11,8 +12,12 @@ pub use extractor::{
     MatugenScheme, PywalContrast, SignedNormalizedF64, WallustBackend, WallustColorspace,
     WallustPalette,
 };
+pub use mode::{ColorScheme, ThemeMode};
 pub use rounding::{RadiusClass, RoundingLevel};
 pub use sizing::{GapClass, IconSizeClass, PaddingClass};
 pub use theme::ThemeEntry;
 pub use typography::{FontWeightClass, TextSizeClass};
-pub use validated::{HexColor, InvalidHexColor, NormalizedF64, Percentage, ScaleFactor, Spacing};
+pub use validated::{
+    FontFamily, HexColor, InvalidFontFamily, InvalidHexColor, NormalizedF64, Percentage,
+    ScaleFactor, Spacing,
+};
@@ -0,0 +1,136 @@
+//! Light/dark palette variant selection.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::themes::Palette;
+
+/// Which palette variant to use when both a light and dark palette exist.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    /// Always use the light palette.
+    Light,
+    /// Always use the dark palette.
+    Dark,
+    /// Follow the system color-scheme preference.
+    #[default]
+    Auto,
+}
+
+impl fmt::Display for ThemeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+            Self::Auto => "auto",
+        };
+        f.write_str(s)
+    }
+}
+
+/// System color-scheme preference.
+///
+/// Mirrors the freedesktop `org.freedesktop.appearance color-scheme` setting
+/// exposed by the settings portal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// No preference expressed. Treated as dark.
+    #[default]
+    NoPreference,
+    /// Prefers dark appearance.
+    PreferDark,
+    /// Prefers light appearance.
+    PreferLight,
+}
+
+impl ColorScheme {
+    /// Maps the raw portal value (`0`, `1`, `2`) to a preference.
+    ///
+    /// Unknown values are treated as no preference, as the portal spec requires.
+    pub fn from_portal_value(value: u32) -> Self {
+        match value {
+            1 => Self::PreferDark,
+            2 => Self::PreferLight,
+            _ => Self::NoPreference,
+        }
+    }
+}
+
+impl ThemeMode {
+    /// Whether this mode resolves to the light variant for the given preference.
+    pub fn is_light(self, system: ColorScheme) -> bool {
+        match self {
+            Self::Light => true,
+            Self::Dark => false,
+            Self::Auto => system == ColorScheme::PreferLight,
+        }
+    }
+
+    /// Picks the light or dark palette for the given system preference.
+    pub fn select<'a>(
+        self,
+        light: &'a Palette,
+        dark: &'a Palette,
+        system: ColorScheme,
+    ) -> &'a Palette {
+        if self.is_light(system) { light } else { dark }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn palettes() -> (Palette, Palette) {
+        let light = Palette {
+            bg: String::from("#ffffff"),
+            ..Palette::default()
+        };
+        let dark = Palette {
+            bg: String::from("#000000"),
+            ..Palette::default()
+        };
+        (light, dark)
+    }
+
+    #[test]
+    fn explicit_modes_ignore_system_preference() {
+        let (light, dark) = palettes();
+
+        let selected = ThemeMode::Light.select(&light, &dark, ColorScheme::PreferDark);
+        assert_eq!(selected.bg, "#ffffff");
+
+        let selected = ThemeMode::Dark.select(&light, &dark, ColorScheme::PreferLight);
+        assert_eq!(selected.bg, "#000000");
+    }
+
+    #[test]
+    fn auto_follows_system_preference() {
+        let (light, dark) = palettes();
+
+        let selected = ThemeMode::Auto.select(&light, &dark, ColorScheme::PreferLight);
+        assert_eq!(selected.bg, "#ffffff");
+
+        let selected = ThemeMode::Auto.select(&light, &dark, ColorScheme::PreferDark);
+        assert_eq!(selected.bg, "#000000");
+    }
+
+    #[test]
+    fn auto_without_preference_is_dark() {
+        let (light, dark) = palettes();
+
+        let selected = ThemeMode::Auto.select(&light, &dark, ColorScheme::NoPreference);
+        assert_eq!(selected.bg, "#000000");
+    }
+
+    #[test]
+    fn portal_values_map_to_preferences() {
+        assert_eq!(ColorScheme::from_portal_value(0), ColorScheme::NoPreference);
+        assert_eq!(ColorScheme::from_portal_value(1), ColorScheme::PreferDark);
+        assert_eq!(ColorScheme::from_portal_value(2), ColorScheme::PreferLight);
+        assert_eq!(ColorScheme::from_portal_value(7), ColorScheme::NoPreference);
+    }
+}
@@ -0,0 +1,160 @@
+//! CSS font family name newtype.
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter},
+    ops::Deref,
+    str::FromStr,
+};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Font family name, injected into the stylesheet as a quoted CSS string.
+///
+/// Rejects empty names and characters that would end or escape the quoted
+/// string (`"`, `\`, line breaks).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct FontFamily(String);
+
+impl schemars::JsonSchema for FontFamily {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("FontFamily")
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Font family name (e.g. \"Inter\")",
+            "type": "string",
+            "pattern": "^[^\"\\\\\\r\\n]*\\S[^\"\\\\\\r\\n]*$"
+        })
+    }
+}
+
+/// Error when parsing an invalid font family.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidFontFamily {
+    /// Name is empty or only whitespace.
+    #[error("font family must not be empty")]
+    Empty,
+
+    /// Character that cannot appear in a quoted CSS string.
+    #[error("font family contains invalid character {1:?} in: {0}")]
+    InvalidCharacter(String, char),
+}
+
+impl FontFamily {
+    /// Creates a font family, validating it fits in a quoted CSS string.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the name is blank or contains `"`, `\` or a line break.
+    pub fn new(value: impl Into<String>) -> Result<Self, InvalidFontFamily> {
+        let s: String = value.into();
+        Self::validate(&s)?;
+        Ok(Self(s))
+    }
+
+    /// Returns the inner string value.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn validate(s: &str) -> Result<(), InvalidFontFamily> {
+        if s.trim().is_empty() {
+            return Err(InvalidFontFamily::Empty);
+        }
+
+        if let Some(invalid) = s.chars().find(|c| matches!(c, '"' | '\\' | '\r' | '\n')) {
+            return Err(InvalidFontFamily::InvalidCharacter(s.to_owned(), invalid));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for FontFamily {
+    fn default() -> Self {
+        Self(String::from("sans-serif"))
+    }
+}
+
+impl Deref for FontFamily {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for FontFamily {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for FontFamily {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for FontFamily {
+    type Err = InvalidFontFamily;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for FontFamily {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::new(s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_names_with_spaces() {
+        assert!(FontFamily::new("JetBrains Mono").is_ok());
+        assert!(FontFamily::new("Noto Sans CJK JP").is_ok());
+    }
+
+    #[test]
+    fn rejects_blank_names() {
+        assert_eq!(FontFamily::new(""), Err(InvalidFontFamily::Empty));
+        assert_eq!(FontFamily::new("   "), Err(InvalidFontFamily::Empty));
+    }
+
+    #[test]
+    fn rejects_characters_that_break_the_css_string() {
+        assert!(matches!(
+            FontFamily::new("Broken\"Font"),
+            Err(InvalidFontFamily::InvalidCharacter(_, '"'))
+        ));
+        assert!(matches!(
+            FontFamily::new("Back\\slash"),
+            Err(InvalidFontFamily::InvalidCharacter(_, '\\'))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_in_toml() {
+        #[derive(Debug, Deserialize)]
+        struct TestConfig {
+            #[allow(dead_code)]
+            font: FontFamily,
+        }
+
+        let result: Result<TestConfig, _> = toml::from_str("font = \"\"");
+        assert!(result.is_err());
+    }
+}
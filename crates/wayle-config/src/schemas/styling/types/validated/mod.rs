@@ -1,11 +1,13 @@
 //! Validated newtypes with built-in constraints.
 
+mod font_family;
 mod hex_color;
 mod normalized;
 mod percentage;
 mod scale;
 mod spacing;
 
+pub use font_family::{FontFamily, InvalidFontFamily};
 pub use hex_color::{HexColor, InvalidHexColor};
 pub use normalized::NormalizedF64;
 pub use percentage::Percentage;
@@ -18,16 +18,20 @@
 //!
 //! Also includes D-Bus macros (`unwrap_*!`, `watch_all!`) for extracting
 //! properties with type-safe defaults.
+//! [`combine_latest!`] merges several properties into one stream of tuples.
+//! [`rate`] caps how often a stream yields, for values that change faster
+//! than a UI needs to repaint.
 //!
 //! Enable `schema` for [`schemars::JsonSchema`] support on `Property<T>`.
 
 #[macro_use]
 mod macros;
 mod property;
+pub mod rate;
 
 use std::sync::Arc;
 
-pub use property::{Property, PropertyStream};
+pub use property::{ComputedProperty, Property, PropertyStream, Unwatched};
 
 /// A [`Property`] holding a service that initializes in the background.
 /// Starts `None`, becomes `Some` once the service is ready.
@@ -26,6 +26,43 @@ macro_rules! watch_all {
     };
 }
 
+/// Combines several [`Property`](crate::Property) values into one stream of
+/// tuples, yielding the latest value of every input whenever any changes.
+///
+/// The first item carries the current values. Changes that land together
+/// are coalesced into a single item, and values are read when the item is
+/// produced, so no update is lost between subscribing and polling.
+///
+/// ```rust,ignore
+/// let mut changes = combine_latest!(device.volume, device.muted, config.show_percentage);
+/// while let Some((volume, muted, show_percentage)) = changes.next().await {
+///     render(volume, muted, show_percentage);
+/// }
+/// ```
+#[macro_export]
+macro_rules! combine_latest {
+    ($($property:expr),+ $(,)?) => {
+        $crate::combine_latest!(@bind [] $($property),+)
+    };
+
+    (@bind [$($bound:ident)*] $head:expr $(, $tail:expr)*) => {{
+        let property = $head.clone();
+        $crate::combine_latest!(@bind [$($bound)* property] $($tail),*)
+    }};
+
+    (@bind [$($bound:ident)+]) => {{
+        use ::futures::StreamExt;
+
+        let streams: Vec<::futures::stream::BoxStream<'static, ()>> = vec![
+            $($bound.watch().map(|_| ()).boxed(),)+
+        ];
+        let inputs = streams.len();
+        ::futures::stream::select_all(streams)
+            .ready_chunks(inputs)
+            .map(move |_| ($($bound.get(),)+))
+    }};
+}
+
 /// Extracts a value from a D-Bus property `Result`, returning
 /// [`Default::default()`] on error and logging the failure at
 /// `debug` level.
@@ -107,3 +144,62 @@ macro_rules! remove_and_cancel {
         $property.set(items);
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use std::task::Poll;
+
+    use futures::{poll, stream::StreamExt};
+
+    use crate::Property;
+
+    #[tokio::test]
+    async fn combine_latest_seeds_with_current_values_once() {
+        let volume = Property::new(50_u32);
+        let muted = Property::new(false);
+
+        let mut combined = combine_latest!(volume, muted);
+
+        assert_eq!(combined.next().await, Some((50, false)));
+        assert_eq!(poll!(combined.next()), Poll::Pending);
+    }
+
+    #[tokio::test]
+    async fn combine_latest_yields_on_any_change() {
+        let volume = Property::new(50_u32);
+        let muted = Property::new(false);
+        let name = Property::new(String::from("speakers"));
+
+        let mut combined = combine_latest!(volume, muted, name);
+        assert_eq!(
+            combined.next().await,
+            Some((50, false, String::from("speakers")))
+        );
+
+        muted.set(true);
+        assert_eq!(
+            combined.next().await,
+            Some((50, true, String::from("speakers")))
+        );
+
+        volume.set(80);
+        assert_eq!(
+            combined.next().await,
+            Some((80, true, String::from("speakers")))
+        );
+    }
+
+    #[test]
+    fn combine_latest_releases_subscriptions_on_drop() {
+        let volume = Property::new(50_u32);
+        let muted = Property::new(false);
+
+        let combined = combine_latest!(volume, muted);
+        assert!(volume.has_subscribers());
+        assert!(muted.has_subscribers());
+
+        drop(combined);
+        assert!(!volume.has_subscribers());
+        assert!(!muted.has_subscribers());
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+use futures::stream::{Stream, StreamExt};
+
+use super::{Property, PropertyStream};
+
+/// Read-only value derived from a [`Property`] with [`Property::map`].
+///
+/// Holds no background task: [`get`](Self::get) applies the mapping to the
+/// source's current value, and each [`watch`](Self::watch) stream maps the
+/// source's stream. Dropping the stream drops the source subscription.
+///
+/// ```
+/// use wayle_core::Property;
+///
+/// let percentage = Property::new(42.4_f64);
+/// let label = percentage.map(|p| format!("{p:.0}%"));
+///
+/// assert_eq!(label.get(), "42%");
+/// ```
+pub struct ComputedProperty<U> {
+    get: Arc<dyn Fn() -> U + Send + Sync>,
+    watch: Arc<dyn Fn() -> PropertyStream<U> + Send + Sync>,
+}
+
+impl<U> Clone for ComputedProperty<U> {
+    fn clone(&self) -> Self {
+        Self {
+            get: Arc::clone(&self.get),
+            watch: Arc::clone(&self.watch),
+        }
+    }
+}
+
+impl<U: Send + Debug + 'static> Debug for ComputedProperty<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComputedProperty")
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+impl<U: Send + 'static> ComputedProperty<U> {
+    pub(super) fn new<T, F>(source: &Property<T>, f: F) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+        F: Fn(&T) -> U + Send + Sync + 'static,
+    {
+        let f = Arc::new(f);
+
+        let get = {
+            let rx = source.rx.clone();
+            let f = Arc::clone(&f);
+            // Clone out of the borrow so `f` runs without holding the
+            // source's lock and may read or set it.
+            move || {
+                let value = rx.borrow().clone();
+                f(&value)
+            }
+        };
+
+        let watch = {
+            let source = source.clone();
+            move || -> PropertyStream<U> {
+                let f = Arc::clone(&f);
+                Box::new(source.watch().map(move |value| f(&value)))
+            }
+        };
+
+        Self {
+            get: Arc::new(get),
+            watch: Arc::new(watch),
+        }
+    }
+
+    pub(super) fn combine<T, V, F>(first: &Property<T>, second: &Property<V>, f: F) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+        V: Clone + Send + Sync + 'static,
+        F: Fn(&T, &V) -> U + Send + Sync + 'static,
+    {
+        let f = Arc::new(f);
+
+        let get = {
+            let first = first.clone();
+            let second = second.clone();
+            let f = Arc::clone(&f);
+            move || f(&first.get(), &second.get())
+        };
+
+        let watch = {
+            let first = first.clone();
+            let second = second.clone();
+            move || -> PropertyStream<U> {
+                let f = Arc::clone(&f);
+                let changes = crate::combine_latest!(first, second);
+                Box::new(changes.map(move |(first, second)| f(&first, &second)))
+            }
+        };
+
+        Self {
+            get: Arc::new(get),
+            watch: Arc::new(watch),
+        }
+    }
+
+    /// Derived value for the source's current value.
+    pub fn get(&self) -> U {
+        (self.get)()
+    }
+
+    /// Yields the derived current value immediately, then one derived value
+    /// per source change.
+    pub fn watch(&self) -> impl Stream<Item = U> + Send + Unpin + 'static {
+        (self.watch)()
+    }
+
+    /// Derives another value from this one.
+    ///
+    /// ```
+    /// use wayle_core::Property;
+    ///
+    /// let level = Property::new(80_u32);
+    /// let low = level.map(|l| *l < 20).map(|low| if *low { "low" } else { "ok" });
+    ///
+    /// assert_eq!(low.get(), "ok");
+    /// ```
+    pub fn map<V, F>(&self, f: F) -> ComputedProperty<V>
+    where
+        V: Send + 'static,
+        F: Fn(&U) -> V + Send + Sync + 'static,
+    {
+        let f = Arc::new(f);
+
+        let get = {
+            let source = Arc::clone(&self.get);
+            let f = Arc::clone(&f);
+            move || f(&source())
+        };
+
+        let watch = {
+            let source = Arc::clone(&self.watch);
+            move || -> PropertyStream<V> {
+                let f = Arc::clone(&f);
+                Box::new(source().map(move |value| f(&value)))
+            }
+        };
+
+        ComputedProperty {
+            get: Arc::new(get),
+            watch: Arc::new(watch),
+        }
+    }
+}
+
+impl<T> From<Property<T>> for ComputedProperty<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Read-only view of `property`, for APIs that accept either kind.
+    fn from(property: Property<T>) -> Self {
+        property.map(T::clone)
+    }
+}
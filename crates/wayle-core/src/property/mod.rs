@@ -1,23 +1,43 @@
 //! Single-producer, multi-consumer reactive values built on
 //! [`tokio::sync::watch`]. See [`Property`] for the main type.
 
+mod computed;
 mod serde;
 mod stream;
 
-use std::sync::{
-    Arc,
-    atomic::{AtomicUsize, Ordering},
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
 };
 
-use futures::stream::Stream;
+use futures::{StreamExt, future, stream::Stream};
 use tokio::sync::{Notify, watch};
 use tokio_stream::wrappers::WatchStream;
+use tracing::trace;
 
+pub use self::computed::ComputedProperty;
 use self::stream::SubscribedStream;
 
 /// Stream of property value changes.
 pub type PropertyStream<T> = Box<dyn Stream<Item = T> + Send + Unpin>;
 
+/// Returned by [`Property::publish`] when no [`.watch()`](Property::watch)
+/// stream is alive to receive the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unwatched;
+
+impl Display for Unwatched {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "property has no watchers")
+    }
+}
+
+impl Error for Unwatched {}
+
 /// A value you can `.get()` or `.watch()` for changes.
 ///
 /// ```ignore
@@ -86,6 +106,40 @@ impl<T: Clone + Send + Sync + 'static> Property<T> {
         });
     }
 
+    /// [`set`](Self::set) that reports whether anyone received the value.
+    ///
+    /// The value is stored either way, so [`get`](Self::get) stays current.
+    /// A monitor can treat [`Unwatched`] as a signal to stop its upstream
+    /// work, e.g. by cancelling its `CancellationToken`.
+    ///
+    /// ```
+    /// use wayle_core::{Property, Unwatched};
+    ///
+    /// let level = Property::new(0_u32);
+    /// assert_eq!(level.publish(10), Err(Unwatched));
+    /// assert_eq!(level.get(), 10);
+    ///
+    /// let _stream = level.watch();
+    /// assert_eq!(level.publish(20), Ok(()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Unwatched`] if no `.watch()` stream is alive.
+    pub fn publish(&self, new_value: T) -> Result<(), Unwatched>
+    where
+        T: PartialEq,
+    {
+        self.set(new_value);
+
+        if self.has_subscribers() {
+            return Ok(());
+        }
+
+        trace!("property published with no watchers");
+        Err(Unwatched)
+    }
+
     /// Unconditional [`set`](Self::set). Always notifies watchers, and
     /// doesn't require `PartialEq`.
     ///
@@ -118,6 +172,11 @@ impl<T: Clone + Send + Sync + 'static> Property<T> {
     /// Each call returns an independent stream. Multiple consumers
     /// can watch the same property concurrently.
     ///
+    /// The first item is read when the stream is first polled, not when
+    /// it's created, so a change landing in between is never missed.
+    /// Initialize from the first item rather than calling
+    /// [`get`](Self::get) beforehand.
+    ///
     /// ```no_run
     /// use futures::stream::StreamExt;
     /// use wayle_core::Property;
@@ -139,6 +198,77 @@ impl<T: Clone + Send + Sync + 'static> Property<T> {
         )
     }
 
+    /// Like [`watch`](Self::watch), but only yields values matching `predicate`.
+    ///
+    /// The current value is yielded first if it matches.
+    ///
+    /// ```no_run
+    /// use futures::stream::StreamExt;
+    /// use wayle_core::Property;
+    ///
+    /// # async fn example() {
+    /// let battery = Property::new(100_u32);
+    ///
+    /// let mut low = battery.filter(|level| *level < 20);
+    /// while let Some(level) = low.next().await {
+    ///     println!("battery low: {level}%");
+    /// }
+    /// # }
+    /// ```
+    pub fn filter<F>(&self, predicate: F) -> impl Stream<Item = T> + Send + 'static
+    where
+        F: Fn(&T) -> bool + Send + 'static,
+    {
+        self.watch()
+            .filter(move |value| future::ready(predicate(value)))
+    }
+
+    /// Derives a read-only [`ComputedProperty`] from this one.
+    ///
+    /// The mapping runs on [`get`](ComputedProperty::get) and for every
+    /// change seen by a [`watch`](ComputedProperty::watch) stream. No task is
+    /// spawned, so nothing outlives the derived property or its streams.
+    ///
+    /// ```
+    /// use wayle_core::Property;
+    ///
+    /// let percentage = Property::new(57.8_f64);
+    /// let label = percentage.map(|p| format!("{p:.0}%"));
+    ///
+    /// assert_eq!(label.get(), "58%");
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> ComputedProperty<U>
+    where
+        U: Send + 'static,
+        F: Fn(&T) -> U + Send + Sync + 'static,
+    {
+        ComputedProperty::new(self, f)
+    }
+
+    /// Derives a read-only value from this property and `other`.
+    ///
+    /// The result follows both sources through
+    /// [`combine_latest!`](crate::combine_latest): its stream yields once up
+    /// front and again whenever either of them changes.
+    ///
+    /// ```
+    /// use wayle_core::Property;
+    ///
+    /// let used = Property::new(3_u32);
+    /// let total = Property::new(4_u32);
+    /// let label = used.combine(&total, |used, total| format!("{used}/{total}"));
+    ///
+    /// assert_eq!(label.get(), "3/4");
+    /// ```
+    pub fn combine<V, U, F>(&self, other: &Property<V>, f: F) -> ComputedProperty<U>
+    where
+        V: Clone + Send + Sync + 'static,
+        U: Send + 'static,
+        F: Fn(&T, &V) -> U + Send + Sync + 'static,
+    {
+        ComputedProperty::combine(self, other, f)
+    }
+
     /// Whether any [`.watch()`](Self::watch) streams are alive.
     pub fn has_subscribers(&self) -> bool {
         self.subscriber_count.load(Ordering::Acquire) > 0
@@ -171,6 +301,41 @@ impl<T: Clone + Send + Sync + 'static> Property<T> {
             self.subscriber_notify.notified().await;
         }
     }
+
+    /// Suspends until the last [`.watch()`](Self::watch) stream is dropped.
+    /// Resolves immediately if nobody is watching.
+    ///
+    /// Lets a monitor cancel its work as soon as the UI stops listening,
+    /// instead of waiting for its next update to find out.
+    ///
+    /// ```no_run
+    /// use wayle_core::Property;
+    ///
+    /// # async fn read_signal() -> u8 { 0 }
+    /// # async fn example() {
+    /// let signal = Property::new(0_u8);
+    ///
+    /// loop {
+    ///     tokio::select! {
+    ///         () = signal.wait_for_no_subscribers() => break,
+    ///         level = read_signal() => signal.set(level),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn wait_for_no_subscribers(&self) {
+        loop {
+            let notified = self.subscriber_notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if !self.has_subscribers() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -211,6 +376,35 @@ mod tests {
         assert_eq!(stream.next().await, Some(2));
     }
 
+    #[tokio::test]
+    async fn first_item_reflects_changes_made_before_polling() {
+        let property = Property::new(1);
+        let mut stream = property.watch();
+
+        property.set(2);
+        assert_eq!(stream.next().await, Some(2));
+
+        property.set(3);
+        assert_eq!(stream.next().await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn combine_follows_both_sources() {
+        let first = Property::new(1);
+        let second = Property::new(10);
+        let sum = first.combine(&second, |a, b| a + b);
+        let mut stream = sum.watch();
+
+        assert_eq!(stream.next().await, Some(11));
+
+        second.set(20);
+        assert_eq!(stream.next().await, Some(21));
+
+        first.set(2);
+        assert_eq!(stream.next().await, Some(22));
+        assert_eq!(sum.get(), 22);
+    }
+
     #[test]
     fn no_subscribers_initially() {
         let property = Property::new(0);
@@ -276,6 +470,117 @@ mod tests {
         waiter.await.unwrap();
     }
 
+    #[test]
+    fn publish_reports_missing_watchers() {
+        let property = Property::new(0);
+        assert_eq!(property.publish(1), Err(Unwatched));
+        assert_eq!(property.get(), 1);
+
+        let stream = property.watch();
+        assert_eq!(property.publish(2), Ok(()));
+
+        drop(stream);
+        assert_eq!(property.publish(3), Err(Unwatched));
+        assert_eq!(property.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn wait_for_no_subscribers_resolves_when_last_watcher_drops() {
+        let property = Property::new(0);
+        property.wait_for_no_subscribers().await;
+
+        let stream_a = property.watch();
+        let stream_b = property.watch();
+
+        let waiting = property.clone();
+        let waiter = tokio::spawn(async move { waiting.wait_for_no_subscribers().await });
+
+        drop(stream_a);
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        drop(stream_b);
+        assert!(waiter.await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn map_derives_current_and_changed_values() {
+        let property = Property::new(57.8_f64);
+        let label = property.map(|p| format!("{p:.0}%"));
+        assert_eq!(label.get(), "58%");
+
+        let mut stream = label.watch();
+        assert_eq!(stream.next().await.as_deref(), Some("58%"));
+
+        property.set(12.1);
+        assert_eq!(label.get(), "12%");
+        assert_eq!(stream.next().await.as_deref(), Some("12%"));
+    }
+
+    #[test]
+    fn map_closure_can_set_its_source() {
+        let property = Property::new(1_u32);
+        let setter = property.clone();
+        let computed = property.map(move |n| {
+            setter.set(n + 1);
+            *n
+        });
+
+        assert_eq!(computed.get(), 1);
+        assert_eq!(property.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn chained_map_follows_source() {
+        let property = Property::new(3_u32);
+        let doubled = property.map(|n| n * 2).map(|n| n + 1);
+        let mut stream = doubled.watch();
+
+        assert_eq!(stream.next().await, Some(7));
+
+        property.set(5);
+        assert_eq!(doubled.get(), 11);
+        assert_eq!(stream.next().await, Some(11));
+    }
+
+    #[test]
+    fn computed_stream_releases_source_subscription() {
+        let property = Property::new(0);
+        let computed = property.map(|n| n + 1);
+        assert!(!property.has_subscribers());
+
+        let stream = computed.watch();
+        assert!(property.has_subscribers());
+
+        drop(stream);
+        assert!(!property.has_subscribers());
+    }
+
+    #[tokio::test]
+    async fn computed_from_property_mirrors_source() {
+        let property = Property::new(String::from("a"));
+        let computed = ComputedProperty::from(property.clone());
+        let mut stream = computed.watch();
+        assert_eq!(stream.next().await.as_deref(), Some("a"));
+
+        property.set(String::from("b"));
+        assert_eq!(computed.get(), "b");
+        assert_eq!(stream.next().await.as_deref(), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn filter_skips_values_failing_predicate() {
+        let property = Property::new(1);
+        let mut stream = property.filter(|n| n % 2 == 0);
+
+        property.set(3);
+        property.set(4);
+        assert_eq!(stream.next().await, Some(4));
+
+        property.set(5);
+        assert_eq!(poll!(stream.next()), Poll::Pending);
+    }
+
     #[tokio::test]
     async fn wait_for_subscribers_returns_immediately_if_already_watched() {
         let property = Property::new(0);
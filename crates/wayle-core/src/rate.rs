@@ -0,0 +1,248 @@
+//! Rate limiting for high-frequency streams.
+//!
+//! CPU, network and CAVA values change many times per second, but a UI only
+//! needs to repaint at a capped rate. Both adaptors keep the latest value
+//! and never drop the last one the source yields.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use futures::stream::StreamExt;
+//! use wayle_core::{Property, rate};
+//!
+//! # async fn example() {
+//! let usage = Property::new(0.0_f64);
+//!
+//! let mut repaints = rate::throttle(usage.watch(), Duration::from_millis(250));
+//! while let Some(usage) = repaints.next().await {
+//!     println!("{usage:.1}%");
+//! }
+//! # }
+//! ```
+
+use std::{pin::Pin, time::Duration};
+
+use futures::{
+    FutureExt,
+    stream::{self, Stream, StreamExt},
+};
+use tokio::time::{Instant, sleep_until};
+
+/// Yields at most one value per `window`.
+///
+/// The first value passes through immediately. Values arriving during the
+/// window replace each other, and the latest is yielded when it closes. If
+/// the source ends mid-window, its last value is still yielded once the
+/// window closes.
+pub fn throttle<S>(
+    source: S,
+    window: Duration,
+) -> impl Stream<Item = S::Item> + Send + Unpin + 'static
+where
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+{
+    let state = Throttle {
+        source: Box::pin(source),
+        window,
+        deadline: None,
+        pending: None,
+        source_done: false,
+    };
+
+    Box::pin(stream::unfold(state, |mut state| async move {
+        let item = state.next().await?;
+        Some((item, state))
+    }))
+}
+
+/// Yields the latest value from `source` on each tick of `ticks`.
+///
+/// Ticks with no new value since the last one yield nothing, so an idle
+/// source doesn't cause repaints. The stream ends once the source has ended
+/// and its last value was yielded, or when `ticks` ends.
+pub fn sample<S, T>(source: S, ticks: T) -> impl Stream<Item = S::Item> + Send + Unpin + 'static
+where
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+    T: Stream + Send + 'static,
+{
+    let state = Sample {
+        source: Box::pin(source),
+        ticks: Box::pin(ticks),
+        latest: None,
+        source_done: false,
+    };
+
+    Box::pin(stream::unfold(state, |mut state| async move {
+        let item = state.next().await?;
+        Some((item, state))
+    }))
+}
+
+struct Throttle<S: Stream> {
+    source: Pin<Box<S>>,
+    window: Duration,
+    deadline: Option<Instant>,
+    pending: Option<S::Item>,
+    source_done: bool,
+}
+
+impl<S: Stream> Throttle<S> {
+    async fn next(&mut self) -> Option<S::Item> {
+        loop {
+            let Some(deadline) = self.deadline else {
+                if self.source_done {
+                    return None;
+                }
+
+                let Some(item) = self.source.next().await else {
+                    self.source_done = true;
+                    return None;
+                };
+                self.deadline = Some(Instant::now() + self.window);
+                return Some(item);
+            };
+
+            if self.source_done {
+                self.deadline = None;
+                if self.pending.is_some() {
+                    sleep_until(deadline).await;
+                }
+                return self.pending.take();
+            }
+
+            tokio::select! {
+                item = self.source.next() => match item {
+                    Some(item) => self.pending = Some(item),
+                    None => self.source_done = true,
+                },
+                () = sleep_until(deadline) => {
+                    let Some(item) = self.pending.take() else {
+                        self.deadline = None;
+                        continue;
+                    };
+                    self.deadline = Some(Instant::now() + self.window);
+                    return Some(item);
+                }
+            }
+        }
+    }
+}
+
+struct Sample<S: Stream, T> {
+    source: Pin<Box<S>>,
+    ticks: Pin<Box<T>>,
+    latest: Option<S::Item>,
+    source_done: bool,
+}
+
+impl<S: Stream, T: Stream> Sample<S, T> {
+    async fn next(&mut self) -> Option<S::Item> {
+        loop {
+            if self.source_done && self.latest.is_none() {
+                return None;
+            }
+
+            tokio::select! {
+                item = self.source.next(), if !self.source_done => match item {
+                    Some(item) => self.latest = Some(item),
+                    None => self.source_done = true,
+                },
+                tick = self.ticks.next() => {
+                    if tick.is_none() {
+                        self.source_done = true;
+                        self.latest = None;
+                        return None;
+                    }
+                    self.drain_ready();
+                    if let Some(item) = self.latest.take() {
+                        return Some(item);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Takes values the source already has, so a tick that races with them
+    /// still sees the latest.
+    fn drain_ready(&mut self) {
+        while !self.source_done {
+            match self.source.next().now_or_never() {
+                Some(Some(item)) => self.latest = Some(item),
+                Some(None) => self.source_done = true,
+                None => return,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::StreamExt;
+    use tokio::{sync::mpsc, time::sleep};
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_millis(100);
+
+    #[tokio::test(start_paused = true)]
+    async fn throttle_passes_first_value_and_keeps_latest() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut throttled = throttle(UnboundedReceiverStream::new(rx), WINDOW);
+
+        tx.send(1).unwrap();
+        assert_eq!(throttled.next().await, Some(1));
+
+        tx.send(2).unwrap();
+        tx.send(3).unwrap();
+        let start = Instant::now();
+        assert_eq!(throttled.next().await, Some(3));
+        assert!(start.elapsed() >= WINDOW);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn throttle_yields_final_value_when_source_ends() {
+        let source = stream::iter([1, 2, 3]);
+        let throttled = throttle(source, WINDOW);
+
+        assert_eq!(throttled.collect::<Vec<_>>().await, vec![1, 3]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn throttle_passes_value_after_idle_window() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut throttled = throttle(UnboundedReceiverStream::new(rx), WINDOW);
+
+        tx.send(1).unwrap();
+        assert_eq!(throttled.next().await, Some(1));
+
+        let next = tokio::spawn(async move { throttled.next().await });
+        sleep(WINDOW * 3).await;
+        tx.send(2).unwrap();
+
+        assert_eq!(next.await.unwrap(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn sample_yields_latest_on_tick() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (tick, ticks) = mpsc::unbounded_channel();
+        let mut sampled = sample(
+            UnboundedReceiverStream::new(rx),
+            UnboundedReceiverStream::new(ticks),
+        );
+
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        tick.send(()).unwrap();
+        assert_eq!(sampled.next().await, Some(2));
+
+        tx.send(3).unwrap();
+        drop(tx);
+        tick.send(()).unwrap();
+        assert_eq!(sampled.next().await, Some(3));
+        assert_eq!(sampled.next().await, None);
+    }
+}
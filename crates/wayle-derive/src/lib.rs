@@ -27,24 +27,89 @@ fn validate_named_struct(input: &DeriveInput) -> Result<&FieldsNamed, TokenStrea
     }
 }
 
-fn should_skip(field: &Field) -> bool {
-    field.attrs.iter().any(|attr| {
+#[derive(Default)]
+struct WayleFieldAttrs {
+    skip: bool,
+    flatten: bool,
+    rename: Option<String>,
+}
+
+fn wayle_field_attrs(field: &Field) -> WayleFieldAttrs {
+    let mut attrs = WayleFieldAttrs::default();
+
+    for attr in &field.attrs {
         if !attr.path().is_ident("wayle") {
-            return false;
+            continue;
         }
 
-        let mut skip = false;
         let _ = attr.parse_nested_meta(|meta| {
             if meta.path.is_ident("skip") {
-                skip = true;
+                attrs.skip = true;
+            } else if meta.path.is_ident("flatten") {
+                attrs.flatten = true;
+            } else if meta.path.is_ident("rename") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                attrs.rename = Some(value.value());
             }
             Ok(())
         });
-        skip
-    })
+    }
+
+    attrs
+}
+
+fn should_skip(field: &Field) -> bool {
+    wayle_field_attrs(field).skip
+}
+
+fn is_flattened(field: &Field) -> bool {
+    wayle_field_attrs(field).flatten
+}
+
+/// Rejects duplicate sibling keys and `rename` on flattened fields.
+///
+/// Collisions between a flattened struct's keys and its siblings can only be
+/// seen once both types are known, so those are checked by a const assertion
+/// generated in [`derive_apply_config_layer`].
+fn validate_keys(fields: &FieldsNamed) -> Result<(), TokenStream> {
+    let mut seen: Vec<String> = Vec::new();
+
+    for field in fields.named.iter().filter(|field| !should_skip(field)) {
+        let attrs = wayle_field_attrs(field);
+
+        if attrs.flatten {
+            if attrs.rename.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "#[wayle(flatten)] fields have no key of their own to rename",
+                )
+                .to_compile_error()
+                .into());
+            }
+            continue;
+        }
+
+        let key = config_key(field);
+        if seen.contains(&key) {
+            return Err(
+                syn::Error::new_spanned(field, format!("duplicate config key '{key}'"))
+                    .to_compile_error()
+                    .into(),
+            );
+        }
+        seen.push(key);
+    }
+
+    Ok(())
 }
 
-fn serde_key(field: &Field) -> String {
+/// TOML key for a field: `#[wayle(rename)]`, then `#[serde(rename)]`, then
+/// the field name.
+fn config_key(field: &Field) -> String {
+    if let Some(name) = wayle_field_attrs(field).rename {
+        return name;
+    }
+
     for attr in &field.attrs {
         if !attr.path().is_ident("serde") {
             continue;
@@ -79,10 +144,16 @@ fn serde_key(field: &Field) -> String {
 /// # Attributes
 ///
 /// - `#[wayle(skip)]` - Skip this field in config layer application
+/// - `#[wayle(rename = "key")]` - Use `key` as the TOML key instead of the field name
+/// - `#[wayle(flatten)]` - Apply the parent table to this field instead of a nested table
 ///
 /// # Generated Code
 ///
 /// For each field, generates: `self.field.apply_config_layer(&toml["field"], "path.field")`
+///
+/// Also generates a hidden, crate-private `__WAYLE_CONFIG_KEYS` constant
+/// listing every key the struct reads, including those of flattened fields
+/// at any depth, and a const assertion that no key appears twice.
 #[proc_macro_derive(ApplyConfigLayer, attributes(wayle))]
 pub fn derive_apply_config_layer(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -93,13 +164,25 @@ pub fn derive_apply_config_layer(input: TokenStream) -> TokenStream {
         Err(err) => return err,
     };
 
+    if let Err(err) = validate_keys(fields) {
+        return err;
+    }
+
+    let config_keys = config_keys_const(name, fields);
+
     let field_updates = fields
         .named
         .iter()
         .filter(|field| !should_skip(field))
         .map(|field| {
             let field_name = &field.ident;
-            let key = serde_key(field);
+            let key = config_key(field);
+
+            if is_flattened(field) {
+                return quote! {
+                    self.#field_name.apply_config_layer(value, path);
+                };
+            }
 
             quote! {
                 if let Some(field_value) = table.get(#key) {
@@ -121,11 +204,89 @@ pub fn derive_apply_config_layer(input: TokenStream) -> TokenStream {
                 }
             }
         }
+
+        #config_keys
     };
 
     TokenStream::from(expanded)
 }
 
+/// Hidden `__WAYLE_CONFIG_KEYS` array of every key `name` reads, with the
+/// keys of flattened fields spliced in, plus a const assertion that no key
+/// appears twice, which would mean a flattened field collides with another
+/// field at some depth.
+fn config_keys_const(name: &Ident, fields: &FieldsNamed) -> TokenStream2 {
+    let active_fields = || fields.named.iter().filter(|field| !should_skip(field));
+
+    let own_keys: Vec<String> = active_fields()
+        .filter(|field| !is_flattened(field))
+        .map(config_key)
+        .collect();
+    let own_count = own_keys.len();
+    let flattened_types: Vec<_> = active_fields()
+        .filter(|field| is_flattened(field))
+        .map(|field| &field.ty)
+        .collect();
+
+    let message = format!("a key of a flattened field collides with another key of `{name}`");
+
+    quote! {
+        impl #name {
+            #[doc(hidden)]
+            pub(crate) const __WAYLE_CONFIG_KEY_COUNT: usize =
+                #own_count #(+ <#flattened_types>::__WAYLE_CONFIG_KEY_COUNT)*;
+
+            #[doc(hidden)]
+            pub(crate) const __WAYLE_CONFIG_KEYS: [&'static str; #name::__WAYLE_CONFIG_KEY_COUNT] = {
+                let mut keys = [""; #name::__WAYLE_CONFIG_KEY_COUNT];
+                let own: [&str; #own_count] = [#(#own_keys),*];
+                let mut len = 0;
+                while len < own.len() {
+                    keys[len] = own[len];
+                    len += 1;
+                }
+                #(
+                    let nested = &<#flattened_types>::__WAYLE_CONFIG_KEYS;
+                    let mut i = 0;
+                    while i < nested.len() {
+                        keys[len] = nested[i];
+                        len += 1;
+                        i += 1;
+                    }
+                )*
+                let _ = len;
+                keys
+            };
+        }
+
+        const _: () = {
+            const fn has_duplicate(keys: &[&str]) -> bool {
+                let mut i = 0;
+                while i < keys.len() {
+                    let mut j = i + 1;
+                    while j < keys.len() {
+                        let (a, b) = (keys[i].as_bytes(), keys[j].as_bytes());
+                        if a.len() == b.len() {
+                            let mut k = 0;
+                            while k < a.len() && a[k] == b[k] {
+                                k += 1;
+                            }
+                            if k == a.len() {
+                                return true;
+                            }
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                false
+            }
+
+            assert!(!has_duplicate(&#name::__WAYLE_CONFIG_KEYS), #message);
+        };
+    }
+}
+
 /// Derive macro for `ApplyRuntimeLayer` trait.
 ///
 /// Walks struct fields and applies TOML values to their runtime layer.
@@ -134,6 +295,8 @@ pub fn derive_apply_config_layer(input: TokenStream) -> TokenStream {
 /// # Attributes
 ///
 /// - `#[wayle(skip)]` - Skip this field in runtime layer application
+/// - `#[wayle(rename = "key")]` - Use `key` as the TOML key instead of the field name
+/// - `#[wayle(flatten)]` - Apply the parent table to this field instead of a nested table
 ///
 /// # Generated Code
 ///
@@ -154,7 +317,13 @@ pub fn derive_apply_runtime_layer(input: TokenStream) -> TokenStream {
         .filter(|field| !should_skip(field))
         .map(|field| {
             let field_name = &field.ident;
-            let key = serde_key(field);
+            let key = config_key(field);
+
+            if is_flattened(field) {
+                return quote! {
+                    self.#field_name.apply_runtime_layer(value, path)?;
+                };
+            }
 
             quote! {
                 if let Some(field_value) = table.get(#key) {
@@ -190,6 +359,8 @@ pub fn derive_apply_runtime_layer(input: TokenStream) -> TokenStream {
 /// # Attributes
 ///
 /// - `#[wayle(skip)]` - Skip this field in runtime value extraction
+/// - `#[wayle(rename = "key")]` - Use `key` as the TOML key instead of the field name
+/// - `#[wayle(flatten)]` - Merge this field's keys into the output table
 ///
 /// # Generated Code
 ///
@@ -211,7 +382,17 @@ pub fn derive_extract_runtime_values(input: TokenStream) -> TokenStream {
         .filter(|field| !should_skip(field))
         .map(|field| {
             let field_name = &field.ident;
-            let key = serde_key(field);
+            let key = config_key(field);
+
+            if is_flattened(field) {
+                return quote! {
+                    if let Some(toml::Value::Table(values)) =
+                        self.#field_name.extract_runtime_values()
+                    {
+                        table.extend(values);
+                    }
+                };
+            }
 
             quote! {
                 if let Some(value) = self.#field_name.extract_runtime_values() {
@@ -333,6 +514,8 @@ pub fn derive_reset_runtime_layer(input: TokenStream) -> TokenStream {
 /// # Attributes
 ///
 /// - `#[wayle(skip)]` - Skip this field in path navigation
+/// - `#[wayle(rename = "key")]` - Use `key` as the TOML key instead of the field name
+/// - `#[wayle(flatten)]` - Try this field with the full path when no sibling key matches
 ///
 /// # Generated Code
 ///
@@ -350,16 +533,29 @@ pub fn derive_clear_runtime_by_path(input: TokenStream) -> TokenStream {
     let match_arms = fields
         .named
         .iter()
-        .filter(|field| !should_skip(field))
+        .filter(|field| !should_skip(field) && !is_flattened(field))
         .map(|field| {
             let field_name = &field.ident;
-            let key = serde_key(field);
+            let key = config_key(field);
 
             quote! {
                 #key => self.#field_name.clear_runtime_by_path(rest),
             }
         });
 
+    let flattened_lookups = fields
+        .named
+        .iter()
+        .filter(|field| !should_skip(field) && is_flattened(field))
+        .map(|field| {
+            let field_name = &field.ident;
+            quote! {
+                if let Ok(cleared) = self.#field_name.clear_runtime_by_path(path) {
+                    return Ok(cleared);
+                }
+            }
+        });
+
     let expanded = quote! {
         impl wayle_config::ClearRuntimeByPath for #name {
             fn clear_runtime_by_path(&self, path: &str) -> Result<bool, String> {
@@ -371,8 +567,183 @@ pub fn derive_clear_runtime_by_path(input: TokenStream) -> TokenStream {
                 match segment {
                     #(#match_arms)*
                     "" => Err(String::from("empty path")),
-                    other => Err(format!("unknown field '{other}'")),
+                    other => {
+                        #(#flattened_lookups)*
+                        Err(format!("unknown field '{other}'"))
+                    }
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Derive macro for `CollectValueSources` trait.
+///
+/// Walks struct fields and records the source layer of every leaf value.
+/// Used when dumping the effective config.
+///
+/// # Attributes
+///
+/// - `#[wayle(skip)]` - Skip this field in source collection
+/// - `#[wayle(rename = "key")]` - Use `key` as the TOML key instead of the field name
+/// - `#[wayle(flatten)]` - Collect this field's sources under the parent path
+///
+/// # Generated Code
+///
+/// For each field, generates: `self.field.collect_value_sources("path.field", sources)`
+#[proc_macro_derive(CollectValueSources, attributes(wayle))]
+pub fn derive_collect_value_sources(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match validate_named_struct(&input) {
+        Ok(fields) => fields,
+        Err(err) => return err,
+    };
+
+    let field_collections = fields
+        .named
+        .iter()
+        .filter(|field| !should_skip(field))
+        .map(|field| {
+            let field_name = &field.ident;
+            let key = config_key(field);
+
+            if is_flattened(field) {
+                return quote! {
+                    self.#field_name.collect_value_sources(path, sources);
+                };
+            }
+
+            quote! {
+                let child_path = if path.is_empty() {
+                    String::from(#key)
+                } else {
+                    format!("{}.{}", path, #key)
+                };
+                self.#field_name.collect_value_sources(&child_path, sources);
+            }
+        });
+
+    let expanded = quote! {
+        impl wayle_config::CollectValueSources for #name {
+            fn collect_value_sources(
+                &self,
+                path: &str,
+                sources: &mut Vec<(String, wayle_config::ValueSource)>,
+            ) {
+                #(#field_collections)*
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Derive macro for `CollectUnknownKeys` trait.
+///
+/// Reports TOML keys that no field reads, so typos in config files can be
+/// surfaced instead of silently dropped.
+///
+/// # Attributes
+///
+/// - `#[wayle(skip)]` - The key is still accepted, but its value isn't checked
+/// - `#[wayle(rename = "key")]` - Accept `key` instead of the field name
+/// - `#[wayle(flatten)]` - Accept this field's keys in the parent table
+///
+/// # Generated Code
+///
+/// Every key in the table that isn't a field key is appended to `unknown`
+/// as a dotted path, then each field's value is checked the same way.
+#[proc_macro_derive(CollectUnknownKeys, attributes(wayle))]
+pub fn derive_collect_unknown_keys(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match validate_named_struct(&input) {
+        Ok(fields) => fields,
+        Err(err) => return err,
+    };
+
+    let known_keys: Vec<String> = fields
+        .named
+        .iter()
+        .filter(|field| !is_flattened(field))
+        .map(config_key)
+        .collect();
+
+    let flattened_fields: Vec<_> = fields
+        .named
+        .iter()
+        .filter(|field| !should_skip(field) && is_flattened(field))
+        .map(|field| &field.ident)
+        .collect();
+
+    let field_checks = fields
+        .named
+        .iter()
+        .filter(|field| !should_skip(field))
+        .map(|field| {
+            let field_name = &field.ident;
+            let key = config_key(field);
+
+            if is_flattened(field) {
+                return quote! {
+                    self.#field_name.collect_nested_unknown_keys(table, path, unknown);
+                };
+            }
+
+            quote! {
+                if let Some(field_value) = table.get(#key) {
+                    let child_path = if path.is_empty() {
+                        String::from(#key)
+                    } else {
+                        format!("{}.{}", path, #key)
+                    };
+                    self.#field_name.collect_unknown_keys(field_value, &child_path, unknown);
+                }
+            }
+        });
+
+    let expanded = quote! {
+        impl wayle_config::CollectUnknownKeys for #name {
+            fn collect_unknown_keys(
+                &self,
+                value: &toml::Value,
+                path: &str,
+                unknown: &mut Vec<String>,
+            ) {
+                let toml::Value::Table(table) = value else {
+                    return;
+                };
+
+                for key in table.keys() {
+                    if !self.accepts_key(key) {
+                        unknown.push(if path.is_empty() {
+                            key.clone()
+                        } else {
+                            format!("{}.{}", path, key)
+                        });
+                    }
                 }
+
+                self.collect_nested_unknown_keys(table, path, unknown);
+            }
+
+            fn accepts_key(&self, key: &str) -> bool {
+                let known: &[&str] = &[#(#known_keys),*];
+                known.contains(&key) #(|| self.#flattened_fields.accepts_key(key))*
+            }
+
+            fn collect_nested_unknown_keys(
+                &self,
+                table: &toml::Table,
+                path: &str,
+                unknown: &mut Vec<String>,
+            ) {
+                #(#field_checks)*
             }
         }
     };
@@ -509,7 +880,8 @@ pub fn derive_subscribe_changes(input: TokenStream) -> TokenStream {
 ///
 /// - Standard derives: `Debug`, `Clone`, `Serialize`, `Deserialize`, `JsonSchema`
 /// - Config layer derives: `ApplyConfigLayer`, `ApplyRuntimeLayer`, `ExtractRuntimeValues`,
-///   `SubscribeChanges`, `ResetConfigLayer`, `CommitConfigReload`
+///   `SubscribeChanges`, `ResetConfigLayer`, `CollectValueSources`, `CollectUnknownKeys`,
+///   `CommitConfigReload`
 /// - `#[serde(default)]` attribute
 /// - `impl Default` from `#[default(...)]` field attributes
 ///
@@ -518,6 +890,9 @@ pub fn derive_subscribe_changes(input: TokenStream) -> TokenStream {
 /// - `#[default(expr)]` - Leaf field with `ConfigProperty<T>`, uses `ConfigProperty::new(expr)`
 /// - No `#[default]` - Container field, uses `FieldType::default()`
 /// - `#[serde(...)]` - Preserved and passed through to the struct
+/// - `#[wayle(...)]` - Preserved for the layer derives. `rename` and `flatten` only
+///   change the layer keys, so pair them with the matching `#[serde(...)]` attribute
+///   to keep serialization in step
 ///
 /// # Bar Button Fields (required by `bar_button`)
 ///
@@ -649,6 +1024,8 @@ fn generate_wayle_config(input: ItemStruct, config_type: ConfigType) -> syn::Res
             wayle_derive::ResetConfigLayer,
             wayle_derive::ResetRuntimeLayer,
             wayle_derive::ClearRuntimeByPath,
+            wayle_derive::CollectValueSources,
+            wayle_derive::CollectUnknownKeys,
             wayle_derive::CommitConfigReload,
         )]
         #[serde(default)]
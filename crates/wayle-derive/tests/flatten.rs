@@ -0,0 +1,150 @@
+#![allow(missing_docs)]
+
+use wayle_config::{
+    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CollectUnknownKeys,
+    CollectValueSources, ConfigProperty, ExtractRuntimeValues, ValueSource,
+};
+use wayle_derive::{
+    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, CollectUnknownKeys,
+    CollectValueSources, ExtractRuntimeValues,
+};
+
+#[derive(
+    ApplyConfigLayer,
+    ApplyRuntimeLayer,
+    ExtractRuntimeValues,
+    ClearRuntimeByPath,
+    CollectValueSources,
+    CollectUnknownKeys,
+)]
+struct Border {
+    #[wayle(rename = "border-show")]
+    show: ConfigProperty<bool>,
+    #[wayle(rename = "border-width")]
+    width: ConfigProperty<u32>,
+}
+
+#[derive(
+    ApplyConfigLayer,
+    ApplyRuntimeLayer,
+    ExtractRuntimeValues,
+    ClearRuntimeByPath,
+    CollectValueSources,
+    CollectUnknownKeys,
+)]
+struct ModuleConfig {
+    enabled: ConfigProperty<bool>,
+    #[wayle(flatten)]
+    border: Border,
+}
+
+fn config() -> ModuleConfig {
+    ModuleConfig {
+        enabled: ConfigProperty::new(false),
+        border: Border {
+            show: ConfigProperty::new(false),
+            width: ConfigProperty::new(1),
+        },
+    }
+}
+
+#[test]
+fn flattened_keys_are_read_from_parent_table() {
+    let config = config();
+
+    let toml_value: toml::Value =
+        toml::from_str("enabled = true\nborder-show = true\nborder-width = 3").unwrap();
+    config.apply_config_layer(&toml_value, "");
+
+    assert!(config.enabled.get());
+    assert!(config.border.show.get());
+    assert_eq!(config.border.width.get(), 3);
+}
+
+#[test]
+fn nested_table_is_not_read() {
+    let config = config();
+
+    let toml_value: toml::Value = toml::from_str("[border]\nborder-show = true").unwrap();
+    config.apply_config_layer(&toml_value, "");
+
+    assert!(!config.border.show.get());
+}
+
+#[test]
+fn runtime_values_merge_into_parent_table() {
+    let config = config();
+
+    let toml_value: toml::Value = toml::from_str("enabled = true\nborder-width = 4").unwrap();
+    config.apply_runtime_layer(&toml_value, "").unwrap();
+
+    let extracted = config.extract_runtime_values().unwrap();
+    assert_eq!(extracted["enabled"].as_bool(), Some(true));
+    assert_eq!(extracted["border-width"].as_integer(), Some(4));
+    assert!(extracted.get("border").is_none());
+}
+
+#[test]
+fn clear_by_path_reaches_flattened_fields() {
+    let config = config();
+
+    let toml_value: toml::Value = toml::from_str("border-width = 4").unwrap();
+    config.apply_runtime_layer(&toml_value, "").unwrap();
+
+    assert_eq!(config.clear_runtime_by_path("border-width"), Ok(true));
+    assert_eq!(config.border.width.get(), 1);
+    assert!(config.clear_runtime_by_path("missing").is_err());
+}
+
+#[test]
+fn value_sources_use_parent_path_for_flattened_fields() {
+    let config = config();
+
+    let toml_value: toml::Value = toml::from_str("border-width = 4").unwrap();
+    config.apply_config_layer(&toml_value, "");
+
+    let mut sources = Vec::new();
+    config.collect_value_sources("module", &mut sources);
+
+    assert_eq!(
+        sources,
+        vec![
+            (String::from("module.enabled"), ValueSource::Default),
+            (String::from("module.border-show"), ValueSource::Default),
+            (String::from("module.border-width"), ValueSource::Config),
+        ]
+    );
+}
+
+#[test]
+fn flattened_keys_are_known_in_parent_table() {
+    let config = config();
+
+    let toml_value: toml::Value =
+        toml::from_str("enabled = true\nborder-width = 4\nborder-colour = 1").unwrap();
+
+    let mut unknown = Vec::new();
+    config.collect_unknown_keys(&toml_value, "module", &mut unknown);
+
+    assert_eq!(unknown, vec![String::from("module.border-colour")]);
+}
+
+#[derive(ApplyConfigLayer)]
+struct Section {
+    title: ConfigProperty<String>,
+    #[wayle(flatten)]
+    module: ModuleConfig,
+}
+
+#[test]
+fn config_keys_include_flattened_fields_at_any_depth() {
+    assert_eq!(Border::__WAYLE_CONFIG_KEYS, ["border-show", "border-width"]);
+    assert_eq!(
+        ModuleConfig::__WAYLE_CONFIG_KEYS,
+        ["enabled", "border-show", "border-width"]
+    );
+    assert_eq!(
+        Section::__WAYLE_CONFIG_KEYS,
+        ["title", "enabled", "border-show", "border-width"]
+    );
+}
@@ -0,0 +1,78 @@
+#![allow(missing_docs)]
+
+use wayle_config::{
+    ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, ConfigProperty, ExtractRuntimeValues,
+};
+use wayle_derive::{ApplyConfigLayer, ApplyRuntimeLayer, ClearRuntimeByPath, ExtractRuntimeValues};
+
+#[derive(ApplyConfigLayer, ApplyRuntimeLayer, ExtractRuntimeValues, ClearRuntimeByPath)]
+struct RenamedConfig {
+    #[wayle(rename = "type")]
+    kind: ConfigProperty<String>,
+    #[wayle(rename = "2fa")]
+    two_factor: ConfigProperty<bool>,
+}
+
+fn config() -> RenamedConfig {
+    RenamedConfig {
+        kind: ConfigProperty::new(String::from("default")),
+        two_factor: ConfigProperty::new(false),
+    }
+}
+
+#[test]
+fn config_layer_reads_renamed_keys() {
+    let config = config();
+
+    let toml_value: toml::Value = toml::from_str("type = \"battery\"\n2fa = true").unwrap();
+    config.apply_config_layer(&toml_value, "");
+
+    assert_eq!(config.kind.get(), "battery");
+    assert!(config.two_factor.get());
+}
+
+#[test]
+fn field_names_are_not_keys() {
+    let config = config();
+
+    let toml_value = toml::toml! {
+        kind = "battery"
+        two_factor = true
+    };
+    config.apply_config_layer(&toml::Value::Table(toml_value), "");
+
+    assert_eq!(config.kind.get(), "default");
+    assert!(!config.two_factor.get());
+}
+
+#[test]
+fn runtime_values_round_trip_under_renamed_keys() {
+    let config = config();
+
+    let toml_value = toml::toml! {
+        type = "network"
+    };
+    config
+        .apply_runtime_layer(&toml::Value::Table(toml_value), "")
+        .unwrap();
+
+    let extracted = config.extract_runtime_values().unwrap();
+    assert_eq!(extracted["type"].as_str(), Some("network"));
+    assert!(extracted.get("kind").is_none());
+
+    let restored = self::config();
+    restored.apply_runtime_layer(&extracted, "").unwrap();
+    assert_eq!(restored.kind.get(), "network");
+}
+
+#[test]
+fn clear_by_path_uses_renamed_key() {
+    let config = config();
+    config
+        .apply_runtime_layer(&toml::Value::Table(toml::toml! { type = "cpu" }), "")
+        .unwrap();
+
+    assert_eq!(config.clear_runtime_by_path("type"), Ok(true));
+    assert_eq!(config.kind.get(), "default");
+    assert!(config.clear_runtime_by_path("kind").is_err());
+}
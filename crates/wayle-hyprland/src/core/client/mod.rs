@@ -1,5 +1,8 @@
+use std::sync::Arc;
+
 use wayle_core::Property;
 
+use super::monitor::Monitor;
 use crate::{
     Address, ClientData, ClientLocation, ClientSize, FocusHistoryId, FullscreenMode, MonitorId,
     ProcessId, WorkspaceInfo,
@@ -100,6 +103,23 @@ impl Client {
         }
     }
 
+    /// The window with keyboard focus, if it sits on the workspace shown on
+    /// the focused monitor.
+    ///
+    /// Hyprland keeps the last window at the top of the focus history when
+    /// focus moves to an empty workspace, so the workspace check is what
+    /// tells "nothing focused" apart from a focused window.
+    pub(crate) fn focused(clients: &[Arc<Self>], monitors: &[Arc<Monitor>]) -> Option<Arc<Self>> {
+        let workspace = Monitor::focused_workspace(monitors)?;
+
+        clients
+            .iter()
+            .find(|client| {
+                client.focus_history_id.get() == 0 && client.workspace.get().id == workspace
+            })
+            .cloned()
+    }
+
     pub(crate) fn update(&self, client_data: ClientData) {
         self.address.set(client_data.address);
         self.mapped.set(client_data.mapped);
@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use wayle_core::Property;
 
 use crate::{
     Address, DirectScanoutBlocker, MonitorData, MonitorId, Reserved, SolitaryBlocker,
-    TearingBlocker, Transform, WorkspaceInfo,
+    TearingBlocker, Transform, WorkspaceId, WorkspaceInfo,
 };
 
 /// A Hyprland monitor (display output) with reactive state.
@@ -115,6 +117,28 @@ impl Monitor {
         }
     }
 
+    /// Name of the special workspace open on the focused monitor, without its
+    /// `special:` prefix.
+    pub(crate) fn focused_special(monitors: &[Arc<Self>]) -> Option<String> {
+        monitors
+            .iter()
+            .find(|monitor| monitor.focused.get())
+            .and_then(|monitor| special_name(&monitor.special_workspace.get()))
+    }
+
+    /// ID of the workspace shown on the focused monitor: its open special
+    /// workspace, or else its active one.
+    pub(crate) fn focused_workspace(monitors: &[Arc<Self>]) -> Option<WorkspaceId> {
+        let monitor = monitors.iter().find(|monitor| monitor.focused.get())?;
+        let special = monitor.special_workspace.get();
+
+        if special_name(&special).is_some() {
+            Some(special.id)
+        } else {
+            Some(monitor.active_workspace.get().id)
+        }
+    }
+
     pub(crate) fn update(&self, monitor_data: MonitorData) {
         self.id.set(monitor_data.id);
         self.name.set(monitor_data.name);
@@ -151,3 +175,42 @@ impl Monitor {
         self.available_modes.set(monitor_data.available_modes);
     }
 }
+
+/// Hyprland reports a closed special workspace as an empty name with ID 0.
+fn special_name(workspace: &WorkspaceInfo) -> Option<String> {
+    let name = workspace
+        .name
+        .strip_prefix("special:")
+        .unwrap_or(&workspace.name);
+
+    (!name.is_empty()).then(|| name.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(id: i64, name: &str) -> WorkspaceInfo {
+        WorkspaceInfo {
+            id,
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn special_name_strips_prefix() {
+        assert_eq!(
+            special_name(&info(-98, "special:scratchpad")).as_deref(),
+            Some("scratchpad")
+        );
+        assert_eq!(
+            special_name(&info(-99, "special")).as_deref(),
+            Some("special")
+        );
+    }
+
+    #[test]
+    fn closed_special_is_none() {
+        assert_eq!(special_name(&info(0, "")), None);
+    }
+}
@@ -12,6 +12,8 @@ pub(super) struct HyprlandDiscovery {
     pub clients: Vec<Arc<Client>>,
     pub monitors: Vec<Arc<Monitor>>,
     pub layers: Vec<Layer>,
+    pub caps_lock: bool,
+    pub num_lock: bool,
 }
 
 impl HyprlandDiscovery {
@@ -37,6 +39,20 @@ impl HyprlandDiscovery {
             vec![]
         });
 
+        let keyboard = hypr_messenger
+            .devices()
+            .await
+            .map(|devices| {
+                devices
+                    .main_keyboard()
+                    .map(|keyboard| (keyboard.caps_lock, keyboard.num_lock))
+            })
+            .unwrap_or_else(|e| {
+                error!(error = %e, "cannot discover keyboards");
+                None
+            });
+        let (caps_lock, num_lock) = keyboard.unwrap_or_default();
+
         let mut clients = Vec::new();
         let mut monitors = Vec::new();
         let mut workspaces = Vec::new();
@@ -59,6 +75,8 @@ impl HyprlandDiscovery {
             clients,
             monitors,
             layers,
+            caps_lock,
+            num_lock,
         }
     }
 }
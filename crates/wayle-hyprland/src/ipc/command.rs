@@ -2,15 +2,28 @@ use std::time::Duration;
 
 use tracing::instrument;
 
-use super::{DismissProps, HyprMessenger, OutputCommand, SetErrorCommand};
+use super::{DismissProps, Dispatch, HyprMessenger, OutputCommand, SetErrorCommand};
 use crate::Result;
 
+/// Prefix that makes Hyprland run the `;`-separated commands that follow in
+/// one request, like `hyprctl --batch`.
+const BATCH_PREFIX: &str = "[[BATCH]]";
+
 impl HyprMessenger {
     #[instrument(skip(self), fields(command = %command), err)]
     pub(crate) async fn dispatch(&self, command: &str) -> Result<String> {
         self.send(&format!("dispatch {command}")).await
     }
 
+    #[instrument(skip(self), fields(count = dispatches.len()), err)]
+    pub(crate) async fn dispatch_batch(&self, dispatches: &[Dispatch<'_>]) -> Result<String> {
+        if dispatches.is_empty() {
+            return Ok(String::new());
+        }
+
+        self.send(&batch_command(dispatches)).await
+    }
+
     #[instrument(skip(self), fields(command = %command), err)]
     pub(crate) async fn keyword(&self, command: &str) -> Result<String> {
         self.send(&format!("keyword {command}")).await
@@ -94,3 +107,36 @@ impl HyprMessenger {
         }
     }
 }
+
+fn batch_command(dispatches: &[Dispatch<'_>]) -> String {
+    let commands: Vec<String> = dispatches
+        .iter()
+        .map(|Dispatch(command)| format!("dispatch {command}"))
+        .collect();
+
+    format!("{BATCH_PREFIX}{}", commands.join(";"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_joins_dispatches_in_order() {
+        let command =
+            batch_command(&[Dispatch("workspace 3"), Dispatch("focuswindow class:kitty")]);
+
+        assert_eq!(
+            command,
+            "[[BATCH]]dispatch workspace 3;dispatch focuswindow class:kitty"
+        );
+    }
+
+    #[test]
+    fn batch_of_one_is_still_prefixed() {
+        assert_eq!(
+            batch_command(&[Dispatch("workspace e+1")]),
+            "[[BATCH]]dispatch workspace e+1"
+        );
+    }
+}
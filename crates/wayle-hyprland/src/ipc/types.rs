@@ -15,3 +15,11 @@ pub enum DismissProps {
     All,
     Total(u32),
 }
+
+/// One dispatcher call for [`HyprlandService::dispatch_batch()`], written
+/// the way [`HyprlandService::dispatch()`] takes it (e.g. `"workspace 3"`).
+///
+/// [`HyprlandService::dispatch_batch()`]: crate::HyprlandService::dispatch_batch
+/// [`HyprlandService::dispatch()`]: crate::HyprlandService::dispatch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dispatch<'a>(pub &'a str);
@@ -39,6 +39,15 @@
 //! - `clients` - All open windows
 //! - `monitors` - Connected displays
 //! - `layers` - Layer shell surfaces (panels, overlays, etc.)
+//! - `active_special` - Special workspace (scratchpad) open on the focused
+//!   monitor, toggled with [`HyprlandService::toggle_special()`]
+//! - `active_window` / `active_window_fullscreen` - Focused window on the
+//!   focused monitor and whether it is fullscreen, e.g. to hide a bar
+//! - `caps_lock` / `num_lock` - Lock key state of the main keyboard
+//!
+//! Hyprland sends no event when a lock key toggles, so while `caps_lock` or
+//! `num_lock` is watched the service re-reads the keyboard state a few times
+//! a second. Nothing is queried while neither is watched.
 //!
 //! # Event Streaming
 //!
@@ -58,6 +67,11 @@
 //! # }
 //! ```
 //!
+//! Events are delivered after the service fields they touch are resynced.
+//! A `MonitorAdded` or `MonitorRemoved` event carries the connector name,
+//! and by the time it arrives `monitors` already includes (or no longer
+//! includes) that output.
+//!
 //! # IPC Commands
 //!
 //! Execute Hyprland commands via [`HyprlandService::dispatch()`]:
@@ -71,6 +85,20 @@
 //! # }
 //! ```
 //!
+//! Several commands can go out in one round-trip with
+//! [`HyprlandService::dispatch_batch()`], like `hyprctl --batch`:
+//!
+//! ```no_run
+//! # use wayle_hyprland::{Dispatch, HyprlandService};
+//! # async fn example() -> wayle_hyprland::Result<()> {
+//! # let service = HyprlandService::new().await?;
+//! service
+//!     .dispatch_batch(&[Dispatch("workspace 3"), Dispatch("focuswindow class:kitty")])
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! [`Property`]: wayle_core::Property
 //! [`Property<T>`]: wayle_core::Property
 
@@ -85,7 +113,7 @@ mod types;
 pub use core::{client::Client, monitor::Monitor, workspace::Workspace};
 
 pub use error::{Error, Result};
-pub use ipc::events::types::HyprlandEvent;
+pub use ipc::{Dispatch, events::types::HyprlandEvent};
 pub use service::HyprlandService;
 pub(crate) use types::*;
 pub use types::{
@@ -1,3 +1,4 @@
+mod lock_keys;
 mod plan;
 mod projector;
 mod reconcile;
@@ -25,6 +26,11 @@ pub(super) struct SyncRuntime {
     pub(super) monitors: Property<Vec<Arc<Monitor>>>,
     pub(super) workspaces: Property<Vec<Arc<Workspace>>>,
     pub(super) layers: Property<Vec<Layer>>,
+    pub(super) active_special: Property<Option<String>>,
+    pub(super) active_window: Property<Option<Arc<Client>>>,
+    pub(super) active_window_fullscreen: Property<bool>,
+    pub(super) caps_lock: Property<bool>,
+    pub(super) num_lock: Property<bool>,
     pub(super) cancellation_token: CancellationToken,
 }
 
@@ -33,7 +39,7 @@ impl ServiceMonitoring for HyprlandService {
 
     #[instrument(skip(self), err)]
     async fn start_monitoring(&self) -> std::result::Result<(), Self::Error> {
-        projector::spawn(SyncRuntime {
+        let runtime = SyncRuntime {
             event_tx: self.event_tx.clone(),
             hyprland_tx: self.hyprland_tx.clone(),
             hypr_messenger: self.hypr_messenger.clone(),
@@ -41,8 +47,16 @@ impl ServiceMonitoring for HyprlandService {
             monitors: self.monitors.clone(),
             workspaces: self.workspaces.clone(),
             layers: self.layers.clone(),
+            active_special: self.active_special.clone(),
+            active_window: self.active_window.clone(),
+            active_window_fullscreen: self.active_window_fullscreen.clone(),
+            caps_lock: self.caps_lock.clone(),
+            num_lock: self.num_lock.clone(),
             cancellation_token: self.cancellation_token.clone(),
-        });
+        };
+
+        lock_keys::spawn(runtime.clone());
+        projector::spawn(runtime);
 
         Ok(())
     }
@@ -0,0 +1,36 @@
+//! Lock key (Caps Lock, Num Lock) tracking.
+//!
+//! Hyprland emits no event when a lock key toggles, so the keyboard state is
+//! re-read on an interval, but only while someone watches the lock flags.
+
+use std::time::Duration;
+
+use tokio::time::{self, MissedTickBehavior};
+
+use super::{SyncRuntime, reconcile};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub(super) fn spawn(runtime: SyncRuntime) {
+    tokio::spawn(async move {
+        let mut interval = time::interval(POLL_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            if !runtime.caps_lock.has_subscribers() && !runtime.num_lock.has_subscribers() {
+                tokio::select! {
+                    _ = runtime.cancellation_token.cancelled() => return,
+                    () = runtime.caps_lock.wait_for_subscribers() => {}
+                    () = runtime.num_lock.wait_for_subscribers() => {}
+                }
+            }
+
+            tokio::select! {
+                _ = runtime.cancellation_token.cancelled() => return,
+                _ = interval.tick() => {}
+            }
+
+            reconcile::sync_keyboards_state(&runtime).await;
+        }
+    });
+}
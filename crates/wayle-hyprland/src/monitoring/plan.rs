@@ -1,7 +1,7 @@
 //! Sync planning based on Hyprland events.
 //!
-//! Maps event types to the domains (clients, monitors, workspaces, layers)
-//! that need reconciliation.
+//! Maps event types to the domains (clients, monitors, workspaces, layers,
+//! keyboards) that need reconciliation.
 
 use crate::HyprlandEvent;
 
@@ -11,6 +11,7 @@ pub(super) struct SyncPlan {
     pub(super) monitors: bool,
     pub(super) workspaces: bool,
     pub(super) layers: bool,
+    pub(super) keyboards: bool,
 }
 
 impl SyncPlan {
@@ -20,11 +21,12 @@ impl SyncPlan {
             monitors: self.monitors || other.monitors,
             workspaces: self.workspaces || other.workspaces,
             layers: self.layers || other.layers,
+            keyboards: self.keyboards || other.keyboards,
         }
     }
 
     pub(super) fn is_empty(self) -> bool {
-        !self.clients && !self.monitors && !self.workspaces && !self.layers
+        !self.clients && !self.monitors && !self.workspaces && !self.layers && !self.keyboards
     }
 }
 
@@ -83,10 +85,20 @@ pub(super) fn for_event(event: &HyprlandEvent) -> SyncPlan {
             monitors: true,
             ..SyncPlan::default()
         },
+        // A reload can move, rescale or rotate monitors without any
+        // monitor event.
+        HyprlandEvent::ConfigReloaded => SyncPlan {
+            monitors: true,
+            ..SyncPlan::default()
+        },
         HyprlandEvent::OpenLayer { .. } | HyprlandEvent::CloseLayer { .. } => SyncPlan {
             layers: true,
             ..SyncPlan::default()
         },
+        HyprlandEvent::ActiveLayout { .. } => SyncPlan {
+            keyboards: true,
+            ..SyncPlan::default()
+        },
         _ => SyncPlan::default(),
     }
 }
@@ -134,6 +146,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn monitor_hotplug_resyncs_monitors_and_workspaces() {
+        let expected = SyncPlan {
+            monitors: true,
+            workspaces: true,
+            ..SyncPlan::default()
+        };
+
+        let events = [
+            HyprlandEvent::MonitorAdded {
+                name: String::from("HDMI-A-1"),
+            },
+            HyprlandEvent::MonitorAddedV2 {
+                id: 1,
+                name: String::from("HDMI-A-1"),
+                description: String::from("Dell U2720Q"),
+            },
+            HyprlandEvent::MonitorRemoved {
+                name: String::from("HDMI-A-1"),
+            },
+            HyprlandEvent::MonitorRemovedV2 {
+                id: 1,
+                name: String::from("HDMI-A-1"),
+                description: String::from("Dell U2720Q"),
+            },
+        ];
+
+        for event in &events {
+            assert_eq!(for_event(event), expected, "{event:?}");
+        }
+    }
+
+    #[test]
+    fn config_reload_resyncs_monitor_geometry() {
+        assert_eq!(
+            for_event(&HyprlandEvent::ConfigReloaded),
+            SyncPlan {
+                monitors: true,
+                ..SyncPlan::default()
+            }
+        );
+    }
+
+    #[test]
+    fn layout_change_resyncs_keyboards() {
+        let plan = for_event(&HyprlandEvent::ActiveLayout {
+            keyboard: String::from("at-translated-set-2-keyboard"),
+            layout: String::from("English (US)"),
+        });
+
+        assert_eq!(
+            plan,
+            SyncPlan {
+                keyboards: true,
+                ..SyncPlan::default()
+            }
+        );
+    }
+
     #[test]
     fn default_plan_is_empty() {
         assert!(SyncPlan::default().is_empty());
@@ -156,5 +227,13 @@ mod tests {
             }
             .is_empty()
         );
+
+        assert!(
+            !SyncPlan {
+                keyboards: true,
+                ..SyncPlan::default()
+            }
+            .is_empty()
+        );
     }
 }
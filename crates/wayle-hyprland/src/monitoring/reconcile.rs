@@ -15,6 +15,7 @@ use crate::core::{client::Client, layer::Layer, monitor::Monitor, workspace::Wor
     sync_workspaces = plan.workspaces,
     sync_clients = plan.clients,
     sync_layers = plan.layers,
+    sync_keyboards = plan.keyboards,
 ))]
 pub(super) async fn sync_model_state(runtime: &SyncRuntime, plan: plan::SyncPlan) {
     if plan.monitors {
@@ -32,6 +33,24 @@ pub(super) async fn sync_model_state(runtime: &SyncRuntime, plan: plan::SyncPlan
     if plan.layers {
         sync_layers_state(runtime).await;
     }
+
+    if plan.keyboards {
+        sync_keyboards_state(runtime).await;
+    }
+
+    if plan.clients || plan.monitors {
+        sync_active_window(runtime);
+    }
+}
+
+fn sync_active_window(runtime: &SyncRuntime) {
+    let active = Client::focused(&runtime.clients.get(), &runtime.monitors.get());
+    let fullscreen = active
+        .as_ref()
+        .is_some_and(|client| client.fullscreen.get().is_fullscreen());
+
+    runtime.active_window.set(active);
+    runtime.active_window_fullscreen.set(fullscreen);
 }
 
 async fn sync_clients_state(runtime: &SyncRuntime) {
@@ -122,6 +141,11 @@ async fn sync_monitors_state(runtime: &SyncRuntime) {
 
         reconciled.push(Arc::new(Monitor::from_props(monitor_data)));
     }
+
+    runtime
+        .active_special
+        .set(Monitor::focused_special(&reconciled));
+
     if reconciled != current_monitors {
         runtime.monitors.set(reconciled);
     }
@@ -140,3 +164,21 @@ async fn sync_layers_state(runtime: &SyncRuntime) {
         runtime.layers.set(live_layers);
     }
 }
+
+pub(super) async fn sync_keyboards_state(runtime: &SyncRuntime) {
+    let devices = match runtime.hypr_messenger.devices().await {
+        Ok(data) => data,
+        Err(e) => {
+            warn!(error = %e, "cannot query devices while syncing model state");
+            return;
+        }
+    };
+
+    let keyboard = devices.main_keyboard();
+    runtime
+        .caps_lock
+        .set(keyboard.is_some_and(|keyboard| keyboard.caps_lock));
+    runtime
+        .num_lock
+        .set(keyboard.is_some_and(|keyboard| keyboard.num_lock));
+}
@@ -13,7 +13,7 @@ use crate::{
     WorkspaceRule,
     core::{client::Client, layer::Layer, monitor::Monitor, workspace::Workspace},
     discovery::HyprlandDiscovery,
-    ipc::{DismissProps, HyprMessenger, OutputCommand, SetErrorCommand, events},
+    ipc::{DismissProps, Dispatch, HyprMessenger, OutputCommand, SetErrorCommand, events},
 };
 
 /// Hyprland compositor service. See [crate-level docs](crate) for usage.
@@ -31,6 +31,21 @@ pub struct HyprlandService {
     pub monitors: Property<Vec<Arc<Monitor>>>,
     /// Layer shell surfaces.
     pub layers: Property<Vec<Layer>>,
+    /// Special workspace (scratchpad) shown on the focused monitor, without
+    /// its `special:` prefix. `None` when none is open.
+    pub active_special: Property<Option<String>>,
+    /// Window with keyboard focus on the focused monitor. `None` when that
+    /// monitor shows an empty workspace. Reactive counterpart of
+    /// [`active_window()`](Self::active_window), kept current from events.
+    pub active_window: Property<Option<Arc<Client>>>,
+    /// Whether [`active_window`](Self::active_window) is fullscreen.
+    pub active_window_fullscreen: Property<bool>,
+    /// Whether Caps Lock is on for the main keyboard. `false` when the
+    /// compositor doesn't report it.
+    pub caps_lock: Property<bool>,
+    /// Whether Num Lock is on for the main keyboard. `false` when the
+    /// compositor doesn't report it.
+    pub num_lock: Property<bool>,
 }
 
 impl HyprlandService {
@@ -53,8 +68,16 @@ impl HyprlandService {
             clients,
             monitors,
             layers,
+            caps_lock,
+            num_lock,
         } = HyprlandDiscovery::new(hypr_messenger.clone()).await;
 
+        let active_special = Monitor::focused_special(&monitors);
+        let active_window = Client::focused(&clients, &monitors);
+        let active_window_fullscreen = active_window
+            .as_ref()
+            .is_some_and(|client| client.fullscreen.get().is_fullscreen());
+
         let service = Arc::new(Self {
             event_tx,
             hyprland_tx,
@@ -64,6 +87,11 @@ impl HyprlandService {
             clients: Property::new(clients),
             monitors: Property::new(monitors),
             layers: Property::new(layers),
+            active_special: Property::new(active_special),
+            active_window: Property::new(active_window),
+            active_window_fullscreen: Property::new(active_window_fullscreen),
+            caps_lock: Property::new(caps_lock),
+            num_lock: Property::new(num_lock),
         });
 
         service.start_monitoring().await?;
@@ -111,6 +139,10 @@ impl HyprlandService {
     ///
     /// The stream emits events for workspace changes, window lifecycle,
     /// monitor configuration, and other compositor events.
+    ///
+    /// Events are forwarded after the service fields they affect have been
+    /// resynced, so on `MonitorAdded` / `MonitorRemoved` the `monitors`
+    /// property already reflects the hotplugged connector.
     pub fn events(&self) -> impl Stream<Item = HyprlandEvent> {
         let hyprland_rx = self.hyprland_tx.subscribe();
 
@@ -126,6 +158,32 @@ impl HyprlandService {
         self.hypr_messenger.dispatch(command).await
     }
 
+    /// Executes several dispatcher commands in one IPC round-trip, in order.
+    ///
+    /// Equivalent to `hyprctl --batch`. Hyprland runs the whole batch within
+    /// one request, so no other client's command lands in between. Returns
+    /// Hyprland's combined reply. An empty batch sends nothing.
+    ///
+    /// Commands must not contain `;`, which separates them in the batch.
+    ///
+    /// # Errors
+    /// Returns error if IPC communication fails.
+    #[instrument(skip(self, dispatches), fields(count = dispatches.len()), err)]
+    pub async fn dispatch_batch(&self, dispatches: &[Dispatch<'_>]) -> Result<String> {
+        self.hypr_messenger.dispatch_batch(dispatches).await
+    }
+
+    /// Shows or hides the special workspace (scratchpad) `name` on the
+    /// focused monitor.
+    ///
+    /// # Errors
+    /// Returns error if the command fails or IPC communication fails.
+    #[instrument(skip(self), fields(name = %name), err)]
+    pub async fn toggle_special(&self, name: &str) -> Result<String> {
+        self.dispatch(&format!("togglespecialworkspace {name}"))
+            .await
+    }
+
     /// Sets a Hyprland configuration keyword at runtime.
     ///
     /// # Errors
@@ -48,10 +48,11 @@ where
 }
 
 /// Window fullscreen state matching Hyprland's `eFullscreenMode`.
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Default, Deserialize, Clone, PartialEq)]
 #[serde(from = "u8")]
 pub enum FullscreenMode {
     /// Not fullscreen.
+    #[default]
     None = 0,
     /// Maximized.
     Maximized = 1,
@@ -61,6 +62,14 @@ pub enum FullscreenMode {
     MaximizedFullscreen = 3,
 }
 
+impl FullscreenMode {
+    /// Whether the window covers its monitor, with or without also being
+    /// maximized.
+    pub fn is_fullscreen(&self) -> bool {
+        matches!(self, Self::Fullscreen | Self::MaximizedFullscreen)
+    }
+}
+
 impl From<u8> for FullscreenMode {
     fn from(value: u8) -> Self {
         match value {
@@ -92,21 +101,31 @@ pub(crate) struct ClientData {
     pub pid: ProcessId,
     pub xwayland: bool,
     pub pinned: bool,
+    // Fields below were added or reshaped across Hyprland releases, so a
+    // missing one falls back to its default instead of failing the parse.
+    #[serde(default)]
     pub fullscreen: FullscreenMode,
+    #[serde(default)]
     pub fullscreen_client: FullscreenMode,
+    #[serde(default)]
     pub over_fullscreen: bool,
+    #[serde(default)]
     pub grouped: Vec<Address>,
+    #[serde(default)]
     pub tags: Vec<String>,
-    #[serde(deserialize_with = "deserialize_optional_address")]
+    #[serde(default, deserialize_with = "deserialize_optional_address")]
     pub swallowing: Option<Address>,
     #[serde(rename = "focusHistoryID")]
     pub focus_history_id: FocusHistoryId,
+    #[serde(default)]
     pub inhibiting_idle: bool,
-    #[serde(deserialize_with = "deserialize_optional_string")]
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
     pub xdg_tag: Option<String>,
-    #[serde(deserialize_with = "deserialize_optional_string")]
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
     pub xdg_description: Option<String>,
+    #[serde(default)]
     pub content_type: String,
+    #[serde(default)]
     pub stable_id: String,
 }
 
@@ -143,6 +162,46 @@ mod tests {
         assert_eq!(FullscreenMode::from(99u8), FullscreenMode::None);
     }
 
+    #[test]
+    fn only_fullscreen_modes_count_as_fullscreen() {
+        assert!(FullscreenMode::Fullscreen.is_fullscreen());
+        assert!(FullscreenMode::MaximizedFullscreen.is_fullscreen());
+        assert!(!FullscreenMode::Maximized.is_fullscreen());
+        assert!(!FullscreenMode::None.is_fullscreen());
+    }
+
+    #[test]
+    fn client_data_parses_grouped_window() {
+        let json = r#"{
+            "address": "0x55d0c6a0e0a0",
+            "mapped": true,
+            "hidden": false,
+            "at": [10, 20],
+            "size": [800, 600],
+            "workspace": {"id": 1, "name": "1"},
+            "floating": false,
+            "monitor": 0,
+            "class": "kitty",
+            "title": "shell",
+            "initialClass": "kitty",
+            "initialTitle": "kitty",
+            "pid": 4242,
+            "xwayland": false,
+            "pinned": false,
+            "fullscreen": 2,
+            "grouped": ["0x55d0c6a0e0a0", "0x55d0c6b1f1b0"],
+            "focusHistoryID": 0
+        }"#;
+
+        let client: ClientData = serde_json::from_str(json).unwrap();
+
+        assert_eq!(client.fullscreen, FullscreenMode::Fullscreen);
+        assert_eq!(client.fullscreen_client, FullscreenMode::None);
+        assert_eq!(client.grouped.len(), 2);
+        assert_eq!(client.swallowing, None);
+        assert!(client.stable_id.is_empty());
+    }
+
     #[test]
     fn deserialize_window_size_creates_correct_struct() {
         #[derive(Deserialize)]
@@ -40,9 +40,13 @@ pub struct KeyboardDevice {
     /// Name of the currently active keymap.
     #[serde(alias = "active_keymap")]
     pub active_keymap: String,
-    /// Whether Caps Lock modifier is currently engaged.
+    /// Whether Caps Lock modifier is currently engaged. `false` when the
+    /// compositor doesn't report it.
+    #[serde(default)]
     pub caps_lock: bool,
-    /// Whether Num Lock modifier is currently engaged.
+    /// Whether Num Lock modifier is currently engaged. `false` when the
+    /// compositor doesn't report it.
+    #[serde(default)]
     pub num_lock: bool,
     /// Whether this is the primary keyboard device.
     pub main: bool,
@@ -63,6 +67,16 @@ pub struct DeviceInfo {
     pub switches: Vec<SwitchDevice>,
 }
 
+impl DeviceInfo {
+    /// The keyboard Hyprland marks as main, or the first one if none is.
+    pub(crate) fn main_keyboard(&self) -> Option<&KeyboardDevice> {
+        self.keyboards
+            .iter()
+            .find(|keyboard| keyboard.main)
+            .or_else(|| self.keyboards.first())
+    }
+}
+
 /// Graphics tablet device from Hyprland.
 ///
 /// The `tablets` array mixes actual tablets, tablet pads, and tablet tools.
@@ -93,3 +107,75 @@ pub struct SwitchDevice {
     /// Human-readable device name.
     pub name: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyboard(name: &str, main: bool, caps_lock: bool) -> serde_json::Value {
+        serde_json::json!({
+            "address": "0x1",
+            "name": name,
+            "rules": "",
+            "model": "",
+            "layout": "us",
+            "variant": "",
+            "options": "",
+            "active_layout_index": 0,
+            "active_keymap": "English (US)",
+            "capsLock": caps_lock,
+            "numLock": false,
+            "main": main,
+        })
+    }
+
+    fn devices(keyboards: Vec<serde_json::Value>) -> serde_json::Result<DeviceInfo> {
+        serde_json::from_value(serde_json::json!({
+            "mice": [],
+            "keyboards": keyboards,
+            "tablets": [],
+            "touch": [],
+            "switches": [],
+        }))
+    }
+
+    #[test]
+    fn main_keyboard_prefers_main_flag() -> serde_json::Result<()> {
+        let info = devices(vec![
+            keyboard("power-button", false, false),
+            keyboard("at-keyboard", true, true),
+        ])?;
+
+        let main = info.main_keyboard().map(|keyboard| keyboard.name.as_str());
+        assert_eq!(main, Some("at-keyboard"));
+        Ok(())
+    }
+
+    #[test]
+    fn main_keyboard_falls_back_to_first() -> serde_json::Result<()> {
+        let info = devices(vec![
+            keyboard("at-keyboard", false, true),
+            keyboard("power-button", false, false),
+        ])?;
+
+        let main = info.main_keyboard().map(|keyboard| keyboard.name.as_str());
+        assert_eq!(main, Some("at-keyboard"));
+        assert!(devices(vec![])?.main_keyboard().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn missing_lock_state_defaults_to_off() -> serde_json::Result<()> {
+        let mut entry = keyboard("at-keyboard", true, true);
+        if let Some(fields) = entry.as_object_mut() {
+            fields.remove("capsLock");
+            fields.remove("numLock");
+        }
+
+        let info = devices(vec![entry])?;
+
+        assert!(!info.keyboards[0].caps_lock);
+        assert!(!info.keyboards[0].num_lock);
+        Ok(())
+    }
+}
@@ -5,6 +5,9 @@
 settings-modules-notification-blocklist = Blocklist
     .description = Glob patterns for app names whose notifications are blocked
 
+settings-modules-notification-coalesce = Coalesce
+    .description = Merge notifications that share an app name and summary
+
 settings-modules-notification-icon-source = Icon Source
     .description = How notification icons are resolved
 
@@ -11,6 +11,12 @@ settings-styling-rounding = Rounding
 settings-styling-theme-provider = Theme Provider
     .description = Source for color palette (wayle, matugen, pywal, wallust)
 
+settings-styling-theme-mode = Theme Mode
+    .description = Light or dark palette (light, dark, auto follows the system)
+
+settings-styling-light-theme = Light Theme
+    .description = Theme used as the light palette, empty keeps one palette
+
 settings-styling-theming-monitor = Theming Monitor
     .description = Monitor whose wallpaper drives color extraction
 
@@ -11,6 +11,9 @@ settings-modules-battery-charging-icon = Charging Icon
 settings-modules-battery-alert-icon = Alert Icon
     .description = Icon shown when battery is missing or in error state
 
+settings-modules-battery-hide-when = Hide When
+    .description = When to hide the module; empty hides it when no battery is present, such as on desktops
+
 settings-modules-battery-border-show = Show Border
     .description = Display border around button
 
@@ -0,0 +1,51 @@
+### Wayle Configuration - Brightness Module
+
+## Brightness Module Configuration
+
+settings-modules-brightness-level-icons = Level Icons
+    .description = Icons for brightness levels from dim to full
+
+settings-modules-brightness-border-show = Show Border
+    .description = Display border around button
+
+settings-modules-brightness-border-color = Border Color
+    .description = Border color token
+
+settings-modules-brightness-icon-show = Show Icon
+    .description = Display module icon
+
+settings-modules-brightness-icon-color = Icon Color
+    .description = Icon foreground color
+
+settings-modules-brightness-icon-bg-color = Icon Background
+    .description = Icon container background color
+
+settings-modules-brightness-label-show = Show Label
+    .description = Display percentage label
+
+settings-modules-brightness-label-color = Label Color
+    .description = Label text color
+
+settings-modules-brightness-format = Format
+    .description = Format string for the label
+
+settings-modules-brightness-label-max-length = Label Max Length
+    .description = Max characters before truncation
+
+settings-modules-brightness-button-bg-color = Button Background
+    .description = Button background color
+
+settings-modules-brightness-left-click = Left Click
+    .description = Action on left click
+
+settings-modules-brightness-right-click = Right Click
+    .description = Action on right click
+
+settings-modules-brightness-middle-click = Middle Click
+    .description = Action on middle click
+
+settings-modules-brightness-scroll-up = Scroll Up
+    .description = Action on scroll up
+
+settings-modules-brightness-scroll-down = Scroll Down
+    .description = Action on scroll down
@@ -35,6 +35,9 @@ settings-modules-keyboard-input-label-max-length = Label Max Length
 settings-modules-keyboard-input-button-bg-color = Button Background
     .description = Button background color
 
+settings-modules-keyboard-input-cycle-on-click = Cycle Layouts on Click
+    .description = Left click selects the next layout, right click the previous, unless that click has its own action
+
 settings-modules-keyboard-input-left-click = Left Click
     .description = Shell command on left click
 
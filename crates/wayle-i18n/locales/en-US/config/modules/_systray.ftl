@@ -17,6 +17,9 @@ settings-modules-systray-blacklist = Blacklist
 settings-modules-systray-overrides = Overrides
     .description = Custom icon and color overrides for tray items
 
+settings-modules-systray-hide-when = Hide When
+    .description = When to hide the module; empty hides it while there are no tray items to show
+
 settings-modules-systray-border-show = Show Border
     .description = Display border around container
 
@@ -0,0 +1,7 @@
+### Wayle Services - Battery
+
+## Power Flow
+
+battery-power-flow-charging = Charging at { $watts } W
+battery-power-flow-discharging = Discharging at { $watts } W
+battery-power-flow-idle = Idle
@@ -0,0 +1,25 @@
+//! Error types for locale selection.
+
+use unic_langid::LanguageIdentifier;
+
+/// Errors that can occur when selecting a language.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The language identifier could not be parsed.
+    #[error("invalid language identifier '{langid}'")]
+    InvalidLanguage {
+        /// The identifier as given.
+        langid: String,
+        /// Underlying parse error.
+        #[source]
+        source: unic_langid::LanguageIdentifierError,
+    },
+
+    /// No embedded translations exist for the language.
+    #[error("no translations available for '{0}'")]
+    UnsupportedLanguage(LanguageIdentifier),
+
+    /// Loading the embedded translations failed.
+    #[error("cannot load translations")]
+    Load(#[from] i18n_embed::I18nEmbedError),
+}
@@ -7,7 +7,11 @@
 //! let greeting = t!("welcome-user", user = "Alice");
 //! ```
 
-use std::sync::OnceLock;
+mod error;
+
+use std::{collections::BTreeSet, sync::OnceLock};
+
+pub use error::Error;
 
 use i18n_embed::{
     DesktopLanguageRequester, LanguageLoader,
@@ -16,6 +20,8 @@ use i18n_embed::{
 #[doc(hidden)]
 pub use i18n_embed_fl::fl as __fl;
 use rust_embed::RustEmbed;
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
 
 #[derive(RustEmbed)]
 #[folder = "locales/"]
@@ -23,8 +29,14 @@ struct Localizations;
 
 static LOADER: OnceLock<FluentLanguageLoader> = OnceLock::new();
 
+/// Environment variable that enables missing-translation diagnostics.
+pub const DEBUG_ENV: &str = "WAYLE_I18N_DEBUG";
+
 /// Returns the language loader, auto-detecting system locale on first access.
 ///
+/// When [`DEBUG_ENV`] is set, every key that falls back to en-US in the
+/// selected language is logged.
+///
 /// # Panics
 ///
 /// Panics if embedded FTL resources fail to load.
@@ -39,10 +51,83 @@ pub fn loader() -> &'static FluentLanguageLoader {
         let requested = DesktopLanguageRequester::requested_languages();
         let _ = i18n_embed::select(&loader, &Localizations, &requested);
 
+        report_missing_keys(&loader);
         loader
     })
 }
 
+/// Overrides the active language, replacing the auto-detected system locale.
+///
+/// Messages looked up through [`t!`] resolve against the new language
+/// immediately. Keys missing from it still fall back to en-US.
+///
+/// # Errors
+///
+/// Returns error if `langid` can't be parsed, has no embedded translations,
+/// or its FTL resources fail to load.
+pub fn set_language(langid: &str) -> Result<(), Error> {
+    let requested: LanguageIdentifier =
+        langid.parse().map_err(|source| Error::InvalidLanguage {
+            langid: langid.to_owned(),
+            source,
+        })?;
+
+    let supported = available_languages()
+        .iter()
+        .any(|available| available.language == requested.language);
+    if !supported {
+        return Err(Error::UnsupportedLanguage(requested));
+    }
+
+    let loader = loader();
+    i18n_embed::select(loader, &Localizations, &[requested])?;
+
+    report_missing_keys(loader);
+    Ok(())
+}
+
+/// Lists the languages with embedded translations.
+pub fn available_languages() -> Vec<LanguageIdentifier> {
+    loader()
+        .available_languages(&Localizations)
+        .unwrap_or_default()
+}
+
+/// Lists keys the active language doesn't translate, sorted.
+///
+/// Lookups for these keys fall back to en-US. Empty when en-US is active.
+pub fn missing_keys() -> Vec<String> {
+    keys_missing_from_current(loader())
+}
+
+fn keys_missing_from_current(loader: &FluentLanguageLoader) -> Vec<String> {
+    let current = loader.current_language();
+    let fallback = loader.fallback_language();
+    if &current == fallback {
+        return Vec::new();
+    }
+
+    let translated: BTreeSet<String> = loader.with_message_iter(&current, |messages| {
+        messages.map(|message| message.id.name.to_owned()).collect()
+    });
+    let all: BTreeSet<String> = loader.with_message_iter(fallback, |messages| {
+        messages.map(|message| message.id.name.to_owned()).collect()
+    });
+
+    all.difference(&translated).cloned().collect()
+}
+
+fn report_missing_keys(loader: &FluentLanguageLoader) {
+    if std::env::var_os(DEBUG_ENV).is_none() {
+        return;
+    }
+
+    let language = loader.current_language();
+    for key in keys_missing_from_current(loader) {
+        warn!(%language, key, "translation missing, falling back to en-US");
+    }
+}
+
 /// Looks up a translated message by key.
 #[macro_export]
 macro_rules! t {
@@ -56,9 +141,43 @@ macro_rules! t {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn keys_from_both_files_work() {
         let _ = t!("app-name");
         let _ = t!("settings-bar-scale");
     }
+
+    #[test]
+    fn fallback_language_is_available() {
+        let fallback: LanguageIdentifier = "en-US".parse().unwrap();
+        assert!(available_languages().contains(&fallback));
+    }
+
+    #[test]
+    fn set_language_accepts_available_language() {
+        assert!(set_language("en-US").is_ok());
+        assert_eq!(loader().current_language().to_string(), "en-US");
+    }
+
+    #[test]
+    fn fallback_language_has_no_missing_keys() {
+        let fallback = loader().fallback_language().clone();
+        i18n_embed::select(loader(), &Localizations, &[fallback]).unwrap();
+
+        assert!(missing_keys().is_empty());
+    }
+
+    #[test]
+    fn set_language_rejects_unknown_language() {
+        assert!(matches!(
+            set_language("tlh"),
+            Err(Error::UnsupportedLanguage(_))
+        ));
+        assert!(matches!(
+            set_language("not a locale"),
+            Err(Error::InvalidLanguage { .. })
+        ));
+    }
 }
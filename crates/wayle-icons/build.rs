@@ -0,0 +1,59 @@
+#![allow(clippy::expect_used, clippy::panic, missing_docs)]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+const ICON_LIST: &str = "bundled-icons.txt";
+const ICONS_DIR: &str = "../../resources/icons/hicolor/scalable/actions";
+
+fn main() {
+    println!("cargo::rerun-if-changed={ICON_LIST}");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("set by cargo"));
+    let icons_dir = manifest_dir.join(ICONS_DIR);
+
+    let list = fs::read_to_string(manifest_dir.join(ICON_LIST)).expect("icon list readable");
+    let names = parse_icon_list(&list);
+
+    let table = render_table(&names, &icons_dir);
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("set by cargo"));
+    fs::write(out_dir.join("bundled_icons.rs"), table).expect("failed to write icon table");
+}
+
+/// Icon names in the list, sorted and without duplicates so the table
+/// can be binary searched.
+fn parse_icon_list(list: &str) -> Vec<&str> {
+    let mut names: Vec<&str> = list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+fn render_table(names: &[&str], icons_dir: &Path) -> String {
+    let mut table = String::from("static BUNDLED_ICONS: &[(&str, &[u8])] = &[\n");
+
+    for name in names {
+        let path = icons_dir.join(format!("{name}.svg"));
+        if !path.is_file() {
+            panic!(
+                "{ICON_LIST} lists {name}, but {} is missing",
+                path.display()
+            );
+        }
+        println!("cargo::rerun-if-changed={}", path.display());
+
+        table.push_str(&format!(
+            "    ({name:?}, include_bytes!({:?})),\n",
+            path.display().to_string()
+        ));
+    }
+
+    table.push_str("];\n");
+    table
+}
@@ -0,0 +1,82 @@
+//! Icons embedded in the binary as an offline fallback.
+//!
+//! Only the icons the shell references by name are embedded; the list lives
+//! in `bundled-icons.txt` next to this crate's manifest. A bundled icon is
+//! written to disk the first time it's looked up and missing, so the bar
+//! renders even when the CDNs are unreachable. The embedded files are
+//! already transformed for GTK symbolic use.
+
+use std::{fs, path::Path};
+
+use tracing::debug;
+
+use crate::error::{Error, Result};
+
+include!(concat!(env!("OUT_DIR"), "/bundled_icons.rs"));
+
+/// Returns the bundled SVG for a full icon name (e.g., "tb-home-symbolic").
+pub fn get(icon_name: &str) -> Option<&'static [u8]> {
+    BUNDLED_ICONS
+        .binary_search_by(|(name, _)| (*name).cmp(icon_name))
+        .ok()
+        .map(|index| BUNDLED_ICONS[index].1)
+}
+
+/// Checks if a bundled copy exists for a full icon name.
+pub fn contains(icon_name: &str) -> bool {
+    get(icon_name).is_some()
+}
+
+/// Lists all bundled icon names without the `.svg` extension.
+pub fn names() -> Vec<String> {
+    BUNDLED_ICONS
+        .iter()
+        .map(|(name, _)| (*name).to_string())
+        .collect()
+}
+
+/// Writes a single bundled icon into `icons_dir`.
+///
+/// Returns `Ok(None)` if the icon isn't bundled.
+pub(crate) fn install(icon_name: &str, icons_dir: &Path) -> Result<Option<String>> {
+    let Some(data) = get(icon_name) else {
+        return Ok(None);
+    };
+
+    let file_path = icons_dir.join(format!("{icon_name}.svg"));
+    fs::write(&file_path, data).map_err(|source| Error::WriteError {
+        path: file_path,
+        source,
+    })?;
+
+    Ok(Some(icon_name.to_string()))
+}
+
+/// Writes every bundled icon into `icons_dir`.
+///
+/// Only used to seed a freshly created icon directory. Returns the number
+/// of icons written.
+pub(crate) fn install_all(icons_dir: &Path) -> Result<usize> {
+    for (name, _) in BUNDLED_ICONS {
+        install(name, icons_dir)?;
+    }
+
+    debug!(count = BUNDLED_ICONS.len(), "Installed bundled icons");
+    Ok(BUNDLED_ICONS.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted_for_lookup() {
+        assert!(BUNDLED_ICONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn looks_up_bundled_icons_by_name() {
+        assert!(contains("ld-headphones-symbolic"));
+        assert!(!contains("tb-not-an-icon-symbolic"));
+    }
+}
@@ -7,12 +7,24 @@ use thiserror::Error;
 pub enum SvgValidationError {
     /// SVG parsing failed.
     ParseError(String),
+    /// SVG contains no path elements.
+    NoPaths,
+    /// SVG carries `<style>` blocks or `class` attributes GTK won't apply.
+    EmbeddedStyles,
+    /// Some paths lack the `gpa:fill`/`gpa:stroke` attributes used for recoloring.
+    MissingSymbolicPaint,
+    /// A filled path covers the whole canvas and renders as a solid block.
+    SolidBlock,
 }
 
 impl std::fmt::Display for SvgValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::ParseError(msg) => write!(f, "{msg}"),
+            Self::NoPaths => write!(f, "no paths to render"),
+            Self::EmbeddedStyles => write!(f, "embedded styles are not supported"),
+            Self::MissingSymbolicPaint => write!(f, "paths are missing symbolic paint attributes"),
+            Self::SolidBlock => write!(f, "a filled path covers the whole icon"),
         }
     }
 }
@@ -116,5 +128,13 @@ pub enum Error {
     Io(#[from] std::io::Error),
 }
 
+impl Error {
+    /// Whether the icon couldn't be downloaded, as opposed to being
+    /// downloaded and rejected or failing to save.
+    pub(crate) fn is_fetch_failure(&self) -> bool {
+        matches!(self, Self::FetchError { .. } | Self::HttpError(_))
+    }
+}
+
 /// Result type alias for icon operations.
 pub type Result<T> = std::result::Result<T, Error>;
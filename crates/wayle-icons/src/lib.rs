@@ -2,8 +2,9 @@
 //!
 //! Features:
 //! - [`IconSource`] trait and implementations for different icon CDNs
-//! - [`IconManager`] for installing and removing icons
+//! - [`IconManager`] for installing, removing, and pruning icons
 //! - [`IconRegistry`] for registering icons with GTK's IconTheme
+//! - [`bundled`] icons embedded as an offline fallback
 //!
 //! # Icon Sources
 //!
@@ -13,11 +14,12 @@
 //! | TablerFilled | `tbf-` | Solid UI icons |
 //! | Simple Icons | `si-` | Brand logos (firefox, spotify) |
 //! | Lucide | `ld-` | Alternative UI icons |
+//! | LocalDirectory | custom | Brand or custom glyphs from a directory on disk |
 //!
 //! # Example
 //!
 //! ```rust,no_run
-//! use wayle_icons::{IconManager, IconRegistry, sources};
+//! use wayle_icons::{IconManager, IconRegistry, InstallOptions, sources};
 //!
 //! # async fn example() -> wayle_icons::Result<()> {
 //! // At app startup, register icon directory with GTK
@@ -26,29 +28,45 @@
 //!
 //! // Install icons from CDN
 //! let manager = IconManager::new()?;
-//! manager.install(&sources::Tabler, &["home", "settings"]).await?;
+//! manager
+//!     .install(&sources::Tabler, &["home", "settings"], InstallOptions::default())
+//!     .await?;
 //!
 //! // Icons are now available via set_icon_name("tb-home")
+//!
+//! // Keep Spotify's brand green instead of following the theme
+//! manager
+//!     .install(&sources::SimpleIcons, &["spotify"], InstallOptions { recolor: false })
+//!     .await?;
+//!
+//! // Available via set_icon_name("si-spotify")
 //! # Ok(())
 //! # }
 //! ```
 
+/// Icons embedded in the binary for offline use.
+pub mod bundled;
+
 /// Error types for icon operations.
 pub mod error;
 
 /// Icon manager for install, remove, and validation operations.
 pub mod manager;
 
+/// Record of installed icons, used for pruning.
+pub mod manifest;
+
 /// GTK IconTheme integration.
 pub mod registry;
 
-/// Icon source definitions (Tabler, Simple Icons, Lucide).
+/// Icon source definitions (Tabler, Simple Icons, Lucide, local directories).
 pub mod sources;
 
 /// SVG transformation for GTK symbolic icon compatibility.
 pub mod transform;
 
 pub use error::{Error, Result};
-pub use manager::{IconManager, InstallFailure, InstallResult};
+pub use manager::{IconManager, InstallFailure, InstallOptions, InstallResult, PruneResult};
+pub use manifest::{IconManifest, ManifestEntry};
 pub use registry::IconRegistry;
 pub use sources::IconSource;
@@ -1,9 +1,14 @@
 //! Icon installation and removal operations.
 //!
 //! The manager handles fetching icons from CDN sources and storing them
-//! in the icon directory for GTK to discover.
+//! in the icon directory for GTK to discover. Every install is recorded in
+//! the [`IconManifest`] so unused icons can be pruned later.
 
-use std::{fs, path::Path};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use futures::future::join_all;
 use tokio::fs as async_fs;
@@ -11,7 +16,9 @@ use tracing::{debug, info, warn};
 use usvg::{Options, Tree};
 
 use crate::{
+    bundled,
     error::{Error, Result, SvgValidationError},
+    manifest::{self, IMPORT_SOURCE, IconManifest},
     registry::IconRegistry,
     sources::{self, CUSTOM_PREFIX, IconSource},
     transform,
@@ -47,6 +54,50 @@ impl InstallResult {
     }
 }
 
+/// Result of [`IconManager::prune`].
+#[derive(Debug, Clone, Default)]
+pub struct PruneResult {
+    /// Icons that were deleted.
+    pub removed: Vec<String>,
+    /// Disk space freed by the deleted files, in bytes.
+    pub freed_bytes: u64,
+}
+
+/// Per-call options for [`IconManager::install`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstallOptions {
+    /// Transform icons into GTK symbolic icons that follow the theme's
+    /// foreground color.
+    ///
+    /// When `false`, the SVG is installed untouched so it keeps its own
+    /// colors, fetched from the source's
+    /// [`brand_url`](IconSource::brand_url) when it has one. These icons are
+    /// named without the `-symbolic` suffix, so `si-spotify` (brand green)
+    /// and `si-spotify-symbolic` (themed) can be installed side by side.
+    pub recolor: bool,
+}
+
+impl Default for InstallOptions {
+    fn default() -> Self {
+        Self { recolor: true }
+    }
+}
+
+impl InstallOptions {
+    /// Name an icon is installed under with these options.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon_name` - Icon name including prefix (e.g., "si-spotify")
+    pub fn installed_name(&self, icon_name: &str) -> String {
+        if self.recolor {
+            format!("{icon_name}-symbolic")
+        } else {
+            icon_name.to_owned()
+        }
+    }
+}
+
 /// Manages icon installation and removal.
 ///
 /// Uses [`IconRegistry`] to determine where icons are stored and provides
@@ -87,18 +138,30 @@ impl IconManager {
 
     /// Installs icons from a source by fetching from CDN.
     ///
+    /// Sources with a [`local_path`](IconSource::local_path), such as
+    /// [`sources::LocalDirectory`], are read from disk instead.
+    ///
     /// Returns an [`InstallResult`] containing both successful and failed installations.
+    /// When the download fails for a recolored icon that has a [`bundled`]
+    /// copy, the bundled copy is installed instead. Icons that download but
+    /// fail validation or can't be written are reported as failures.
     ///
     /// # Arguments
     ///
-    /// * `source` - The icon source (Tabler, SimpleIcons, etc.)
+    /// * `source` - The icon source (Tabler, SimpleIcons, a local directory, etc.)
     /// * `slugs` - Icon identifiers to install (e.g., "home", "settings")
+    /// * `options` - Whether icons are recolored or keep their own colors
     ///
     /// # Errors
     ///
     /// Returns error only if the icon directory cannot be created. Individual
     /// icon failures are captured in [`InstallResult::failed`].
-    pub async fn install(&self, source: &dyn IconSource, slugs: &[&str]) -> Result<InstallResult> {
+    pub async fn install(
+        &self,
+        source: &dyn IconSource,
+        slugs: &[&str],
+        options: InstallOptions,
+    ) -> Result<InstallResult> {
         let icons_dir = self.registry.icons_dir();
         async_fs::create_dir_all(&icons_dir)
             .await
@@ -111,36 +174,73 @@ impl IconManager {
         let fetch_data: Vec<_> = slugs
             .iter()
             .map(|slug| {
-                let url = source.cdn_url(slug);
+                let url = match source.brand_url(slug) {
+                    Some(url) if !options.recolor => url,
+                    _ => source.cdn_url(slug),
+                };
+                let local_path = source.local_path(slug);
                 let icon_name = source.icon_name(slug);
-                (*slug, url, icon_name)
+                (*slug, url, local_path, icon_name)
             })
             .collect();
 
         let futures: Vec<_> = fetch_data
             .iter()
-            .map(|(slug, url, icon_name)| self.fetch_and_save(slug, url, icon_name, &icons_dir))
+            .map(|(slug, url, local_path, icon_name)| {
+                self.fetch_and_save(
+                    slug,
+                    url,
+                    local_path.as_deref(),
+                    icon_name,
+                    &icons_dir,
+                    options,
+                )
+            })
             .collect();
 
         let results = join_all(futures).await;
 
         let mut install_result = InstallResult::default();
-        for (slug, result) in fetch_data.iter().map(|(s, _, _)| *s).zip(results) {
-            match result {
+        for ((slug, _, _, icon_name), result) in fetch_data.iter().zip(results) {
+            let err = match result {
                 Ok(name) => {
                     info!(icon = %name, source = source_name, "Installed icon");
                     install_result.installed.push(name);
+                    continue;
                 }
-                Err(err) => {
-                    warn!(slug = %slug, source = source_name, error = %err, "cannot install icon");
-                    install_result.failed.push(InstallFailure {
-                        slug: slug.to_string(),
-                        error: err.to_string(),
-                    });
+                Err(err) => err,
+            };
+
+            let bundled = if options.recolor && err.is_fetch_failure() {
+                bundled::install(&options.installed_name(icon_name), &icons_dir)
+            } else {
+                Ok(None)
+            };
+
+            let err = match bundled {
+                Ok(Some(name)) => {
+                    info!(
+                        icon = %name,
+                        source = source_name,
+                        error = %err,
+                        "CDN fetch failed, installed bundled icon"
+                    );
+                    install_result.installed.push(name);
+                    continue;
                 }
-            }
+                Ok(None) => err,
+                Err(bundled_err) => bundled_err,
+            };
+
+            warn!(slug = %slug, source = source_name, error = %err, "cannot install icon");
+            install_result.failed.push(InstallFailure {
+                slug: slug.to_string(),
+                error: err.to_string(),
+            });
         }
 
+        self.record_installed(&install_result.installed, source_name);
+
         Ok(install_result)
     }
 
@@ -148,9 +248,37 @@ impl IconManager {
         &self,
         slug: &str,
         url: &str,
+        local_path: Option<&Path>,
         icon_name: &str,
         icons_dir: &Path,
+        options: InstallOptions,
     ) -> Result<String> {
+        let svg_content = match local_path {
+            Some(path) => Self::read_local(path).await?,
+            None => self.fetch(slug, url).await?,
+        };
+
+        Self::validate_svg(&svg_content, slug)?;
+
+        let content = if options.recolor {
+            transform::to_symbolic(&svg_content)
+        } else {
+            svg_content
+        };
+
+        let name = options.installed_name(icon_name);
+        let file_path = icons_dir.join(format!("{name}.svg"));
+        async_fs::write(&file_path, &content)
+            .await
+            .map_err(|source| Error::WriteError {
+                path: file_path,
+                source,
+            })?;
+
+        Ok(name)
+    }
+
+    async fn fetch(&self, slug: &str, url: &str) -> Result<String> {
         debug!(url = %url, "Fetching icon");
 
         let response = self.client.get(url).send().await?;
@@ -163,19 +291,24 @@ impl IconManager {
             });
         }
 
-        let svg_content = response.text().await?;
-        Self::validate_svg(&svg_content, slug)?;
+        Ok(response.text().await?)
+    }
 
-        let transformed = transform::to_symbolic(&svg_content);
-        let file_path = icons_dir.join(format!("{icon_name}-symbolic.svg"));
-        async_fs::write(&file_path, &transformed)
+    async fn read_local(path: &Path) -> Result<String> {
+        debug!(path = %path.display(), "Reading local icon");
+
+        if !async_fs::try_exists(path).await.unwrap_or(false) {
+            return Err(Error::NotFound {
+                name: path.display().to_string(),
+            });
+        }
+
+        async_fs::read_to_string(path)
             .await
-            .map_err(|source| Error::WriteError {
-                path: file_path,
+            .map_err(|source| Error::ReadError {
+                path: path.to_path_buf(),
                 source,
-            })?;
-
-        Ok(format!("{icon_name}-symbolic"))
+            })
     }
 
     /// Removes an installed icon by name.
@@ -201,10 +334,99 @@ impl IconManager {
             source,
         })?;
 
+        self.update_manifest(|manifest| {
+            manifest.forget(icon_name);
+        });
+
         info!(icon = %icon_name, "Removed icon");
         Ok(())
     }
 
+    /// Deletes installed icons that are not in `keep`.
+    ///
+    /// Only icons recorded in the [`IconManifest`] are considered, so icons
+    /// from `wayle icons setup`, copied in by hand, or installed before the
+    /// manifest existed are never touched. Manifest entries whose file is
+    /// already gone are dropped without being counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - Full icon names still in use (e.g., "tb-home-symbolic")
+    ///
+    /// # Errors
+    ///
+    /// Returns error if an icon file cannot be deleted. Icons deleted before
+    /// the failure stay removed from the manifest.
+    pub fn prune(&self, keep: &HashSet<String>) -> Result<PruneResult> {
+        let icons_dir = self.registry.icons_dir();
+        let mut result = PruneResult::default();
+        let mut outcome = Ok(());
+
+        self.update_manifest(|manifest| {
+            let stale: Vec<String> = manifest
+                .iter()
+                .map(|(name, _)| name)
+                .filter(|name| !keep.contains(*name))
+                .map(String::from)
+                .collect();
+
+            for name in stale {
+                let file_path = icons_dir.join(format!("{name}.svg"));
+
+                if let Ok(metadata) = fs::metadata(&file_path) {
+                    if let Err(source) = fs::remove_file(&file_path) {
+                        outcome = Err(Error::DeleteError { name, source });
+                        return;
+                    }
+
+                    info!(icon = %name, "Pruned icon");
+                    result.freed_bytes += metadata.len();
+                    result.removed.push(name.clone());
+                }
+
+                manifest.forget(&name);
+            }
+        });
+
+        outcome.map(|()| result)
+    }
+
+    /// Returns the record of icons installed through this manager.
+    pub fn manifest(&self) -> IconManifest {
+        IconManifest::load(&self.manifest_path())
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        manifest::path(self.registry.base_path())
+    }
+
+    fn record_installed(&self, names: &[String], source: &str) {
+        if names.is_empty() {
+            return;
+        }
+
+        self.update_manifest(|manifest| {
+            for name in names {
+                manifest.record(name, source);
+            }
+        });
+    }
+
+    /// Applies `update` to the manifest and writes it back.
+    ///
+    /// Failing to save only logs a warning: the icons themselves are
+    /// already in place, and a stale manifest just means fewer are pruned.
+    fn update_manifest(&self, update: impl FnOnce(&mut IconManifest)) {
+        let path = self.manifest_path();
+        let mut manifest = IconManifest::load(&path);
+
+        update(&mut manifest);
+
+        if let Err(err) = manifest.save(&path) {
+            warn!(error = %err, "cannot save icon manifest");
+        }
+    }
+
     /// Lists all installed icons.
     ///
     /// Returns icon names without the `.svg` extension.
@@ -241,6 +463,72 @@ impl IconManager {
             .exists()
     }
 
+    /// Lints an installed icon for patterns that stop GTK recoloring it.
+    ///
+    /// This is a heuristic text check of the SVG markup, not a render: it
+    /// catches embedded CSS, paths without Grappa paint attributes and filled
+    /// full-canvas paths, but passing it doesn't guarantee the icon looks
+    /// right.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon_name` - Full icon name including prefix (e.g., "tb-home-symbolic")
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the icon isn't installed, or
+    /// [`Error::InvalidSvg`] naming the problem found.
+    pub fn lint(&self, icon_name: &str) -> Result<()> {
+        let content = self.read_installed(icon_name)?;
+
+        transform::check_symbolic(&content).map_err(|reason| Error::InvalidSvg {
+            slug: icon_name.to_string(),
+            reason,
+        })
+    }
+
+    /// Re-transforms an installed icon with strict normalization.
+    ///
+    /// Embedded styles are flattened and every painted shape is forced to
+    /// `currentColor`. The repaired icon is linted again before returning.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon_name` - Full icon name including prefix (e.g., "tb-home-symbolic")
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the icon isn't installed, cannot be rewritten, or
+    /// still fails the lint after repair.
+    pub fn repair(&self, icon_name: &str) -> Result<()> {
+        let content = self.read_installed(icon_name)?;
+        let repaired = transform::to_symbolic_strict(&content);
+
+        let file_path = self.registry.icons_dir().join(format!("{icon_name}.svg"));
+        fs::write(&file_path, &repaired).map_err(|source| Error::WriteError {
+            path: file_path,
+            source,
+        })?;
+
+        info!(icon = %icon_name, "Repaired icon");
+        self.lint(icon_name)
+    }
+
+    fn read_installed(&self, icon_name: &str) -> Result<String> {
+        let file_path = self.registry.icons_dir().join(format!("{icon_name}.svg"));
+
+        if !file_path.exists() {
+            return Err(Error::NotFound {
+                name: icon_name.to_string(),
+            });
+        }
+
+        fs::read_to_string(&file_path).map_err(|source| Error::ReadError {
+            path: file_path,
+            source,
+        })
+    }
+
     /// Imports a local SVG file as a custom icon.
     ///
     /// Validates the SVG using usvg, transforms it for GTK compatibility,
@@ -281,6 +569,8 @@ impl IconManager {
             source,
         })?;
 
+        self.record_installed(std::slice::from_ref(&icon_name), IMPORT_SOURCE);
+
         info!(icon = %icon_name, path = %path.display(), "Imported custom icon");
         Ok(icon_name)
     }
@@ -334,6 +624,8 @@ impl IconManager {
             }
         }
 
+        self.record_installed(&result.installed, IMPORT_SOURCE);
+
         Ok(result)
     }
 
@@ -341,7 +633,7 @@ impl IconManager {
         &self,
         path: &Path,
         icons_dir: &Path,
-        prefixes: &[&str],
+        prefixes: &[String],
     ) -> Result<String> {
         let stem = path
             .file_stem()
@@ -390,3 +682,91 @@ impl IconManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recolored_and_brand_colored_icons_get_distinct_names() {
+        let themed = InstallOptions::default();
+        let brand = InstallOptions { recolor: false };
+
+        assert_eq!(themed.installed_name("si-spotify"), "si-spotify-symbolic");
+        assert_eq!(brand.installed_name("si-spotify"), "si-spotify");
+    }
+
+    #[test]
+    fn only_download_failures_fall_back_to_bundled() {
+        let fetch = Error::FetchError {
+            slug: String::from("home"),
+            icon_source: String::from("cdn"),
+            status: reqwest::StatusCode::NOT_FOUND,
+        };
+        let invalid = Error::InvalidSvg {
+            slug: String::from("home"),
+            reason: SvgValidationError::NoPaths,
+        };
+        let write = Error::WriteError {
+            path: PathBuf::from("/icons/home.svg"),
+            source: std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+        };
+
+        assert!(fetch.is_fetch_failure());
+        assert!(!invalid.is_fetch_failure());
+        assert!(!write.is_fetch_failure());
+    }
+
+    fn manager_with_icons(names: &[&str]) -> (tempfile::TempDir, IconManager) {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let manager = IconManager::with_registry(IconRegistry::with_path(dir.path().to_path_buf()));
+        let icons_dir = manager.registry().icons_dir();
+        fs::create_dir_all(&icons_dir).expect("icons dir");
+
+        for name in names {
+            fs::write(icons_dir.join(format!("{name}.svg")), "<svg/>").expect("icon file");
+        }
+        let installed: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+        manager.record_installed(&installed, "tabler");
+
+        (dir, manager)
+    }
+
+    #[test]
+    fn prune_removes_tracked_icons_not_kept() {
+        let (_dir, manager) = manager_with_icons(&["tb-home-symbolic", "tb-bell-symbolic"]);
+        let keep = HashSet::from([String::from("tb-home-symbolic")]);
+
+        let result = manager.prune(&keep).expect("prune");
+
+        assert_eq!(result.removed, vec![String::from("tb-bell-symbolic")]);
+        assert_eq!(result.freed_bytes, 6);
+        assert!(manager.is_installed("tb-home-symbolic"));
+        assert!(!manager.is_installed("tb-bell-symbolic"));
+        assert!(manager.manifest().get("tb-bell-symbolic").is_none());
+    }
+
+    #[test]
+    fn prune_leaves_untracked_icons() {
+        let (_dir, manager) = manager_with_icons(&[]);
+        let untracked = manager
+            .registry()
+            .icons_dir()
+            .join("cm-manual-symbolic.svg");
+        fs::write(&untracked, "<svg/>").expect("icon file");
+
+        let result = manager.prune(&HashSet::new()).expect("prune");
+
+        assert!(result.removed.is_empty());
+        assert!(untracked.exists());
+    }
+
+    #[test]
+    fn remove_forgets_manifest_entry() {
+        let (_dir, manager) = manager_with_icons(&["tb-home-symbolic"]);
+
+        manager.remove("tb-home-symbolic").expect("remove");
+
+        assert!(manager.manifest().is_empty());
+    }
+}
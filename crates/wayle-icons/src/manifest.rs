@@ -0,0 +1,154 @@
+//! Record of which icons were installed, from where, and when.
+//!
+//! Stored as `manifest.json` next to the theme's `index.theme`. Only icons
+//! installed through [`IconManager`](crate::IconManager) are tracked, which
+//! is what lets [`IconManager::prune`](crate::IconManager::prune) tell them
+//! apart from icons copied in by hand.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::{Error, Result};
+
+/// File name of the manifest inside the registry's base path.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Source recorded for icons imported from local SVG files.
+pub const IMPORT_SOURCE: &str = "import";
+
+/// Where and when a single icon was installed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Source CLI name (e.g., "tabler"), or [`IMPORT_SOURCE`].
+    pub source: String,
+    /// Install time as seconds since the Unix epoch.
+    pub installed_at: u64,
+}
+
+/// Installed icons keyed by full icon name (e.g., "tb-home-symbolic").
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IconManifest {
+    icons: BTreeMap<String, ManifestEntry>,
+}
+
+impl IconManifest {
+    /// Reads the manifest at `path`.
+    ///
+    /// A missing file is an empty manifest. An unreadable one is logged and
+    /// treated as empty too, so a corrupt manifest never blocks installs.
+    pub(crate) fn load(path: &Path) -> Self {
+        let Ok(content) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+
+        serde_json::from_str(&content).unwrap_or_else(|err| {
+            warn!(path = %path.display(), error = %err, "cannot parse icon manifest, starting fresh");
+            Self::default()
+        })
+    }
+
+    /// Writes the manifest to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be written.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(|err| Error::WriteError {
+            path: path.to_path_buf(),
+            source: err.into(),
+        })?;
+
+        fs::write(path, content).map_err(|source| Error::WriteError {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Records `icon_name` as installed from `source` just now.
+    pub(crate) fn record(&mut self, icon_name: &str, source: &str) {
+        let installed_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+
+        self.icons.insert(
+            icon_name.to_owned(),
+            ManifestEntry {
+                source: source.to_owned(),
+                installed_at,
+            },
+        );
+    }
+
+    /// Stops tracking `icon_name`.
+    pub(crate) fn forget(&mut self, icon_name: &str) -> Option<ManifestEntry> {
+        self.icons.remove(icon_name)
+    }
+
+    /// Returns the entry for `icon_name`, if it is tracked.
+    pub fn get(&self, icon_name: &str) -> Option<&ManifestEntry> {
+        self.icons.get(icon_name)
+    }
+
+    /// Iterates tracked icons in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ManifestEntry)> {
+        self.icons
+            .iter()
+            .map(|(name, entry)| (name.as_str(), entry))
+    }
+
+    /// Number of tracked icons.
+    pub fn len(&self) -> usize {
+        self.icons.len()
+    }
+
+    /// Returns true if no icons are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.icons.is_empty()
+    }
+}
+
+/// Path of the manifest for icons stored under `base_path`.
+pub(crate) fn path(base_path: &Path) -> PathBuf {
+    base_path.join(MANIFEST_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_then_forget() {
+        let mut manifest = IconManifest::default();
+        manifest.record("tb-home-symbolic", "tabler");
+
+        assert_eq!(
+            manifest
+                .get("tb-home-symbolic")
+                .map(|entry| entry.source.as_str()),
+            Some("tabler")
+        );
+
+        assert!(manifest.forget("tb-home-symbolic").is_some());
+        assert!(manifest.is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut manifest = IconManifest::default();
+        manifest.record("si-spotify", "simple-icons");
+        manifest.record("cm-logo-symbolic", IMPORT_SOURCE);
+
+        let json = serde_json::to_string(&manifest).unwrap_or_default();
+        let parsed: IconManifest = serde_json::from_str(&json).unwrap_or_default();
+
+        assert_eq!(parsed, manifest);
+    }
+}
@@ -10,7 +10,10 @@ use gtk4::{gdk, glib};
 use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
 use tracing::{debug, info, warn};
 
-use crate::error::{Error, Result};
+use crate::{
+    bundled,
+    error::{Error, Result},
+};
 
 const SYSTEM_ICONS_PATH: &str = "/usr/share/wayle/icons";
 
@@ -107,11 +110,15 @@ impl IconRegistry {
 
     /// Initializes the icon registry with GTK and starts watching for changes.
     ///
-    /// 1. Creates the icon directory structure if it doesn't exist
+    /// 1. Creates the icon directory structure if it doesn't exist, seeding
+    ///    a new directory with the [`bundled`] icons
     /// 2. Creates the `index.theme` file if missing
     /// 3. Registers the directory with GTK's IconTheme
     /// 4. Starts a background watcher that refreshes icons when files change
     ///
+    /// An existing directory is left as is, so icons the user removed stay
+    /// removed. Use [`Self::install_bundled`] when a lookup misses.
+    ///
     /// Should be invoked once at application startup before displaying any
     /// widgets that use Wayle icons.
     ///
@@ -122,7 +129,13 @@ impl IconRegistry {
     /// - File writing fails
     /// - No display is available (GTK not initialized)
     pub fn init(&self) -> Result<()> {
+        let first_run = !self.icons_dir().exists();
         self.ensure_setup()?;
+
+        if first_run && let Err(err) = bundled::install_all(&self.icons_dir()) {
+            warn!(error = %err, "cannot install bundled icons");
+        }
+
         self.register_with_gtk()?;
         self.start_watcher();
 
@@ -130,6 +143,31 @@ impl IconRegistry {
         Ok(())
     }
 
+    /// Writes the [`bundled`] copy of `icon_name` if it isn't installed.
+    ///
+    /// Meant for icon lookups that miss. Makes GTK rescan the icon
+    /// directory so the icon resolves right away. Returns `true` if the
+    /// icon was written.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the icon cannot be written or no display is
+    /// available.
+    pub fn install_bundled(&self, icon_name: &str) -> Result<bool> {
+        let icons_dir = self.icons_dir();
+        if icons_dir.join(format!("{icon_name}.svg")).exists() {
+            return Ok(false);
+        }
+
+        if bundled::install(icon_name, &icons_dir)?.is_none() {
+            return Ok(false);
+        }
+
+        debug!(icon = icon_name, "Installed bundled icon on lookup");
+        self.register_with_gtk()?;
+        Ok(true)
+    }
+
     fn start_watcher(&self) {
         let icons_dir = self.icons_dir();
         let base_path = self.base_path.clone();
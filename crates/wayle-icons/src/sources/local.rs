@@ -0,0 +1,103 @@
+//! Local directory source - custom and brand glyphs kept on disk.
+//!
+//! Reads `<path>/<slug>.svg` instead of fetching from a CDN.
+
+use std::{fs, path::PathBuf};
+
+use super::IconSource;
+use crate::error::{Error, Result};
+
+/// A directory of SVG files installed under a custom prefix.
+///
+/// Useful for glyphs that aren't on Tabler, Lucide or Simple Icons. Icons
+/// go through the same symbolic transform as CDN icons, so `logo.svg` from a
+/// source with prefix `brand` installs as `brand-logo-symbolic`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalDirectory {
+    /// Directory holding the SVG files.
+    pub path: PathBuf,
+    /// Prefix added to icon names (e.g., "brand" for "brand-logo").
+    pub prefix: String,
+}
+
+impl LocalDirectory {
+    /// Creates a source for the SVGs in `path`, named with `prefix`.
+    pub fn new(path: impl Into<PathBuf>, prefix: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Lists the slugs of all SVG files in the directory, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the directory cannot be read.
+    pub fn slugs(&self) -> Result<Vec<String>> {
+        let entries = fs::read_dir(&self.path).map_err(|source| Error::ReadError {
+            path: self.path.clone(),
+            source,
+        })?;
+
+        let mut slugs: Vec<String> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension().is_some_and(|ext| ext == "svg") {
+                    path.file_stem().and_then(|s| s.to_str()).map(String::from)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        slugs.sort();
+        Ok(slugs)
+    }
+}
+
+impl IconSource for LocalDirectory {
+    fn display_name(&self) -> &'static str {
+        "Local Directory"
+    }
+
+    fn cli_name(&self) -> &'static str {
+        "local"
+    }
+
+    fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    fn description(&self) -> &'static str {
+        "SVG files from a directory on disk (custom and brand glyphs)"
+    }
+
+    fn website(&self) -> &'static str {
+        ""
+    }
+
+    fn cdn_url(&self, slug: &str) -> String {
+        format!("file://{}", self.path.join(format!("{slug}.svg")).display())
+    }
+
+    fn local_path(&self, slug: &str) -> Option<PathBuf> {
+        Some(self.path.join(format!("{slug}.svg")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_icons_with_custom_prefix() {
+        let source = LocalDirectory::new("/usr/share/brand-glyphs", "brand");
+
+        assert_eq!(source.icon_name("logo"), "brand-logo");
+        assert_eq!(
+            source.local_path("logo"),
+            Some(PathBuf::from("/usr/share/brand-glyphs/logo.svg"))
+        );
+    }
+}
@@ -1,17 +1,22 @@
 //! Icon source definitions for different CDN providers.
 //!
-//! Each source knows its CDN URL pattern and naming prefix.
+//! Each source knows its CDN URL pattern and naming prefix. [`LocalDirectory`]
+//! reads from a directory on disk instead, for glyphs no CDN carries.
 
+mod local;
 mod lucide;
 mod material;
 mod simple_icons;
 mod tabler;
 
+pub use local::LocalDirectory;
 pub use lucide::Lucide;
 pub use material::Material;
 pub use simple_icons::SimpleIcons;
 pub use tabler::{Tabler, TablerFilled};
 
+use std::path::PathBuf;
+
 use crate::error::{Error, Result};
 
 /// Custom icon prefix for user-imported icons.
@@ -26,7 +31,7 @@ pub trait IconSource: Send + Sync {
     fn cli_name(&self) -> &'static str;
 
     /// Prefix added to icon names (e.g., "tb" for "tb-home").
-    fn prefix(&self) -> &'static str;
+    fn prefix(&self) -> &str;
 
     /// Brief description of what this source provides.
     fn description(&self) -> &'static str;
@@ -41,6 +46,29 @@ pub trait IconSource: Send + Sync {
     /// * `slug` - The icon identifier (e.g., "home", "settings").
     fn cdn_url(&self, slug: &str) -> String;
 
+    /// Local file to read the icon from instead of fetching [`Self::cdn_url`].
+    ///
+    /// CDN sources return `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `slug` - The icon identifier.
+    fn local_path(&self, _slug: &str) -> Option<PathBuf> {
+        None
+    }
+
+    /// CDN URL for a copy of the icon painted in its brand color.
+    ///
+    /// Used instead of [`Self::cdn_url`] when an icon is installed without
+    /// recoloring. Sources whose icons have no brand color return `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `slug` - The icon identifier.
+    fn brand_url(&self, _slug: &str) -> Option<String> {
+        None
+    }
+
     /// Generates the full icon name with prefix.
     ///
     /// # Arguments
@@ -89,8 +117,8 @@ pub fn all() -> Vec<Box<dyn IconSource>> {
 }
 
 /// Returns all known icon prefixes including custom.
-pub fn all_prefixes() -> Vec<&'static str> {
-    let mut prefixes: Vec<_> = all().iter().map(|s| s.prefix()).collect();
-    prefixes.push(CUSTOM_PREFIX);
+pub fn all_prefixes() -> Vec<String> {
+    let mut prefixes: Vec<_> = all().iter().map(|s| s.prefix().to_owned()).collect();
+    prefixes.push(CUSTOM_PREFIX.to_owned());
     prefixes
 }
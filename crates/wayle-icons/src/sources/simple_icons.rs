@@ -32,4 +32,8 @@ impl IconSource for SimpleIcons {
     fn cdn_url(&self, slug: &str) -> String {
         format!("https://unpkg.com/simple-icons@latest/icons/{}.svg", slug)
     }
+
+    fn brand_url(&self, slug: &str) -> Option<String> {
+        Some(format!("https://cdn.simpleicons.org/{slug}"))
+    }
 }
@@ -14,6 +14,10 @@
 //! 2. **Scales coordinates** from source size (typically 24x24) to 16x16
 //! 3. **Adds GTK Grappa attributes** for CSS color support
 //! 4. **Detects stroke vs fill** icons and applies appropriate attributes
+//!
+//! [`check_symbolic`] lints an already-transformed file for markup patterns
+//! that make GTK render it as a solid block, and [`to_symbolic_strict`] re-runs
+//! the transformation after flattening styles and forcing `currentColor`.
 
 use std::fmt::Write;
 
@@ -22,6 +26,8 @@ use usvg::{
     tiny_skia_path::{PathSegment, Transform},
 };
 
+use crate::error::SvgValidationError;
+
 const TARGET_SIZE: f32 = 16.0;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -54,6 +60,182 @@ pub(crate) fn to_symbolic(svg_content: &str) -> String {
     build_gtk_svg(&paths, style, scale)
 }
 
+/// Re-transforms an SVG after stricter normalization.
+///
+/// Drops `<style>` blocks plus `style` and `class` attributes, and rewrites
+/// every non-`none` `fill`/`stroke` to `currentColor` before running
+/// [`to_symbolic`]. Works on both source SVGs and previously transformed files.
+pub(crate) fn to_symbolic_strict(svg_content: &str) -> String {
+    to_symbolic(&normalize_strict(svg_content))
+}
+
+/// Lints a transformed SVG for markup that stops GTK recoloring it.
+///
+/// A heuristic, not a render: checks that the file parses, has at least one
+/// path, carries no embedded CSS, marks every path with a Grappa paint
+/// attribute, and doesn't contain a filled path covering the whole canvas.
+pub(crate) fn check_symbolic(svg_content: &str) -> Result<(), SvgValidationError> {
+    let tree = Tree::from_str(svg_content, &Options::default())
+        .map_err(|err| SvgValidationError::ParseError(err.to_string()))?;
+
+    if svg_content.contains("<style") || has_attribute(svg_content, "class") {
+        return Err(SvgValidationError::EmbeddedStyles);
+    }
+
+    let path_count = svg_content.matches("<path").count();
+    if path_count == 0 {
+        return Err(SvgValidationError::NoPaths);
+    }
+
+    let grappa_count =
+        svg_content.matches("gpa:fill=").count() + svg_content.matches("gpa:stroke=").count();
+    if grappa_count < path_count {
+        return Err(SvgValidationError::MissingSymbolicPaint);
+    }
+
+    let source_size = tree.size().width().max(tree.size().height());
+    let scale = if source_size > 0.0 {
+        TARGET_SIZE / source_size
+    } else {
+        1.0
+    };
+
+    if has_solid_block(tree.root(), Transform::from_scale(scale, scale)) {
+        return Err(SvgValidationError::SolidBlock);
+    }
+
+    Ok(())
+}
+
+fn has_solid_block(group: &usvg::Group, parent_transform: Transform) -> bool {
+    group.children().iter().any(|node| match node {
+        Node::Path(path) => {
+            path.fill().is_some()
+                && path
+                    .data()
+                    .clone()
+                    .transform(parent_transform)
+                    .is_some_and(|data| is_bounding_box_path(&data, TARGET_SIZE))
+        }
+        Node::Group(child) => {
+            has_solid_block(child, parent_transform.pre_concat(child.transform()))
+        }
+        _ => false,
+    })
+}
+
+fn normalize_strict(content: &str) -> String {
+    let mut output = strip_style_blocks(content);
+
+    for name in ["style", "class"] {
+        output = rewrite_attribute(&output, name, |_| None);
+    }
+
+    for name in ["fill", "stroke"] {
+        output = rewrite_attribute(&output, name, |value| {
+            let value = if value == "none" {
+                "none"
+            } else {
+                "currentColor"
+            };
+            Some(format!("{name}=\"{value}\""))
+        });
+    }
+
+    output
+}
+
+fn strip_style_blocks(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("<style") {
+        output.push_str(&rest[..start]);
+
+        let after = &rest[start..];
+        let Some(tag_end) = after.find('>') else {
+            rest = "";
+            break;
+        };
+
+        let after_tag = &after[tag_end + 1..];
+        rest = if after[..tag_end].ends_with('/') {
+            after_tag
+        } else {
+            after_tag
+                .find("</style>")
+                .map_or("", |end| &after_tag[end + "</style>".len()..])
+        };
+    }
+
+    output.push_str(rest);
+    output
+}
+
+fn has_attribute(content: &str, name: &str) -> bool {
+    find_attribute(content, name).is_some()
+}
+
+/// Finds ` name="value"` or ` name='value'`, returning the byte range of the
+/// whole attribute and the value.
+fn find_attribute<'a>(content: &'a str, name: &str) -> Option<(usize, usize, &'a str)> {
+    let needle = format!("{name}=");
+    let mut offset = 0;
+
+    while let Some(found) = content[offset..].find(&needle) {
+        let start = offset + found;
+        let value_start = start + needle.len();
+        offset = value_start;
+
+        let preceded_by_space = content[..start]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_whitespace);
+        if !preceded_by_space {
+            continue;
+        }
+
+        let Some(quote) = content[value_start..].chars().next() else {
+            continue;
+        };
+        if quote != '"' && quote != '\'' {
+            continue;
+        }
+
+        let inner_start = value_start + 1;
+        let Some(len) = content[inner_start..].find(quote) else {
+            continue;
+        };
+
+        let end = inner_start + len + 1;
+        return Some((start, end, &content[inner_start..inner_start + len]));
+    }
+
+    None
+}
+
+fn rewrite_attribute(
+    content: &str,
+    name: &str,
+    replace: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some((start, end, value)) = find_attribute(rest, name) {
+        output.push_str(&rest[..start]);
+        if let Some(replacement) = replace(value) {
+            output.push_str(&replacement);
+        } else if output.ends_with(char::is_whitespace) {
+            output.pop();
+        }
+        rest = &rest[end..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
 fn detect_icon_style(content: &str) -> IconStyle {
     if content.contains(r#"stroke="currentColor""#) {
         IconStyle::Stroke
@@ -499,6 +681,106 @@ mod tests {
         }
     }
 
+    mod check_symbolic_tests {
+        use super::*;
+
+        #[test]
+        fn accepts_transformed_fill_icon() {
+            let svg = to_symbolic(
+                r#"<svg viewBox="0 0 24 24" fill="currentColor"><path d="M4 4L20 4L12 20Z"/></svg>"#,
+            );
+
+            assert_eq!(check_symbolic(&svg), Ok(()));
+        }
+
+        #[test]
+        fn rejects_embedded_styles() {
+            let svg = r#"<svg width="16" height="16" xmlns:gpa="https://www.gtk.org/grappa"><style>.a{fill:red}</style><path class="a" d="M0 0L8 8" gpa:fill="foreground"/></svg>"#;
+
+            assert_eq!(check_symbolic(svg), Err(SvgValidationError::EmbeddedStyles));
+        }
+
+        #[test]
+        fn rejects_paths_without_grappa_paint() {
+            let svg = r#"<svg width="16" height="16"><path d="M0 0L8 8L0 8Z" fill="red"/></svg>"#;
+
+            assert_eq!(
+                check_symbolic(svg),
+                Err(SvgValidationError::MissingSymbolicPaint)
+            );
+        }
+
+        #[test]
+        fn rejects_filled_full_canvas_path() {
+            let svg = r#"<svg width="16" height="16" xmlns:gpa="https://www.gtk.org/grappa"><path d="M0 0L16 0L16 16L0 16Z" fill="rgb(0,0,0)" gpa:fill="foreground"/></svg>"#;
+
+            assert_eq!(check_symbolic(svg), Err(SvgValidationError::SolidBlock));
+        }
+
+        #[test]
+        fn rejects_svg_without_paths() {
+            assert_eq!(
+                check_symbolic("<svg width='16' height='16'/>"),
+                Err(SvgValidationError::NoPaths)
+            );
+        }
+    }
+
+    mod to_symbolic_strict_tests {
+        use super::*;
+
+        #[test]
+        fn flattens_styles_and_forces_current_color() {
+            let svg = r##"<svg viewBox="0 0 24 24"><style>.a{fill:#f00}</style><path class="a" style="opacity:.5" fill="#123456" d="M4 4L20 4L12 20Z"/></svg>"##;
+            let normalized = normalize_strict(svg);
+
+            assert!(!normalized.contains("<style"));
+            assert!(!normalized.contains("class="));
+            assert!(!normalized.contains("style="));
+            assert!(normalized.contains(r#"fill="currentColor""#));
+        }
+
+        #[test]
+        fn keeps_markup_after_self_closing_style() {
+            let svg =
+                r#"<svg><style/><path d="M4 4L20 4Z"/><style>.a{}</style><path d="M1 1Z"/></svg>"#;
+
+            assert_eq!(
+                strip_style_blocks(svg),
+                r#"<svg><path d="M4 4L20 4Z"/><path d="M1 1Z"/></svg>"#
+            );
+        }
+
+        #[test]
+        fn keeps_none_paint_and_ignores_stroke_width() {
+            let normalized = normalize_strict(
+                r#"<path d="M0 0" stroke='rgb(0,0,0)' stroke-width='2' fill='none'/>"#,
+            );
+
+            assert!(normalized.contains(r#"stroke="currentColor""#));
+            assert!(normalized.contains("stroke-width='2'"));
+            assert!(normalized.contains(r#"fill="none""#));
+        }
+
+        #[test]
+        fn repaired_icon_passes_check() {
+            let svg = r#"<svg viewBox="0 0 24 24"><style>path{fill:#000}</style><path class="x" d="M4 4L20 4L12 20Z"/></svg>"#;
+            let repaired = to_symbolic_strict(svg);
+
+            assert_eq!(check_symbolic(&repaired), Ok(()));
+        }
+
+        #[test]
+        fn retransforming_stroke_icon_stays_stroke() {
+            let original = to_symbolic(
+                r#"<svg viewBox="0 0 24 24" stroke="currentColor" fill="none"><path d="M4 4L20 20"/></svg>"#,
+            );
+            let repaired = to_symbolic_strict(&original);
+
+            assert!(repaired.contains("gpa:stroke='foreground'"));
+        }
+    }
+
     mod build_path_element_tests {
         use super::*;
 
@@ -181,6 +181,7 @@ mod sys {
             data: *mut c_void,
         ) -> c_int;
         pub fn wl_proxy_get_version(proxy: *mut WlProxy) -> u32;
+        pub fn wl_proxy_destroy(proxy: *mut WlProxy);
         pub fn wl_proxy_marshal_flags(
             proxy: *mut WlProxy,
             opcode: u32,
@@ -255,6 +256,17 @@ pub unsafe fn wl_display_get_registry(display: *mut WlDisplay) -> *mut WlRegistr
     }
 }
 
+/// `wl_registry` has no destroy request, so this only frees the client-side
+/// proxy and its listener. Objects bound through it stay valid.
+///
+/// # Safety
+///
+/// `registry` must be a valid `wl_registry` proxy that has not been
+/// destroyed. After this call, `registry` is invalid.
+pub unsafe fn destroy_registry(registry: *mut WlRegistry) {
+    unsafe { sys::wl_proxy_destroy(registry as *mut WlProxy) }
+}
+
 /// # Safety
 ///
 /// `manager` must be a valid `zwp_idle_inhibit_manager_v1` proxy.
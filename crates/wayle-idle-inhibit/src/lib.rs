@@ -15,6 +15,20 @@
 //! // And not any more
 //! ```
 //!
+//! When several independent reasons need to keep the display awake, share
+//! one inhibitor through an [`IdleInhibitRegistry`]:
+//!
+//! ```ignore
+//! use wayle_idle_inhibit::IdleInhibitRegistry;
+//!
+//! let registry = IdleInhibitRegistry::default();
+//! registry.attach_surface(&window.surface());
+//! let media = registry.acquire("media");
+//! let manual = registry.acquire("manual");
+//! drop(media);
+//! // Still inhibited: "manual" is active
+//! ```
+//!
 //! # Linking Note (gtk4-layer-shell)
 //!
 //! If your binary also uses `gtk4-layer-shell`, it must be linked **before**
@@ -35,10 +49,11 @@
 //! ```
 
 mod ffi;
+mod registry;
 
 use std::{
     ptr::{self, NonNull},
-    sync::OnceLock,
+    sync::{Mutex, PoisonError},
 };
 
 use glib::object::{ObjectExt, ObjectType};
@@ -46,14 +61,38 @@ use tracing::{debug, error};
 
 use crate::ffi::{
     REGISTRY_LISTENER, RegistryState, WlDisplay, WlProxy, create_inhibitor, destroy_inhibitor,
-    gdk_wayland_display_get_wl_display, gdk_wayland_surface_get_wl_surface,
+    destroy_registry, gdk_wayland_display_get_wl_display, gdk_wayland_surface_get_wl_surface,
     wl_display_get_registry, wl_display_roundtrip, wl_proxy_add_listener,
 };
+pub use crate::registry::{IdleInhibitRegistry, InhibitGuard};
 
 // === === === === === === === === === ===
 // ===           Public API            ===
 // === === === === === === === === === ===
 
+/// Whether idle inhibition can work in this session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InhibitSupport {
+    /// The compositor advertises `zwp_idle_inhibit_manager_v1`.
+    Supported,
+    /// Not running on a Wayland display.
+    NotWayland,
+    /// On Wayland, but the compositor doesn't offer the idle-inhibit protocol.
+    ProtocolUnavailable,
+}
+
+/// Reports whether [`IdleInhibitor::new`] can succeed in this session.
+///
+/// Probes the Wayland registry once the display is up and caches the
+/// answer, so later calls and inhibitor creation are free. Calls made
+/// before a Wayland display exists aren't cached and probe again.
+pub fn support_status() -> InhibitSupport {
+    match manager() {
+        Ok(_) => InhibitSupport::Supported,
+        Err(status) => status,
+    }
+}
+
 /// Wayland idle inhibitor.
 ///
 /// Prevents the compositor from blanking/dimming/locking the display due to
@@ -72,9 +111,10 @@ impl IdleInhibitor {
     /// Creates an inhibitor for the given GDK surface.
     ///
     /// Returns `None` if not on Wayland, compositor lacks idle-inhibit support,
-    /// or the surface is not a Wayland surface.
+    /// or the surface is not a Wayland surface. Use [`support_status`] to
+    /// tell the first two apart before constructing.
     pub fn new(surface: &gdk4::Surface) -> Option<Self> {
-        let mgr = manager()?;
+        let mgr = manager().ok()?;
 
         if !surface.type_().name().starts_with("GdkWayland") {
             error!(surface_type = %surface.type_().name(), "not a Wayland surface");
@@ -116,7 +156,16 @@ impl Drop for IdleInhibitor {
 // ===         Global Manager          ===
 // === === === === === === === === === ===
 
-static MANAGER: OnceLock<Option<ManagerPtr>> = OnceLock::new();
+/// Outcome of the first registry probe that reached the compositor.
+///
+/// Failures before that point, such as running before the display is up,
+/// aren't stored, so they don't stick for the whole session.
+static MANAGER: Mutex<Option<Probe>> = Mutex::new(None);
+
+enum Probe {
+    Bound(ManagerPtr),
+    Missing,
+}
 
 struct ManagerPtr(*mut ffi::IdleInhibitManager);
 
@@ -125,30 +174,29 @@ struct ManagerPtr(*mut ffi::IdleInhibitManager);
 /// The pointer is obtained from `wl_registry.bind` and remains valid for the
 /// application lifetime. Wayland protocol handles reference counting.
 unsafe impl Send for ManagerPtr {}
-unsafe impl Sync for ManagerPtr {}
 
-fn bind_manager() -> Option<*mut ffi::IdleInhibitManager> {
+fn bind_manager() -> Result<Probe, InhibitSupport> {
     if !ffi::is_available() {
         debug!("wayland-client symbols not available");
-        return None;
+        return Err(InhibitSupport::NotWayland);
     }
 
-    let display = gdk4::Display::default()?;
+    let display = gdk4::Display::default().ok_or(InhibitSupport::NotWayland)?;
     if display.type_().name() != "GdkWaylandDisplay" {
         debug!("not a Wayland display");
-        return None;
+        return Err(InhibitSupport::NotWayland);
     }
 
-    let wl_display = get_wl_display(&display).or_else(|| {
+    let wl_display = get_wl_display(&display).ok_or_else(|| {
         error!("gdk_wayland_display_get_wl_display returned null");
-        None
+        InhibitSupport::ProtocolUnavailable
     })?;
 
     // SAFETY: wl_display is valid.
-    let registry =
-        NonNull::new(unsafe { wl_display_get_registry(wl_display.as_ptr()) }).or_else(|| {
+    let registry = NonNull::new(unsafe { wl_display_get_registry(wl_display.as_ptr()) })
+        .ok_or_else(|| {
             error!("wl_display_get_registry returned null");
-            None
+            InhibitSupport::ProtocolUnavailable
         })?;
 
     let mut state = RegistryState {
@@ -165,27 +213,40 @@ fn bind_manager() -> Option<*mut ffi::IdleInhibitManager> {
     }
 
     // SAFETY: Blocking roundtrip invokes registry callbacks.
-    if unsafe { wl_display_roundtrip(wl_display.as_ptr()) } < 0 {
+    let roundtrip = unsafe { wl_display_roundtrip(wl_display.as_ptr()) };
+
+    // SAFETY: The registry is only needed for the roundtrip above. Destroying
+    // it before `state` goes out of scope keeps its listener from ever
+    // seeing a dangling pointer; the bound manager outlives it.
+    unsafe { destroy_registry(registry.as_ptr()) };
+
+    if roundtrip < 0 {
         error!("wl_display_roundtrip failed");
-        return None;
+        return Err(InhibitSupport::ProtocolUnavailable);
     }
 
-    NonNull::new(state.manager)
-        .map(|ptr| {
+    match NonNull::new(state.manager) {
+        Some(ptr) => {
             debug!(ptr = ?ptr.as_ptr(), "idle_inhibit_manager bound");
-            ptr.as_ptr()
-        })
-        .or_else(|| {
+            Ok(Probe::Bound(ManagerPtr(ptr.as_ptr())))
+        }
+        None => {
             error!("zwp_idle_inhibit_manager_v1 not available");
-            None
-        })
+            Ok(Probe::Missing)
+        }
+    }
 }
 
-fn manager() -> Option<*mut ffi::IdleInhibitManager> {
-    MANAGER
-        .get_or_init(|| bind_manager().map(ManagerPtr))
-        .as_ref()
-        .map(|m| m.0)
+fn manager() -> Result<*mut ffi::IdleInhibitManager, InhibitSupport> {
+    let mut manager = MANAGER.lock().unwrap_or_else(PoisonError::into_inner);
+    if manager.is_none() {
+        *manager = Some(bind_manager()?);
+    }
+
+    match manager.as_ref() {
+        Some(Probe::Bound(bound)) => Ok(bound.0),
+        _ => Err(InhibitSupport::ProtocolUnavailable),
+    }
 }
 
 // === === === === === === === === === ===
@@ -0,0 +1,191 @@
+//! Reference-counted inhibition shared between named reasons.
+
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use tracing::debug;
+
+use crate::IdleInhibitor;
+
+/// Shares one [`IdleInhibitor`] between any number of named reasons.
+///
+/// Each [`acquire`](Self::acquire) returns an [`InhibitGuard`]. The Wayland
+/// inhibitor is created with the first guard and destroyed when the last
+/// guard drops. The same reason may be acquired more than once; it stays
+/// active until all of its guards are gone.
+///
+/// The inhibitor lives on the first attached surface. Surfaces come and go
+/// with their windows, so callers [`attach_surface`](Self::attach_surface)
+/// when one is realized and [`detach_surface`](Self::detach_surface) before
+/// it's destroyed; the inhibitor moves to the next surface if needed.
+///
+/// Bound to the GTK main thread, like the surfaces it inhibits on.
+#[derive(Clone, Default)]
+pub struct IdleInhibitRegistry {
+    inner: Rc<RefCell<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    surfaces: Vec<gdk4::Surface>,
+    reasons: Reasons,
+    inhibitor: Option<IdleInhibitor>,
+}
+
+impl Inner {
+    /// Creates the inhibitor if a reason is active and a surface is known.
+    fn ensure_inhibitor(&mut self) {
+        if self.inhibitor.is_some() || self.reasons.is_empty() {
+            return;
+        }
+        if let Some(surface) = self.surfaces.first() {
+            self.inhibitor = IdleInhibitor::new(surface);
+        }
+    }
+}
+
+/// Guard count per reason. A reason is dropped once its count reaches zero.
+#[derive(Default)]
+struct Reasons(BTreeMap<String, usize>);
+
+impl Reasons {
+    fn acquire(&mut self, reason: &str) {
+        *self.0.entry(reason.to_owned()).or_default() += 1;
+    }
+
+    fn release(&mut self, reason: &str) {
+        if let Some(count) = self.0.get_mut(reason) {
+            *count -= 1;
+            if *count == 0 {
+                self.0.remove(reason);
+            }
+        }
+    }
+
+    fn active(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl IdleInhibitRegistry {
+    /// Makes `surface` available to inhibit on.
+    ///
+    /// Attaching a surface that's already attached does nothing.
+    pub fn attach_surface(&self, surface: &gdk4::Surface) {
+        let mut inner = self.inner.borrow_mut();
+        if !inner.surfaces.contains(surface) {
+            inner.surfaces.push(surface.clone());
+        }
+        inner.ensure_inhibitor();
+    }
+
+    /// Stops using `surface`, moving the inhibitor off it if it was there.
+    pub fn detach_surface(&self, surface: &gdk4::Surface) {
+        let mut inner = self.inner.borrow_mut();
+        let Some(index) = inner.surfaces.iter().position(|s| s == surface) else {
+            return;
+        };
+
+        inner.surfaces.remove(index);
+        if index == 0 {
+            inner.inhibitor.take();
+            inner.ensure_inhibitor();
+        }
+    }
+
+    /// Inhibits idle for `reason` until the returned guard drops.
+    ///
+    /// If the inhibitor can't be created yet (no surface is attached, or
+    /// [`IdleInhibitor::new`] fails), the reason is still tracked and
+    /// creation is retried on the next acquire or attach.
+    pub fn acquire(&self, reason: &str) -> InhibitGuard {
+        let mut inner = self.inner.borrow_mut();
+        inner.reasons.acquire(reason);
+        inner.ensure_inhibitor();
+        debug!(reason, "idle inhibition acquired");
+
+        InhibitGuard {
+            inner: Rc::clone(&self.inner),
+            reason: reason.to_owned(),
+        }
+    }
+
+    /// Reasons currently holding at least one guard, sorted.
+    pub fn active_reasons(&self) -> Vec<String> {
+        self.inner.borrow().reasons.active()
+    }
+
+    /// Whether any reason currently holds a guard.
+    pub fn is_active(&self) -> bool {
+        !self.inner.borrow().reasons.is_empty()
+    }
+}
+
+/// Keeps its reason's inhibition active while it exists.
+///
+/// Returned by [`IdleInhibitRegistry::acquire`].
+#[must_use = "idle inhibition is released as soon as the guard drops"]
+pub struct InhibitGuard {
+    inner: Rc<RefCell<Inner>>,
+    reason: String,
+}
+
+impl InhibitGuard {
+    /// The reason this guard was acquired for.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Drop for InhibitGuard {
+    fn drop(&mut self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.reasons.release(&self.reason);
+        debug!(reason = %self.reason, "idle inhibition released");
+
+        if inner.reasons.is_empty() {
+            inner.inhibitor.take();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reason_stays_active_until_its_last_guard() {
+        let mut reasons = Reasons::default();
+        reasons.acquire("media");
+        reasons.acquire("media");
+
+        reasons.release("media");
+        assert_eq!(reasons.active(), vec!["media"]);
+
+        reasons.release("media");
+        assert!(reasons.is_empty());
+    }
+
+    #[test]
+    fn active_reasons_are_sorted() {
+        let mut reasons = Reasons::default();
+        reasons.acquire("startup");
+        reasons.acquire("manual");
+        reasons.acquire("media");
+
+        reasons.release("media");
+        assert_eq!(reasons.active(), vec!["manual", "startup"]);
+    }
+
+    #[test]
+    fn releasing_an_unknown_reason_is_ignored() {
+        let mut reasons = Reasons::default();
+        reasons.acquire("manual");
+
+        reasons.release("media");
+        assert_eq!(reasons.active(), vec!["manual"]);
+    }
+}
@@ -6,10 +6,16 @@ pub mod types;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use derive_more::Debug;
-use futures::stream::Stream;
+use futures::{
+    future,
+    stream::{Stream, StreamExt},
+};
 use tokio_util::sync::CancellationToken;
-pub(crate) use types::{LiveTrackMetadataParams, TrackMetadataParams, TrackProperties};
-use wayle_core::{Property, watch_all};
+pub use types::MetadataDelta;
+pub(crate) use types::{
+    LiveTrackMetadataParams, MetadataFields, TrackMetadataParams, TrackProperties,
+};
+use wayle_core::{Property, combine_latest, watch_all};
 use wayle_traits::{ModelMonitoring, Reactive};
 use zbus::zvariant::OwnedValue;
 
@@ -132,4 +138,38 @@ impl TrackMetadata {
             track_id
         )
     }
+
+    /// Watch which of title, artist, album, art URL and length changed.
+    ///
+    /// The first item flags every field ([`MetadataDelta::ALL`]) so
+    /// consumers can render the initial metadata from it. After that, items
+    /// are only emitted when at least one of those fields differs from the
+    /// previous item; updates that touch none of them are skipped.
+    pub fn changes(&self) -> impl Stream<Item = MetadataDelta> + Send + 'static {
+        let mut previous: Option<MetadataFields> = None;
+
+        combine_latest!(
+            self.title,
+            self.artist,
+            self.album,
+            self.art_url,
+            self.length
+        )
+        .filter_map(move |(title, artist, album, art_url, length)| {
+            let fields = MetadataFields {
+                title,
+                artist,
+                album,
+                art_url,
+                length,
+            };
+
+            let delta = previous.as_ref().map_or(MetadataDelta::ALL, |previous| {
+                MetadataDelta::between(previous, &fields)
+            });
+            previous = Some(fields);
+
+            future::ready((!delta.is_empty()).then_some(delta))
+        })
+    }
 }
@@ -17,6 +17,59 @@ pub struct LiveTrackMetadataParams<'a> {
     pub(crate) art_resolver: Option<ArtResolver>,
 }
 
+/// Which metadata fields changed in an update.
+///
+/// Produced by [`Player::metadata_changes()`](crate::core::player::Player::metadata_changes).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetadataDelta {
+    /// Track title changed.
+    pub title: bool,
+    /// Artist changed.
+    pub artist: bool,
+    /// Album changed.
+    pub album: bool,
+    /// Album art URL changed.
+    pub art_url: bool,
+    /// Track duration changed.
+    pub length: bool,
+}
+
+impl MetadataDelta {
+    /// Every field flagged, used for the first item of a change stream.
+    pub const ALL: Self = Self {
+        title: true,
+        artist: true,
+        album: true,
+        art_url: true,
+        length: true,
+    };
+
+    /// Whether no field changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub(crate) fn between(old: &MetadataFields, new: &MetadataFields) -> Self {
+        Self {
+            title: old.title != new.title,
+            artist: old.artist != new.artist,
+            album: old.album != new.album,
+            art_url: old.art_url != new.art_url,
+            length: old.length != new.length,
+        }
+    }
+}
+
+/// The fields [`MetadataDelta`] compares.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MetadataFields {
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub art_url: Option<String>,
+    pub length: Option<Duration>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct TrackProperties {
     pub title: String,
@@ -108,7 +161,41 @@ mod tests {
 
     use zbus::zvariant::{Array, Signature, Value};
 
-    use super::TrackProperties;
+    use super::{MetadataDelta, MetadataFields, TrackProperties};
+
+    fn fields(title: &str, art_url: Option<&str>) -> MetadataFields {
+        MetadataFields {
+            title: String::from(title),
+            artist: String::from("Artist"),
+            album: String::from("Album"),
+            art_url: art_url.map(String::from),
+            length: Some(Duration::from_secs(200)),
+        }
+    }
+
+    #[test]
+    fn metadata_delta_flags_only_changed_fields() {
+        let old = fields("Song", Some("file:///a.png"));
+        let new = fields("Song", Some("file:///b.png"));
+
+        let delta = MetadataDelta::between(&old, &new);
+
+        assert_eq!(
+            delta,
+            MetadataDelta {
+                art_url: true,
+                ..MetadataDelta::default()
+            }
+        );
+    }
+
+    #[test]
+    fn metadata_delta_is_empty_for_identical_fields() {
+        let old = fields("Song", None);
+
+        assert!(MetadataDelta::between(&old, &old.clone()).is_empty());
+        assert!(!MetadataDelta::ALL.is_empty());
+    }
 
     #[test]
     fn track_properties_from_mpris_with_empty_map_returns_defaults() {
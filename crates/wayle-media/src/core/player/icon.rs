@@ -0,0 +1,151 @@
+use std::{env, path::PathBuf};
+
+const DESKTOP_ENTRY_GROUP: &str = "[Desktop Entry]";
+const ICON_KEY: &str = "Icon";
+const DEFAULT_DATA_DIRS: &str = "/usr/local/share:/usr/share";
+
+/// Theme icon names for players that don't send a usable `DesktopEntry`,
+/// keyed by their lowercased MPRIS `Identity`.
+const IDENTITY_ICONS: &[(&str, &str)] = &[
+    ("spotify", "spotify"),
+    ("mozilla firefox", "firefox"),
+    ("firefox", "firefox"),
+    ("librewolf", "librewolf"),
+    ("chromium", "chromium"),
+    ("google chrome", "google-chrome"),
+    ("brave", "brave-browser"),
+    ("vivaldi", "vivaldi"),
+    ("mpv", "mpv"),
+    ("mpv media player", "mpv"),
+    ("vlc media player", "vlc"),
+    ("celluloid", "io.github.celluloid_player.Celluloid"),
+    ("kodi", "kodi"),
+    ("rhythmbox", "org.gnome.Rhythmbox3"),
+    ("elisa", "elisa"),
+    ("strawberry", "strawberry"),
+    ("tidal hi-fi", "tidal-hifi"),
+    (
+        "jellyfin media player",
+        "com.github.iwalton3.jellyfin-media-player",
+    ),
+];
+
+/// Resolves a player's icon from the `Icon` key of its desktop entry,
+/// falling back to a known icon for its identity.
+pub(crate) async fn resolve(desktop_entry: Option<&str>, identity: &str) -> Option<String> {
+    if let Some(entry) = desktop_entry.filter(|entry| !entry.is_empty())
+        && let Some(icon) = desktop_entry_icon(entry).await
+    {
+        return Some(icon);
+    }
+
+    identity_icon(identity).map(String::from)
+}
+
+async fn desktop_entry_icon(entry: &str) -> Option<String> {
+    let file_name = format!("{entry}.desktop");
+
+    for dir in application_dirs() {
+        let Ok(contents) = tokio::fs::read_to_string(dir.join(&file_name)).await else {
+            continue;
+        };
+
+        return parse_icon(&contents);
+    }
+
+    None
+}
+
+/// `applications` directories in XDG lookup order, most specific first.
+fn application_dirs() -> Vec<PathBuf> {
+    let data_home = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")));
+
+    let data_dirs = env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|dirs| !dirs.is_empty())
+        .unwrap_or_else(|| String::from(DEFAULT_DATA_DIRS));
+
+    data_home
+        .into_iter()
+        .chain(data_dirs.split(':').map(PathBuf::from))
+        .map(|dir| dir.join("applications"))
+        .collect()
+}
+
+/// Reads the unlocalized `Icon` key from the `[Desktop Entry]` group.
+fn parse_icon(contents: &str) -> Option<String> {
+    let mut in_entry_group = false;
+
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_entry_group = line == DESKTOP_ENTRY_GROUP;
+            continue;
+        }
+
+        if !in_entry_group {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        if key.trim() == ICON_KEY {
+            let value = value.trim();
+            return (!value.is_empty()).then(|| value.to_owned());
+        }
+    }
+
+    None
+}
+
+fn identity_icon(identity: &str) -> Option<&'static str> {
+    let identity = identity.trim().to_lowercase();
+
+    IDENTITY_ICONS
+        .iter()
+        .find(|(name, _)| *name == identity)
+        .map(|(_, icon)| *icon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_icon_reads_desktop_entry_group_only() {
+        let contents = "\
+[Desktop Entry]
+Name=Spotify
+Icon[de]=spotify-de
+Icon = spotify-client
+
+[Desktop Action Quit]
+Icon=application-exit
+";
+
+        assert_eq!(parse_icon(contents).as_deref(), Some("spotify-client"));
+    }
+
+    #[test]
+    fn parse_icon_ignores_other_groups() {
+        let contents = "\
+[Desktop Action Play]
+Icon=media-playback-start
+
+[Desktop Entry]
+Name=Player
+";
+
+        assert_eq!(parse_icon(contents), None);
+    }
+
+    #[test]
+    fn identity_icon_matches_case_insensitively() {
+        assert_eq!(identity_icon("Mozilla Firefox"), Some("firefox"));
+        assert_eq!(identity_icon("VLC media player"), Some("vlc"));
+        assert_eq!(identity_icon("Some Unknown Player"), None);
+    }
+}
@@ -1,3 +1,4 @@
+mod icon;
 pub(crate) mod monitoring;
 mod types;
 
@@ -7,7 +8,7 @@ use derive_more::Debug;
 use futures::{Stream, StreamExt};
 use tokio_util::sync::CancellationToken;
 pub(crate) use types::{LivePlayerParams, PlayerParams};
-use wayle_core::{NULL_PATH, Property, unwrap_dbus, unwrap_dbus_or, watch_all};
+use wayle_core::{ComputedProperty, NULL_PATH, Property, unwrap_dbus, unwrap_dbus_or, watch_all};
 use wayle_traits::{ModelMonitoring, Reactive};
 use zbus::{
     fdo::PropertiesProxy,
@@ -17,10 +18,10 @@ use zbus::{
 };
 
 use crate::{
-    core::metadata::{LiveTrackMetadataParams, TrackMetadata, TrackMetadataParams},
+    core::metadata::{LiveTrackMetadataParams, MetadataDelta, TrackMetadata, TrackMetadataParams},
     error::Error,
     proxy::{MediaPlayer2PlayerProxy, MediaPlayer2Proxy},
-    types::{LoopMode, PlaybackState, PlayerId, ShuffleMode, Volume},
+    types::{LoopMode, PlaybackState, PlayerId, SeekDirection, ShuffleMode, Volume},
 };
 
 /// An MPRIS media player with reactive properties and playback control.
@@ -32,7 +33,7 @@ use crate::{
 /// # Control Methods
 ///
 /// - `play_pause()`, `next()`, `previous()` - Basic playback
-/// - `seek()`, `set_position()` - Position control
+/// - `seek()`, `seek_relative()`, `set_position()` - Position control
 /// - `set_volume()`, `set_loop_mode()`, `set_shuffle_mode()` - Settings
 /// - `toggle_loop()`, `toggle_shuffle()` - Convenience toggles
 #[derive(Clone, Debug)]
@@ -45,6 +46,7 @@ pub struct Player {
     pub(crate) cancellation_token: Option<CancellationToken>,
     #[debug(skip)]
     pub(crate) position_poll_interval: Duration,
+    pub(crate) icon_name: Property<Option<String>>,
 
     /// D-Bus bus name identifier.
     pub id: PlayerId,
@@ -63,6 +65,12 @@ pub struct Player {
     pub volume: Property<Volume>,
     /// Current playback position.
     pub position: Property<Duration>,
+    /// Playback progress through the current track, from 0.0 to 1.0.
+    ///
+    /// `position / length`, clamped. `None` when the track length is
+    /// unknown or zero. Watching this keeps the position polled, and it is
+    /// re-read as soon as the track changes.
+    pub progress: ComputedProperty<Option<f64>>,
 
     /// Current track information.
     pub metadata: Arc<TrackMetadata>,
@@ -136,6 +144,9 @@ impl Reactive for Player {
             None,
             Duration::from_secs(1),
         );
+        player
+            .icon_name
+            .set(icon::resolve(desktop_entry.as_deref(), &player.identity.get()).await);
         player.desktop_entry.set(desktop_entry);
 
         Self::refresh_properties(&player, &player_proxy).await;
@@ -193,6 +204,9 @@ impl Reactive for Player {
             Some(params.cancellation_token.child_token()),
             params.position_poll_interval,
         );
+        player
+            .icon_name
+            .set(icon::resolve(desktop_entry.as_deref(), &player.identity.get()).await);
         player.desktop_entry.set(desktop_entry);
 
         Self::refresh_properties(&player, &player_proxy).await;
@@ -220,6 +234,11 @@ impl Player {
         cancellation_token: Option<CancellationToken>,
         position_poll_interval: Duration,
     ) -> Self {
+        let position = Property::new(Duration::ZERO);
+        let progress = position.combine(&metadata.length, |position, length| {
+            progress(*position, *length)
+        });
+
         Self {
             proxy,
             position_proxy,
@@ -228,12 +247,14 @@ impl Player {
             id,
             identity: Property::new(identity),
             desktop_entry: Property::new(None),
+            icon_name: Property::new(None),
 
             playback_state: Property::new(PlaybackState::Stopped),
             loop_mode: Property::new(LoopMode::None),
             shuffle_mode: Property::new(ShuffleMode::Off),
             volume: Property::new(Volume::default()),
-            position: Property::new(Duration::ZERO),
+            position,
+            progress,
 
             metadata,
 
@@ -343,6 +364,33 @@ impl Player {
         Ok(())
     }
 
+    /// Seeks by `offset` from the current position without leaving the track.
+    ///
+    /// The target is clamped to the start and end of the track. When the
+    /// player doesn't report a track length, the offset is passed to MPRIS
+    /// `Seek` as is and the player decides how to handle overshoot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::OperationNotSupported` if the player can't seek.
+    /// Returns `Error::Control` if the D-Bus operation fails.
+    pub async fn seek_relative(
+        &self,
+        offset: Duration,
+        direction: SeekDirection,
+    ) -> Result<(), Error> {
+        if !self.can_seek.get() {
+            return Err(Error::OperationNotSupported(String::from("seek")));
+        }
+
+        let Some(length) = self.metadata.length.get() else {
+            return self.seek(direction.offset_micros(offset)).await;
+        };
+
+        let target = direction.target(self.position.get(), offset, length);
+        self.set_position(target).await
+    }
+
     /// Set position to an absolute value.
     ///
     /// # Errors
@@ -402,22 +450,60 @@ impl Player {
         }))
     }
 
+    /// Theme icon name for the player application.
+    ///
+    /// Read from the `Icon` key of the player's `DesktopEntry`, falling back
+    /// to a known icon for its `Identity` (e.g. `"Mozilla Firefox"` maps to
+    /// `firefox`). `None` when neither gives a name.
+    pub fn icon_name(&self) -> Property<Option<String>> {
+        self.icon_name.clone()
+    }
+
+    /// Whether the loop mode can be changed.
+    ///
+    /// True when the player accepts control commands (`CanControl`) and
+    /// exposes the optional `LoopStatus` property with a known value.
+    pub fn can_set_loop(&self) -> bool {
+        self.can_control.get()
+            && self.can_loop.get()
+            && self.loop_mode.get() != LoopMode::Unsupported
+    }
+
+    /// Whether shuffle can be toggled.
+    ///
+    /// True when the player accepts control commands (`CanControl`) and
+    /// exposes the optional `Shuffle` property.
+    pub fn can_set_shuffle(&self) -> bool {
+        self.can_control.get()
+            && self.can_shuffle.get()
+            && self.shuffle_mode.get() != ShuffleMode::Unsupported
+    }
+
     /// Set loop mode.
     ///
     /// # Errors
     ///
-    /// Returns `Error::Control` if the D-Bus operation fails,
-    /// or if the loop mode is unsupported
+    /// Returns `Error::OperationNotSupported` if the player can't change its
+    /// loop mode (see [`can_set_loop`](Self::can_set_loop)) or `mode` is
+    /// `LoopMode::Unsupported`, and `Error::Control` if the D-Bus operation fails
     pub async fn set_loop_mode(&self, mode: LoopMode) -> Result<(), Error> {
         let status = match mode {
             LoopMode::None => "None",
             LoopMode::Track => "Track",
             LoopMode::Playlist => "Playlist",
             LoopMode::Unsupported => {
-                return Err(Error::Control(String::from("loop mode not supported")));
+                return Err(Error::OperationNotSupported(String::from(
+                    "loop mode not supported",
+                )));
             }
         };
 
+        if !self.can_set_loop() {
+            return Err(Error::OperationNotSupported(String::from(
+                "loop mode not supported",
+            )));
+        }
+
         self.proxy
             .set_loop_status(status)
             .await
@@ -429,17 +515,26 @@ impl Player {
     ///
     /// # Errors
     ///
-    /// Returns `Error::Control` if the D-Bus operation fails,
-    /// or if shuffle is unsupported
+    /// Returns `Error::OperationNotSupported` if the player can't toggle
+    /// shuffle (see [`can_set_shuffle`](Self::can_set_shuffle)) or `mode` is
+    /// `ShuffleMode::Unsupported`, and `Error::Control` if the D-Bus operation fails
     pub async fn set_shuffle_mode(&self, mode: ShuffleMode) -> Result<(), Error> {
         let shuffle = match mode {
             ShuffleMode::On => true,
             ShuffleMode::Off => false,
             ShuffleMode::Unsupported => {
-                return Err(Error::Control(String::from("shuffle not supported")));
+                return Err(Error::OperationNotSupported(String::from(
+                    "shuffle not supported",
+                )));
             }
         };
 
+        if !self.can_set_shuffle() {
+            return Err(Error::OperationNotSupported(String::from(
+                "shuffle not supported",
+            )));
+        }
+
         self.proxy
             .set_shuffle(shuffle)
             .await
@@ -466,7 +561,8 @@ impl Player {
     ///
     /// # Errors
     ///
-    /// Returns `Error::OperationNotSupported` if loop mode is unsupported
+    /// Returns `Error::OperationNotSupported` if loop mode is unsupported,
+    /// and `Error::Control` if the D-Bus operation fails
     pub async fn toggle_loop(&self) -> Result<(), Error> {
         let current = self.loop_mode.get();
         let next = match current {
@@ -487,7 +583,8 @@ impl Player {
     ///
     /// # Errors
     ///
-    /// Returns `Error::OperationNotSupported` if shuffle is unsupported
+    /// Returns `Error::OperationNotSupported` if shuffle is unsupported,
+    /// and `Error::Control` if the D-Bus operation fails
     pub async fn toggle_shuffle(&self) -> Result<(), Error> {
         let current = self.shuffle_mode.get();
         let next = match current {
@@ -523,6 +620,13 @@ impl Player {
         self.can_shuffle.set(can_shuffle);
     }
 
+    /// Watch which metadata fields changed, so a UI can update only the
+    /// affected widgets. See [`TrackMetadata::changes()`] for the seeding
+    /// behavior.
+    pub fn metadata_changes(&self) -> impl Stream<Item = MetadataDelta> + Send + 'static {
+        self.metadata.changes()
+    }
+
     /// Watch for any player property changes.
     ///
     /// Returns a stream that emits a clone of the player whenever any property changes,
@@ -532,6 +636,7 @@ impl Player {
             self,
             identity,
             desktop_entry,
+            icon_name,
             playback_state,
             loop_mode,
             shuffle_mode,
@@ -547,3 +652,36 @@ impl Player {
         )
     }
 }
+
+fn progress(position: Duration, length: Option<Duration>) -> Option<f64> {
+    let length = length.filter(|length| !length.is_zero())?;
+    Some((position.as_secs_f64() / length.as_secs_f64()).clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_is_position_over_length() {
+        let progress = progress(Duration::from_secs(45), Some(Duration::from_secs(180)));
+        assert_eq!(progress, Some(0.25));
+    }
+
+    #[test]
+    fn progress_is_clamped_to_track() {
+        let length = Some(Duration::from_secs(180));
+
+        assert_eq!(progress(Duration::from_secs(200), length), Some(1.0));
+        assert_eq!(progress(Duration::ZERO, length), Some(0.0));
+    }
+
+    #[test]
+    fn progress_is_none_without_usable_length() {
+        assert_eq!(progress(Duration::from_secs(10), None), None);
+        assert_eq!(
+            progress(Duration::from_secs(10), Some(Duration::ZERO)),
+            None
+        );
+    }
+}
@@ -85,6 +85,7 @@ async fn monitor_properties(
                 if let Ok(status) = change.get().await {
                     let mode = LoopMode::from(status.as_str());
                     player.loop_mode.set(mode);
+                    player.can_loop.set(true);
                 }
             }
 
@@ -92,6 +93,7 @@ async fn monitor_properties(
                 if let Ok(shuffle) = change.get().await {
                     let mode = ShuffleMode::from(shuffle);
                     player.shuffle_mode.set(mode);
+                    player.can_shuffle.set(true);
                 }
             }
 
@@ -149,6 +151,14 @@ async fn monitor_position(
     interval_duration: Duration,
     cancellation_token: CancellationToken,
 ) {
+    let Some(metadata) = weak_player
+        .upgrade()
+        .map(|player| Arc::clone(&player.metadata))
+    else {
+        return;
+    };
+    let mut track_changes = Box::pin(metadata.changes().skip(1));
+
     let mut ticker = interval(interval_duration);
     ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
     ticker.tick().await;
@@ -156,6 +166,21 @@ async fn monitor_position(
     loop {
         tokio::select! {
             _ = cancellation_token.cancelled() => return,
+            Some(_) = track_changes.next() => {
+                let Some(player) = weak_player.upgrade() else {
+                    return;
+                };
+
+                if !player.position.has_subscribers() {
+                    continue;
+                }
+
+                // A new track restarts the position even while paused, so
+                // don't wait for the next playing tick to pick it up.
+                if let Ok(position) = player.position().await {
+                    player.position.set(position);
+                }
+            }
             _ = ticker.tick() => {
                 let Some(player) = weak_player.upgrade() else {
                     return;
@@ -48,10 +48,11 @@ pub trait Media {
     /// An empty string for `player_id` targets the active player.
     async fn set_loop_status(&self, player_id: String, mode: String) -> Result<()>;
 
-    /// Lists all available media players.
+    /// Lists all available media players with what each is playing.
     ///
-    /// Returns a list of tuples: (player_id, identity, playback_state).
-    async fn list_players(&self) -> Result<Vec<(String, String, String)>>;
+    /// Returns a list of tuples:
+    /// (player_id, identity, playback_state, title, artist).
+    async fn list_players(&self) -> Result<Vec<(String, String, String, String, String)>>;
 
     /// Gets the active player ID.
     ///
@@ -68,6 +69,11 @@ pub trait Media {
     /// An empty string for `player_id` targets the active player.
     async fn get_player_info(&self, player_id: String) -> Result<HashMap<String, String>>;
 
+    /// Gets the metadata of the track a player is on.
+    ///
+    /// An empty string for `player_id` targets the active player.
+    async fn player_metadata(&self, player_id: String) -> Result<HashMap<String, String>>;
+
     /// The currently active player ID.
     #[zbus(property)]
     fn active_player(&self) -> Result<String>;
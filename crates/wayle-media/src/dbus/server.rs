@@ -178,11 +178,12 @@ impl MediaDaemon {
             .map_err(|e| fdo::Error::Failed(e.to_string()))
     }
 
-    /// Lists all available media players.
+    /// Lists all available media players with what each is playing.
     ///
-    /// Returns a list of tuples: (player_id, identity, playback_state).
+    /// Returns a list of tuples:
+    /// (player_id, identity, playback_state, title, artist).
     #[instrument(skip(self))]
-    pub async fn list_players(&self) -> Vec<(String, String, String)> {
+    pub async fn list_players(&self) -> Vec<(String, String, String, String, String)> {
         self.service
             .players()
             .into_iter()
@@ -190,7 +191,9 @@ impl MediaDaemon {
                 let id = player.id.to_string();
                 let identity = player.identity.get();
                 let state = format!("{:?}", player.playback_state.get());
-                (id, identity, state)
+                let title = player.metadata.title.get();
+                let artist = player.metadata.artist.get();
+                (id, identity, state, title, artist)
             })
             .collect()
     }
@@ -288,6 +291,48 @@ impl MediaDaemon {
         Ok(info)
     }
 
+    /// Gets the metadata of the track a player is on.
+    ///
+    /// Always has `title`, `artist`, `album` and `album_artist`, which are
+    /// empty when unknown. `length_us`, `art_url`, `cover_art` and
+    /// `track_id` are only present when the player provides them.
+    /// An empty string for `player_id` targets the active player.
+    #[instrument(skip(self), fields(player = %player_id))]
+    pub async fn player_metadata(
+        &self,
+        player_id: String,
+    ) -> fdo::Result<std::collections::HashMap<String, String>> {
+        let id = self.resolve_player(&player_id)?;
+
+        let player = self
+            .service
+            .player(&id)
+            .await
+            .map_err(|e| fdo::Error::Failed(e.to_string()))?;
+        let metadata = &player.metadata;
+
+        let mut info = std::collections::HashMap::new();
+        info.insert("title".to_string(), metadata.title.get());
+        info.insert("artist".to_string(), metadata.artist.get());
+        info.insert("album".to_string(), metadata.album.get());
+        info.insert("album_artist".to_string(), metadata.album_artist.get());
+
+        if let Some(length) = metadata.length.get() {
+            info.insert("length_us".to_string(), length.as_micros().to_string());
+        }
+        if let Some(art_url) = metadata.art_url.get() {
+            info.insert("art_url".to_string(), art_url);
+        }
+        if let Some(cover_art) = metadata.cover_art.get() {
+            info.insert("cover_art".to_string(), cover_art);
+        }
+        if let Some(track_id) = metadata.track_id.get() {
+            info.insert("track_id".to_string(), track_id);
+        }
+
+        Ok(info)
+    }
+
     /// The currently active player ID.
     #[zbus(property)]
     pub async fn active_player(&self) -> String {
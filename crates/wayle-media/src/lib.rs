@@ -89,6 +89,10 @@
 //! - `.get()` - Current value snapshot
 //! - `.watch()` - Stream yielding on changes
 //!
+//! The one exception is `Player::progress`, a read-only
+//! [`ComputedProperty`](wayle_core::ComputedProperty) holding `position`
+//! over the track length (0.0 to 1.0) for progress bars.
+//!
 //! # Service Fields
 //!
 //! | Field | Type | Description |
@@ -100,7 +104,7 @@
 //!
 //! On [`Player`](core::player::Player):
 //! - `play_pause()`, `next()`, `previous()` - Playback
-//! - `seek()`, `set_position()` - Position
+//! - `seek()`, `seek_relative()`, `set_position()` - Position
 //! - `set_volume()`, `set_loop_mode()`, `set_shuffle_mode()` - Settings
 
 mod builder;
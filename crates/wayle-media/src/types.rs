@@ -1,4 +1,4 @@
-use std::{fmt, ops::Deref};
+use std::{fmt, ops::Deref, time::Duration};
 
 /// MPRIS player identifier (D-Bus bus name).
 ///
@@ -118,6 +118,35 @@ impl From<f64> for Volume {
     }
 }
 
+/// Direction of a relative seek.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekDirection {
+    /// Towards the end of the track.
+    Forward,
+    /// Towards the start of the track.
+    Backward,
+}
+
+impl SeekDirection {
+    /// Position reached by moving `offset` from `position`, clamped to
+    /// `[0, length]`.
+    pub fn target(self, position: Duration, offset: Duration, length: Duration) -> Duration {
+        match self {
+            Self::Forward => position.saturating_add(offset).min(length),
+            Self::Backward => position.saturating_sub(offset).min(length),
+        }
+    }
+
+    /// Signed offset in microseconds as expected by MPRIS `Seek`.
+    pub fn offset_micros(self, offset: Duration) -> i64 {
+        let micros = i64::try_from(offset.as_micros()).unwrap_or(i64::MAX);
+        match self {
+            Self::Forward => micros,
+            Self::Backward => -micros,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +258,41 @@ mod tests {
         let volume = Volume::new(0.5);
         assert_eq!(volume.as_percentage(), 50.0);
     }
+
+    #[test]
+    fn seek_forward_clamps_to_length() {
+        let target = SeekDirection::Forward.target(
+            Duration::from_secs(175),
+            Duration::from_secs(10),
+            Duration::from_secs(180),
+        );
+        assert_eq!(target, Duration::from_secs(180));
+    }
+
+    #[test]
+    fn seek_backward_clamps_to_start() {
+        let target = SeekDirection::Backward.target(
+            Duration::from_secs(4),
+            Duration::from_secs(10),
+            Duration::from_secs(180),
+        );
+        assert_eq!(target, Duration::ZERO);
+    }
+
+    #[test]
+    fn seek_within_bounds_moves_by_offset() {
+        let target = SeekDirection::Forward.target(
+            Duration::from_secs(60),
+            Duration::from_secs(10),
+            Duration::from_secs(180),
+        );
+        assert_eq!(target, Duration::from_secs(70));
+    }
+
+    #[test]
+    fn seek_offset_micros_is_signed_by_direction() {
+        let offset = Duration::from_secs(10);
+        assert_eq!(SeekDirection::Forward.offset_micros(offset), 10_000_000);
+        assert_eq!(SeekDirection::Backward.offset_micros(offset), -10_000_000);
+    }
 }
@@ -6,7 +6,7 @@ use wayle_core::{Property, unwrap_dbus, unwrap_dbus_or};
 use wayle_traits::{ModelMonitoring, Reactive};
 use zbus::{Connection, zvariant::OwnedObjectPath};
 
-use self::types::{AccessPointParams, Bssid, LiveAccessPointParams};
+use self::types::{AccessPointParams, Bssid, LiveAccessPointParams, signal_bars};
 use crate::{
     error::Error,
     proxy::access_point::AccessPointProxy,
@@ -135,6 +135,11 @@ impl AccessPoint {
         &self.object_path
     }
 
+    /// Current signal quality as 0-4 bars, for picking a signal icon.
+    pub fn signal_bars(&self) -> u8 {
+        signal_bars(self.strength.get())
+    }
+
     async fn from_path(
         connection: &Connection,
         path: OwnedObjectPath,
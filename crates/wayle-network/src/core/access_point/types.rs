@@ -167,10 +167,35 @@ impl Display for SecurityType {
     }
 }
 
+/// Maps a signal strength percentage to 0-4 bars, using the same
+/// thresholds as `nmcli`.
+pub(crate) fn signal_bars(strength: u8) -> u8 {
+    match strength {
+        81.. => 4,
+        56..=80 => 3,
+        31..=55 => 2,
+        6..=30 => 1,
+        _ => 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn signal_bars_follow_nmcli_thresholds() {
+        assert_eq!(signal_bars(0), 0);
+        assert_eq!(signal_bars(5), 0);
+        assert_eq!(signal_bars(6), 1);
+        assert_eq!(signal_bars(30), 1);
+        assert_eq!(signal_bars(31), 2);
+        assert_eq!(signal_bars(56), 3);
+        assert_eq!(signal_bars(80), 3);
+        assert_eq!(signal_bars(81), 4);
+        assert_eq!(signal_bars(100), 4);
+    }
+
     #[test]
     fn from_flags_returns_enterprise_when_rsn_has_802_1x() {
         let security = SecurityType::from_flags(
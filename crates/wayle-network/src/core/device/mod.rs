@@ -16,7 +16,7 @@ pub use types::DeviceStateChangedEvent;
 use types::{AppliedConnection, DeviceProperties};
 pub(crate) use types::{DeviceParams, LiveDeviceParams};
 use wayle_core::{Property, unwrap_dbus, unwrap_dbus_or};
-use wayle_traits::{ModelMonitoring, Reactive};
+use wayle_traits::{ModelMonitoring, Reactive, Refresh};
 use zbus::{
     Connection,
     zvariant::{OwnedObjectPath, OwnedValue},
@@ -206,6 +206,17 @@ impl Reactive for Device {
     }
 }
 
+impl Refresh for Device {
+    type Error = Error;
+
+    async fn refresh(&self) -> Result<(), Self::Error> {
+        let proxy = DeviceProxy::new(&self.connection, &self.object_path).await?;
+        let props = Self::fetch_properties(&proxy).await?;
+        self.apply_properties(props);
+        Ok(())
+    }
+}
+
 impl Device {
     pub(crate) async fn from_path(
         connection: &Connection,
@@ -394,6 +405,49 @@ impl Device {
         }
     }
 
+    fn apply_properties(&self, props: DeviceProperties) {
+        self.udi.set(props.udi);
+        self.udev_path.set(props.udev_path);
+        self.interface.set(props.interface);
+        self.ip_interface.set(props.ip_interface);
+        self.driver.set(props.driver);
+        self.driver_version.set(props.driver_version);
+        self.firmware_version.set(props.firmware_version);
+        self.capabilities
+            .set(NMDeviceCapabilities::from_bits_truncate(props.capabilities));
+        self.state.set(NMDeviceState::from_u32(props.state));
+        self.state_reason.set((
+            NMDeviceState::from_u32(props.state_reason.0),
+            NMDeviceStateReason::from_u32(props.state_reason.1),
+        ));
+        self.active_connection.set(props.active_connection);
+        self.ip4_config.set(props.ip4_config);
+        self.dhcp4_config.set(props.dhcp4_config);
+        self.ip6_config.set(props.ip6_config);
+        self.dhcp6_config.set(props.dhcp6_config);
+        self.managed.set(props.managed);
+        self.autoconnect.set(props.autoconnect);
+        self.firmware_missing.set(props.firmware_missing);
+        self.nm_plugin_missing.set(props.nm_plugin_missing);
+        self.device_type
+            .set(NMDeviceType::from_u32(props.device_type));
+        self.available_connections.set(props.available_connections);
+        self.physical_port_id.set(props.physical_port_id);
+        self.mtu.set(props.mtu);
+        self.metered.set(NMMetered::from_u32(props.metered));
+        self.real.set(props.real);
+        self.ip4_connectivity
+            .set(NMConnectivityState::from_u32(props.ip4_connectivity));
+        self.ip6_connectivity
+            .set(NMConnectivityState::from_u32(props.ip6_connectivity));
+        self.interface_flags
+            .set(NMDeviceInterfaceFlags::from_bits_truncate(
+                props.interface_flags,
+            ));
+        self.hw_address.set(props.hw_address);
+        self.ports.set(props.ports);
+    }
+
     /// Whether or not this device is managed by NetworkManager.
     ///
     /// # Errors
@@ -9,6 +9,7 @@ use derive_more::Debug;
 use futures::{Stream, StreamExt, future::join_all};
 use tokio_util::sync::CancellationToken;
 use tracing::warn;
+pub use types::SavedConnection;
 pub(crate) use types::{LiveSettingsParams, SettingsParams};
 use wayle_core::{Property, unwrap_dbus};
 use wayle_traits::{ModelMonitoring, Reactive};
@@ -244,6 +245,32 @@ impl Settings {
         SettingsController::save_hostname(&self.zbus_connection, hostname).await
     }
 
+    /// Snapshot of every saved connection profile.
+    pub fn saved_connections(&self) -> Vec<SavedConnection> {
+        self.connections
+            .get()
+            .iter()
+            .map(SavedConnection::from)
+            .collect()
+    }
+
+    /// Saved connection profile identified by name or UUID.
+    ///
+    /// A UUID match wins over a name match, since names need not be unique.
+    pub fn find_connection(&self, id: &str) -> Option<ConnectionSettings> {
+        let connections = self.connections.get();
+
+        connections
+            .iter()
+            .find(|connection| connection.uuid.get() == id)
+            .or_else(|| {
+                connections
+                    .iter()
+                    .find(|connection| connection.id.get() == id)
+            })
+            .cloned()
+    }
+
     /// Saved connection profiles matching the given SSID.
     ///
     /// A single SSID may have multiple profiles with different configurations.
@@ -1,5 +1,48 @@
 use tokio_util::sync::CancellationToken;
-use zbus::Connection;
+use zbus::{Connection, zvariant::OwnedObjectPath};
+
+use crate::{
+    core::{access_point::types::Ssid, settings_connection::ConnectionSettings},
+    types::connectivity::ConnectionType,
+};
+
+/// Snapshot of a saved connection profile, for listing and managing
+/// known networks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedConnection {
+    /// Human-readable profile name (e.g. "Home WiFi").
+    pub id: String,
+    /// Stable unique identifier of the profile.
+    pub uuid: String,
+    /// SSID of the network, for wireless profiles.
+    pub ssid: Option<Ssid>,
+    /// Kind of connection this profile configures.
+    pub connection_type: ConnectionType,
+    /// Whether NetworkManager may activate the profile automatically.
+    pub autoconnect: bool,
+    /// D-Bus object path of the settings profile.
+    pub settings_path: OwnedObjectPath,
+}
+
+impl SavedConnection {
+    /// Whether `id` names this profile, by UUID or by display name.
+    pub fn matches(&self, id: &str) -> bool {
+        self.uuid == id || self.id == id
+    }
+}
+
+impl From<&ConnectionSettings> for SavedConnection {
+    fn from(profile: &ConnectionSettings) -> Self {
+        Self {
+            id: profile.id.get(),
+            uuid: profile.uuid.get(),
+            ssid: profile.wifi_ssid.get(),
+            connection_type: profile.connection_type.get(),
+            autoconnect: profile.autoconnect.get(),
+            settings_path: profile.object_path.clone(),
+        }
+    }
+}
 
 #[doc(hidden)]
 pub struct SettingsParams<'a> {
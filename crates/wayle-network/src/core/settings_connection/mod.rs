@@ -47,6 +47,9 @@ pub struct ConnectionSettings {
     /// WiFi SSID, if this is a wireless connection.
     pub wifi_ssid: Property<Option<Ssid>>,
 
+    /// Whether NetworkManager may activate this profile automatically.
+    pub autoconnect: Property<bool>,
+
     /// Whether the in-memory state differs from the on-disk state.
     pub unsaved: Property<bool>,
 
@@ -135,6 +138,31 @@ impl ConnectionSettings {
         ConnectionSettingsControls::delete(&self.connection, &self.object_path).await
     }
 
+    /// Allow or prevent NetworkManager from activating this profile
+    /// automatically, and save the change to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NetworkError::OperationFailed` if reading or updating the
+    /// settings fails.
+    pub async fn set_autoconnect(&self, autoconnect: bool) -> Result<(), Error> {
+        let mut settings = self.get_settings().await?;
+
+        let value = zvariant::Value::from(autoconnect)
+            .try_to_owned()
+            .map_err(|err| Error::OperationFailed {
+                operation: "convert to owned value",
+                source: err.into(),
+            })?;
+
+        settings
+            .entry(String::from("connection"))
+            .or_default()
+            .insert(String::from("autoconnect"), value);
+
+        self.update(settings).await
+    }
+
     /// Get the settings maps describing this network configuration.
     ///
     /// This will never include any secrets required for connection to the
@@ -270,11 +298,12 @@ impl ConnectionSettings {
 
         let (id, uuid, connection_type, wifi_ssid) = match settings {
             Ok(ref settings_map) => extract_identity(settings_map),
-            Err(err) => {
+            Err(ref err) => {
                 tracing::debug!("cannot fetch GetSettings for {:?}: {}", path, err);
                 (String::new(), String::new(), ConnectionType::None, None)
             }
         };
+        let autoconnect = settings.as_ref().map_or(true, extract_autoconnect);
 
         Ok(SettingsConnectionProperties {
             unsaved: unwrap_dbus!(unsaved, path),
@@ -284,6 +313,7 @@ impl ConnectionSettings {
             uuid,
             connection_type,
             wifi_ssid,
+            autoconnect,
         })
     }
 
@@ -301,6 +331,7 @@ impl ConnectionSettings {
             uuid: Property::new(props.uuid),
             connection_type: Property::new(props.connection_type),
             wifi_ssid: Property::new(props.wifi_ssid),
+            autoconnect: Property::new(props.autoconnect),
             unsaved: Property::new(props.unsaved),
             flags: Property::new(NMConnectionSettingsFlags::from_bits_truncate(props.flags)),
             filename: Property::new(props.filename),
@@ -375,6 +406,16 @@ fn extract_identity(
     (id, uuid, connection_type, wifi_ssid)
 }
 
+/// Reads `connection.autoconnect`, which NetworkManager omits when it is
+/// left at its default of `true`.
+fn extract_autoconnect(settings_map: &HashMap<String, HashMap<String, OwnedValue>>) -> bool {
+    settings_map
+        .get("connection")
+        .and_then(|conn| conn.get("autoconnect"))
+        .and_then(|val| bool::try_from(val).ok())
+        .unwrap_or(true)
+}
+
 struct SettingsConnectionProperties {
     unsaved: bool,
     flags: u32,
@@ -383,4 +424,38 @@ struct SettingsConnectionProperties {
     uuid: String,
     connection_type: ConnectionType,
     wifi_ssid: Option<Ssid>,
+    autoconnect: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use zbus::zvariant::Value;
+
+    use super::*;
+
+    fn settings_with(key: &str, value: Value<'_>) -> HashMap<String, HashMap<String, OwnedValue>> {
+        let mut connection = HashMap::new();
+        connection.insert(key.to_owned(), value.try_to_owned().unwrap());
+
+        HashMap::from([(String::from("connection"), connection)])
+    }
+
+    #[test]
+    fn autoconnect_defaults_to_true_when_omitted() {
+        let settings = settings_with("id", Value::from("Home"));
+        assert!(extract_autoconnect(&settings));
+        assert!(extract_autoconnect(&HashMap::new()));
+    }
+
+    #[test]
+    fn autoconnect_reads_explicit_value() {
+        assert!(!extract_autoconnect(&settings_with(
+            "autoconnect",
+            Value::from(false)
+        )));
+        assert!(extract_autoconnect(&settings_with(
+            "autoconnect",
+            Value::from(true)
+        )));
+    }
 }
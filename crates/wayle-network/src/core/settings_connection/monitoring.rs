@@ -5,7 +5,7 @@ use tokio_util::sync::CancellationToken;
 use tracing::debug;
 use wayle_traits::ModelMonitoring;
 
-use super::{ConnectionSettings, extract_identity};
+use super::{ConnectionSettings, extract_autoconnect, extract_identity};
 use crate::{
     error::Error, proxy::settings::connection::SettingsConnectionProxy,
     types::flags::NMConnectionSettingsFlags,
@@ -80,6 +80,7 @@ async fn monitor(
                     settings.uuid.set(uuid);
                     settings.connection_type.set(connection_type);
                     settings.wifi_ssid.set(wifi_ssid);
+                    settings.autoconnect.set(extract_autoconnect(&settings_map));
                 }
             }
             else => {
@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use zbus::zvariant::OwnedObjectPath;
 
+use crate::types::states::NMActiveConnectionStateReason;
+
 /// Network service errors
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -60,4 +64,38 @@ pub enum Error {
         /// Reason for conversion failure.
         reason: String,
     },
+
+    /// No saved connection profile matches the given name or UUID.
+    #[error("no saved connection named {0}")]
+    ConnectionNotFound(String),
+
+    /// No saved VPN profile matches the given name or UUID.
+    #[error("no VPN connection named {0}")]
+    VpnNotFound(String),
+
+    /// VPN activation needs secrets (password, OTP) that no secret agent
+    /// provided. The UI should prompt for credentials and retry.
+    #[error("cannot activate VPN {id}: secrets required")]
+    VpnSecretsRequired {
+        /// Name of the VPN profile.
+        id: String,
+    },
+
+    /// VPN activation ended without the tunnel coming up.
+    #[error("cannot activate VPN {id}: {reason:?}")]
+    VpnActivationFailed {
+        /// Name of the VPN profile.
+        id: String,
+        /// Why NetworkManager deactivated the connection.
+        reason: NMActiveConnectionStateReason,
+    },
+
+    /// VPN activation neither came up nor failed in time.
+    #[error("cannot activate VPN {id}: timed out after {timeout:?}")]
+    VpnActivationTimedOut {
+        /// Name of the VPN profile.
+        id: String,
+        /// How long activation was waited on.
+        timeout: Duration,
+    },
 }
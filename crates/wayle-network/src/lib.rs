@@ -65,6 +65,58 @@
 //! # }
 //! ```
 //!
+//! # VPN Control
+//!
+//! ```rust,no_run
+//! # use wayle_network::{Error, NetworkService};
+//! # async fn example() -> Result<(), Error> {
+//! # let net = NetworkService::new().await?;
+//! for vpn in net.vpn_connections.get().iter() {
+//!     println!("{}: {:?}", vpn.id, vpn.state);
+//! }
+//!
+//! match net.activate_vpn("Work VPN").await {
+//!     Err(Error::VpnSecretsRequired { id }) => println!("{id} needs credentials"),
+//!     result => result?,
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Saved Connections
+//!
+//! ```rust,no_run
+//! # use wayle_network::{Error, NetworkService};
+//! # async fn example() -> Result<(), Error> {
+//! # let net = NetworkService::new().await?;
+//! for saved in net.saved_connections() {
+//!     println!("{} ({:?}) autoconnect={}", saved.id, saved.connection_type, saved.autoconnect);
+//! }
+//!
+//! net.set_connection_autoconnect("Coffee Shop", false).await?;
+//! net.forget_connection("Old Hotspot").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Metered Connections
+//!
+//! `is_metered` is the one signal to check before heavy background work
+//! such as update checks or downloads. It counts NetworkManager's guessed
+//! state the same as an explicit setting, so a phone hotspot reads as
+//! metered unless the profile says otherwise.
+//!
+//! ```rust,no_run
+//! # use wayle_network::NetworkService;
+//! # async fn example() -> Result<(), wayle_network::Error> {
+//! # let net = NetworkService::new().await?;
+//! if !net.is_metered.get() {
+//!     println!("safe to sync");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Reactive Properties
 //!
 //! All fields are [`Property<T>`](wayle_core::Property):
@@ -79,6 +131,8 @@
 //! | `wired` | `Property<Option<Arc<Wired>>>` | Ethernet device (reactive for hot-plug) |
 //! | `settings` | `Settings` | Connection profile management |
 //! | `primary` | `Property<ConnectionType>` | Active connection type |
+//! | `is_metered` | `Property<bool>` | Primary connection is metered (explicit or guessed) |
+//! | `vpn_connections` | `Property<Vec<VpnConnection>>` | VPN profiles and their state |
 
 /// Core network domain models.
 pub mod core;
@@ -89,6 +143,8 @@ mod proxy;
 mod service;
 /// Network type definitions
 pub mod types;
+/// VPN connection functionality
+pub mod vpn;
 /// WiFi device functionality
 pub mod wifi;
 /// Wired device functionality
@@ -13,7 +13,8 @@ use super::{
     error::Error,
     proxy::manager::NetworkManagerProxy,
     service::NetworkService,
-    types::connectivity::ConnectionType,
+    types::connectivity::{ConnectionType, NMMetered},
+    vpn::spawn_vpn_monitoring,
     wifi::{LiveWifiParams, Wifi},
     wired::{LiveWiredParams, Wired},
 };
@@ -29,6 +30,13 @@ impl ServiceMonitoring for NetworkService {
         )
         .await?;
 
+        spawn_metered_monitoring(
+            self.zbus_connection.clone(),
+            self.is_metered.clone(),
+            self.cancellation_token.child_token(),
+        )
+        .await?;
+
         spawn_device_monitoring(
             self.zbus_connection.clone(),
             self.wifi.clone(),
@@ -36,6 +44,14 @@ impl ServiceMonitoring for NetworkService {
             self.settings.clone(),
             self.cancellation_token.child_token(),
         )
+        .await?;
+
+        spawn_vpn_monitoring(
+            self.zbus_connection.clone(),
+            self.settings.clone(),
+            self.vpn_connections.clone(),
+            self.cancellation_token.child_token(),
+        )
         .await
     }
 }
@@ -74,6 +90,39 @@ async fn spawn_primary_monitoring(
     Ok(())
 }
 
+async fn spawn_metered_monitoring(
+    connection: Connection,
+    is_metered: Property<bool>,
+    cancellation_token: CancellationToken,
+) -> Result<(), Error> {
+    let nm_proxy = NetworkManagerProxy::new(&connection)
+        .await
+        .map_err(Error::DbusError)?;
+
+    let initial = nm_proxy.metered().await?;
+    update_metered(initial, &is_metered);
+
+    let mut metered_changed = nm_proxy.receive_metered_changed().await;
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    debug!("NetworkMonitoring metered monitoring cancelled");
+                    return;
+                }
+                Some(change) = metered_changed.next() => {
+                    if let Ok(metered) = change.get().await {
+                        update_metered(metered, &is_metered);
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
 async fn spawn_device_monitoring(
     connection: Connection,
     wifi: Property<Option<Arc<Wifi>>>,
@@ -209,3 +258,9 @@ fn update_primary_connection(nm_type: &str, primary: &Property<ConnectionType>)
     debug!(?connection_type, "Primary connection type resolved");
     primary.set(connection_type);
 }
+
+fn update_metered(value: u32, is_metered: &Property<bool>) {
+    let metered = NMMetered::from_u32(value);
+    debug!(?metered, "Metered state resolved");
+    is_metered.set(metered.is_metered());
+}
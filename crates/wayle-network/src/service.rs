@@ -12,6 +12,7 @@ use super::{
     core::access_point::types::{AccessPointParams, LiveAccessPointParams},
     error::Error,
     types::connectivity::ConnectionType,
+    vpn::{VpnConnection, VpnControls},
     wifi::Wifi,
     wired::Wired,
 };
@@ -30,7 +31,7 @@ use crate::{
             wifi::{DeviceWifi, DeviceWifiParams, LiveDeviceWifiParams},
             wired::{DeviceWired, DeviceWiredParams, LiveDeviceWiredParams},
         },
-        settings::{LiveSettingsParams, Settings},
+        settings::{LiveSettingsParams, SavedConnection, Settings},
         settings_connection::{
             ConnectionSettings, ConnectionSettingsParams, LiveConnectionSettingsParams,
         },
@@ -57,6 +58,15 @@ pub struct NetworkService {
     pub wired: Property<Option<Arc<Wired>>>,
     /// Primary connection type as reported by NetworkManager.
     pub primary: Property<ConnectionType>,
+    /// Whether the primary connection is metered.
+    ///
+    /// This is the canonical signal for deferring heavy background work on
+    /// tethered or capped links. Follows NetworkManager's global metered
+    /// state, counting guessed values (e.g. a phone hotspot) like explicit
+    /// ones.
+    pub is_metered: Property<bool>,
+    /// Saved VPN and WireGuard profiles with their activation state.
+    pub vpn_connections: Property<Vec<VpnConnection>>,
 }
 
 impl NetworkService {
@@ -137,6 +147,8 @@ impl NetworkService {
             wifi: Property::new(wifi),
             wired: Property::new(wired),
             primary,
+            is_metered: Property::new(false),
+            vpn_connections: Property::new(Vec::new()),
         };
 
         service.start_monitoring().await?;
@@ -144,6 +156,84 @@ impl NetworkService {
         Ok(service)
     }
 
+    /// Activates a saved VPN profile, identified by name or UUID.
+    ///
+    /// Resolves once the tunnel is up, so a secret agent prompt (if any) is
+    /// part of the wait.
+    ///
+    /// # Errors
+    /// Returns `NetworkError::VpnNotFound` if no VPN profile matches `id`.
+    /// Returns `NetworkError::VpnSecretsRequired` if NetworkManager had no
+    /// secrets to connect with.
+    /// Returns `NetworkError::VpnActivationFailed` if activation failed otherwise.
+    /// Returns `NetworkError::VpnActivationTimedOut` if the tunnel neither came
+    /// up nor failed in time.
+    #[instrument(skip(self), err)]
+    pub async fn activate_vpn(&self, id: &str) -> Result<(), Error> {
+        let vpn = self.find_vpn(id)?;
+        VpnControls::activate(&self.zbus_connection, &vpn).await
+    }
+
+    /// Deactivates a VPN profile, identified by name or UUID. Does nothing if
+    /// it isn't active.
+    ///
+    /// # Errors
+    /// Returns `NetworkError::VpnNotFound` if no VPN profile matches `id`.
+    /// Returns `NetworkError::OperationFailed` if deactivation fails.
+    #[instrument(skip(self), err)]
+    pub async fn deactivate_vpn(&self, id: &str) -> Result<(), Error> {
+        let vpn = self.find_vpn(id)?;
+        VpnControls::deactivate(&self.zbus_connection, &vpn).await
+    }
+
+    fn find_vpn(&self, id: &str) -> Result<VpnConnection, Error> {
+        self.vpn_connections
+            .get()
+            .into_iter()
+            .find(|vpn| vpn.matches(id))
+            .ok_or_else(|| Error::VpnNotFound(id.to_owned()))
+    }
+
+    /// Snapshot of every saved connection profile (WiFi, wired, VPN, ...).
+    ///
+    /// For reactive updates, watch `settings.connections` instead.
+    pub fn saved_connections(&self) -> Vec<SavedConnection> {
+        self.settings.saved_connections()
+    }
+
+    /// Deletes a saved connection profile, identified by name or UUID.
+    ///
+    /// If the profile is active, NetworkManager disconnects it.
+    ///
+    /// # Errors
+    /// Returns `NetworkError::ConnectionNotFound` if no profile matches `id`.
+    /// Returns `NetworkError::OperationFailed` if deletion fails.
+    #[instrument(skip(self), err)]
+    pub async fn forget_connection(&self, id: &str) -> Result<(), Error> {
+        self.find_connection(id)?.delete().await
+    }
+
+    /// Sets whether a saved connection profile, identified by name or UUID,
+    /// may be activated automatically.
+    ///
+    /// # Errors
+    /// Returns `NetworkError::ConnectionNotFound` if no profile matches `id`.
+    /// Returns `NetworkError::OperationFailed` if updating the profile fails.
+    #[instrument(skip(self), err)]
+    pub async fn set_connection_autoconnect(
+        &self,
+        id: &str,
+        autoconnect: bool,
+    ) -> Result<(), Error> {
+        self.find_connection(id)?.set_autoconnect(autoconnect).await
+    }
+
+    fn find_connection(&self, id: &str) -> Result<ConnectionSettings, Error> {
+        self.settings
+            .find_connection(id)
+            .ok_or_else(|| Error::ConnectionNotFound(id.to_owned()))
+    }
+
     /// Objects that implement the Connection.Active interface represent an attempt to
     /// connect to a network using the details provided by a Connection object.
     ///
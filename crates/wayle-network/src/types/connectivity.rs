@@ -104,6 +104,14 @@ impl NMMetered {
             _ => Self::Unknown,
         }
     }
+
+    /// Whether traffic should be treated as metered.
+    ///
+    /// Guessed values count the same as explicit ones, so `GuessYes` is
+    /// metered and `Unknown` is not.
+    pub fn is_metered(self) -> bool {
+        matches!(self, Self::Yes | Self::GuessYes)
+    }
 }
 
 /// Primary network connection type as reported by NetworkManager's
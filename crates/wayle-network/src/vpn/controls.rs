@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use tracing::{debug, instrument};
+use zbus::{Connection, zvariant::OwnedObjectPath};
+
+use super::{VpnConnection, activation_failure};
+use crate::{
+    error::Error,
+    proxy::{active_connection::ConnectionActiveProxy, manager::NetworkManagerProxy},
+    types::states::{NMActiveConnectionState, NMActiveConnectionStateReason},
+};
+
+const NO_SECRETS_ERROR: &str = "org.freedesktop.NetworkManager.AgentManager.NoSecrets";
+
+/// Longer than NetworkManager's own 60s VPN connect timeout, so its
+/// failure reason normally arrives first.
+const ACTIVATION_TIMEOUT: Duration = Duration::from_secs(90);
+
+pub(crate) struct VpnControls;
+
+impl VpnControls {
+    #[instrument(skip(connection, vpn), fields(vpn = %vpn.id), err)]
+    pub(crate) async fn activate(
+        connection: &Connection,
+        vpn: &VpnConnection,
+    ) -> Result<(), Error> {
+        let proxy = NetworkManagerProxy::new(connection).await?;
+        let any = OwnedObjectPath::try_from("/").map_err(|err| Error::DbusError(err.into()))?;
+
+        let active_path = proxy
+            .activate_connection(&vpn.settings_path, &any, &any)
+            .await
+            .map_err(|err| {
+                if is_no_secrets(&err) {
+                    return Error::VpnSecretsRequired { id: vpn.id.clone() };
+                }
+
+                Error::OperationFailed {
+                    operation: "activate VPN connection",
+                    source: err.into(),
+                }
+            })?;
+
+        debug!(path = %active_path, "VPN activation started");
+
+        tokio::time::timeout(
+            ACTIVATION_TIMEOUT,
+            Self::wait_for_activation(connection, &vpn.id, &active_path),
+        )
+        .await
+        .map_err(|_| Error::VpnActivationTimedOut {
+            id: vpn.id.clone(),
+            timeout: ACTIVATION_TIMEOUT,
+        })?
+    }
+
+    #[instrument(skip(connection, vpn), fields(vpn = %vpn.id), err)]
+    pub(crate) async fn deactivate(
+        connection: &Connection,
+        vpn: &VpnConnection,
+    ) -> Result<(), Error> {
+        let Some(active_path) = vpn.active_path.as_ref() else {
+            return Ok(());
+        };
+
+        let proxy = NetworkManagerProxy::new(connection).await?;
+
+        proxy
+            .deactivate_connection(active_path)
+            .await
+            .map_err(|err| Error::OperationFailed {
+                operation: "deactivate VPN connection",
+                source: err.into(),
+            })
+    }
+
+    /// Resolves once the activation either comes up or is torn down, so that
+    /// failures such as missing secrets reach the caller.
+    async fn wait_for_activation(
+        connection: &Connection,
+        id: &str,
+        active_path: &OwnedObjectPath,
+    ) -> Result<(), Error> {
+        let proxy = ConnectionActiveProxy::new(connection, active_path).await?;
+        let mut state_changes = proxy.receive_active_connection_state_changed().await?;
+
+        match proxy.state().await.map(NMActiveConnectionState::from_u32) {
+            Ok(NMActiveConnectionState::Activated) => return Ok(()),
+            Ok(_) => {}
+            Err(_) => {
+                return Err(activation_failure(
+                    id,
+                    NMActiveConnectionStateReason::Unknown,
+                ));
+            }
+        }
+
+        while let Some(signal) = state_changes.next().await {
+            let Ok(args) = signal.args() else { continue };
+
+            match NMActiveConnectionState::from_u32(args.state) {
+                NMActiveConnectionState::Activated => return Ok(()),
+                NMActiveConnectionState::Deactivated => {
+                    let reason = NMActiveConnectionStateReason::from_u32(args.reason);
+                    return Err(activation_failure(id, reason));
+                }
+                _ => {}
+            }
+        }
+
+        Err(activation_failure(
+            id,
+            NMActiveConnectionStateReason::Unknown,
+        ))
+    }
+}
+
+fn is_no_secrets(err: &zbus::Error) -> bool {
+    matches!(err, zbus::Error::MethodError(name, _, _) if name.as_str() == NO_SECRETS_ERROR)
+}
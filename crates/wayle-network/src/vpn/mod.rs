@@ -0,0 +1,153 @@
+mod controls;
+mod monitoring;
+
+pub(crate) use controls::VpnControls;
+pub(crate) use monitoring::spawn_vpn_monitoring;
+use zbus::zvariant::OwnedObjectPath;
+
+use crate::{
+    error::Error,
+    types::{
+        connectivity::ConnectionType,
+        states::{NMActiveConnectionState, NMActiveConnectionStateReason},
+    },
+};
+
+/// Activation state of a VPN profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VpnState {
+    /// Not active.
+    #[default]
+    Disconnected,
+    /// Activation in progress, including waiting for secrets.
+    Connecting,
+    /// Tunnel is up.
+    Connected,
+    /// Being torn down.
+    Disconnecting,
+}
+
+impl VpnState {
+    pub(crate) fn from_active_state(state: NMActiveConnectionState) -> Self {
+        match state {
+            NMActiveConnectionState::Activating => Self::Connecting,
+            NMActiveConnectionState::Activated => Self::Connected,
+            NMActiveConnectionState::Deactivating => Self::Disconnecting,
+            NMActiveConnectionState::Unknown | NMActiveConnectionState::Deactivated => {
+                Self::Disconnected
+            }
+        }
+    }
+}
+
+/// A saved VPN or WireGuard profile and its current activation state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VpnConnection {
+    /// Human-readable profile name (e.g. "Work VPN").
+    pub id: String,
+    /// Stable unique identifier of the profile.
+    pub uuid: String,
+    /// [`Vpn`](ConnectionType::Vpn) for plugin-based VPNs (OpenVPN,
+    /// OpenConnect, ...), [`WireGuard`](ConnectionType::WireGuard) for native
+    /// WireGuard profiles.
+    pub kind: ConnectionType,
+    /// D-Bus object path of the settings profile.
+    pub settings_path: OwnedObjectPath,
+    /// D-Bus object path of the active connection, if activated.
+    pub active_path: Option<OwnedObjectPath>,
+    /// Current activation state.
+    pub state: VpnState,
+}
+
+impl VpnConnection {
+    /// Whether `id` names this profile, by UUID or by display name.
+    pub fn matches(&self, id: &str) -> bool {
+        self.uuid == id || self.id == id
+    }
+
+    pub(crate) fn is_vpn_type(kind: &ConnectionType) -> bool {
+        matches!(kind, ConnectionType::Vpn | ConnectionType::WireGuard)
+    }
+}
+
+/// Maps the reason an activation ended in `Deactivated` to an error.
+pub(crate) fn activation_failure(id: &str, reason: NMActiveConnectionStateReason) -> Error {
+    match reason {
+        NMActiveConnectionStateReason::NoSecrets => Error::VpnSecretsRequired { id: id.to_owned() },
+        reason => Error::VpnActivationFailed {
+            id: id.to_owned(),
+            reason,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vpn(id: &str, uuid: &str) -> VpnConnection {
+        VpnConnection {
+            id: id.to_owned(),
+            uuid: uuid.to_owned(),
+            kind: ConnectionType::Vpn,
+            settings_path: OwnedObjectPath::try_from("/org/freedesktop/NetworkManager/Settings/7")
+                .unwrap(),
+            active_path: None,
+            state: VpnState::Disconnected,
+        }
+    }
+
+    #[test]
+    fn state_maps_from_active_connection_state() {
+        assert_eq!(
+            VpnState::from_active_state(NMActiveConnectionState::Activating),
+            VpnState::Connecting
+        );
+        assert_eq!(
+            VpnState::from_active_state(NMActiveConnectionState::Activated),
+            VpnState::Connected
+        );
+        assert_eq!(
+            VpnState::from_active_state(NMActiveConnectionState::Deactivating),
+            VpnState::Disconnecting
+        );
+        assert_eq!(
+            VpnState::from_active_state(NMActiveConnectionState::Deactivated),
+            VpnState::Disconnected
+        );
+    }
+
+    #[test]
+    fn matches_by_uuid_or_name() {
+        let vpn = vpn("Work VPN", "5b1c3a52-0d1e-4a8f-9f0e-2a7c1f4e8d11");
+
+        assert!(vpn.matches("Work VPN"));
+        assert!(vpn.matches("5b1c3a52-0d1e-4a8f-9f0e-2a7c1f4e8d11"));
+        assert!(!vpn.matches("Home VPN"));
+    }
+
+    #[test]
+    fn only_vpn_and_wireguard_are_vpn_types() {
+        assert!(VpnConnection::is_vpn_type(&ConnectionType::Vpn));
+        assert!(VpnConnection::is_vpn_type(&ConnectionType::WireGuard));
+        assert!(!VpnConnection::is_vpn_type(&ConnectionType::Wifi));
+        assert!(!VpnConnection::is_vpn_type(&ConnectionType::Other(
+            String::from("bridge")
+        )));
+    }
+
+    #[test]
+    fn missing_secrets_is_a_distinct_error() {
+        let err = activation_failure("Work VPN", NMActiveConnectionStateReason::NoSecrets);
+        assert!(matches!(err, Error::VpnSecretsRequired { id } if id == "Work VPN"));
+
+        let err = activation_failure("Work VPN", NMActiveConnectionStateReason::LoginFailed);
+        assert!(matches!(
+            err,
+            Error::VpnActivationFailed {
+                reason: NMActiveConnectionStateReason::LoginFailed,
+                ..
+            }
+        ));
+    }
+}
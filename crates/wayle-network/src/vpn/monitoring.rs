@@ -0,0 +1,134 @@
+use std::{collections::HashMap, pin::Pin, sync::Arc};
+
+use futures::{
+    Stream, StreamExt,
+    stream::{self, select_all},
+};
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+use wayle_core::Property;
+use zbus::{Connection, zvariant::OwnedObjectPath};
+
+use super::{VpnConnection, VpnState};
+use crate::{
+    core::settings::Settings,
+    error::Error,
+    proxy::{active_connection::ConnectionActiveProxy, manager::NetworkManagerProxy},
+    types::states::NMActiveConnectionState,
+};
+
+type StateStream = Pin<Box<dyn Stream<Item = ()> + Send>>;
+
+struct ActiveState {
+    path: OwnedObjectPath,
+    state: NMActiveConnectionState,
+}
+
+/// Keeps `vpn_connections` in sync with the saved VPN profiles and their
+/// activation state.
+///
+/// Rebuilds the list when profiles are added or removed, when the set of
+/// active connections changes, and when an active VPN changes state.
+pub(crate) async fn spawn_vpn_monitoring(
+    connection: Connection,
+    settings: Arc<Settings>,
+    vpn_connections: Property<Vec<VpnConnection>>,
+    cancellation_token: CancellationToken,
+) -> Result<(), Error> {
+    let nm_proxy = NetworkManagerProxy::new(&connection).await?;
+
+    let mut state_changes = refresh(&connection, &nm_proxy, &settings, &vpn_connections).await;
+    let mut active_changed = nm_proxy.receive_active_connections_changed().await;
+    let mut profiles_changed = settings.connections.watch();
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    debug!("VPN monitoring cancelled");
+                    return;
+                }
+                Some(_) = profiles_changed.next() => {}
+                Some(_) = active_changed.next() => {}
+                Some(()) = state_changes.next() => {}
+            }
+
+            state_changes = refresh(&connection, &nm_proxy, &settings, &vpn_connections).await;
+        }
+    });
+
+    Ok(())
+}
+
+async fn refresh(
+    connection: &Connection,
+    nm_proxy: &NetworkManagerProxy<'static>,
+    settings: &Settings,
+    vpn_connections: &Property<Vec<VpnConnection>>,
+) -> stream::SelectAll<StateStream> {
+    let active = active_connections(connection, nm_proxy).await;
+
+    let vpns: Vec<VpnConnection> = settings
+        .connections
+        .get()
+        .iter()
+        .filter(|profile| VpnConnection::is_vpn_type(&profile.connection_type.get()))
+        .map(|profile| {
+            let uuid = profile.uuid.get();
+            let active = active.get(&uuid);
+
+            VpnConnection {
+                id: profile.id.get(),
+                kind: profile.connection_type.get(),
+                settings_path: profile.object_path.clone(),
+                active_path: active.map(|active| active.path.clone()),
+                state: active.map_or(VpnState::Disconnected, |active| {
+                    VpnState::from_active_state(active.state)
+                }),
+                uuid,
+            }
+        })
+        .collect();
+
+    let mut streams: Vec<StateStream> = Vec::new();
+    for path in vpns.iter().filter_map(|vpn| vpn.active_path.as_ref()) {
+        let Ok(proxy) = ConnectionActiveProxy::new(connection, path.clone()).await else {
+            continue;
+        };
+        let Ok(changes) = proxy.receive_active_connection_state_changed().await else {
+            continue;
+        };
+        streams.push(Box::pin(changes.map(|_| ())));
+    }
+
+    vpn_connections.set(vpns);
+
+    select_all(streams)
+}
+
+async fn active_connections(
+    connection: &Connection,
+    nm_proxy: &NetworkManagerProxy<'static>,
+) -> HashMap<String, ActiveState> {
+    let paths = nm_proxy.active_connections().await.unwrap_or_default();
+    let mut active = HashMap::with_capacity(paths.len());
+
+    for path in paths {
+        let Ok(proxy) = ConnectionActiveProxy::new(connection, path.clone()).await else {
+            continue;
+        };
+        let (Ok(uuid), Ok(state)) = tokio::join!(proxy.uuid(), proxy.state()) else {
+            continue;
+        };
+
+        active.insert(
+            uuid,
+            ActiveState {
+                path,
+                state: NMActiveConnectionState::from_u32(state),
+            },
+        );
+    }
+
+    active
+}
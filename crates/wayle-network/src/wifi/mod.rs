@@ -43,6 +43,10 @@ pub struct Wifi {
     pub ip4_address: Property<Option<String>>,
     /// Visible access points.
     pub access_points: Property<Vec<Arc<AccessPoint>>>,
+    /// Access point the device is associated with, taken from
+    /// `access_points`. Its `strength` updates live as NetworkManager
+    /// reports changes, without a rescan.
+    pub active_access_point: Property<Option<Arc<AccessPoint>>>,
     #[debug(skip)]
     settings: Arc<Settings>,
 }
@@ -104,7 +108,8 @@ impl Wifi {
             strength,
             frequency,
             ip4_address,
-            access_points
+            access_points,
+            active_access_point
         )
     }
 
@@ -195,6 +200,7 @@ impl Wifi {
             frequency: Property::new(frequency),
             ip4_address: Property::new(ip4_address),
             access_points: Property::new(vec![]),
+            active_access_point: Property::new(None),
             settings,
         })
     }
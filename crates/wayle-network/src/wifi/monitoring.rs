@@ -55,6 +55,7 @@ impl ModelMonitoring for Wifi {
             cancellation_token,
         )
         .await;
+        sync_active_access_point(&self, &self.device.active_access_point.get());
 
         let cancel_token = cancellation_token.clone();
         let weak_self = Arc::downgrade(&self);
@@ -187,12 +188,14 @@ async fn monitor_wifi(
                             &wifi.access_points,
                             &cancellation_token
                         ).await;
+                        sync_active_access_point(&wifi, &wifi.device.active_access_point.get());
                     }
                 }
 
                 Some(removed) = ap_removed.next() => {
                     if let Ok(args) = removed.args() {
                         handle_ap_removed(&args.access_point, &wifi.access_points);
+                        sync_active_access_point(&wifi, &wifi.device.active_access_point.get());
                     }
                 }
 
@@ -207,6 +210,8 @@ async fn monitor_wifi(
                         continue;
                     };
 
+                    sync_active_access_point(&wifi, &new_ap_path);
+
                     let streams = handle_access_point_changed(
                         &wifi.device.core.connection,
                         new_ap_path,
@@ -303,6 +308,29 @@ async fn handle_ap_added(
     }
 }
 
+/// Points `active_access_point` at the live entry in `access_points` for
+/// `active_path`, or `None` if it isn't known yet. Called again when
+/// access points are added, so a late `AccessPointAdded` still resolves.
+/// Compares by identity since a re-added AP keeps its BSSID but is a new,
+/// separately monitored object.
+fn sync_active_access_point(wifi: &Wifi, active_path: &OwnedObjectPath) {
+    let active = wifi
+        .access_points
+        .get()
+        .into_iter()
+        .find(|ap| ap.object_path() == active_path);
+
+    let unchanged = match (wifi.active_access_point.get(), &active) {
+        (Some(current), Some(active)) => Arc::ptr_eq(&current, active),
+        (None, None) => true,
+        _ => false,
+    };
+
+    if !unchanged {
+        wifi.active_access_point.replace(active);
+    }
+}
+
 fn handle_ap_removed(ap_path: &OwnedObjectPath, access_points: &Property<Vec<Arc<AccessPoint>>>) {
     remove_and_cancel!(access_points, ap_path.clone());
 }
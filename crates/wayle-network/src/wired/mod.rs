@@ -4,15 +4,16 @@ mod types;
 use std::sync::Arc;
 
 pub(crate) use types::{LiveWiredParams, WiredParams};
-use wayle_core::Property;
+use wayle_core::{Property, unwrap_dbus};
 use wayle_traits::{ModelMonitoring, Reactive};
 
 use super::{
     core::{
         config::ip4_config::Ip4Config,
-        device::wired::{DeviceWired, DeviceWiredParams, LiveDeviceWiredParams},
+        device::wired::{DeviceWired, DeviceWiredParams, LiveDeviceWiredParams, types::SpeedMbps},
     },
     error::Error,
+    proxy::devices::wired::DeviceWiredProxy,
     types::states::NetworkStatus,
 };
 
@@ -25,6 +26,11 @@ pub struct Wired {
     pub connectivity: Property<NetworkStatus>,
     /// IPv4 address assigned to this device.
     pub ip4_address: Property<Option<String>>,
+    /// Whether a cable is plugged in and the link is up.
+    pub carrier: Property<bool>,
+    /// Negotiated link speed in megabits/second (Mb/s). `None` while
+    /// unplugged or when the driver doesn't report a speed.
+    pub speed_mbps: Property<Option<SpeedMbps>>,
 }
 
 impl PartialEq for Wired {
@@ -77,11 +83,35 @@ impl Wired {
         let connectivity = NetworkStatus::from_device_state(*device_state);
         let ip4_address =
             Ip4Config::resolve_address(&device.core.connection, device.core.ip4_config.get()).await;
+        let speed_mbps = link_speed(device.speed.get());
+
+        let wired_proxy =
+            DeviceWiredProxy::new(&device.core.connection, device.core.object_path.clone()).await?;
+        let carrier = unwrap_dbus!(wired_proxy.carrier().await);
 
         Ok(Self {
             device,
             connectivity: Property::new(connectivity),
             ip4_address: Property::new(ip4_address),
+            carrier: Property::new(carrier),
+            speed_mbps: Property::new(speed_mbps),
         })
     }
 }
+
+/// NetworkManager reports `0` when the speed is unknown.
+fn link_speed(speed: SpeedMbps) -> Option<SpeedMbps> {
+    (speed > 0).then_some(speed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn link_speed_treats_zero_as_unknown() {
+        assert_eq!(link_speed(0), None);
+        assert_eq!(link_speed(100), Some(100));
+        assert_eq!(link_speed(1000), Some(1000));
+    }
+}
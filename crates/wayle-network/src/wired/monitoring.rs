@@ -5,11 +5,11 @@ use tokio_util::sync::CancellationToken;
 use tracing::debug;
 use wayle_traits::ModelMonitoring;
 
-use super::Wired;
+use super::{Wired, link_speed};
 use crate::{
     core::config::ip4_config::Ip4Config,
     error::Error,
-    proxy::devices::DeviceProxy,
+    proxy::devices::{DeviceProxy, wired::DeviceWiredProxy},
     types::states::{NMDeviceState, NetworkStatus},
 };
 
@@ -32,9 +32,16 @@ impl ModelMonitoring for Wired {
         )
         .await
         .map_err(Error::DbusError)?;
+        let wired_proxy = DeviceWiredProxy::new(
+            &self.device.core.connection,
+            self.device.core.object_path.clone(),
+        )
+        .await
+        .map_err(Error::DbusError)?;
 
         tokio::spawn(async move {
-            let _ = monitor_wired_connectivity(weak_self, device_proxy, cancel_token).await;
+            let _ = monitor_wired_connectivity(weak_self, device_proxy, wired_proxy, cancel_token)
+                .await;
         });
 
         Ok(())
@@ -44,10 +51,16 @@ impl ModelMonitoring for Wired {
 async fn monitor_wired_connectivity(
     weak_wired: Weak<Wired>,
     proxy: DeviceProxy<'static>,
+    wired_proxy: DeviceWiredProxy<'static>,
     cancellation_token: CancellationToken,
 ) -> Result<(), Error> {
     let mut connectivity_changed = proxy.receive_state_changed().await;
     let mut ip4_config_changed = proxy.receive_ip4_config_changed().await;
+    let mut carrier_changed = wired_proxy.receive_carrier_changed().await;
+    let Some(speed) = weak_wired.upgrade().map(|wired| wired.device.speed.clone()) else {
+        return Ok(());
+    };
+    let mut speed_changed = speed.watch();
 
     loop {
         let Some(wired) = weak_wired.upgrade() else {
@@ -65,6 +78,10 @@ async fn monitor_wired_connectivity(
                     let status = NetworkStatus::from_device_state(device_state);
                     wired.connectivity.set(status);
 
+                    if let Ok(speed) = wired_proxy.speed().await {
+                        wired.device.speed.set(speed);
+                    }
+
                     if status == NetworkStatus::Connected {
                         let ip = Ip4Config::resolve_address(
                             &wired.device.core.connection,
@@ -83,6 +100,14 @@ async fn monitor_wired_connectivity(
                     wired.ip4_address.set(ip);
                 }
             }
+            Some(change) = carrier_changed.next() => {
+                if let Ok(carrier) = change.get().await {
+                    wired.carrier.set(carrier);
+                }
+            }
+            Some(speed) = speed_changed.next() => {
+                wired.speed_mbps.set(link_speed(speed));
+            }
             else => {
                 break;
             }
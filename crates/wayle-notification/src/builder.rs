@@ -1,7 +1,4 @@
-use std::{
-    collections::HashMap,
-    sync::{Arc, Mutex, atomic::AtomicU32},
-};
+use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use tokio::sync::broadcast;
@@ -16,25 +13,32 @@ use crate::{
     daemon::NotificationDaemon,
     error::Error,
     events::NotificationEvent,
+    expiry_timer::ExpiryTimerManager,
+    ids::NotificationIds,
     persistence::{NotificationStore, StoredNotification},
     popup_timer::PopupTimerManager,
     service::NotificationService,
+    sound::{NotificationSoundPlayer, SilentSoundPlayer},
     types::dbus::{SERVICE_NAME, SERVICE_PATH, WAYLE_SERVICE_NAME, WAYLE_SERVICE_PATH},
     wayle_daemon::WayleDaemon,
 };
 
 const EVENT_CHANNEL_CAPACITY: usize = 10_000;
+const CLOSED_CHANNEL_CAPACITY: usize = 256;
 
 /// Builder for configuring and creating a NotificationService instance.
 ///
-/// Allows customization of popup duration, do-not-disturb mode, and
-/// automatic removal of expired notifications.
+/// Allows customization of popup duration, do-not-disturb mode, automatic
+/// removal of expired notifications, and the player used for notification
+/// sounds.
 #[derive(Debug)]
 pub struct NotificationServiceBuilder {
     popup_duration: Property<u32>,
     dnd: Property<bool>,
     remove_expired: Property<bool>,
     blocklist: Property<Vec<String>>,
+    coalesce: Property<bool>,
+    sound_player: Option<Arc<dyn NotificationSoundPlayer>>,
     register_wayle_daemon: bool,
 }
 
@@ -45,6 +49,8 @@ impl Default for NotificationServiceBuilder {
             dnd: Property::new(false),
             remove_expired: Property::new(true),
             blocklist: Property::new(vec![]),
+            coalesce: Property::new(false),
+            sound_player: None,
             register_wayle_daemon: false,
         }
     }
@@ -87,6 +93,23 @@ impl NotificationServiceBuilder {
         }
     }
 
+    /// Sets whether notifications without `replaces_id` update an earlier one
+    /// from the same app with the same summary instead of adding a new entry.
+    pub fn coalesce(self, coalesce: bool) -> Self {
+        self.coalesce.set(coalesce);
+        self
+    }
+
+    /// Sets the player for sounds requested through the `sound-file` and
+    /// `sound-name` hints.
+    ///
+    /// Without one, notifications stay silent and the daemon doesn't
+    /// advertise the `sound` capability.
+    pub fn sound_player(mut self, player: Arc<dyn NotificationSoundPlayer>) -> Self {
+        self.sound_player = Some(player);
+        self
+    }
+
     /// Enables the Wayle D-Bus daemon for CLI control.
     ///
     /// When enabled, the service registers at `com.wayle.Notifications1`,
@@ -109,6 +132,7 @@ impl NotificationServiceBuilder {
             Error::ServiceInitializationFailed(format!("D-Bus connection failed: {err}"))
         })?;
         let (notif_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let (closed_tx, _) = broadcast::channel(CLOSED_CHANNEL_CAPACITY);
         let cancellation_token = CancellationToken::new();
 
         let store = init_store();
@@ -120,19 +144,18 @@ impl NotificationServiceBuilder {
             .max()
             .unwrap_or(0);
 
-        let mut initial_owners = HashMap::new();
-        for notification in &stored_notifications {
-            if let Some(app_name) = notification.app_name.get() {
-                initial_owners.insert(notification.id, app_name);
-            }
-        }
+        let initial_owners = stored_notifications.iter().filter_map(|notification| {
+            let app_name = notification.app_name.get()?;
+            Some((notification.id, app_name, notification.summary.get()))
+        });
 
         let freedesktop_daemon = NotificationDaemon {
-            counter: AtomicU32::new(max_id + 1),
+            ids: NotificationIds::new(max_id + 1, initial_owners),
             zbus_connection: connection.clone(),
             notif_tx: notif_tx.clone(),
             blocklist: self.blocklist.clone(),
-            id_owners: Mutex::new(initial_owners),
+            coalesce: self.coalesce.clone(),
+            plays_sounds: self.sound_player.is_some(),
         };
 
         register_dbus_object(&connection, SERVICE_PATH, freedesktop_daemon).await?;
@@ -141,10 +164,12 @@ impl NotificationServiceBuilder {
 
         let popups = Property::new(vec![]);
         let popup_timers = Arc::new(PopupTimerManager::new(popups.clone()));
+        let expiry_timers = Arc::new(ExpiryTimerManager::new(notif_tx.clone()));
 
         let service = Arc::new(NotificationService {
             cancellation_token,
             notif_tx,
+            closed_tx,
             store,
             connection: connection.clone(),
             notifications: Property::new(stored_notifications),
@@ -153,11 +178,22 @@ impl NotificationServiceBuilder {
             dnd: self.dnd,
             remove_expired: self.remove_expired,
             blocklist: self.blocklist,
+            coalesce: self.coalesce,
             popup_timers,
+            expiry_timers,
+            sound_player: self
+                .sound_player
+                .unwrap_or_else(|| Arc::new(SilentSoundPlayer)),
         });
 
         service.start_monitoring().await?;
 
+        if service.remove_expired.get() {
+            for notification in service.notifications.get().iter() {
+                service.expiry_timers.schedule(notification);
+            }
+        }
+
         if self.register_wayle_daemon {
             let wayle_daemon = WayleDaemon {
                 service: Arc::clone(&service),
@@ -0,0 +1,506 @@
+/// Tags from the notification spec's markup subset, plus `br`.
+const KNOWN_TAGS: [&str; 6] = ["b", "i", "u", "a", "img", "br"];
+
+/// A hyperlink from an `<a href>` tag in a notification body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyLink {
+    /// Link target, as given in `href`.
+    pub url: String,
+    /// Visible link text without markup, or the URL if the tag had none.
+    pub text: String,
+}
+
+/// A notification body converted to Pango markup, with its links pulled out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedBody {
+    /// Pango markup that always parses.
+    pub markup: String,
+    /// Links in the order they appear in the body.
+    pub links: Vec<BodyLink>,
+}
+
+/// Converts a notification body into Pango markup.
+///
+/// The spec allows `<b>`, `<i>`, `<u>`, `<a href>` and `<img src alt>`.
+/// Styling tags and links are kept, links are also collected into
+/// [`ParsedBody::links`], images are replaced by their `alt` text, and
+/// `<br>` becomes a newline. Other well-formed tags, such as `<span>`, are
+/// dropped and their text kept. Text that only looks like a tag, such as
+/// the `<b and c>` in `a<b and c>d`, stays literal. Unbalanced tags are
+/// closed and stray `<` or `&` are escaped.
+pub fn parse(body: &str) -> ParsedBody {
+    let mut parser = Parser::default();
+    let mut rest = body;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '<' => match rest
+                .find('>')
+                .and_then(|end| Some((Tag::parse(&rest[1..end])?, end)))
+            {
+                Some((tag, end)) => {
+                    parser.tag(tag);
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+                None => parser.text('<'),
+            },
+            '&' => match entity(rest) {
+                Some((decoded, len)) => {
+                    parser.text(decoded);
+                    rest = &rest[len..];
+                    continue;
+                }
+                None => parser.text('&'),
+            },
+            c => parser.text(c),
+        }
+
+        rest = &rest[c.len_utf8()..];
+    }
+
+    parser.finish()
+}
+
+/// A well-formed tag.
+struct Tag<'a> {
+    /// The tag's name if it's in [`KNOWN_TAGS`], `None` for any other tag.
+    name: Option<&'static str>,
+    closing: bool,
+    attributes: &'a str,
+}
+
+impl<'a> Tag<'a> {
+    /// Recognizes the text between `<` and `>` as a tag.
+    ///
+    /// A tag is a name starting with a letter, followed by `key=value`
+    /// attributes and an optional `/`. Closing tags take no attributes.
+    fn parse(tag: &'a str) -> Option<Self> {
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name_len = tag
+            .find(|c: char| c.is_whitespace() || c == '/')
+            .unwrap_or(tag.len());
+        let (name, attributes) = tag.split_at(name_len);
+
+        let valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        let valid_attributes = if closing {
+            attributes.trim().is_empty()
+        } else {
+            well_formed_attributes(attributes)
+        };
+        if !valid_name || !valid_attributes {
+            return None;
+        }
+
+        let name = name.to_ascii_lowercase();
+        Some(Self {
+            name: KNOWN_TAGS.into_iter().find(|known| *known == name),
+            closing,
+            attributes,
+        })
+    }
+}
+
+/// Whether `attributes` is a run of `key=value` pairs, optionally ending in
+/// `/`. Values may be quoted; bare words without a value don't count.
+fn well_formed_attributes(attributes: &str) -> bool {
+    let mut rest = attributes;
+
+    loop {
+        let trimmed = rest.trim_start();
+        if trimmed.is_empty() || trimmed == "/" {
+            return true;
+        }
+        if trimmed.len() == rest.len() {
+            return false;
+        }
+
+        let key_len = trimmed
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':')))
+            .unwrap_or(trimmed.len());
+        if key_len == 0 {
+            return false;
+        }
+
+        let Some(value) = trimmed[key_len..].trim_start().strip_prefix('=') else {
+            return false;
+        };
+        let value = value.trim_start();
+
+        rest = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => match value[1..].find(quote) {
+                Some(end) => &value[end + 2..],
+                None => return false,
+            },
+            Some(_) => {
+                let end = value
+                    .find(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '<'))
+                    .unwrap_or(value.len());
+                if end == 0 {
+                    return false;
+                }
+                &value[end..]
+            }
+            None => return false,
+        };
+    }
+}
+
+#[derive(Default)]
+struct Parser {
+    output: String,
+    open: Vec<&'static str>,
+    links: Vec<BodyLink>,
+    link: Option<BodyLink>,
+    /// Number of styling tags that were open when the current link started.
+    link_depth: usize,
+}
+
+impl Parser {
+    fn text(&mut self, c: char) {
+        push_escaped(&mut self.output, c);
+        if let Some(link) = self.link.as_mut() {
+            link.text.push(c);
+        }
+    }
+
+    fn tag(&mut self, tag: Tag<'_>) {
+        let Some(name) = tag.name else {
+            return;
+        };
+
+        match name {
+            "br" => self.text('\n'),
+            "a" => {
+                self.end_link();
+                if !tag.closing {
+                    self.start_link(tag.attributes);
+                }
+            }
+            "img" => {
+                if !tag.closing {
+                    for c in attribute(tag.attributes, "alt").unwrap_or_default().chars() {
+                        self.text(c);
+                    }
+                }
+            }
+            style => self.style(style, tag.closing),
+        }
+    }
+
+    fn start_link(&mut self, attributes: &str) {
+        let Some(url) = attribute(attributes, "href").filter(|url| !url.trim().is_empty()) else {
+            return;
+        };
+
+        self.output.push_str("<a href=\"");
+        for c in url.chars() {
+            match c {
+                '"' => self.output.push_str("&quot;"),
+                c => push_escaped(&mut self.output, c),
+            }
+        }
+        self.output.push_str("\">");
+
+        self.link_depth = self.open.len();
+        self.link = Some(BodyLink {
+            url,
+            text: String::new(),
+        });
+    }
+
+    fn style(&mut self, tag: &'static str, closing: bool) {
+        if !closing {
+            self.output.push('<');
+            self.output.push_str(tag);
+            self.output.push('>');
+            self.open.push(tag);
+            return;
+        }
+
+        let Some(index) = self.open.iter().rposition(|open| *open == tag) else {
+            return;
+        };
+
+        // Pango needs the link closed before a tag opened outside it.
+        if self.link.is_some() && index < self.link_depth {
+            self.end_link();
+        }
+
+        for open in self.open.drain(index..).rev() {
+            close(&mut self.output, open);
+        }
+    }
+
+    fn end_link(&mut self) {
+        let Some(mut link) = self.link.take() else {
+            return;
+        };
+
+        for open in self.open.drain(self.link_depth..).rev() {
+            close(&mut self.output, open);
+        }
+        self.output.push_str("</a>");
+
+        let text = link.text.trim();
+        link.text = if text.is_empty() {
+            link.url.clone()
+        } else {
+            text.to_owned()
+        };
+        self.links.push(link);
+    }
+
+    fn finish(mut self) -> ParsedBody {
+        self.end_link();
+
+        for tag in self.open.drain(..).rev() {
+            close(&mut self.output, tag);
+        }
+
+        ParsedBody {
+            markup: self.output,
+            links: self.links,
+        }
+    }
+}
+
+fn close(output: &mut String, tag: &str) {
+    output.push_str("</");
+    output.push_str(tag);
+    output.push('>');
+}
+
+fn push_escaped(output: &mut String, c: char) {
+    match c {
+        '&' => output.push_str("&amp;"),
+        '<' => output.push_str("&lt;"),
+        '>' => output.push_str("&gt;"),
+        c => output.push(c),
+    }
+}
+
+/// Decodes the entity at the start of `text`, returning the character and
+/// the length it spans.
+fn entity(text: &str) -> Option<(char, usize)> {
+    let end = text.find(';')?;
+    let name = &text[1..end];
+
+    let decoded = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        _ => {
+            let digits = name.strip_prefix('#')?;
+            let code = match digits.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => digits.parse().ok()?,
+            };
+            char::from_u32(code).filter(|c| *c != '\0')?
+        }
+    };
+
+    Some((decoded, end + 1))
+}
+
+/// Value of attribute `name` in a tag's attribute list, with entities
+/// decoded.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes;
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if rest.is_empty() {
+            return None;
+        }
+
+        let key_len = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = &rest[..key_len];
+        rest = rest[key_len..].trim_start();
+
+        let Some(value) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+
+        let (raw, remaining) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let inner = &value[1..];
+                match inner.find(quote) {
+                    Some(end) => (&inner[..end], &inner[end + 1..]),
+                    None => (inner, ""),
+                }
+            }
+            _ => {
+                let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                value.split_at(end)
+            }
+        };
+
+        if key.eq_ignore_ascii_case(name) {
+            return Some(decode(raw));
+        }
+
+        rest = remaining;
+    }
+}
+
+fn decode(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '&'
+            && let Some((decoded, len)) = entity(rest)
+        {
+            output.push(decoded);
+            rest = &rest[len..];
+            continue;
+        }
+
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(url: &str, text: &str) -> BodyLink {
+        BodyLink {
+            url: url.to_owned(),
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn keeps_supported_styling() {
+        assert_eq!(
+            parse("<b>3</b> new <i>messages</i> from <u>Ana</u>").markup,
+            "<b>3</b> new <i>messages</i> from <u>Ana</u>"
+        );
+    }
+
+    #[test]
+    fn drops_unknown_tags_but_keeps_text() {
+        assert_eq!(parse("<span>Build</span> failed").markup, "Build failed");
+        assert_eq!(
+            parse("<font color='red' size=2>Alert</font><hr/>").markup,
+            "Alert"
+        );
+        assert_eq!(parse("<b class=\"x\">bold</b>").markup, "<b>bold</b>");
+    }
+
+    #[test]
+    fn keeps_tag_like_text_literal() {
+        assert_eq!(parse("a<b and c>d").markup, "a&lt;b and c&gt;d");
+        assert_eq!(parse("x <3 and y> 2").markup, "x &lt;3 and y&gt; 2");
+        assert_eq!(
+            parse("<span title=\"open>").markup,
+            "&lt;span title=\"open&gt;"
+        );
+        assert_eq!(parse("</b extra>").markup, "&lt;/b extra&gt;");
+    }
+
+    #[test]
+    fn closes_nested_and_unclosed_tags() {
+        assert_eq!(
+            parse("<b>bold <i>both</b> plain").markup,
+            "<b>bold <i>both</i></b> plain"
+        );
+        assert_eq!(parse("<u>open <b>more").markup, "<u>open <b>more</b></u>");
+        assert_eq!(parse("stray</i> text").markup, "stray text");
+    }
+
+    #[test]
+    fn extracts_links_and_keeps_their_text() {
+        let parsed = parse(
+            "See <a href=\"https://example.com/pr/1\">the PR</a> or \
+             <a href='https://example.com/?a=1&amp;b=2'><b>docs</b></a>",
+        );
+
+        assert_eq!(
+            parsed.markup,
+            "See <a href=\"https://example.com/pr/1\">the PR</a> or \
+             <a href=\"https://example.com/?a=1&amp;b=2\"><b>docs</b></a>"
+        );
+        assert_eq!(
+            parsed.links,
+            vec![
+                link("https://example.com/pr/1", "the PR"),
+                link("https://example.com/?a=1&b=2", "docs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unclosed_link_ends_at_next_link_or_end() {
+        let parsed =
+            parse("<a href=\"https://a.example\">first <a href=\"https://b.example\">second");
+
+        assert_eq!(
+            parsed.markup,
+            "<a href=\"https://a.example\">first </a><a href=\"https://b.example\">second</a>"
+        );
+        assert_eq!(
+            parsed.links,
+            vec![
+                link("https://a.example", "first"),
+                link("https://b.example", "second"),
+            ]
+        );
+    }
+
+    #[test]
+    fn link_without_text_uses_url() {
+        let parsed = parse("<a href=\"https://example.com\"></a><a>no href</a>");
+
+        assert_eq!(
+            parsed.links,
+            vec![link("https://example.com", "https://example.com")]
+        );
+    }
+
+    #[test]
+    fn closes_link_before_enclosing_style() {
+        assert_eq!(
+            parse("<b>see <a href=\"https://example.com\">here</b> now</a>").markup,
+            "<b>see <a href=\"https://example.com\">here</a></b> now"
+        );
+    }
+
+    #[test]
+    fn images_become_alt_text() {
+        assert_eq!(
+            parse("Avatar: <img src=\"face.png\" alt=\"Ana &amp; Bo\"/>").markup,
+            "Avatar: Ana &amp; Bo"
+        );
+        assert_eq!(parse("<img src=\"face.png\">").markup, "");
+    }
+
+    #[test]
+    fn escapes_stray_markup_characters() {
+        assert_eq!(parse("a < b & c > d").markup, "a &lt; b &amp; c &gt; d");
+        assert_eq!(
+            parse("Tom &amp; Jerry &#38; &#xD800; &bogus;").markup,
+            "Tom &amp; Jerry &amp; &amp;#xD800; &amp;bogus;"
+        );
+    }
+
+    #[test]
+    fn line_breaks_become_newlines() {
+        assert_eq!(parse("one<br/>two<BR>three").markup, "one\ntwo\nthree");
+    }
+}
@@ -1,6 +1,8 @@
 //! Notification data and internal types.
 
 mod controls;
+/// Body markup normalization and link extraction.
+pub mod markup;
 /// The [`Notification`](notification::Notification) struct.
 pub mod notification;
 /// Action and hint types.
@@ -9,6 +9,7 @@ use zbus::Connection;
 
 use super::{
     controls::NotificationControls,
+    markup::{self, BodyLink},
     types::{Action, NotificationHints, NotificationProps},
 };
 use crate::{
@@ -51,6 +52,12 @@ pub struct Notification {
     ///
     /// If the body is omitted, just the summary is displayed.
     pub body: Property<Option<String>>,
+    /// `body` as Pango markup: supported tags and links kept, anything
+    /// else escaped, and unbalanced tags closed, so it can go straight into
+    /// a label, which makes the links clickable.
+    pub body_markup: Property<Option<String>>,
+    /// Hyperlinks from `<a href>` tags in `body`, in order.
+    pub links: Property<Vec<BodyLink>>,
     /// Available actions for this notification.
     ///
     /// Each action has an identifier and a human-readable label.
@@ -132,6 +139,35 @@ impl Notification {
         NotificationControls::invoke(&self.zbus_connection, &self.id, action_key).await
     }
 
+    /// Takes on the content of `incoming`, a notification that reuses this
+    /// one's ID, so watchers see its fields change instead of a new entry.
+    pub(crate) fn update(&self, incoming: &Notification) {
+        self.app_name.set(incoming.app_name.get());
+        self.replaces_id.set(incoming.replaces_id.get());
+        self.app_icon.set(incoming.app_icon.get());
+        self.summary.set(incoming.summary.get());
+        self.body.set(incoming.body.get());
+        self.body_markup.set(incoming.body_markup.get());
+        self.links.set(incoming.links.get());
+        self.actions.set(incoming.actions.get());
+        self.default_action.set(incoming.default_action.get());
+        self.hints.set(incoming.hints.get());
+        self.expire_timeout.set(incoming.expire_timeout.get());
+        self.urgency.set(incoming.urgency.get());
+        self.category.set(incoming.category.get());
+        self.timestamp.set(incoming.timestamp.get());
+        self.image_path.set(incoming.image_path.get());
+        self.desktop_entry.set(incoming.desktop_entry.get());
+        self.is_transient.set(incoming.is_transient.get());
+        self.is_resident.set(incoming.is_resident.get());
+        self.sound_file.set(incoming.sound_file.get());
+        self.sound_name.set(incoming.sound_name.get());
+        self.suppress_sound.set(incoming.suppress_sound.get());
+        self.x.set(incoming.x.get());
+        self.y.set(incoming.y.get());
+        self.action_icons.set(incoming.action_icons.get());
+    }
+
     #[allow(clippy::too_many_lines)]
     fn from_props(
         props: NotificationProps,
@@ -162,6 +198,9 @@ impl Notification {
             None
         };
 
+        let parsed_body = body.as_deref().map(markup::parse).unwrap_or_default();
+        let body_markup = body.as_ref().map(|_| parsed_body.markup);
+
         let urgency = &props
             .hints
             .get("urgency")
@@ -259,6 +298,8 @@ impl Notification {
             actions: Property::new(parsed_actions),
             default_action: Property::new(default_action),
             body: Property::new(body),
+            body_markup: Property::new(body_markup),
+            links: Property::new(parsed_body.links),
             hints: Property::new(hints),
             expire_timeout: Property::new(expire_timeout),
             urgency: Property::new(*urgency),
@@ -1,11 +1,3 @@
-use std::{
-    collections::HashMap,
-    sync::{
-        Mutex,
-        atomic::{AtomicU32, Ordering},
-    },
-};
-
 use chrono::Utc;
 use derive_more::Debug;
 use tokio::sync::broadcast;
@@ -22,13 +14,15 @@ use crate::{
         types::{BorrowedImageData, IncomingHints, NotificationHints, NotificationProps},
     },
     events::NotificationEvent,
-    glob, image_cache,
+    glob,
+    ids::NotificationIds,
+    image_cache,
     types::{Capabilities, ClosedReason, Name, SpecVersion, Vendor, Version},
 };
 
 #[derive(Debug)]
 pub(crate) struct NotificationDaemon {
-    pub counter: AtomicU32,
+    pub ids: NotificationIds,
     #[debug(skip)]
     pub zbus_connection: Connection,
     #[debug(skip)]
@@ -36,7 +30,8 @@ pub(crate) struct NotificationDaemon {
     #[debug(skip)]
     pub blocklist: Property<Vec<String>>,
     #[debug(skip)]
-    pub id_owners: Mutex<HashMap<u32, String>>,
+    pub coalesce: Property<bool>,
+    pub plays_sounds: bool,
 }
 
 #[zbus::interface(name = "org.freedesktop.Notifications")]
@@ -61,7 +56,9 @@ impl NotificationDaemon {
         hints: IncomingHints<'_>,
         expire_timeout: i32,
     ) -> fdo::Result<u32> {
-        let id = self.resolve_id(replaces_id, &app_name);
+        let id = self
+            .ids
+            .resolve(replaces_id, &app_name, &summary, self.coalesce.get());
 
         let blocked = self
             .blocklist
@@ -75,7 +72,7 @@ impl NotificationDaemon {
         }
 
         let hints = normalize_hints(hints);
-        self.register_owner(id, &app_name);
+        self.ids.register(id, &app_name, &summary);
 
         let notif = Notification::new(
             NotificationProps {
@@ -102,7 +99,7 @@ impl NotificationDaemon {
 
     #[instrument(skip(self), fields(notification_id = %id))]
     pub async fn close_notification(&self, id: u32) -> fdo::Result<()> {
-        self.remove_owner(id);
+        self.ids.release(id);
         let _ = self
             .notif_tx
             .send(NotificationEvent::Remove(id, ClosedReason::Closed));
@@ -110,13 +107,20 @@ impl NotificationDaemon {
     }
 
     pub async fn get_capabilities(&self) -> Vec<String> {
-        vec![
+        let mut capabilities = vec![
             Capabilities::Body.to_string(),
             Capabilities::BodyMarkup.to_string(),
+            Capabilities::BodyHyperlinks.to_string(),
             Capabilities::Actions.to_string(),
             Capabilities::IconStatic.to_string(),
             Capabilities::Persistence.to_string(),
-        ]
+        ];
+
+        if self.plays_sounds {
+            capabilities.push(Capabilities::Sound.to_string());
+        }
+
+        capabilities
     }
 
     pub async fn get_server_information(&self) -> (Name, Vendor, Version, SpecVersion) {
@@ -129,53 +133,6 @@ impl NotificationDaemon {
     }
 }
 
-impl NotificationDaemon {
-    /// Only allows an app to reuse `replaces_id` values it owns.
-    /// Assigns a new ID otherwise.
-    fn resolve_id(&self, replaces_id: u32, app_name: &str) -> u32 {
-        if replaces_id == 0 {
-            let new_id = self.counter.fetch_add(1, Ordering::Relaxed);
-            debug!(new_id, "assigned new notification id");
-            return new_id;
-        }
-
-        let owned_by_caller = self
-            .id_owners
-            .lock()
-            .unwrap_or_else(|poisoned| poisoned.into_inner())
-            .get(&replaces_id)
-            .is_some_and(|owner| owner == app_name);
-
-        if owned_by_caller {
-            debug!(replaces_id, "reusing replaces_id owned by same app");
-            replaces_id
-        } else {
-            let new_id = self.counter.fetch_add(1, Ordering::Relaxed);
-            debug!(
-                replaces_id,
-                new_id, "replaces_id belongs to different app, assigned new id"
-            );
-            new_id
-        }
-    }
-
-    fn register_owner(&self, id: u32, app_name: &str) {
-        let mut owners = self
-            .id_owners
-            .lock()
-            .unwrap_or_else(|poisoned| poisoned.into_inner());
-        owners.insert(id, app_name.to_owned());
-    }
-
-    fn remove_owner(&self, id: u32) {
-        let mut owners = self
-            .id_owners
-            .lock()
-            .unwrap_or_else(|poisoned| poisoned.into_inner());
-        owners.remove(&id);
-    }
-}
-
 fn normalize_hints(hints: IncomingHints<'_>) -> NotificationHints {
     normalize_hints_with(hints, image_cache::cache_borrowed_image)
 }
@@ -0,0 +1,204 @@
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use chrono::Utc;
+use tokio::{sync::broadcast, time::Instant};
+use tokio_util::sync::CancellationToken;
+
+use crate::{core::notification::Notification, events::NotificationEvent, types::ClosedReason};
+
+struct ExpiryTimer {
+    started_at: Instant,
+    duration: Duration,
+    paused: bool,
+    cancel: CancellationToken,
+}
+
+impl ExpiryTimer {
+    fn remaining(&self) -> Duration {
+        if self.paused {
+            return self.duration;
+        }
+        self.duration.saturating_sub(self.started_at.elapsed())
+    }
+}
+
+/// Schedules notification expiry with pausable countdowns.
+///
+/// A notification expires once its `expire_timeout` has run for the time it
+/// was not paused. The close event is sent as
+/// [`ClosedReason::Expired`] through the service's event channel.
+pub(crate) struct ExpiryTimerManager {
+    timers: Mutex<HashMap<u32, ExpiryTimer>>,
+    notif_tx: broadcast::Sender<NotificationEvent>,
+}
+
+impl ExpiryTimerManager {
+    pub(crate) fn new(notif_tx: broadcast::Sender<NotificationEvent>) -> Self {
+        Self {
+            timers: Mutex::new(HashMap::new()),
+            notif_tx,
+        }
+    }
+
+    /// Schedules expiry from the notification's timestamp and timeout.
+    ///
+    /// Notifications with no timeout (server default) or a zero timeout stay
+    /// until dismissed. Ones already past their timeout expire immediately.
+    pub(crate) fn schedule(&self, notification: &Notification) {
+        let Some(ttl) = notification.expire_timeout.get().filter(|ttl| *ttl > 0) else {
+            self.cancel(notification.id);
+            return;
+        };
+
+        let expires_at = notification.timestamp.get() + Duration::from_millis(u64::from(ttl));
+        let remaining = (expires_at - Utc::now()).to_std().unwrap_or(Duration::ZERO);
+
+        self.start(notification.id, remaining);
+    }
+
+    /// Starts a countdown, replacing any existing timer for `id`.
+    pub(crate) fn start(&self, id: u32, duration: Duration) {
+        let cancel = CancellationToken::new();
+
+        {
+            let Ok(mut timers) = self.timers.lock() else {
+                return;
+            };
+
+            if let Some(existing) = timers.insert(
+                id,
+                ExpiryTimer {
+                    started_at: Instant::now(),
+                    duration,
+                    paused: false,
+                    cancel: cancel.clone(),
+                },
+            ) {
+                existing.cancel.cancel();
+            }
+        }
+
+        let notif_tx = self.notif_tx.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                () = tokio::time::sleep(duration) => {
+                    tracing::debug!(id = id, "notification expired");
+                    let _ = notif_tx.send(NotificationEvent::Remove(id, ClosedReason::Expired));
+                }
+                () = cancel.cancelled() => {}
+            }
+        });
+    }
+
+    /// Stops the countdown for `id`, keeping the time left.
+    pub(crate) fn pause(&self, id: u32) {
+        let Ok(mut timers) = self.timers.lock() else {
+            return;
+        };
+        let Some(timer) = timers.get_mut(&id) else {
+            return;
+        };
+        if timer.paused {
+            return;
+        }
+
+        timer.duration = timer.remaining();
+        timer.paused = true;
+        timer.cancel.cancel();
+    }
+
+    /// Continues a paused countdown from the time it had left.
+    pub(crate) fn resume(&self, id: u32) {
+        let remaining = {
+            let Ok(timers) = self.timers.lock() else {
+                return;
+            };
+            match timers.get(&id) {
+                Some(timer) if timer.paused => timer.duration,
+                _ => return,
+            }
+        };
+
+        self.start(id, remaining);
+    }
+
+    /// Time left before `id` expires, or `None` if it has no timer.
+    pub(crate) fn remaining(&self, id: u32) -> Option<Duration> {
+        let timers = self.timers.lock().ok()?;
+        timers.get(&id).map(ExpiryTimer::remaining)
+    }
+
+    /// Cancels and forgets the timer for `id`.
+    pub(crate) fn cancel(&self, id: u32) {
+        if let Ok(mut timers) = self.timers.lock()
+            && let Some(timer) = timers.remove(&id)
+        {
+            timer.cancel.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager() -> (ExpiryTimerManager, broadcast::Receiver<NotificationEvent>) {
+        let (notif_tx, notif_rx) = broadcast::channel(8);
+        (ExpiryTimerManager::new(notif_tx), notif_rx)
+    }
+
+    async fn advance(duration: Duration) {
+        tokio::task::yield_now().await;
+        tokio::time::advance(duration).await;
+        tokio::task::yield_now().await;
+    }
+
+    fn expired_id(event: NotificationEvent) -> Option<u32> {
+        match event {
+            NotificationEvent::Remove(id, ClosedReason::Expired) => Some(id),
+            _ => None,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expires_after_duration() {
+        let (timers, mut rx) = manager();
+        timers.start(1, Duration::from_secs(5));
+
+        advance(Duration::from_secs(4)).await;
+        assert!(rx.try_recv().is_err());
+
+        advance(Duration::from_secs(1)).await;
+        assert_eq!(rx.try_recv().ok().and_then(expired_id), Some(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn paused_time_does_not_count() {
+        let (timers, mut rx) = manager();
+        timers.start(1, Duration::from_secs(5));
+
+        advance(Duration::from_secs(3)).await;
+        timers.pause(1);
+        timers.pause(1);
+        advance(Duration::from_secs(10)).await;
+
+        assert!(rx.try_recv().is_err());
+        assert_eq!(timers.remaining(1), Some(Duration::from_secs(2)));
+
+        timers.resume(1);
+        advance(Duration::from_secs(2)).await;
+        assert_eq!(rx.try_recv().ok().and_then(expired_id), Some(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_timer_never_fires() {
+        let (timers, mut rx) = manager();
+        timers.start(1, Duration::from_secs(5));
+        timers.cancel(1);
+
+        advance(Duration::from_secs(10)).await;
+
+        assert!(rx.try_recv().is_err());
+        assert_eq!(timers.remaining(1), None);
+    }
+}
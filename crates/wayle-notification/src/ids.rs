@@ -0,0 +1,167 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+};
+
+use tracing::debug;
+
+/// The app and summary a notification ID was last used for.
+#[derive(Debug, Clone)]
+struct IdOwner {
+    app_name: String,
+    summary: String,
+}
+
+/// Hands out notification IDs and decides when an incoming notification
+/// reuses an existing one.
+#[derive(Debug)]
+pub(crate) struct NotificationIds {
+    counter: AtomicU32,
+    owners: Mutex<HashMap<u32, IdOwner>>,
+}
+
+impl NotificationIds {
+    /// Starts allocating at `next_id`, with `owners` as `(id, app_name, summary)`
+    /// for notifications restored from a previous session.
+    pub fn new(next_id: u32, owners: impl IntoIterator<Item = (u32, String, String)>) -> Self {
+        let owners = owners
+            .into_iter()
+            .map(|(id, app_name, summary)| (id, IdOwner { app_name, summary }))
+            .collect();
+
+        Self {
+            counter: AtomicU32::new(next_id),
+            owners: Mutex::new(owners),
+        }
+    }
+
+    /// Picks the ID for an incoming notification.
+    ///
+    /// A non-zero `replaces_id` is reused only if the same app owns it. With
+    /// `coalesce` set, a notification without `replaces_id` reuses the ID of
+    /// the newest one from the same app with the same summary. Anything else
+    /// gets a fresh ID.
+    pub fn resolve(&self, replaces_id: u32, app_name: &str, summary: &str, coalesce: bool) -> u32 {
+        let owners = self
+            .owners
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if replaces_id != 0 {
+            let owned_by_caller = owners
+                .get(&replaces_id)
+                .is_some_and(|owner| owner.app_name == app_name);
+
+            if owned_by_caller {
+                debug!(replaces_id, "reusing replaces_id owned by same app");
+                return replaces_id;
+            }
+
+            let new_id = self.next_id();
+            debug!(
+                replaces_id,
+                new_id, "replaces_id belongs to different app, assigned new id"
+            );
+            return new_id;
+        }
+
+        if coalesce {
+            let matching = owners
+                .iter()
+                .filter(|(_, owner)| owner.app_name == app_name && owner.summary == summary)
+                .map(|(id, _)| *id)
+                .max();
+
+            if let Some(id) = matching {
+                debug!(id, "coalescing with notification of same app and summary");
+                return id;
+            }
+        }
+
+        let new_id = self.next_id();
+        debug!(new_id, "assigned new notification id");
+        new_id
+    }
+
+    /// Records that `id` now belongs to `app_name` with `summary`.
+    pub fn register(&self, id: u32, app_name: &str, summary: &str) {
+        let mut owners = self
+            .owners
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        owners.insert(
+            id,
+            IdOwner {
+                app_name: app_name.to_owned(),
+                summary: summary.to_owned(),
+            },
+        );
+    }
+
+    /// Forgets `id`, so it can no longer be replaced or coalesced into.
+    pub fn release(&self, id: u32) {
+        let mut owners = self
+            .owners
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        owners.remove(&id);
+    }
+
+    fn next_id(&self) -> u32 {
+        self.counter.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notify(ids: &NotificationIds, replaces_id: u32, app: &str, summary: &str) -> u32 {
+        let id = ids.resolve(replaces_id, app, summary, false);
+        ids.register(id, app, summary);
+        id
+    }
+
+    #[test]
+    fn second_notification_replaces_first() {
+        let ids = NotificationIds::new(1, []);
+
+        let first = notify(&ids, 0, "firefox", "Downloading 10%");
+        let second = notify(&ids, first, "firefox", "Downloading 50%");
+
+        assert_eq!(second, first);
+    }
+
+    #[test]
+    fn replaces_id_from_other_app_gets_new_id() {
+        let ids = NotificationIds::new(1, []);
+
+        let first = notify(&ids, 0, "firefox", "Download");
+        let second = notify(&ids, first, "spotify", "Now playing");
+
+        assert_ne!(second, first);
+    }
+
+    #[test]
+    fn coalesces_same_app_and_summary_when_enabled() {
+        let ids = NotificationIds::new(1, [(7, String::from("rsync"), String::from("Sync"))]);
+
+        assert_eq!(ids.resolve(0, "rsync", "Sync", true), 7);
+        assert_ne!(ids.resolve(0, "rsync", "Sync", false), 7);
+        assert_ne!(ids.resolve(0, "rsync", "Other", true), 7);
+        assert_ne!(ids.resolve(0, "backup", "Sync", true), 7);
+    }
+
+    #[test]
+    fn released_id_is_not_coalesced() {
+        let ids = NotificationIds::new(1, []);
+        let first = notify(&ids, 0, "rsync", "Sync");
+
+        ids.release(first);
+
+        assert_ne!(ids.resolve(0, "rsync", "Sync", true), first);
+    }
+}
@@ -38,6 +38,17 @@ fn cache_image_data(
 ) -> Option<String> {
     let color_type = png_color_type(bits_per_sample, channels)?;
 
+    if !has_valid_layout(width, height, rowstride, channels, data.len()) {
+        warn!(
+            width,
+            height,
+            rowstride,
+            len = data.len(),
+            "image-data dimensions don't match its pixel buffer, skipping PNG cache"
+        );
+        return None;
+    }
+
     let dir = cache_dir();
     let path = dir.join(format!("{}.png", content_hash(data)));
 
@@ -82,6 +93,32 @@ fn png_color_type(bits_per_sample: i32, channels: i32) -> Option<ColorType> {
     }
 }
 
+/// Whether `data` holds `height` rows of `rowstride` bytes, each with room
+/// for `width` pixels. The last row may omit its padding.
+fn has_valid_layout(width: i32, height: i32, rowstride: i32, channels: i32, len: usize) -> bool {
+    let (Ok(width), Ok(height), Ok(rowstride), Ok(channels)) = (
+        usize::try_from(width),
+        usize::try_from(height),
+        usize::try_from(rowstride),
+        usize::try_from(channels),
+    ) else {
+        return false;
+    };
+
+    let Some(row_bytes) = width.checked_mul(channels) else {
+        return false;
+    };
+
+    if width == 0 || height == 0 || rowstride < row_bytes {
+        return false;
+    }
+
+    rowstride
+        .checked_mul(height - 1)
+        .and_then(|padded| padded.checked_add(row_bytes))
+        .is_some_and(|needed| len >= needed)
+}
+
 fn strip_rowstride_padding<'a>(
     width: i32,
     channels: i32,
@@ -158,3 +195,26 @@ fn content_hash(data: &[u8]) -> u64 {
 fn path_to_string(path: &Path) -> String {
     path.to_string_lossy().into_owned()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_accepts_padded_rows_without_trailing_padding() {
+        // 2x2 RGB, rows padded to 8 bytes, last row unpadded.
+        assert!(has_valid_layout(2, 2, 8, 3, 8 + 6));
+    }
+
+    #[test]
+    fn layout_rejects_short_buffer() {
+        assert!(!has_valid_layout(2, 2, 8, 4, 15));
+    }
+
+    #[test]
+    fn layout_rejects_degenerate_dimensions() {
+        assert!(!has_valid_layout(0, 1, 0, 4, 16));
+        assert!(!has_valid_layout(-1, 1, 4, 4, 16));
+        assert!(!has_valid_layout(2, 2, 4, 4, 16));
+    }
+}
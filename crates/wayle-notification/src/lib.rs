@@ -19,8 +19,28 @@
 //! | `notifications` | `Vec<Arc<Notification>>` | All received notifications |
 //! | `popups` | `Vec<Arc<Notification>>` | Currently visible popups |
 //! | `popup_duration` | `u32` | Popup display time in ms |
-//! | `dnd` | `bool` | Do Not Disturb mode (suppresses popups) |
+//! | `dnd` | `bool` | Do Not Disturb mode (suppresses popups and sounds) |
 //! | `remove_expired` | `bool` | Auto-remove expired notifications |
+//! | `coalesce` | `bool` | Merge same-app, same-summary notifications |
+//!
+//! # Replacing Notifications
+//!
+//! A notification sent with the `replaces_id` of one the same app sent
+//! earlier updates that notification in place: its fields change, and it
+//! keeps its position in `notifications` and `popups`. With `coalesce`
+//! enabled, a notification without `replaces_id` is treated the same way
+//! when an earlier one has the same app name and summary, which keeps
+//! progress notifications from filling the list with duplicates.
+//!
+//! # Expiry
+//!
+//! With `remove_expired` enabled, a notification whose `expire_timeout` is
+//! positive closes as [`Expired`](types::ClosedReason::Expired) once that
+//! many milliseconds have passed. A timeout of 0 keeps it until dismissed,
+//! and a negative one (server default) does too.
+//! [`NotificationService::pause_expiry`] and
+//! [`NotificationService::resume_expiry`] stop and restart the countdown,
+//! so a popup can hold its notification open while hovered.
 //!
 //! # Example
 //!
@@ -43,11 +63,36 @@
 //! # }
 //! ```
 //!
+//! # Actions and Close Reasons
+//!
+//! [`NotificationService::invoke_action`] sends `ActionInvoked` back to the
+//! app, and [`NotificationService::closed_events`] yields the
+//! [`ClosedReason`](types::ClosedReason) whenever a notification is removed.
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use wayle_notification::NotificationService;
+//!
+//! # async fn example() -> Result<(), wayle_notification::Error> {
+//! let service = NotificationService::new().await?;
+//! let mut closed = service.closed_events();
+//!
+//! service.invoke_action(42, "reply").await?;
+//!
+//! while let Some((id, reason)) = closed.next().await {
+//!     println!("{id} closed: {reason:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Configuration
 //!
 //! | Method | Effect |
 //! |--------|--------|
 //! | `with_daemon()` | Control notifications from scripts or other processes |
+//! | `coalesce(bool)` | Merge same-app, same-summary notifications |
+//! | `sound_player(player)` | Play `sound-file` / `sound-name` hints |
 //!
 //! ```no_run
 //! use wayle_notification::NotificationService;
@@ -61,6 +106,16 @@
 //! # }
 //! ```
 //!
+//! # Sounds
+//!
+//! Notifications can ask for a sound with the `sound-file` or `sound-name`
+//! hint, or opt out with `suppress-sound`. The crate has no sound backend,
+//! so these stay silent until a
+//! [`NotificationSoundPlayer`](sound::NotificationSoundPlayer) is passed to
+//! `sound_player()`. The service calls it for each incoming notification
+//! that requests a sound, unless `suppress-sound` is set or Do Not Disturb
+//! is on.
+//!
 //! # D-Bus Interface
 //!
 //! When `with_daemon()` is enabled, the service registers on the session bus.
@@ -78,7 +133,9 @@ pub(crate) mod daemon;
 /// Error types.
 pub mod error;
 pub(crate) mod events;
+pub(crate) mod expiry_timer;
 mod glob;
+mod ids;
 pub(crate) mod image_cache;
 pub(crate) mod monitoring;
 pub(crate) mod persistence;
@@ -86,6 +143,8 @@ pub(crate) mod popup_timer;
 pub(crate) mod proxy;
 /// Service implementation.
 pub mod service;
+/// Pluggable playback of notification sounds.
+pub mod sound;
 /// freedesktop notification types (Urgency, ClosedReason, Capabilities, etc.).
 pub mod types;
 pub(crate) mod wayle_daemon;
@@ -1,7 +1,5 @@
 use std::{sync::Arc, time::Duration};
 
-use chrono::Utc;
-use tokio::sync::broadcast;
 use tracing::{debug, info, instrument, warn};
 use wayle_core::Property;
 use wayle_traits::ServiceMonitoring;
@@ -11,9 +9,11 @@ use crate::{
     core::notification::Notification,
     error::Error,
     events::NotificationEvent,
+    expiry_timer::ExpiryTimerManager,
     persistence::NotificationStore,
     popup_timer::PopupTimerManager,
     service::NotificationService,
+    sound::{NotificationSound, NotificationSoundPlayer},
     types::{
         ClosedReason, Signal,
         dbus::{SERVICE_INTERFACE, SERVICE_PATH},
@@ -40,8 +40,10 @@ async fn handle_notifications(service: &NotificationService) -> Result<(), Error
     let cancellation_token = service.cancellation_token.clone();
     let remove_expired = service.remove_expired.clone();
     let connection = service.connection.clone();
-    let notif_tx = service.notif_tx.clone();
+    let closed_tx = service.closed_tx.clone();
     let popup_timers = service.popup_timers.clone();
+    let expiry_timers = service.expiry_timers.clone();
+    let sound_player = service.sound_player.clone();
 
     tokio::spawn(async move {
         loop {
@@ -58,7 +60,7 @@ async fn handle_notifications(service: &NotificationService) -> Result<(), Error
                                 &notification_list,
                                 &store,
                                 &remove_expired,
-                                &notif_tx
+                                &expiry_timers,
                             );
                             handle_popup_added(
                                 &notif,
@@ -67,9 +69,11 @@ async fn handle_notifications(service: &NotificationService) -> Result<(), Error
                                 dnd.clone(),
                                 &popup_timers,
                             );
+                            play_sound(&notif, sound_player.as_ref(), &dnd);
                         }
                         NotificationEvent::Remove(id, reason) => {
-                            handle_notification_removed(
+                            expiry_timers.cancel(id);
+                            let removed = handle_notification_removed(
                                 id,
                                 reason,
                                 &notification_list,
@@ -78,6 +82,10 @@ async fn handle_notifications(service: &NotificationService) -> Result<(), Error
                                 &connection,
                                 &popup_timers,
                             ).await;
+
+                            if removed {
+                                let _ = closed_tx.send((id, reason));
+                            }
                         }
                     }
                 }
@@ -88,6 +96,21 @@ async fn handle_notifications(service: &NotificationService) -> Result<(), Error
     Ok(())
 }
 
+fn play_sound(
+    notification: &Notification,
+    player: &dyn NotificationSoundPlayer,
+    dnd: &Property<bool>,
+) {
+    if dnd.get() {
+        return;
+    }
+
+    if let Some(sound) = NotificationSound::of(notification) {
+        debug!(id = notification.id, ?sound, "playing notification sound");
+        player.play(notification, &sound);
+    }
+}
+
 fn handle_popup_added(
     incoming_popup: &Notification,
     popups: &Property<Vec<Arc<Notification>>>,
@@ -99,11 +122,19 @@ fn handle_popup_added(
         return;
     }
 
-    let incoming_popup = Arc::new(incoming_popup.clone());
     let mut list = popups.get();
-    list.retain(|popup| popup != &incoming_popup);
-    list.insert(0, incoming_popup.clone());
-    popups.replace(list);
+    let incoming_popup = match list.iter().find(|popup| popup.id == incoming_popup.id) {
+        Some(existing) => {
+            existing.update(incoming_popup);
+            existing.clone()
+        }
+        None => {
+            let popup = Arc::new(incoming_popup.clone());
+            list.insert(0, popup.clone());
+            popups.replace(list);
+            popup
+        }
+    };
 
     let default_duration = Duration::from_millis(popup_duration.get() as u64);
 
@@ -124,72 +155,47 @@ fn handle_notification_added(
     notifications: &Property<Vec<Arc<Notification>>>,
     store: &Option<NotificationStore>,
     remove_expired: &Property<bool>,
-    notif_tx: &broadcast::Sender<NotificationEvent>,
+    expiry_timers: &ExpiryTimerManager,
 ) {
     if incoming_notif.is_transient.get() {
         return;
     }
 
-    let notif_arc = Arc::new(incoming_notif.clone());
     let mut list = notifications.get();
 
-    let replaced = list
-        .iter()
-        .find(|notif| notif.id == notif_arc.id)
-        .map(|notif| (notif.id, notif.app_name.get()));
-    if let Some((replaced_id, replaced_app)) = &replaced {
-        debug!(
-            incoming_id = notif_arc.id,
-            incoming_app = ?notif_arc.app_name.get(),
-            replaced_id,
-            replaced_app = ?replaced_app,
-            "replacing existing notification"
-        );
-    } else {
-        debug!(
-            id = notif_arc.id,
-            app = ?notif_arc.app_name.get(),
-            summary = %notif_arc.summary.get(),
-            list_size = list.len(),
-            "adding new notification"
-        );
-    }
-
-    list.retain(|notif| notif.id != notif_arc.id);
-    list.insert(0, notif_arc.clone());
-
-    notifications.replace(list);
+    let notif_arc = match list.iter().find(|notif| notif.id == incoming_notif.id) {
+        Some(existing) => {
+            debug!(
+                id = existing.id,
+                app = ?existing.app_name.get(),
+                summary = %incoming_notif.summary.get(),
+                "updating existing notification in place"
+            );
+            existing.update(incoming_notif);
+            existing.clone()
+        }
+        None => {
+            debug!(
+                id = incoming_notif.id,
+                app = ?incoming_notif.app_name.get(),
+                summary = %incoming_notif.summary.get(),
+                list_size = list.len(),
+                "adding new notification"
+            );
+            let notif_arc = Arc::new(incoming_notif.clone());
+            list.insert(0, notif_arc.clone());
+            notifications.replace(list);
+            notif_arc
+        }
+    };
 
     if let Some(store) = store.as_ref() {
         let _ = store.add(incoming_notif);
     };
 
-    if !remove_expired.get() {
-        return;
+    if remove_expired.get() {
+        expiry_timers.schedule(&notif_arc);
     }
-
-    let Some(ttl) = notif_arc.expire_timeout.get() else {
-        return;
-    };
-
-    let expiration_time = notif_arc.timestamp.get() + Duration::from_millis(ttl as u64);
-    let now = Utc::now();
-
-    if expiration_time <= now {
-        let mut list = notifications.get();
-        list.retain(|notif| notif.id != notif_arc.id);
-        notifications.set(list);
-        return;
-    }
-
-    let time_until_expiration = (expiration_time - now).to_std().unwrap_or(Duration::ZERO);
-    let id = notif_arc.id;
-    let tx = notif_tx.clone();
-
-    tokio::spawn(async move {
-        tokio::time::sleep(time_until_expiration).await;
-        let _ = tx.send(NotificationEvent::Remove(id, ClosedReason::Expired));
-    });
 }
 
 async fn handle_notification_removed(
@@ -200,7 +206,7 @@ async fn handle_notification_removed(
     store: &Option<NotificationStore>,
     connection: &Connection,
     popup_timers: &Arc<PopupTimerManager>,
-) {
+) -> bool {
     if !matches!(reason, ClosedReason::Expired) {
         popup_timers.cancel(id);
 
@@ -214,7 +220,7 @@ async fn handle_notification_removed(
     notif_list.retain(|notif| notif.id != id);
 
     if notif_list.len() == prev_len {
-        return;
+        return false;
     }
 
     notifications.set(notif_list);
@@ -236,4 +242,125 @@ async fn handle_notification_removed(
     {
         warn!(id = id, error = %err, "cannot emit NotificationClosed signal");
     }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as StdError;
+
+    use futures::StreamExt;
+    use tokio::{net::UnixStream, sync::broadcast, time::timeout};
+    use tokio_util::sync::CancellationToken;
+    use zbus::{Guid, connection::Builder};
+
+    use super::*;
+    use crate::{
+        core::types::IncomingHints, daemon::NotificationDaemon, ids::NotificationIds,
+        sound::SilentSoundPlayer,
+    };
+
+    /// Two ends of a peer-to-peer connection, so notifications can be built
+    /// without a session bus. Both ends must stay alive for the test.
+    async fn peer_connections() -> Result<(Connection, Connection), Box<dyn StdError>> {
+        let (server, client) = UnixStream::pair()?;
+        let guid = Guid::generate();
+
+        let (server, client) = tokio::try_join!(
+            Builder::unix_stream(server).server(guid)?.p2p().build(),
+            Builder::unix_stream(client).p2p().build(),
+        )?;
+        Ok((server, client))
+    }
+
+    fn service(
+        connection: Connection,
+        notif_tx: broadcast::Sender<NotificationEvent>,
+    ) -> NotificationService {
+        let (closed_tx, _) = broadcast::channel(8);
+        let popups = Property::new(vec![]);
+
+        NotificationService {
+            cancellation_token: CancellationToken::new(),
+            closed_tx,
+            store: None,
+            connection,
+            notifications: Property::new(vec![]),
+            popup_timers: Arc::new(PopupTimerManager::new(popups.clone())),
+            popups,
+            popup_duration: Property::new(5000),
+            dnd: Property::new(false),
+            remove_expired: Property::new(false),
+            blocklist: Property::new(vec![]),
+            coalesce: Property::new(false),
+            expiry_timers: Arc::new(ExpiryTimerManager::new(notif_tx.clone())),
+            sound_player: Arc::new(SilentSoundPlayer),
+            notif_tx,
+        }
+    }
+
+    /// Waits briefly for `property` to satisfy `done`.
+    async fn settles<T>(property: &Property<T>, done: impl Fn(&T) -> bool) -> bool
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let mut values = Box::pin(property.watch());
+        let reached = async {
+            while let Some(value) = values.next().await {
+                if done(&value) {
+                    return true;
+                }
+            }
+            false
+        };
+
+        timeout(Duration::from_secs(1), reached)
+            .await
+            .unwrap_or(false)
+    }
+
+    #[tokio::test]
+    async fn notify_with_replaces_id_updates_the_listed_notification()
+    -> Result<(), Box<dyn StdError>> {
+        let (connection, _peer) = peer_connections().await?;
+        let (notif_tx, _) = broadcast::channel(8);
+        let service = service(connection.clone(), notif_tx.clone());
+        service.start_monitoring().await?;
+
+        let daemon = NotificationDaemon {
+            ids: NotificationIds::new(1, []),
+            zbus_connection: connection,
+            notif_tx,
+            blocklist: Property::new(vec![]),
+            coalesce: Property::new(false),
+            plays_sounds: false,
+        };
+        let notify = |replaces_id, summary: &str| {
+            daemon.notify(
+                String::from("firefox"),
+                replaces_id,
+                String::new(),
+                summary.to_owned(),
+                String::new(),
+                vec![],
+                IncomingHints::default(),
+                -1,
+            )
+        };
+
+        let first = notify(0, "Downloading 10%")?;
+        assert!(settles(&service.notifications, |list| list.len() == 1).await);
+        let listed = service.notifications.get();
+
+        let second = notify(first, "Downloading 50%")?;
+        assert_eq!(second, first);
+        assert!(settles(&listed[0].summary, |summary| summary == "Downloading 50%").await);
+
+        let notifications = service.notifications.get();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].id, first);
+        assert_eq!(service.popups.get().len(), 1);
+        Ok(())
+    }
 }
@@ -1,7 +1,9 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use derive_more::Debug;
+use futures::Stream;
 use tokio::sync::broadcast;
+use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 use tokio_util::sync::CancellationToken;
 use tracing::{instrument, warn};
 use wayle_core::Property;
@@ -9,8 +11,8 @@ use zbus::Connection;
 
 use crate::{
     builder::NotificationServiceBuilder, core::notification::Notification, error::Error,
-    events::NotificationEvent, persistence::NotificationStore, popup_timer::PopupTimerManager,
-    types::ClosedReason,
+    events::NotificationEvent, expiry_timer::ExpiryTimerManager, persistence::NotificationStore,
+    popup_timer::PopupTimerManager, sound::NotificationSoundPlayer, types::ClosedReason,
 };
 
 /// Desktop notification service. See [crate-level docs](crate) for usage.
@@ -21,6 +23,8 @@ pub struct NotificationService {
     #[debug(skip)]
     pub(crate) notif_tx: broadcast::Sender<NotificationEvent>,
     #[debug(skip)]
+    pub(crate) closed_tx: broadcast::Sender<(u32, ClosedReason)>,
+    #[debug(skip)]
     pub(crate) store: Option<NotificationStore>,
     #[debug(skip)]
     pub(crate) connection: Connection,
@@ -31,14 +35,21 @@ pub struct NotificationService {
     pub popups: Property<Vec<Arc<Notification>>>,
     /// Popup display duration in milliseconds.
     pub popup_duration: Property<u32>,
-    /// Do Not Disturb mode; suppresses popups when true.
+    /// Do Not Disturb mode; suppresses popups and sounds when true.
     pub dnd: Property<bool>,
     /// Auto-remove expired notifications.
     pub remove_expired: Property<bool>,
     /// Glob patterns for blocking notifications by app name.
     pub blocklist: Property<Vec<String>>,
+    /// Merge notifications without `replaces_id` into an earlier one from
+    /// the same app with the same summary.
+    pub coalesce: Property<bool>,
     #[debug(skip)]
     pub(crate) popup_timers: Arc<PopupTimerManager>,
+    #[debug(skip)]
+    pub(crate) expiry_timers: Arc<ExpiryTimerManager>,
+    #[debug(skip)]
+    pub(crate) sound_player: Arc<dyn NotificationSoundPlayer>,
 }
 
 impl NotificationService {
@@ -56,6 +67,41 @@ impl NotificationService {
         NotificationServiceBuilder::new()
     }
 
+    /// Invokes an action on a notification, emitting `ActionInvoked` to the
+    /// app that sent it.
+    ///
+    /// Per the spec the notification is then closed as dismissed, unless it
+    /// is marked resident.
+    ///
+    /// # Errors
+    /// Returns `Error::NotificationNotFound` if no notification has `id`, or
+    /// an error if the D-Bus signal emission fails.
+    #[instrument(skip(self), err)]
+    pub async fn invoke_action(&self, id: u32, action_key: &str) -> Result<(), Error> {
+        let notification = self
+            .notifications
+            .get()
+            .into_iter()
+            .find(|notif| notif.id == id)
+            .ok_or(Error::NotificationNotFound(id))?;
+
+        notification.invoke(action_key).await?;
+
+        if !notification.is_resident.get() {
+            notification.dismiss();
+        }
+
+        Ok(())
+    }
+
+    /// Returns a stream of `(id, reason)` for each notification that leaves
+    /// the list, mirroring the `NotificationClosed` signal sent to apps.
+    ///
+    /// Only closes that happen after subscribing are yielded.
+    pub fn closed_events(&self) -> impl Stream<Item = (u32, ClosedReason)> + Send + 'static {
+        BroadcastStream::new(self.closed_tx.subscribe()).filter_map(|result| result.ok())
+    }
+
     /// Dismisses all notifications and emits `NotificationClosed` for each.
     ///
     /// # Errors
@@ -94,6 +140,12 @@ impl NotificationService {
         self.blocklist.set(patterns)
     }
 
+    /// Sets whether notifications with the same app name and summary are
+    /// merged when the app doesn't set `replaces_id`.
+    pub fn set_coalesce(&self, coalesce: bool) {
+        self.coalesce.set(coalesce)
+    }
+
     /// Removes a popup from the visible list without affecting notification history.
     ///
     /// Cancels any running popup timer for this ID.
@@ -114,6 +166,30 @@ impl NotificationService {
     pub fn release_popup(&self, id: u32) {
         self.popup_timers.resume(id);
     }
+
+    /// Pauses the expiry countdown of a timed notification, e.g. while the
+    /// user hovers its popup.
+    ///
+    /// Time spent paused doesn't count toward `expire_timeout`.
+    pub fn pause_expiry(&self, id: u32) {
+        self.expiry_timers.pause(id);
+    }
+
+    /// Resumes the expiry countdown after [`pause_expiry`](Self::pause_expiry).
+    ///
+    /// The notification closes as expired once the rest of its timeout
+    /// elapses.
+    pub fn resume_expiry(&self, id: u32) {
+        self.expiry_timers.resume(id);
+    }
+
+    /// Time left before a notification expires.
+    ///
+    /// `None` when it has no running or paused countdown: its timeout is 0
+    /// or left to the server, or `remove_expired` is off.
+    pub fn expiry_remaining(&self, id: u32) -> Option<Duration> {
+        self.expiry_timers.remaining(id)
+    }
 }
 
 impl Drop for NotificationService {
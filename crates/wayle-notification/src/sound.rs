@@ -0,0 +1,104 @@
+use std::fmt::Debug;
+
+use crate::core::notification::Notification;
+
+/// A sound requested by a notification through its `sound-file` or
+/// `sound-name` hint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationSound {
+    /// Path to a sound file, from the `sound-file` hint.
+    File(String),
+    /// Themeable sound name from the freedesktop sound naming spec, such as
+    /// `message-new-instant`, from the `sound-name` hint.
+    Name(String),
+}
+
+impl NotificationSound {
+    /// The sound `notification` asks for, or `None` when it names no sound
+    /// or sets `suppress-sound`.
+    ///
+    /// `sound-file` wins over `sound-name` when both are present, since it
+    /// is the more specific of the two.
+    pub fn of(notification: &Notification) -> Option<Self> {
+        Self::from_hints(
+            notification.sound_file.get(),
+            notification.sound_name.get(),
+            notification.suppress_sound.get(),
+        )
+    }
+
+    fn from_hints(file: Option<String>, name: Option<String>, suppress: bool) -> Option<Self> {
+        if suppress {
+            return None;
+        }
+
+        let non_empty = |value: Option<String>| value.filter(|value| !value.is_empty());
+
+        non_empty(file)
+            .map(Self::File)
+            .or_else(|| non_empty(name).map(Self::Name))
+    }
+}
+
+/// Plays the sounds notifications ask for.
+///
+/// The service has no sound backend of its own. Implement this on top of
+/// libcanberra, PulseAudio or PipeWire and pass it to
+/// [`NotificationServiceBuilder::sound_player`](crate::NotificationServiceBuilder::sound_player)
+/// to make notifications audible. The service only calls it for
+/// notifications that aren't suppressed and while Do Not Disturb is off.
+pub trait NotificationSoundPlayer: Debug + Send + Sync {
+    /// Plays `sound` for `notification`.
+    ///
+    /// Called from the service's event loop, so it should hand the sound off
+    /// to the backend and return without waiting for playback to finish.
+    fn play(&self, notification: &Notification, sound: &NotificationSound);
+}
+
+/// Player that ignores every sound. Used when no player is configured.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SilentSoundPlayer;
+
+impl NotificationSoundPlayer for SilentSoundPlayer {
+    fn play(&self, _notification: &Notification, _sound: &NotificationSound) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sound(file: Option<&str>, name: Option<&str>, suppress: bool) -> Option<NotificationSound> {
+        NotificationSound::from_hints(file.map(String::from), name.map(String::from), suppress)
+    }
+
+    #[test]
+    fn file_is_preferred_over_name() {
+        assert_eq!(
+            sound(Some("/tmp/ding.oga"), Some("message-new-instant"), false),
+            Some(NotificationSound::File(String::from("/tmp/ding.oga")))
+        );
+    }
+
+    #[test]
+    fn name_is_used_without_file() {
+        assert_eq!(
+            sound(None, Some("message-new-instant"), false),
+            Some(NotificationSound::Name(String::from("message-new-instant")))
+        );
+        assert_eq!(
+            sound(Some(""), Some("bell"), false),
+            Some(NotificationSound::Name(String::from("bell")))
+        );
+    }
+
+    #[test]
+    fn suppress_sound_wins() {
+        assert_eq!(sound(Some("/tmp/ding.oga"), Some("bell"), true), None);
+    }
+
+    #[test]
+    fn no_hints_means_no_sound() {
+        assert_eq!(sound(None, None, false), None);
+        assert_eq!(sound(Some(""), Some(""), false), None);
+    }
+}
@@ -3,15 +3,17 @@ mod monitoring;
 #[doc(hidden)]
 pub mod types;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use controls::PowerProfilesController;
 use derive_more::Debug;
 use futures::{Stream, StreamExt};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
 use tokio_util::sync::CancellationToken;
 pub(crate) use types::LivePowerProfilesParams;
 use types::{PowerProfilesParams, PowerProfilesProps};
-use wayle_core::{Property, unwrap_dbus};
+use wayle_core::{ComputedProperty, Property, unwrap_dbus};
 use wayle_traits::{ModelMonitoring, Reactive};
 use zbus::Connection;
 
@@ -19,7 +21,8 @@ use super::{
     error::Error,
     proxy::power_profiles::PowerProfilesProxy,
     types::profile::{
-        HoldCookie, PerformanceDegradationReason, PowerProfile, Profile, ProfileHold,
+        HoldCookie, PerformanceDegradationReason, PowerProfile, Profile, ProfileChangeSource,
+        ProfileHold,
     },
 };
 
@@ -30,11 +33,15 @@ pub struct PowerProfiles {
     cancellation_token: Option<CancellationToken>,
     #[debug(skip)]
     zbus_connection: Connection,
+    #[debug(skip)]
+    requested_profile: Arc<Mutex<Option<PowerProfile>>>,
+    #[debug(skip)]
+    profile_change_tx: broadcast::Sender<(PowerProfile, ProfileChangeSource)>,
     /// Currently active profile.
     pub active_profile: Property<PowerProfile>,
     /// Performance degradation reason, if any.
     pub performance_degraded: Property<PerformanceDegradationReason>,
-    /// Available profiles with driver info.
+    /// Profiles this hardware supports, with display names and icons.
     pub profiles: Property<Vec<Profile>>,
     /// Daemon-supported actions.
     pub actions: Property<Vec<String>>,
@@ -78,7 +85,29 @@ impl PowerProfiles {
     /// # Errors
     /// Returns error if profile setting fails.
     pub async fn set_active_profile(&self, profile: PowerProfile) -> Result<(), Error> {
-        PowerProfilesController::set_active_profile(&self.zbus_connection, profile).await
+        if profile != self.active_profile.get() {
+            self.set_requested_profile(Some(profile));
+        }
+
+        let result =
+            PowerProfilesController::set_active_profile(&self.zbus_connection, profile).await;
+        if result.is_err() {
+            self.set_requested_profile(None);
+        }
+
+        result
+    }
+
+    /// The entry of [`profiles`](Self::profiles) for the active profile,
+    /// e.g. to show its name and icon. `None` until the daemon lists it.
+    pub fn active_profile_info(&self) -> ComputedProperty<Option<Profile>> {
+        self.active_profile
+            .combine(&self.profiles, |active, profiles| {
+                profiles
+                    .iter()
+                    .find(|profile| profile.profile == *active)
+                    .cloned()
+            })
     }
 
     /// Forces the passed profile to be activated until either the caller quits,
@@ -110,6 +139,40 @@ impl PowerProfiles {
         Ok(stream.filter_map(|signal| async move { signal.args().ok().map(|args| args.cookie) }))
     }
 
+    /// Returns a stream of active profile changes, each with what caused it.
+    ///
+    /// Changes requested through [`set_active_profile()`](Self::set_active_profile)
+    /// are [`UserRequested`](ProfileChangeSource::UserRequested). Otherwise a
+    /// change to a profile some application holds is
+    /// [`HoldApplied`](ProfileChangeSource::HoldApplied), and anything else,
+    /// such as ppd switching on battery or another client, is
+    /// [`SystemAutomatic`](ProfileChangeSource::SystemAutomatic).
+    ///
+    /// Only live instances emit changes.
+    pub fn profile_changes(&self) -> impl Stream<Item = (PowerProfile, ProfileChangeSource)> {
+        BroadcastStream::new(self.profile_change_tx.subscribe())
+            .filter_map(|change| async move { change.ok() })
+    }
+
+    /// Takes the pending [`set_active_profile()`](Self::set_active_profile)
+    /// request, if any.
+    pub(crate) fn take_requested_profile(&self) -> Option<PowerProfile> {
+        self.requested_profile
+            .lock()
+            .ok()
+            .and_then(|mut requested| requested.take())
+    }
+
+    fn set_requested_profile(&self, profile: Option<PowerProfile>) {
+        if let Ok(mut requested) = self.requested_profile.lock() {
+            *requested = profile;
+        }
+    }
+
+    pub(crate) fn emit_profile_change(&self, profile: PowerProfile, source: ProfileChangeSource) {
+        let _ = self.profile_change_tx.send((profile, source));
+    }
+
     async fn from_connection(connection: &Connection) -> Result<PowerProfilesProps, Error> {
         let proxy = PowerProfilesProxy::new(connection).await?;
 
@@ -135,9 +198,13 @@ impl PowerProfiles {
         connection: &Connection,
         cancellation_token: Option<CancellationToken>,
     ) -> Self {
+        let (profile_change_tx, _) = broadcast::channel(16);
+
         Self {
             zbus_connection: connection.clone(),
             cancellation_token,
+            requested_profile: Arc::new(Mutex::new(None)),
+            profile_change_tx,
             active_profile: Property::new(PowerProfile::from(props.active_profile.as_str())),
             performance_degraded: Property::new(PerformanceDegradationReason::from(
                 props.performance_degraded.as_str(),
@@ -10,7 +10,9 @@ use super::PowerProfiles;
 use crate::{
     error::Error,
     proxy::power_profiles::PowerProfilesProxy,
-    types::profile::{PerformanceDegradationReason, PowerProfile, Profile, ProfileHold},
+    types::profile::{
+        PerformanceDegradationReason, PowerProfile, Profile, ProfileChangeSource, ProfileHold,
+    },
 };
 
 const PPD_BUS_NAME: &str = "org.freedesktop.UPower.PowerProfiles";
@@ -59,7 +61,18 @@ async fn monitor_property_changes(
                 Some(change) = active_profile_changed.next() => {
                     if let Ok(new_profile) = change.get().await {
                         let profile = PowerProfile::from(new_profile.as_str());
+                        let changed = power_profiles.active_profile.get() != profile;
                         power_profiles.active_profile.set(profile);
+
+                        if changed {
+                            let holds = current_holds(&proxy, &power_profiles);
+                            let source = ProfileChangeSource::classify(
+                                profile,
+                                power_profiles.take_requested_profile(),
+                                &holds,
+                            );
+                            power_profiles.emit_profile_change(profile, source);
+                        }
                     }
                 }
 
@@ -100,6 +113,22 @@ async fn monitor_property_changes(
     Ok(())
 }
 
+/// Holds as of the signal that carried the profile change. ppd updates
+/// `ActiveProfileHolds` in the same PropertiesChanged, so the proxy cache
+/// already has them even if the holds stream hasn't been polled yet.
+fn current_holds(
+    proxy: &PowerProfilesProxy<'_>,
+    power_profiles: &PowerProfiles,
+) -> Vec<ProfileHold> {
+    match proxy.cached_active_profile_holds() {
+        Ok(Some(raw_holds)) => raw_holds
+            .into_iter()
+            .filter_map(|hold| ProfileHold::try_from(hold).ok())
+            .collect(),
+        _ => power_profiles.active_profile_holds.get(),
+    }
+}
+
 async fn monitor_daemon_lifecycle(
     weak_power_profiles: Weak<PowerProfiles>,
     connection: &zbus::Connection,
@@ -14,7 +14,7 @@
 //!
 //! // List available profiles
 //! for p in service.power_profiles.profiles.get() {
-//!     println!("  {} (driver: {})", p.profile, p.driver);
+//!     println!("  {} [{}] (driver: {})", p.display_name, p.icon_name, p.driver);
 //! }
 //! # Ok(())
 //! # }
@@ -69,7 +69,10 @@
 //! |-------|------|-------------|
 //! | `active_profile` | [`PowerProfile`](types::profile::PowerProfile) | Currently active profile |
 //! | `performance_degraded` | [`PerformanceDegradationReason`](types::profile::PerformanceDegradationReason) | Why performance is degraded |
-//! | `profiles` | `Vec<Profile>` | Available profiles and their drivers |
+//! | `profiles` | `Vec<Profile>` | Available profiles with display names, icons and drivers |
+//!
+//! [`active_profile_info()`](PowerProfiles::active_profile_info) pairs
+//! `active_profile` with its [`Profile`](types::profile::Profile) entry.
 //! | `actions` | `Vec<String>` | Daemon-supported actions |
 //! | `active_profile_holds` | `Vec<ProfileHold>` | Applications holding a profile |
 //!
@@ -104,6 +107,12 @@
 //! # Control Methods
 //!
 //! - [`set_active_profile()`](PowerProfiles::set_active_profile) - Switch power profile
+//!
+//! # Change Attribution
+//!
+//! [`profile_changes()`](PowerProfiles::profile_changes) yields each profile
+//! switch with a [`ProfileChangeSource`](types::profile::ProfileChangeSource),
+//! so a UI can say why it happened (e.g. "switched to power-saver on battery").
 
 mod builder;
 mod error;
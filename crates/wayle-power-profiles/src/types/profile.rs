@@ -34,6 +34,27 @@ impl From<&str> for PowerProfile {
     }
 }
 
+impl PowerProfile {
+    /// Human-readable name, e.g. "Power Saver".
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::PowerSaver => "Power Saver",
+            Self::Balanced => "Balanced",
+            Self::Performance => "Performance",
+            Self::Unknown => "Unknown",
+        }
+    }
+
+    /// Symbolic icon name from the freedesktop `power-profile-*` set.
+    pub fn icon_name(&self) -> &'static str {
+        match self {
+            Self::PowerSaver => "power-profile-power-saver-symbolic",
+            Self::Balanced | Self::Unknown => "power-profile-balanced-symbolic",
+            Self::Performance => "power-profile-performance-symbolic",
+        }
+    }
+}
+
 impl Display for PowerProfile {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -80,15 +101,55 @@ impl Display for PerformanceDegradationReason {
     }
 }
 
-/// Profile information with driver details.
+/// A profile the daemon offers on this hardware, ready for display.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Profile {
+    /// Profile name as the daemon reports it, e.g. `power-saver`.
+    pub id: String,
+    /// Human-readable name, e.g. "Power Saver". Profiles this crate doesn't
+    /// know are named after their ID.
+    pub display_name: String,
+    /// Symbolic icon name for the profile.
+    pub icon_name: String,
     /// Driver name providing this profile
     pub driver: String,
     /// The power profile type
     pub profile: PowerProfile,
 }
 
+impl Profile {
+    pub(crate) fn new(id: String, driver: String) -> Self {
+        let profile = PowerProfile::from(id.as_str());
+        let display_name = match profile {
+            PowerProfile::Unknown => humanize(&id),
+            known => known.display_name().to_string(),
+        };
+
+        Self {
+            display_name,
+            icon_name: profile.icon_name().to_string(),
+            id,
+            driver,
+            profile,
+        }
+    }
+}
+
+/// Turns an ID such as `low-power` into "Low Power".
+fn humanize(id: &str) -> String {
+    id.split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 impl TryFrom<HashMap<String, OwnedValue>> for Profile {
     type Error = Error;
 
@@ -102,7 +163,7 @@ impl TryFrom<HashMap<String, OwnedValue>> for Profile {
             })?
             .clone();
 
-        let profile_str = dict
+        let id = dict
             .get("Profile")
             .and_then(|v| v.downcast_ref::<String>().ok())
             .ok_or_else(|| Error::InvalidFieldType {
@@ -110,9 +171,7 @@ impl TryFrom<HashMap<String, OwnedValue>> for Profile {
                 expected: "String".to_string(),
             })?;
 
-        let profile = PowerProfile::from(profile_str.as_str());
-
-        Ok(Profile { driver, profile })
+        Ok(Profile::new(id, driver))
     }
 }
 
@@ -167,6 +226,49 @@ impl TryFrom<HashMap<String, OwnedValue>> for ProfileHold {
     }
 }
 
+/// Why the active profile changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileChangeSource {
+    /// Requested through [`PowerProfiles::set_active_profile`](crate::PowerProfiles::set_active_profile).
+    UserRequested,
+    /// An application is holding the new profile.
+    HoldApplied,
+    /// Changed by the daemon or another client, e.g. ppd switching to
+    /// power-saver on battery.
+    SystemAutomatic,
+}
+
+impl ProfileChangeSource {
+    /// Attributes a change to `profile`. `requested` is the profile last
+    /// asked for through this service and `holds` are the holds active
+    /// alongside the change.
+    pub(crate) fn classify(
+        profile: PowerProfile,
+        requested: Option<PowerProfile>,
+        holds: &[ProfileHold],
+    ) -> Self {
+        if requested == Some(profile) {
+            return Self::UserRequested;
+        }
+
+        if holds.iter().any(|hold| hold.profile == profile) {
+            return Self::HoldApplied;
+        }
+
+        Self::SystemAutomatic
+    }
+}
+
+impl Display for ProfileChangeSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::UserRequested => write!(f, "user-requested"),
+            Self::HoldApplied => write!(f, "hold-applied"),
+            Self::SystemAutomatic => write!(f, "system-automatic"),
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::panic)]
 mod tests {
@@ -174,7 +276,9 @@ mod tests {
 
     use zbus::zvariant::{OwnedValue, Str};
 
-    use super::{PerformanceDegradationReason, PowerProfile, Profile, ProfileHold};
+    use super::{
+        PerformanceDegradationReason, PowerProfile, Profile, ProfileChangeSource, ProfileHold,
+    };
     use crate::error::Error;
 
     #[test]
@@ -261,6 +365,19 @@ mod tests {
         let profile = result.unwrap();
         assert_eq!(profile.driver, "platform_profile");
         assert_eq!(profile.profile, PowerProfile::Performance);
+        assert_eq!(profile.id, "performance");
+        assert_eq!(profile.display_name, "Performance");
+        assert_eq!(profile.icon_name, "power-profile-performance-symbolic");
+    }
+
+    #[test]
+    fn profile_new_names_unknown_profiles_after_their_id() {
+        let profile = Profile::new(String::from("low-power"), String::from("amd_pmf"));
+
+        assert_eq!(profile.profile, PowerProfile::Unknown);
+        assert_eq!(profile.id, "low-power");
+        assert_eq!(profile.display_name, "Low Power");
+        assert_eq!(profile.icon_name, "power-profile-balanced-symbolic");
     }
 
     #[test]
@@ -418,4 +535,45 @@ mod tests {
             assert_eq!(original, parsed, "Round-trip failed for {:?}", original);
         }
     }
+
+    fn hold(profile: PowerProfile) -> ProfileHold {
+        ProfileHold {
+            application_id: String::from("org.example.Game"),
+            profile,
+            reason: String::from("playing"),
+        }
+    }
+
+    #[test]
+    fn change_source_prefers_user_request() {
+        let source = ProfileChangeSource::classify(
+            PowerProfile::Performance,
+            Some(PowerProfile::Performance),
+            &[hold(PowerProfile::Performance)],
+        );
+
+        assert_eq!(source, ProfileChangeSource::UserRequested);
+    }
+
+    #[test]
+    fn change_source_detects_hold() {
+        let source = ProfileChangeSource::classify(
+            PowerProfile::Performance,
+            Some(PowerProfile::Balanced),
+            &[hold(PowerProfile::Performance)],
+        );
+
+        assert_eq!(source, ProfileChangeSource::HoldApplied);
+    }
+
+    #[test]
+    fn change_source_falls_back_to_automatic() {
+        let source = ProfileChangeSource::classify(
+            PowerProfile::PowerSaver,
+            None,
+            &[hold(PowerProfile::Performance)],
+        );
+
+        assert_eq!(source, ProfileChangeSource::SystemAutomatic);
+    }
 }
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use zbus::Connection;
+
+use crate::{
+    error::Error,
+    proxy::{manager::ManagerProxy, session::SessionProxy},
+    service::SessionService,
+};
+
+/// Builder for configuring and creating a SessionService instance.
+#[derive(Default)]
+pub struct SessionServiceBuilder {
+    lock_command: Option<String>,
+}
+
+impl SessionServiceBuilder {
+    /// Creates a new SessionServiceBuilder with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Locks the session by running `command` (e.g. `hyprlock`) through
+    /// `sh -c` instead of asking logind to lock it.
+    ///
+    /// Useful when no idle daemon is listening for logind's `Lock` signal.
+    pub fn with_lock_command(mut self, command: impl Into<String>) -> Self {
+        self.lock_command = Some(command.into());
+        self
+    }
+
+    /// Builds the SessionService.
+    ///
+    /// # Errors
+    /// Returns error if the system D-Bus connection or proxy creation fails.
+    pub async fn build(self) -> Result<Arc<SessionService>, Error> {
+        let connection = Connection::system().await.map_err(|err| {
+            Error::ServiceInitializationFailed(format!("System D-Bus connection failed: {err}"))
+        })?;
+
+        let manager = ManagerProxy::new(&connection).await?;
+        let session = SessionProxy::new(&connection).await?;
+
+        Ok(Arc::new(SessionService {
+            manager,
+            session,
+            lock_command: self.lock_command,
+        }))
+    }
+}
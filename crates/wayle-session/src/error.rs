@@ -0,0 +1,36 @@
+use crate::types::PowerAction;
+
+/// Session service errors
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// D-Bus communication error
+    #[error("D-Bus operation failed: {0}")]
+    DbusError(
+        #[from]
+        #[source]
+        zbus::Error,
+    ),
+
+    /// Service initialization failed
+    #[error("cannot initialize session service: {0}")]
+    ServiceInitializationFailed(String),
+
+    /// The system cannot perform the action at all, e.g. hibernation without
+    /// a swap device or a hypervisor that does not support suspend.
+    #[error("cannot {0}: not supported on this system")]
+    NotSupported(PowerAction),
+
+    /// Polkit denied the action for this user.
+    #[error("cannot {0}: not authorized")]
+    NotAuthorized(PowerAction),
+
+    /// The configured lock command could not be started.
+    #[error("cannot run lock command '{command}': {source}")]
+    LockCommandFailed {
+        /// Command line that failed to start.
+        command: String,
+        /// Underlying spawn error.
+        #[source]
+        source: std::io::Error,
+    },
+}
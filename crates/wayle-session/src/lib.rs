@@ -0,0 +1,64 @@
+//! Power and session actions via systemd-logind D-Bus.
+//!
+//! # Quick Start
+//!
+//! ```rust,no_run
+//! use wayle_session::{SessionService, types::PowerAction};
+//!
+//! # async fn example() -> Result<(), wayle_session::Error> {
+//! let session = SessionService::new().await?;
+//!
+//! // Only offer what this machine and user can do
+//! for action in session.available_actions().await {
+//!     println!("{action}");
+//! }
+//!
+//! session.perform(PowerAction::Suspend).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Errors
+//!
+//! Power actions fail with [`Error::NotSupported`] when the system cannot do
+//! them at all (e.g. hibernation without swap) and [`Error::NotAuthorized`]
+//! when polkit denies them, so callers can tell the two apart.
+//!
+//! # Locking
+//!
+//! By default [`lock()`](SessionService::lock) asks logind to lock the
+//! session, which relies on an idle daemon (hypridle, swayidle) to start the
+//! locker. To run a locker directly:
+//!
+//! ```rust,no_run
+//! use wayle_session::SessionService;
+//!
+//! # async fn example() -> Result<(), wayle_session::Error> {
+//! let session = SessionService::builder()
+//!     .with_lock_command("hyprlock")
+//!     .build()
+//!     .await?;
+//!
+//! session.lock().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Control Methods
+//!
+//! - [`capability()`](SessionService::capability) - logind's `CanSuspend`, `CanHibernate`, ...
+//! - [`available_actions()`](SessionService::available_actions) - Actions worth showing
+//! - [`perform()`](SessionService::perform) - Suspend, hibernate, power off or reboot
+//! - [`lock()`](SessionService::lock) - Lock the session
+
+mod builder;
+mod error;
+mod proxy;
+mod service;
+
+/// Power action and capability types.
+pub mod types;
+
+pub use builder::SessionServiceBuilder;
+pub use error::Error;
+pub use service::SessionService;
@@ -0,0 +1,24 @@
+use zbus::{Result, proxy};
+
+#[proxy(
+    interface = "org.freedesktop.login1.Manager",
+    default_service = "org.freedesktop.login1",
+    default_path = "/org/freedesktop/login1"
+)]
+pub(crate) trait Manager {
+    async fn power_off(&self, interactive: bool) -> Result<()>;
+
+    async fn reboot(&self, interactive: bool) -> Result<()>;
+
+    async fn suspend(&self, interactive: bool) -> Result<()>;
+
+    async fn hibernate(&self, interactive: bool) -> Result<()>;
+
+    async fn can_power_off(&self) -> Result<String>;
+
+    async fn can_reboot(&self) -> Result<String>;
+
+    async fn can_suspend(&self) -> Result<String>;
+
+    async fn can_hibernate(&self) -> Result<String>;
+}
@@ -0,0 +1,2 @@
+pub(crate) mod manager;
+pub(crate) mod session;
@@ -0,0 +1,10 @@
+use zbus::{Result, proxy};
+
+#[proxy(
+    interface = "org.freedesktop.login1.Session",
+    default_service = "org.freedesktop.login1",
+    default_path = "/org/freedesktop/login1/session/auto"
+)]
+pub(crate) trait Session {
+    async fn lock(&self) -> Result<()>;
+}
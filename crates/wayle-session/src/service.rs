@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use derive_more::Debug;
+use tokio::process::Command;
+use tracing::instrument;
+
+use crate::{
+    builder::SessionServiceBuilder,
+    error::Error,
+    proxy::{manager::ManagerProxy, session::SessionProxy},
+    types::{Capability, PowerAction},
+};
+
+const ACCESS_DENIED: &str = "org.freedesktop.DBus.Error.AccessDenied";
+const INTERACTIVE_AUTH_REQUIRED: &str =
+    "org.freedesktop.DBus.Error.InteractiveAuthorizationRequired";
+const NOT_SUPPORTED: &str = "org.freedesktop.DBus.Error.NotSupported";
+
+/// Entry point for logind power and session actions. See [crate-level docs](crate).
+#[derive(Debug)]
+pub struct SessionService {
+    #[debug(skip)]
+    pub(crate) manager: ManagerProxy<'static>,
+    #[debug(skip)]
+    pub(crate) session: SessionProxy<'static>,
+    pub(crate) lock_command: Option<String>,
+}
+
+impl SessionService {
+    /// Creates a service that locks through logind.
+    ///
+    /// To lock with a custom command, use [`Self::builder()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the system D-Bus connection fails.
+    #[instrument]
+    pub async fn new() -> Result<Arc<Self>, Error> {
+        Self::builder().build().await
+    }
+
+    /// Returns a builder for advanced configuration.
+    pub fn builder() -> SessionServiceBuilder {
+        SessionServiceBuilder::new()
+    }
+
+    /// Asks logind whether `action` is possible for this user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::DbusError` if logind cannot be queried.
+    #[instrument(skip(self), err)]
+    pub async fn capability(&self, action: PowerAction) -> Result<Capability, Error> {
+        let answer = match action {
+            PowerAction::Suspend => self.manager.can_suspend().await?,
+            PowerAction::Hibernate => self.manager.can_hibernate().await?,
+            PowerAction::PowerOff => self.manager.can_power_off().await?,
+            PowerAction::Reboot => self.manager.can_reboot().await?,
+        };
+
+        Ok(Capability::from(answer.as_str()))
+    }
+
+    /// Actions the user can request, for building a power menu.
+    ///
+    /// Actions whose capability cannot be queried are left out.
+    pub async fn available_actions(&self) -> Vec<PowerAction> {
+        let mut available = Vec::with_capacity(PowerAction::ALL.len());
+
+        for action in PowerAction::ALL {
+            if let Ok(capability) = self.capability(action).await
+                && capability.is_available()
+            {
+                available.push(action);
+            }
+        }
+
+        available
+    }
+
+    /// Requests `action` from logind, letting polkit prompt if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotSupported` if the system cannot perform `action`.
+    /// Returns `Error::NotAuthorized` if the user may not perform `action`.
+    /// Returns `Error::DbusError` if the request fails otherwise.
+    #[instrument(skip(self), err)]
+    pub async fn perform(&self, action: PowerAction) -> Result<(), Error> {
+        match self.capability(action).await? {
+            Capability::NotApplicable => return Err(Error::NotSupported(action)),
+            Capability::No => return Err(Error::NotAuthorized(action)),
+            Capability::Yes | Capability::Challenge => {}
+        }
+
+        let result = match action {
+            PowerAction::Suspend => self.manager.suspend(true).await,
+            PowerAction::Hibernate => self.manager.hibernate(true).await,
+            PowerAction::PowerOff => self.manager.power_off(true).await,
+            PowerAction::Reboot => self.manager.reboot(true).await,
+        };
+
+        result.map_err(|err| denial_error(action, err))
+    }
+
+    /// Suspends the system. See [`perform`](Self::perform).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`perform`](Self::perform).
+    pub async fn suspend(&self) -> Result<(), Error> {
+        self.perform(PowerAction::Suspend).await
+    }
+
+    /// Hibernates the system. See [`perform`](Self::perform).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`perform`](Self::perform).
+    pub async fn hibernate(&self) -> Result<(), Error> {
+        self.perform(PowerAction::Hibernate).await
+    }
+
+    /// Shuts the system down. See [`perform`](Self::perform).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`perform`](Self::perform).
+    pub async fn power_off(&self) -> Result<(), Error> {
+        self.perform(PowerAction::PowerOff).await
+    }
+
+    /// Reboots the system. See [`perform`](Self::perform).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`perform`](Self::perform).
+    pub async fn reboot(&self) -> Result<(), Error> {
+        self.perform(PowerAction::Reboot).await
+    }
+
+    /// Locks the current session.
+    ///
+    /// Runs the configured lock command if one was set, without waiting for
+    /// it to exit (lockers run until unlocked). Otherwise asks logind to lock
+    /// the session, which notifies whichever locker is listening.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::LockCommandFailed` if the lock command cannot start.
+    /// Returns `Error::DbusError` if the logind request fails.
+    #[instrument(skip(self), err)]
+    pub async fn lock(&self) -> Result<(), Error> {
+        let Some(command) = self.lock_command.as_deref() else {
+            return Ok(self.session.lock().await?);
+        };
+
+        Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .spawn()
+            .map(drop)
+            .map_err(|source| Error::LockCommandFailed {
+                command: command.to_owned(),
+                source,
+            })
+    }
+}
+
+fn denial_error(action: PowerAction, err: zbus::Error) -> Error {
+    let zbus::Error::MethodError(name, _, _) = &err else {
+        return Error::DbusError(err);
+    };
+
+    match denial_kind(name.as_str()) {
+        Some(Denial::Unauthorized) => Error::NotAuthorized(action),
+        Some(Denial::Unsupported) => Error::NotSupported(action),
+        None => Error::DbusError(err),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Denial {
+    Unauthorized,
+    Unsupported,
+}
+
+fn denial_kind(error_name: &str) -> Option<Denial> {
+    match error_name {
+        ACCESS_DENIED | INTERACTIVE_AUTH_REQUIRED => Some(Denial::Unauthorized),
+        NOT_SUPPORTED => Some(Denial::Unsupported),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polkit_denials_map_to_unauthorized() {
+        assert_eq!(denial_kind(ACCESS_DENIED), Some(Denial::Unauthorized));
+        assert_eq!(
+            denial_kind(INTERACTIVE_AUTH_REQUIRED),
+            Some(Denial::Unauthorized)
+        );
+    }
+
+    #[test]
+    fn not_supported_is_distinct_from_other_errors() {
+        assert_eq!(denial_kind(NOT_SUPPORTED), Some(Denial::Unsupported));
+        assert_eq!(
+            denial_kind("org.freedesktop.login1.OperationInProgress"),
+            None
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::{self, Display, Formatter};
+
+/// System power transition handled by logind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerAction {
+    /// Suspend to RAM.
+    Suspend,
+    /// Suspend to disk.
+    Hibernate,
+    /// Shut the machine down.
+    PowerOff,
+    /// Restart the machine.
+    Reboot,
+}
+
+impl PowerAction {
+    /// Every action, in the order a power menu usually lists them.
+    pub const ALL: [Self; 4] = [Self::Suspend, Self::Hibernate, Self::Reboot, Self::PowerOff];
+}
+
+impl Display for PowerAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Suspend => write!(f, "suspend"),
+            Self::Hibernate => write!(f, "hibernate"),
+            Self::PowerOff => write!(f, "power off"),
+            Self::Reboot => write!(f, "reboot"),
+        }
+    }
+}
+
+/// Answer to logind's `CanSuspend`, `CanHibernate`, `CanPowerOff` and
+/// `CanReboot` queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// Allowed without further authentication.
+    Yes,
+    /// Allowed after polkit authentication.
+    Challenge,
+    /// Supported, but the user is not allowed to do it.
+    No,
+    /// Not supported by the hardware or configuration.
+    NotApplicable,
+}
+
+impl Capability {
+    /// Whether the action can be requested, possibly behind a polkit prompt.
+    pub fn is_available(self) -> bool {
+        matches!(self, Self::Yes | Self::Challenge)
+    }
+}
+
+impl From<&str> for Capability {
+    fn from(value: &str) -> Self {
+        match value {
+            "yes" => Self::Yes,
+            "challenge" => Self::Challenge,
+            "no" => Self::No,
+            _ => Self::NotApplicable,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capability_parses_logind_answers() {
+        assert_eq!(Capability::from("yes"), Capability::Yes);
+        assert_eq!(Capability::from("challenge"), Capability::Challenge);
+        assert_eq!(Capability::from("no"), Capability::No);
+        assert_eq!(Capability::from("na"), Capability::NotApplicable);
+        assert_eq!(Capability::from(""), Capability::NotApplicable);
+    }
+
+    #[test]
+    fn only_yes_and_challenge_are_available() {
+        assert!(Capability::Yes.is_available());
+        assert!(Capability::Challenge.is_available());
+        assert!(!Capability::No.is_available());
+        assert!(!Capability::NotApplicable.is_available());
+    }
+}
@@ -26,6 +26,7 @@ bar-window-title-empty = Desktop
 ## Idle Inhibit
 bar-idle-inhibit-on = On
 bar-idle-inhibit-off = Off
+bar-idle-inhibit-tooltip = Keeping the display awake: { $reasons }
 
 ## Keybind Mode
 bar-keybind-mode-default = default
@@ -0,0 +1,5 @@
+### Brightness Dropdown
+
+dropdown-brightness-title = Brightness
+dropdown-brightness-no-device = No backlight found
+dropdown-brightness-no-permission = Brightness can't be changed. Add your user to the video group or make sure systemd-logind is running.
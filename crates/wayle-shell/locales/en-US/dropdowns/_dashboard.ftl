@@ -53,5 +53,7 @@ dropdown-dashboard-temp = CPU Temp
 ## User Session
 dropdown-dashboard-lock = Lock
 dropdown-dashboard-logout = Log Out
+dropdown-dashboard-suspend = Suspend
+dropdown-dashboard-hibernate = Hibernate
 dropdown-dashboard-reboot = Reboot
 dropdown-dashboard-power-off = Power Off
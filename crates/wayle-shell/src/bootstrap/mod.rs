@@ -19,21 +19,23 @@ use wayle_brightness::BrightnessService;
 use wayle_config::{ConfigService, infrastructure::schema};
 use wayle_core::{DeferredService, Property};
 use wayle_hyprland::HyprlandService;
+use wayle_idle_inhibit::IdleInhibitRegistry;
 use wayle_ipc::shell::APP_ID;
 use wayle_media::MediaService;
 use wayle_network::NetworkService;
 use wayle_notification::NotificationService;
 use wayle_power_profiles::PowerProfilesService;
+use wayle_session::SessionService;
 use wayle_sysinfo::SysinfoService;
 use wayle_systray::{SystemTrayService, types::TrayMode};
 use wayle_wallpaper::WallpaperService;
 use zbus::{Connection, fdo::DBusProxy};
 
 use crate::{
-    services::{IdleInhibitService, ShellIpcService},
+    services::{ColorSchemeService, IdleInhibitService, ShellIpcService},
     shell::ShellServices,
     startup::StartupTimer,
-    watchers::build_extractor_config,
+    watchers::{build_extractor_config, systray_sort_policy},
 };
 
 async fn spawned<T, E: Display>(handle: JoinHandle<Result<T, E>>) -> Result<T, String> {
@@ -83,6 +85,7 @@ struct DaemonServices {
 
 struct OptionalServices {
     hyprland: Option<Arc<HyprlandService>>,
+    session: Option<Arc<SessionService>>,
 }
 
 pub async fn is_already_running() -> bool {
@@ -156,10 +159,13 @@ pub async fn init_services() -> Result<(StartupTimer, ShellServices), Box<dyn Er
         battery: core.battery,
         bluetooth,
         brightness: core.brightness,
+        color_scheme: ColorSchemeService::start().scheme(),
         config: config_service,
         hyprland: optional.hyprland,
         power_profiles,
+        session: optional.session,
         idle_inhibit: core.idle_inhibit,
+        idle_inhibit_registry: IdleInhibitRegistry::default(),
         media: daemons.media,
         network: core.network,
         notification: daemons.notification,
@@ -231,10 +237,12 @@ async fn init_core_services(
 
 async fn init_optional_services(timer: &StartupTimer) -> OptionalServices {
     let hyprland_task = tokio::spawn(HyprlandService::new());
+    let session_task = tokio::spawn(SessionService::new());
 
     let hyprland = timer.time("Hyprland", spawned(hyprland_task)).await.ok();
+    let session = try_service!(timer, "Session", spawned(session_task), no_wrap);
 
-    OptionalServices { hyprland }
+    OptionalServices { hyprland, session }
 }
 
 fn spawn_deferred_bluetooth(property: DeferredService<BluetoothService>) {
@@ -292,12 +300,14 @@ async fn init_daemon_services(
         NotificationService::builder()
             .with_daemon()
             .blocklist(blocklist)
+            .coalesce(modules.notification.coalesce.get())
             .build(),
     );
     let systray_task = tokio::spawn(
         SystemTrayService::builder()
             .with_daemon()
             .mode(TrayMode::Auto)
+            .sort_policy(systray_sort_policy(&modules.systray))
             .build(),
     );
 
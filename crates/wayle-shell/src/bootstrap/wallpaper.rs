@@ -27,7 +27,12 @@ pub(super) async fn build_wallpaper_service(
     };
 
     let t = Instant::now();
+    // The config below is the source of truth for the shell, so restoring
+    // the saved session first would render twice and could start cycling
+    // that the config has turned off. With restore off, the service doesn't
+    // write the session state file either.
     let service = WallpaperService::builder()
+        .restore_on_start(false)
         .transition(transition)
         .theming_monitor(theming_monitor)
         .color_extractor(color_extractor)
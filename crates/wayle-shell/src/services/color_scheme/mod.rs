@@ -0,0 +1,98 @@
+//! System light/dark preference from the settings portal.
+
+mod proxy;
+
+use futures::StreamExt;
+use proxy::SettingsProxy;
+use tracing::{debug, warn};
+use wayle_config::schemas::styling::ColorScheme;
+use wayle_core::Property;
+use zbus::{Connection, zvariant::Value};
+
+const NAMESPACE: &str = "org.freedesktop.appearance";
+const KEY: &str = "color-scheme";
+
+/// Tracks the portal's `org.freedesktop.appearance color-scheme` setting.
+///
+/// The preference stays [`ColorScheme::NoPreference`] when no settings
+/// portal is running.
+pub struct ColorSchemeService {
+    scheme: Property<ColorScheme>,
+}
+
+impl ColorSchemeService {
+    /// Starts following the portal setting in the background.
+    pub fn start() -> Self {
+        let scheme = Property::new(ColorScheme::default());
+
+        let watched = scheme.clone();
+        tokio::spawn(async move {
+            if let Err(err) = follow_portal(&watched).await {
+                warn!(error = %err, "cannot follow system color scheme");
+            }
+        });
+
+        Self { scheme }
+    }
+
+    /// Returns the shared preference for consumers to watch.
+    pub fn scheme(&self) -> Property<ColorScheme> {
+        self.scheme.clone()
+    }
+}
+
+async fn follow_portal(scheme: &Property<ColorScheme>) -> zbus::Result<()> {
+    let connection = Connection::session().await?;
+    let settings = SettingsProxy::new(&connection).await?;
+
+    let mut changes = settings.receive_setting_changed().await?;
+    let initial = settings.read_one(NAMESPACE, KEY).await?;
+    scheme.set(color_scheme(&initial));
+
+    while let Some(signal) = changes.next().await {
+        let Ok(args) = signal.args() else {
+            continue;
+        };
+        if args.namespace() != &NAMESPACE || args.key() != &KEY {
+            continue;
+        }
+
+        let preference = color_scheme(args.value());
+        debug!(?preference, "system color scheme changed");
+        scheme.set(preference);
+    }
+
+    Ok(())
+}
+
+/// Reads the portal's `u32` value, which some portals wrap in an extra
+/// variant.
+fn color_scheme(value: &Value<'_>) -> ColorScheme {
+    match value {
+        Value::Value(inner) => color_scheme(inner),
+        Value::U32(raw) => ColorScheme::from_portal_value(*raw),
+        _ => ColorScheme::NoPreference,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_plain_and_wrapped_values() {
+        assert_eq!(color_scheme(&Value::U32(2)), ColorScheme::PreferLight);
+        assert_eq!(
+            color_scheme(&Value::Value(Box::new(Value::U32(1)))),
+            ColorScheme::PreferDark
+        );
+    }
+
+    #[test]
+    fn unexpected_types_mean_no_preference() {
+        assert_eq!(
+            color_scheme(&Value::from("dark")),
+            ColorScheme::NoPreference
+        );
+    }
+}
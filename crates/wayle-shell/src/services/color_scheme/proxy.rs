@@ -0,0 +1,18 @@
+//! Settings portal D-Bus proxy.
+
+use zbus::{
+    proxy,
+    zvariant::{OwnedValue, Value},
+};
+
+#[proxy(
+    interface = "org.freedesktop.portal.Settings",
+    default_service = "org.freedesktop.portal.Desktop",
+    default_path = "/org/freedesktop/portal/desktop"
+)]
+pub(super) trait Settings {
+    async fn read_one(&self, namespace: &str, key: &str) -> zbus::Result<OwnedValue>;
+
+    #[zbus(signal)]
+    fn setting_changed(&self, namespace: &str, key: &str, value: Value<'_>) -> zbus::Result<()>;
+}
@@ -1,7 +1,9 @@
 //! Shell-specific services that run alongside the UI.
 
+pub mod color_scheme;
 pub mod idle_inhibit;
 pub mod shell_ipc;
 
+pub use color_scheme::ColorSchemeService;
 pub use idle_inhibit::IdleInhibitService;
 pub use shell_ipc::ShellIpcService;
@@ -30,8 +30,9 @@ impl DevicePicker {
                 let Some(device) = self.cached_output_devices.get(index).cloned() else {
                     return;
                 };
+                let audio = self.audio.clone();
                 sender.command(|_out, _shutdown| async move {
-                    if let Err(err) = device.set_as_default().await {
+                    if let Err(err) = audio.set_default_output(&device, true).await {
                         warn!(error = %err, "failed to set default output");
                     }
                 });
@@ -9,6 +9,7 @@ pub(super) struct AppVolumeInit {
     pub icon: Option<String>,
     pub volume: f64,
     pub muted: bool,
+    pub corked: bool,
     pub stream_index: u32,
 }
 
@@ -16,13 +17,18 @@ pub(super) struct AppVolumeItem {
     pub name: String,
     pub icon: Option<String>,
     pub muted: bool,
+    pub corked: bool,
     pub stream_index: u32,
     slider: DebouncedSlider,
 }
 
 #[derive(Debug)]
 pub(super) enum AppVolumeItemMsg {
-    SetBackendState { volume: f64, muted: bool },
+    SetBackendState {
+        volume: f64,
+        muted: bool,
+        corked: bool,
+    },
     VolumeCommitted(f64),
     ToggleMute,
 }
@@ -47,6 +53,8 @@ impl FactoryComponent for AppVolumeItem {
             set_orientation: gtk::Orientation::Vertical,
             #[watch]
             set_class_active: ("audio-muted", self.muted),
+            #[watch]
+            set_class_active: ("audio-paused", self.corked),
 
             gtk::Box {
                 add_css_class: "audio-app-header",
@@ -107,6 +115,7 @@ impl FactoryComponent for AppVolumeItem {
             name: init.name,
             icon: init.icon,
             muted: init.muted,
+            corked: init.corked,
             stream_index: init.stream_index,
             slider: DebouncedSlider::new(init.volume),
         }
@@ -139,9 +148,14 @@ impl FactoryComponent for AppVolumeItem {
 
     fn update(&mut self, msg: Self::Input, sender: FactorySender<Self>) {
         match msg {
-            AppVolumeItemMsg::SetBackendState { volume, muted } => {
+            AppVolumeItemMsg::SetBackendState {
+                volume,
+                muted,
+                corked,
+            } => {
                 self.slider.set_value(volume);
                 self.muted = muted;
+                self.corked = corked;
             }
             AppVolumeItemMsg::VolumeCommitted(volume) => {
                 let _ = sender.output(AppVolumeItemOutput::VolumeChanged(
@@ -71,12 +71,14 @@ impl AppVolumes {
                 let icon = Self::resolve_stream_icon(&props, icon_source);
                 let volume = stream.volume.get().average_percentage();
                 let muted = stream.muted.get();
+                let corked = stream.corked.get();
 
                 Some(AppVolumeInit {
                     name,
                     icon,
                     volume,
                     muted,
+                    corked,
                     stream_index: stream.key.index,
                 })
             })
@@ -113,6 +115,7 @@ impl AppVolumes {
                 AppVolumeItemMsg::SetBackendState {
                     volume: stream.volume.get().average_percentage(),
                     muted: stream.muted.get(),
+                    corked: stream.corked.get(),
                 },
             );
         }
@@ -39,11 +39,12 @@ pub(super) fn spawn_per_stream(
         let stream_index = stream.key.index;
         let volume = stream.volume.clone();
         let muted = stream.muted.clone();
+        let corked = stream.corked.clone();
         watch_cancellable_throttled!(
             sender,
             token.clone(),
             VOLUME_THROTTLE,
-            [volume.watch(), muted.watch()],
+            [volume.watch(), muted.watch(), corked.watch()],
             |out| {
                 let _ = out.send(AppVolumesCmd::AppStreamPropertyChanged(stream_index));
             }
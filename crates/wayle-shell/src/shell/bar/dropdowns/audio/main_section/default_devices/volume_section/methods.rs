@@ -33,6 +33,7 @@ impl VolumeSection {
         self.device_icon = device.trigger_icon();
         self.slider.set_value(device.volume_percentage());
         self.muted = device.muted();
+        self.slider.set_muted(self.muted);
     }
 
     pub(super) fn resume_device_watcher(&mut self, sender: &ComponentSender<Self>) {
@@ -4,7 +4,7 @@ mod watchers;
 
 use std::sync::Arc;
 
-use gtk::{glib, prelude::*};
+use gtk::prelude::*;
 use relm4::{gtk, prelude::*};
 use wayle_audio::AudioService;
 use wayle_widgets::{WatcherToken, prelude::DebouncedSlider};
@@ -174,6 +174,7 @@ impl Component for VolumeSection {
         };
 
         let slider = DebouncedSlider::with_label(volume);
+        slider.set_muted(muted);
         if let Some(scale) = slider.scale() {
             scale.add_css_class("audio-volume-slider");
         }
@@ -182,13 +183,9 @@ impl Component for VolumeSection {
         }
 
         let commit_sender = sender.input_sender().clone();
-        slider.connect_closure(
-            "committed",
-            false,
-            glib::closure_local!(move |_slider: DebouncedSlider, percentage: f64| {
-                commit_sender.emit(VolumeSectionInput::VolumeCommitted(percentage));
-            }),
-        );
+        slider.connect_committed(move |_, percentage| {
+            commit_sender.emit(VolumeSectionInput::VolumeCommitted(percentage));
+        });
 
         watchers::spawn_default_device(&sender, &init.audio, init.kind);
 
@@ -256,6 +253,7 @@ impl Component for VolumeSection {
                 if let Some(ref device) = self.device {
                     self.slider.set_value(device.volume_percentage());
                     self.muted = device.muted();
+                    self.slider.set_muted(self.muted);
                 }
             }
         }
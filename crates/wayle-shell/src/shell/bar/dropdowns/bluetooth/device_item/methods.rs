@@ -23,6 +23,7 @@ impl DeviceItem {
                 != snapshot
                     .battery
                     .map(|percent| t!("dropdown-bluetooth-battery", percent = percent))
+            || self.codec != snapshot.codec.map(|codec| codec.to_string())
     }
 
     pub(crate) fn update_from_snapshot(&mut self, snapshot: DeviceSnapshot) {
@@ -44,6 +45,7 @@ impl DeviceItem {
             .battery
             .map(|percent| t!("dropdown-bluetooth-battery", percent = percent));
         self.battery_icon = snapshot.battery.map(battery_level_icon);
+        self.codec = snapshot.codec.map(|codec| codec.to_string());
         self.icon = snapshot.icon;
         self.connected = snapshot.connected;
         self.paired = snapshot.paired;
@@ -20,6 +20,7 @@ pub(crate) struct DeviceItem {
     device_type: String,
     battery_text: Option<String>,
     battery_icon: Option<&'static str>,
+    codec: Option<String>,
     icon: &'static str,
 
     connected: bool,
@@ -142,6 +143,30 @@ impl FactoryComponent for DeviceItem {
                                 .as_deref()
                                 .unwrap_or_default(),
                     },
+
+                    #[name = "codec_separator"]
+                    gtk::Label {
+                        add_css_class:
+                            "bluetooth-detail-separator",
+                        set_label: DETAIL_SEPARATOR,
+                        #[watch]
+                        set_visible:
+                            self.codec.is_some(),
+                    },
+
+                    #[name = "codec_label"]
+                    gtk::Label {
+                        add_css_class:
+                            "bluetooth-device-detail",
+                        #[watch]
+                        set_visible:
+                            self.codec.is_some(),
+                        #[watch]
+                        set_label:
+                            self.codec
+                                .as_deref()
+                                .unwrap_or_default(),
+                    },
                 },
             },
 
@@ -239,12 +264,14 @@ impl FactoryComponent for DeviceItem {
             .battery
             .map(|percent| t!("dropdown-bluetooth-battery", percent = percent));
         let battery_icon = snapshot.battery.map(battery_level_icon);
+        let codec = snapshot.codec.map(|codec| codec.to_string());
 
         Self {
             name: snapshot.name,
             device_type,
             battery_text,
             battery_icon,
+            codec,
             icon: snapshot.icon,
             connected: snapshot.connected,
             paired: snapshot.paired,
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use wayle_bluetooth::core::device::Device;
+use wayle_bluetooth::{core::device::Device, types::device::BluetoothCodec};
 
 const MAJOR_COMPUTER: u32 = 0x01;
 const MAJOR_PHONE: u32 = 0x02;
@@ -336,6 +336,7 @@ pub(crate) struct DeviceSnapshot {
     pub icon: &'static str,
     pub device_type_key: &'static str,
     pub battery: Option<u8>,
+    pub codec: Option<BluetoothCodec>,
     pub connected: bool,
     pub paired: bool,
     pub category: DeviceCategory,
@@ -371,6 +372,7 @@ pub(crate) fn categorize_device(device: &Arc<Device>) -> Option<DeviceSnapshot>
         icon: device_icon(icon_hint.as_deref(), class),
         device_type_key: device_type_key(icon_hint.as_deref(), class),
         battery: device.battery_percentage.get(),
+        codec: device.active_codec.get(),
         connected,
         paired,
         category,
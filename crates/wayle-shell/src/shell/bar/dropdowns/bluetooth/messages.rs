@@ -16,6 +16,7 @@ pub(crate) enum BluetoothDropdownMsg {
     ScanRequested,
     DeviceAction(DeviceActionMsg),
     PairingCard(PairingCardOutput),
+    VisibilityChanged(bool),
 }
 
 #[derive(Debug)]
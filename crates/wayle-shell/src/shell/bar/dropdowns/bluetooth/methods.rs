@@ -1,6 +1,6 @@
 use relm4::{factory::FactoryVecDequeGuard, gtk, prelude::*};
-use tracing::warn;
-use wayle_bluetooth::types::agent::PairingRequest;
+use tracing::{debug, warn};
+use wayle_bluetooth::{Error, types::agent::PairingRequest};
 use zbus::zvariant::OwnedObjectPath;
 
 use super::{
@@ -116,28 +116,34 @@ impl BluetoothDropdown {
             | DeviceActionMsg::Forget(path) => path.clone(),
         };
 
+        let connect_cancel = self.connect_cancel.clone();
+
         sender.command(move |out, _shutdown| async move {
             let Ok(device) = bluetooth.device(path.clone()).await else {
                 let _ = out.send(BluetoothDropdownCmd::DeviceActionFailed(path));
                 return;
             };
 
-            let action_fut = async {
-                match action {
-                    DeviceActionMsg::Connect(_) => device.connect().await,
-                    DeviceActionMsg::Disconnect(_) => device.disconnect().await,
-                    DeviceActionMsg::Forget(_) => device.forget().await,
+            let result = match action {
+                DeviceActionMsg::Connect(_) => {
+                    device
+                        .connect_cancellable(ACTION_TIMEOUT, &connect_cancel)
+                        .await
+                }
+                DeviceActionMsg::Disconnect(_) => {
+                    with_timeout("disconnect device", device.disconnect()).await
                 }
+                DeviceActionMsg::Forget(_) => with_timeout("forget device", device.forget()).await,
             };
 
-            match tokio::time::timeout(ACTION_TIMEOUT, action_fut).await {
-                Ok(Ok(())) => {}
-                Ok(Err(err)) => {
-                    warn!(error = %err, "bluetooth device action failed");
+            match result {
+                Ok(()) => {}
+                Err(Error::Cancelled { .. }) => {
+                    debug!("bluetooth connect cancelled");
                     let _ = out.send(BluetoothDropdownCmd::DeviceActionFailed(path));
                 }
-                Err(_elapsed) => {
-                    warn!("bluetooth device action timed out");
+                Err(err) => {
+                    warn!(error = %err, "bluetooth device action failed");
                     let _ = out.send(BluetoothDropdownCmd::DeviceActionFailed(path));
                 }
             }
@@ -239,6 +245,18 @@ impl BluetoothDropdown {
     }
 }
 
+async fn with_timeout(
+    operation: &'static str,
+    action: impl Future<Output = Result<(), Error>>,
+) -> Result<(), Error> {
+    tokio::time::timeout(ACTION_TIMEOUT, action)
+        .await
+        .unwrap_or(Err(Error::Timeout {
+            operation,
+            timeout: ACTION_TIMEOUT,
+        }))
+}
+
 fn reconcile_list(
     guard: &mut FactoryVecDequeGuard<'_, DeviceItem>,
     new_snapshots: &[DeviceSnapshot],
@@ -10,6 +10,7 @@ use std::{sync::Arc, time::Duration};
 
 use gtk::prelude::*;
 use relm4::{gtk, prelude::*};
+use tokio_util::sync::CancellationToken;
 use wayle_bluetooth::BluetoothService;
 use wayle_widgets::{WatcherToken, prelude::*};
 
@@ -39,6 +40,7 @@ pub(crate) struct BluetoothDropdown {
     state_watcher: WatcherToken,
     device_watcher: WatcherToken,
     scan_token: WatcherToken,
+    connect_cancel: CancellationToken,
 }
 
 #[relm4::component(pub(crate))]
@@ -329,7 +331,7 @@ impl Component for BluetoothDropdown {
 
     fn init(
         init: Self::Init,
-        _root: Self::Root,
+        root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
         let my_devices = Self::build_device_list(&sender);
@@ -357,8 +359,16 @@ impl Component for BluetoothDropdown {
             state_watcher: WatcherToken::new(),
             device_watcher: WatcherToken::new(),
             scan_token: WatcherToken::new(),
+            connect_cancel: CancellationToken::new(),
         };
 
+        let input_sender = sender.input_sender().clone();
+        root.connect_visible_notify(move |popover| {
+            input_sender.emit(BluetoothDropdownMsg::VisibilityChanged(
+                popover.is_visible(),
+            ));
+        });
+
         let pairing_card_widget = model.pairing_card.widget();
         let my_devices_widget = model.my_devices.widget();
         let available_devices_widget = model.available_devices.widget();
@@ -384,6 +394,12 @@ impl Component for BluetoothDropdown {
             BluetoothDropdownMsg::PairingCard(output) => {
                 self.handle_pairing_output(output, &sender);
             }
+
+            BluetoothDropdownMsg::VisibilityChanged(visible) => {
+                if !visible {
+                    std::mem::take(&mut self.connect_cancel).cancel();
+                }
+            }
         }
     }
 
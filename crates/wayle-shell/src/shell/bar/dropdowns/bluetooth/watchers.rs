@@ -69,6 +69,7 @@ pub(super) fn spawn_device_watchers(
         let name = device.name.clone();
         let alias = device.alias.clone();
         let battery = device.battery_percentage.clone();
+        let codec = device.active_codec.clone();
 
         watch_cancellable!(
             sender,
@@ -78,7 +79,8 @@ pub(super) fn spawn_device_watchers(
                 paired.watch(),
                 name.watch(),
                 alias.watch(),
-                battery.watch()
+                battery.watch(),
+                codec.watch()
             ],
             |out| {
                 let _ = out.send(BluetoothDropdownCmd::DevicePropertyChanged);
@@ -0,0 +1,22 @@
+use relm4::prelude::*;
+
+use super::{BrightnessDropdown, messages::BrightnessDropdownInit};
+use crate::shell::{
+    bar::dropdowns::{DropdownFactory, DropdownInstance},
+    services::ShellServices,
+};
+
+pub(crate) struct Factory;
+
+impl DropdownFactory for Factory {
+    fn create(services: &ShellServices) -> Option<DropdownInstance> {
+        let brightness = services.brightness.clone()?;
+        let config = services.config.clone();
+
+        let init = BrightnessDropdownInit { brightness, config };
+        let controller = BrightnessDropdown::builder().launch(init).detach();
+
+        let popover = controller.widget().clone();
+        Some(DropdownInstance::new(popover, Box::new(controller)))
+    }
+}
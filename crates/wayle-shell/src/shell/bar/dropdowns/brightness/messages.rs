@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use wayle_brightness::{BacklightDevice, BrightnessService};
+use wayle_config::ConfigService;
+
+pub(crate) struct BrightnessDropdownInit {
+    pub brightness: Arc<BrightnessService>,
+    pub config: Arc<ConfigService>,
+}
+
+#[derive(Debug)]
+pub(crate) enum BrightnessDropdownInput {
+    BrightnessCommitted(f64),
+}
+
+#[derive(Debug)]
+pub(crate) enum BrightnessDropdownCmd {
+    ScaleChanged(f32),
+    DeviceChanged(Option<Arc<BacklightDevice>>),
+    BrightnessChanged,
+    PermissionDenied,
+}
@@ -0,0 +1,50 @@
+use relm4::prelude::*;
+use tracing::warn;
+use wayle_brightness::Percentage;
+
+use super::{BrightnessDropdown, messages::BrightnessDropdownCmd};
+
+impl BrightnessDropdown {
+    pub(super) fn sync_from_device(&mut self) {
+        match self.device {
+            Some(ref device) => {
+                self.device_name = device.name.to_string();
+                self.slider.set_value(device.percentage().value());
+            }
+            None => self.device_name.clear(),
+        }
+    }
+
+    pub(super) fn resume_device_watcher(&mut self, sender: &ComponentSender<Self>) {
+        let Some(ref device) = self.device else {
+            return;
+        };
+        let token = self.device_watcher.reset();
+        super::watchers::spawn_device(sender, device, token);
+    }
+
+    pub(super) fn commit_brightness(&self, percentage: f64, sender: &ComponentSender<Self>) {
+        let Some(ref device) = self.device else {
+            return;
+        };
+
+        let device = device.clone();
+        sender.oneshot_command(async move {
+            match device.set_percentage(Percentage::new(percentage)).await {
+                Ok(()) => BrightnessDropdownCmd::BrightnessChanged,
+                Err(err) if err.is_permission_denied() => {
+                    warn!(
+                        error = %err,
+                        device = %device.name,
+                        "no permission to set brightness"
+                    );
+                    BrightnessDropdownCmd::PermissionDenied
+                }
+                Err(err) => {
+                    warn!(error = %err, device = %device.name, "failed to set brightness");
+                    BrightnessDropdownCmd::BrightnessChanged
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,211 @@
+mod factory;
+mod messages;
+mod methods;
+mod watchers;
+
+use std::sync::Arc;
+
+use gtk::prelude::*;
+use relm4::{gtk, prelude::*};
+use wayle_brightness::BacklightDevice;
+use wayle_widgets::{WatcherToken, prelude::*};
+
+pub(super) use self::factory::Factory;
+use self::messages::{BrightnessDropdownCmd, BrightnessDropdownInit, BrightnessDropdownInput};
+use crate::{i18n::t, shell::bar::dropdowns::scaled_dimension};
+
+const BASE_WIDTH: f32 = 340.0;
+const BASE_HEIGHT: f32 = 120.0;
+
+/// Lowest selectable percentage, so the slider can't switch the panel off.
+const MIN_PERCENTAGE: f64 = 1.0;
+const MAX_PERCENTAGE: f64 = 100.0;
+const STEP: f64 = 1.0;
+
+pub(crate) struct BrightnessDropdown {
+    scaled_width: i32,
+    scaled_height: i32,
+    device: Option<Arc<BacklightDevice>>,
+    device_name: String,
+    permission_denied: bool,
+    slider: DebouncedSlider,
+    device_watcher: WatcherToken,
+}
+
+#[relm4::component(pub(crate))]
+impl Component for BrightnessDropdown {
+    type Init = BrightnessDropdownInit;
+    type Input = BrightnessDropdownInput;
+    type Output = ();
+    type CommandOutput = BrightnessDropdownCmd;
+
+    view! {
+        #[root]
+        gtk::Popover {
+            set_css_classes: &["dropdown", "brightness-dropdown"],
+            set_has_arrow: false,
+            #[watch]
+            set_width_request: model.scaled_width,
+            #[watch]
+            set_height_request: model.scaled_height,
+
+            #[template]
+            Dropdown {
+
+                #[template]
+                DropdownHeader {
+                    #[template_child]
+                    icon {
+                        set_visible: true,
+                        set_icon_name: Some("ld-sun-symbolic"),
+                    },
+                    #[template_child]
+                    label {
+                        set_label: &t!("dropdown-brightness-title"),
+                    },
+                    #[template_child]
+                    actions {
+                        set_visible: false,
+                    },
+                },
+
+                #[template]
+                DropdownContent {
+                    set_vexpand: true,
+
+                    gtk::Box {
+                        add_css_class: "brightness-device",
+                        set_orientation: gtk::Orientation::Vertical,
+                        #[watch]
+                        set_visible: model.device.is_some(),
+
+                        gtk::Label {
+                            add_css_class: "brightness-device-name",
+                            set_halign: gtk::Align::Start,
+                            set_ellipsize: gtk::pango::EllipsizeMode::End,
+                            #[watch]
+                            set_label: &model.device_name,
+                        },
+
+                        gtk::Box {
+                            add_css_class: "brightness-slider-row",
+
+                            #[local_ref]
+                            slider_widget -> gtk::Box {
+                                #[watch]
+                                set_sensitive: !model.permission_denied,
+                            },
+                        },
+
+                        gtk::Label {
+                            add_css_class: "brightness-no-permission",
+                            set_halign: gtk::Align::Start,
+                            set_wrap: true,
+                            #[watch]
+                            set_visible: model.permission_denied,
+                            set_label: &t!("dropdown-brightness-no-permission"),
+                        },
+                    },
+
+                    gtk::Box {
+                        add_css_class: "brightness-no-device",
+                        set_halign: gtk::Align::Center,
+                        #[watch]
+                        set_visible: model.device.is_none(),
+
+                        gtk::Image {
+                            add_css_class: "brightness-no-device-icon",
+                            set_icon_name: Some("tb-alert-triangle-symbolic"),
+                        },
+                        gtk::Label {
+                            add_css_class: "brightness-no-device-label",
+                            set_label: &t!("dropdown-brightness-no-device"),
+                        },
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        _root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let device = init.brightness.primary.get();
+        let initial = device
+            .as_ref()
+            .map(|device| device.percentage().value())
+            .unwrap_or(MAX_PERCENTAGE);
+
+        let slider = DebouncedSlider::with_label(initial);
+        slider.set_range(MIN_PERCENTAGE, MAX_PERCENTAGE, STEP);
+        if let Some(scale) = slider.scale() {
+            scale.add_css_class("brightness-slider");
+        }
+        if let Some(label) = slider.label_widget() {
+            label.add_css_class("brightness-slider-value");
+        }
+
+        let commit_sender = sender.input_sender().clone();
+        slider.connect_committed(move |_, percentage| {
+            commit_sender.emit(BrightnessDropdownInput::BrightnessCommitted(percentage));
+        });
+
+        let scale = init.config.config().styling.scale.get().value();
+        watchers::spawn(&sender, &init.config, &init.brightness);
+
+        let mut model = Self {
+            scaled_width: scaled_dimension(BASE_WIDTH, scale),
+            scaled_height: scaled_dimension(BASE_HEIGHT, scale),
+            device,
+            device_name: String::new(),
+            permission_denied: false,
+            slider,
+            device_watcher: WatcherToken::new(),
+        };
+
+        model.sync_from_device();
+        model.resume_device_watcher(&sender);
+
+        let slider_widget = model.slider.upcast_ref::<gtk::Box>();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, _root: &Self::Root) {
+        match msg {
+            BrightnessDropdownInput::BrightnessCommitted(percentage) => {
+                self.commit_brightness(percentage, &sender);
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        msg: BrightnessDropdownCmd,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        match msg {
+            BrightnessDropdownCmd::ScaleChanged(scale) => {
+                self.scaled_width = scaled_dimension(BASE_WIDTH, scale);
+                self.scaled_height = scaled_dimension(BASE_HEIGHT, scale);
+            }
+            BrightnessDropdownCmd::DeviceChanged(device) => {
+                self.device = device;
+                self.permission_denied = false;
+                self.sync_from_device();
+                self.resume_device_watcher(&sender);
+            }
+            BrightnessDropdownCmd::BrightnessChanged => {
+                self.sync_from_device();
+            }
+            BrightnessDropdownCmd::PermissionDenied => {
+                self.permission_denied = true;
+                self.sync_from_device();
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::{sync::Arc, time::Duration};
+
+use relm4::ComponentSender;
+use tokio_util::sync::CancellationToken;
+use wayle_brightness::{BacklightDevice, BrightnessService};
+use wayle_config::ConfigService;
+use wayle_widgets::{watch, watch_cancellable_throttled};
+
+use super::{BrightnessDropdown, messages::BrightnessDropdownCmd};
+
+const BRIGHTNESS_THROTTLE: Duration = Duration::from_millis(30);
+
+pub(super) fn spawn(
+    sender: &ComponentSender<BrightnessDropdown>,
+    config: &Arc<ConfigService>,
+    brightness: &Arc<BrightnessService>,
+) {
+    let scale = config.config().styling.scale.clone();
+    watch!(sender, [scale.watch()], |out| {
+        let _ = out.send(BrightnessDropdownCmd::ScaleChanged(scale.get().value()));
+    });
+
+    let primary = brightness.primary.clone();
+    watch!(sender, [primary.watch()], |out| {
+        let _ = out.send(BrightnessDropdownCmd::DeviceChanged(primary.get()));
+    });
+}
+
+pub(super) fn spawn_device(
+    sender: &ComponentSender<BrightnessDropdown>,
+    device: &Arc<BacklightDevice>,
+    token: CancellationToken,
+) {
+    let brightness = device.brightness.clone();
+    watch_cancellable_throttled!(
+        sender,
+        token,
+        BRIGHTNESS_THROTTLE,
+        [brightness.watch()],
+        |out| {
+            let _ = out.send(BrightnessDropdownCmd::BrightnessChanged);
+        }
+    );
+}
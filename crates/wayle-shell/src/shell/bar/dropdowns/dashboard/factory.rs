@@ -19,6 +19,7 @@ impl DropdownFactory for Factory {
             network: services.network.clone(),
             notification: services.notification.clone(),
             power_profiles: services.power_profiles.clone(),
+            session: services.session.clone(),
             sysinfo: services.sysinfo.clone(),
             idle_inhibit: services.idle_inhibit.clone(),
         };
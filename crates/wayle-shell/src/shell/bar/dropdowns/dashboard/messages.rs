@@ -9,6 +9,7 @@ use wayle_media::MediaService;
 use wayle_network::NetworkService;
 use wayle_notification::NotificationService;
 use wayle_power_profiles::PowerProfilesService;
+use wayle_session::SessionService;
 use wayle_sysinfo::SysinfoService;
 
 use crate::services::IdleInhibitService;
@@ -22,6 +23,7 @@ pub(crate) struct DashboardDropdownInit {
     pub network: Option<Arc<NetworkService>>,
     pub notification: Option<Arc<NotificationService>>,
     pub power_profiles: DeferredService<PowerProfilesService>,
+    pub session: Option<Arc<SessionService>>,
     pub sysinfo: Arc<SysinfoService>,
     pub idle_inhibit: Arc<IdleInhibitService>,
 }
@@ -159,6 +159,7 @@ impl Component for DashboardDropdown {
             .launch(UserSessionInit {
                 username,
                 config: init.config.clone(),
+                session: init.session.clone(),
             })
             .detach();
 
@@ -1,16 +1,20 @@
 use std::sync::Arc;
 
 use wayle_config::ConfigService;
+use wayle_session::{SessionService, types::PowerAction};
 
 pub(crate) struct UserSessionInit {
     pub username: String,
     pub config: Arc<ConfigService>,
+    pub session: Option<Arc<SessionService>>,
 }
 
 #[derive(Debug)]
 pub(crate) enum UserSessionInput {
     Lock,
     Logout,
+    Suspend,
+    Hibernate,
     Reboot,
     PowerOff,
 }
@@ -18,4 +22,5 @@ pub(crate) enum UserSessionInput {
 #[derive(Debug)]
 pub(crate) enum UserSessionCmd {
     FaceChanged(bool),
+    ActionsAvailable(Vec<PowerAction>),
 }
@@ -0,0 +1,50 @@
+use tracing::warn;
+use wayle_session::types::PowerAction;
+
+use super::UserSessionSection;
+use crate::process;
+
+impl UserSessionSection {
+    pub(super) fn is_available(&self, action: PowerAction) -> bool {
+        self.available_actions.contains(&action)
+    }
+
+    /// A configured command always gets its button. Without one, the
+    /// button is only shown when logind allows the action.
+    pub(super) fn shows_power_button(&self, action: PowerAction) -> bool {
+        !self.command_for(action).is_empty() || self.is_available(action)
+    }
+
+    /// Runs the configured command for `action`, or asks logind when none
+    /// is set.
+    pub(super) fn run_or_perform(&self, action: PowerAction) {
+        let command = self.command_for(action);
+        if command.is_empty() {
+            self.perform(action);
+        } else {
+            process::run_if_set(&command);
+        }
+    }
+
+    pub(super) fn perform(&self, action: PowerAction) {
+        let Some(session) = self.session.clone() else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            if let Err(err) = session.perform(action).await {
+                warn!(error = %err, %action, "power action failed");
+            }
+        });
+    }
+
+    fn command_for(&self, action: PowerAction) -> String {
+        let dashboard = &self.config.config().modules.dashboard;
+
+        match action {
+            PowerAction::Reboot => dashboard.dropdown_reboot_command.get(),
+            PowerAction::PowerOff => dashboard.dropdown_poweroff_command.get(),
+            PowerAction::Suspend | PowerAction::Hibernate => String::new(),
+        }
+    }
+}
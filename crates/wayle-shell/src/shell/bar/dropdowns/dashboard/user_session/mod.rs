@@ -1,4 +1,5 @@
 mod messages;
+mod methods;
 mod watchers;
 
 use std::{env, path::PathBuf, sync::Arc};
@@ -9,6 +10,7 @@ use gtk::{
 };
 use relm4::{gtk, prelude::*};
 use wayle_config::ConfigService;
+use wayle_session::{SessionService, types::PowerAction};
 use wayle_widgets::prelude::IconButton;
 
 pub(crate) use self::messages::UserSessionInit;
@@ -21,6 +23,8 @@ pub(crate) struct UserSessionSection {
     face_path: PathBuf,
     face_css_provider: CssProvider,
     config: Arc<ConfigService>,
+    session: Option<Arc<SessionService>>,
+    available_actions: Vec<PowerAction>,
 }
 
 impl UserSessionSection {
@@ -118,11 +122,41 @@ impl Component for UserSessionSection {
                         },
                     },
 
+                    #[template]
+                    #[name = "suspend_btn"]
+                    IconButton {
+                        add_css_class: "session-btn",
+                        set_tooltip_text: Some(&t!("dropdown-dashboard-suspend")),
+                        #[watch]
+                        set_visible: model.is_available(PowerAction::Suspend),
+                        connect_clicked => UserSessionInput::Suspend,
+
+                        gtk::Image {
+                            set_icon_name: Some("ld-moon-symbolic"),
+                        },
+                    },
+
+                    #[template]
+                    #[name = "hibernate_btn"]
+                    IconButton {
+                        add_css_class: "session-btn",
+                        set_tooltip_text: Some(&t!("dropdown-dashboard-hibernate")),
+                        #[watch]
+                        set_visible: model.is_available(PowerAction::Hibernate),
+                        connect_clicked => UserSessionInput::Hibernate,
+
+                        gtk::Image {
+                            set_icon_name: Some("ld-snowflake-symbolic"),
+                        },
+                    },
+
                     #[template]
                     #[name = "reboot_btn"]
                     IconButton {
                         add_css_class: "session-btn",
                         set_tooltip_text: Some(&t!("dropdown-dashboard-reboot")),
+                        #[watch]
+                        set_visible: model.shows_power_button(PowerAction::Reboot),
                         connect_clicked => UserSessionInput::Reboot,
 
                         gtk::Image {
@@ -135,6 +169,8 @@ impl Component for UserSessionSection {
                     IconButton {
                         set_css_classes: &["icon", "session-btn", "danger"],
                         set_tooltip_text: Some(&t!("dropdown-dashboard-power-off")),
+                        #[watch]
+                        set_visible: model.shows_power_button(PowerAction::PowerOff),
                         connect_clicked => UserSessionInput::PowerOff,
 
                         gtk::Image {
@@ -168,6 +204,9 @@ impl Component for UserSessionSection {
         );
 
         watchers::spawn_face_watcher(&sender, &face_path);
+        if let Some(session) = &init.session {
+            watchers::spawn_capability_query(&sender, session);
+        }
 
         let model = Self {
             username: init.username,
@@ -175,6 +214,8 @@ impl Component for UserSessionSection {
             face_path,
             face_css_provider,
             config: init.config,
+            session: init.session,
+            available_actions: Vec::new(),
         };
 
         model.update_face_css();
@@ -193,11 +234,17 @@ impl Component for UserSessionSection {
             UserSessionInput::Logout => {
                 process::run_if_set(&dashboard.dropdown_logout_command.get());
             }
+            UserSessionInput::Suspend => {
+                self.perform(PowerAction::Suspend);
+            }
+            UserSessionInput::Hibernate => {
+                self.perform(PowerAction::Hibernate);
+            }
             UserSessionInput::Reboot => {
-                process::run_if_set(&dashboard.dropdown_reboot_command.get());
+                self.run_or_perform(PowerAction::Reboot);
             }
             UserSessionInput::PowerOff => {
-                process::run_if_set(&dashboard.dropdown_poweroff_command.get());
+                self.run_or_perform(PowerAction::PowerOff);
             }
         }
     }
@@ -213,6 +260,9 @@ impl Component for UserSessionSection {
                 self.has_face = exists;
                 self.update_face_css();
             }
+            UserSessionCmd::ActionsAvailable(actions) => {
+                self.available_actions = actions;
+            }
         }
     }
 }
@@ -4,6 +4,7 @@ use notify::{Event, Watcher, event::EventKind};
 use relm4::ComponentSender;
 use tokio::sync::mpsc;
 use tracing::error;
+use wayle_session::SessionService;
 
 use super::{UserSessionSection, messages::UserSessionCmd};
 
@@ -53,3 +54,14 @@ pub(super) fn spawn_face_watcher(sender: &ComponentSender<UserSessionSection>, f
         }
     });
 }
+
+pub(super) fn spawn_capability_query(
+    sender: &ComponentSender<UserSessionSection>,
+    session: &Arc<SessionService>,
+) {
+    let session = session.clone();
+
+    sender.oneshot_command(async move {
+        UserSessionCmd::ActionsAvailable(session.available_actions().await)
+    });
+}
@@ -84,8 +84,8 @@ impl PlayerView {
         self.can_go_previous = player.can_go_previous.get();
         self.can_go_next = player.can_go_next.get();
         self.can_seek = player.can_seek.get();
-        self.can_loop = player.can_loop.get();
-        self.can_shuffle = player.can_shuffle.get();
+        self.can_loop = player.can_set_loop();
+        self.can_shuffle = player.can_set_shuffle();
     }
 
     pub(super) fn display_title(&self) -> Cow<'_, str> {
@@ -155,8 +155,8 @@ impl PlayerView {
         self.can_go_previous = player.can_go_previous.get();
         self.can_go_next = player.can_go_next.get();
         self.can_seek = player.can_seek.get();
-        self.can_loop = player.can_loop.get();
-        self.can_shuffle = player.can_shuffle.get();
+        self.can_loop = player.can_set_loop();
+        self.can_shuffle = player.can_set_shuffle();
 
         self.source_icon = helpers::resolve_source_icon(player);
         self.refresh_metadata();
@@ -481,10 +481,12 @@ impl Component for PlayerView {
 
             PlayerViewCmd::LoopModeChanged(mode) => {
                 self.loop_mode = mode;
+                self.refresh_capabilities();
             }
 
             PlayerViewCmd::ShuffleModeChanged(mode) => {
                 self.shuffle_mode = mode;
+                self.refresh_capabilities();
             }
 
             PlayerViewCmd::Noop => {}
@@ -44,6 +44,7 @@ pub(super) fn spawn_player(
         let _ = out.send(PlayerViewCmd::ShuffleModeChanged(shuffle_mode.get()));
     });
 
+    let can_control = player.can_control.clone();
     let can_go_next = player.can_go_next.clone();
     let can_go_previous = player.can_go_previous.clone();
     let can_seek = player.can_seek.clone();
@@ -54,6 +55,7 @@ pub(super) fn spawn_player(
         sender,
         caps_token,
         [
+            can_control.watch(),
             can_go_next.watch(),
             can_go_previous.watch(),
             can_seek.watch(),
@@ -1,6 +1,7 @@
 mod audio;
 mod battery;
 mod bluetooth;
+mod brightness;
 mod calendar;
 mod dashboard;
 mod media;
@@ -41,6 +42,7 @@ register_dropdowns! {
     "audio" => audio::Factory,
     "battery" => battery::Factory,
     "bluetooth" => bluetooth::Factory,
+    "brightness" => brightness::Factory,
     "calendar" => calendar::Factory,
     "dashboard" => dashboard::Factory,
     "media" => media::Factory,
@@ -126,16 +126,8 @@ fn build_item_init(
     icon_source: IconSource,
     notification: &Arc<Notification>,
 ) -> NotificationItemInit {
-    let resolved_icon = resolve_icon(
-        icon_source,
-        &notification.app_name.get(),
-        &notification.app_icon.get(),
-        &notification.image_path.get(),
-        &notification.desktop_entry.get(),
-    );
-
     NotificationItemInit {
         notification: notification.clone(),
-        resolved_icon,
+        icon_source,
     }
 }
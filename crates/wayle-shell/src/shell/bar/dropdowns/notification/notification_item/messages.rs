@@ -1,12 +1,11 @@
 use std::sync::Arc;
 
+use wayle_config::schemas::modules::notification::IconSource;
 use wayle_notification::core::notification::Notification;
 
-use crate::shell::notification_popup::helpers::ResolvedIcon;
-
 pub(crate) struct NotificationItemInit {
     pub notification: Arc<Notification>,
-    pub resolved_icon: ResolvedIcon,
+    pub icon_source: IconSource,
 }
 
 #[derive(Debug)]
@@ -14,6 +13,11 @@ pub(crate) enum NotificationItemInput {
     RefreshTime,
 }
 
+#[derive(Debug)]
+pub(crate) enum NotificationItemCmd {
+    ContentChanged,
+}
+
 #[derive(Debug)]
 pub(crate) enum NotificationItemOutput {
     Dismissed(u32),
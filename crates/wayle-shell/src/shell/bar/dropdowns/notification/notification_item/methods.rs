@@ -12,6 +12,8 @@ const MAX_ACTIONS_PER_ROW: usize = 3;
 
 impl NotificationItem {
     pub(super) fn apply_icon(&self, icon: &gtk::Image, icon_container: &gtk::Box) {
+        icon_container.remove_css_class("file-icon");
+
         match &self.resolved_icon {
             ResolvedIcon::Named(name) => {
                 icon.set_icon_name(Some(name));
@@ -1,20 +1,25 @@
 pub(crate) mod messages;
 mod methods;
+mod watchers;
 
 use std::sync::Arc;
 
 use gtk::prelude::*;
 use relm4::{gtk, prelude::*};
+use wayle_config::schemas::modules::notification::IconSource;
 use wayle_notification::core::notification::Notification;
 
-use self::messages::{NotificationItemInit, NotificationItemInput, NotificationItemOutput};
+use self::messages::{
+    NotificationItemCmd, NotificationItemInit, NotificationItemInput, NotificationItemOutput,
+};
 use crate::shell::notification_popup::helpers::{
-    ResolvedIcon, relative_time, sanitize_markup, urgency_css_class,
+    ResolvedIcon, notification_icon, relative_time, urgency_css_class,
 };
 
 pub(crate) struct NotificationItem {
     pub(crate) notification: Arc<Notification>,
 
+    icon_source: IconSource,
     resolved_icon: ResolvedIcon,
     time_label: String,
 }
@@ -24,7 +29,7 @@ impl FactoryComponent for NotificationItem {
     type Init = NotificationItemInit;
     type Input = NotificationItemInput;
     type Output = NotificationItemOutput;
-    type CommandOutput = ();
+    type CommandOutput = NotificationItemCmd;
     type ParentWidget = gtk::Box;
 
     view! {
@@ -64,6 +69,7 @@ impl FactoryComponent for NotificationItem {
                             set_hexpand: true,
                             set_halign: gtk::Align::Start,
                             set_ellipsize: gtk::pango::EllipsizeMode::End,
+                            #[watch]
                             set_label: &self.notification.summary.get(),
                         },
 
@@ -89,12 +95,9 @@ impl FactoryComponent for NotificationItem {
                         set_lines: 2,
                         set_wrap: true,
                         set_wrap_mode: gtk::pango::WrapMode::WordChar,
-                        set_label: &self
-                            .notification
-                            .body
-                            .get()
-                            .as_deref()
-                            .map_or_else(String::new, sanitize_markup),
+                        #[watch]
+                        set_label: &self.notification.body_markup.get().unwrap_or_default(),
+                        #[watch]
                         set_visible: self.notification.body.get().is_some(),
                     },
                 },
@@ -110,10 +113,12 @@ impl FactoryComponent for NotificationItem {
 
     fn init_model(init: Self::Init, _index: &Self::Index, _sender: FactorySender<Self>) -> Self {
         let time_label = Self::time_to_string(relative_time(&init.notification.timestamp.get()));
+        let resolved_icon = notification_icon(init.icon_source, &init.notification);
 
         Self {
             notification: init.notification,
-            resolved_icon: init.resolved_icon,
+            icon_source: init.icon_source,
+            resolved_icon,
             time_label,
         }
     }
@@ -138,6 +143,7 @@ impl FactoryComponent for NotificationItem {
         });
 
         self.setup_default_action(&widgets.main_row);
+        watchers::spawn_content_watcher(&sender, &self.notification);
 
         widgets
     }
@@ -150,4 +156,20 @@ impl FactoryComponent for NotificationItem {
             }
         }
     }
+
+    fn update_cmd_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::CommandOutput,
+        sender: FactorySender<Self>,
+    ) {
+        match msg {
+            NotificationItemCmd::ContentChanged => {
+                self.resolved_icon = notification_icon(self.icon_source, &self.notification);
+                self.apply_icon(&widgets.icon, &widgets.icon_container);
+            }
+        }
+
+        self.update_view(widgets, sender);
+    }
 }
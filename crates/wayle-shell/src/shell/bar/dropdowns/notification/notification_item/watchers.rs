@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use relm4::FactorySender;
+use wayle_notification::core::notification::Notification;
+use wayle_widgets::watch;
+
+use super::{NotificationItem, messages::NotificationItemCmd};
+
+pub(super) fn spawn_content_watcher(
+    sender: &FactorySender<NotificationItem>,
+    notification: &Arc<Notification>,
+) {
+    let summary = notification.summary.clone();
+    let body_markup = notification.body_markup.clone();
+    let app_icon = notification.app_icon.clone();
+    let image_path = notification.image_path.clone();
+
+    watch!(
+        sender,
+        [
+            summary.watch(),
+            body_markup.watch(),
+            app_icon.watch(),
+            image_path.watch()
+        ],
+        |out| {
+            let _ = out.send(NotificationItemCmd::ContentChanged);
+        }
+    );
+}
@@ -95,14 +95,16 @@ impl Component for Bar {
         let is_floating = inset_edge > 0.0 || inset_ends > 0.0;
 
         let monitor_name = init.monitor.connector().map(|s| s.to_string());
+        let monitor_description = init.monitor.description().map(|s| s.to_string());
 
         let ipc_state = init.services.shell_ipc.state();
 
         let visible_on_startup = {
             let connector = monitor_name.as_deref().unwrap_or("unknown");
             let layouts = config.bar.layout.get();
-            let config_visible = watchers::layout::find_layout(&layouts, connector)
-                .is_some_and(|layout| layout.show);
+            let config_visible =
+                watchers::layout::find_layout(&layouts, connector, monitor_description.as_deref())
+                    .is_some_and(|layout| layout.show);
 
             config_visible && !ipc_state.hidden_bars.get().contains(connector)
         };
@@ -7,7 +7,10 @@ use super::{BatteryInit, BatteryModule};
 use crate::shell::{
     bar::{
         dropdowns::DropdownRegistry,
-        modules::registry::{ModuleFactory, ModuleInstance, dynamic_controller, require_service},
+        modules::{
+            HideConditions, hide_when,
+            registry::{ModuleFactory, ModuleInstance, dynamic_controller, require_service},
+        },
     },
     services::ShellServices,
 };
@@ -23,6 +26,9 @@ impl ModuleFactory for Factory {
     ) -> Option<ModuleInstance> {
         let battery = require_service("battery", "battery", services.battery.clone())?;
 
+        let empty = battery.device.is_present.map(|present| !present);
+        let conditions = HideConditions::empty(empty).with_battery(Some(&battery));
+
         let init = BatteryInit {
             settings: settings.clone(),
             battery,
@@ -30,6 +36,11 @@ impl ModuleFactory for Factory {
             dropdowns: dropdowns.clone(),
         };
         let controller = dynamic_controller(BatteryModule::builder().launch(init).detach());
+        let controller = hide_when(
+            controller,
+            &services.config.config().modules.battery.hide_when,
+            conditions,
+        );
         Some(ModuleInstance { controller, class })
     }
 }
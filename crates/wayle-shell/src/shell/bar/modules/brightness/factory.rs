@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use relm4::prelude::*;
+use wayle_widgets::prelude::BarSettings;
+
+use super::{BrightnessInit, BrightnessModule};
+use crate::shell::{
+    bar::{
+        dropdowns::DropdownRegistry,
+        modules::registry::{ModuleFactory, ModuleInstance, dynamic_controller, require_service},
+    },
+    services::ShellServices,
+};
+
+pub(crate) struct Factory;
+
+impl ModuleFactory for Factory {
+    fn create(
+        settings: &BarSettings,
+        services: &ShellServices,
+        dropdowns: &Rc<DropdownRegistry>,
+        class: Option<String>,
+    ) -> Option<ModuleInstance> {
+        let brightness = require_service("brightness", "brightness", services.brightness.clone())?;
+
+        let init = BrightnessInit {
+            settings: settings.clone(),
+            brightness,
+            config: services.config.clone(),
+            dropdowns: dropdowns.clone(),
+        };
+        let controller = dynamic_controller(BrightnessModule::builder().launch(init).detach());
+        Some(ModuleInstance { controller, class })
+    }
+}
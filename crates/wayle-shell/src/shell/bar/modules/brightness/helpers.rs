@@ -0,0 +1,62 @@
+use serde_json::json;
+
+pub(crate) fn select_icon(percentage: u16, level_icons: &[String]) -> String {
+    if level_icons.is_empty() {
+        return String::new();
+    }
+
+    let index = if percentage == 0 {
+        0
+    } else {
+        let step = 100.0 / level_icons.len() as f64;
+        let idx = ((f64::from(percentage) - 1.0) / step).floor() as usize;
+        idx.min(level_icons.len() - 1)
+    };
+
+    level_icons.get(index).cloned().unwrap_or_default()
+}
+
+pub(crate) fn format_label(format: &str, percentage: u16) -> String {
+    let ctx = json!({
+        "percent": percentage,
+    });
+    crate::template::render(format, ctx).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_icons() -> Vec<String> {
+        vec![
+            String::from("dim"),
+            String::from("medium"),
+            String::from("full"),
+        ]
+    }
+
+    #[test]
+    fn zero_percent_returns_first_icon() {
+        assert_eq!(select_icon(0, &make_icons()), "dim");
+    }
+
+    #[test]
+    fn icons_split_range_evenly() {
+        let icons = make_icons();
+        assert_eq!(select_icon(33, &icons), "dim");
+        assert_eq!(select_icon(34, &icons), "medium");
+        assert_eq!(select_icon(67, &icons), "full");
+        assert_eq!(select_icon(100, &icons), "full");
+    }
+
+    #[test]
+    fn empty_icons_returns_empty() {
+        assert_eq!(select_icon(50, &[]), "");
+    }
+
+    #[test]
+    fn format_label_renders_percent() {
+        assert_eq!(format_label("{{ percent }}%", 80), "80%");
+        assert_eq!(format_label("Bri: {{percent}}", 5), "Bri: 5");
+    }
+}
@@ -0,0 +1,21 @@
+use relm4::ComponentController;
+use wayle_brightness::BacklightDevice;
+use wayle_config::schemas::modules::BrightnessConfig;
+use wayle_widgets::prelude::BarButtonInput;
+
+use super::{
+    BrightnessModule,
+    helpers::{format_label, select_icon},
+};
+
+impl BrightnessModule {
+    pub(super) fn update_display(&self, config: &BrightnessConfig, device: &BacklightDevice) {
+        let percentage = device.percentage().value().round() as u16;
+
+        let label = format_label(&config.format.get(), percentage);
+        self.bar_button.emit(BarButtonInput::SetLabel(label));
+
+        let icon = select_icon(percentage, &config.level_icons.get());
+        self.bar_button.emit(BarButtonInput::SetIcon(icon));
+    }
+}
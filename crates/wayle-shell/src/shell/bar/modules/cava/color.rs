@@ -1,8 +1,8 @@
 use wayle_config::{
     ConfigService,
-    schemas::styling::{ColorValue, CssToken},
+    schemas::styling::{ColorScheme, ColorValue, CssToken},
 };
-use wayle_styling::resolve_palette;
+use wayle_styling::{resolve_palette, select_palette};
 
 /// RGBA color with components normalized to `[0.0, 1.0]`.
 pub(crate) struct Rgba {
@@ -12,10 +12,14 @@ pub(crate) struct Rgba {
     pub alpha: f64,
 }
 
-pub(super) fn resolve_rgba(color: &ColorValue, config: &ConfigService) -> Rgba {
+pub(super) fn resolve_rgba(
+    color: &ColorValue,
+    config: &ConfigService,
+    color_scheme: ColorScheme,
+) -> Rgba {
     let hex = match color {
         ColorValue::Token(token) => {
-            let raw_palette = config.config().styling.palette();
+            let raw_palette = select_palette(&config.config().styling, color_scheme);
             let palette = resolve_palette(&raw_palette, &config.config().styling);
             match token {
                 CssToken::BgBase => palette.bg.clone(),
@@ -32,6 +36,7 @@ pub(super) fn resolve_rgba(color: &ColorValue, config: &ConfigService) -> Rgba {
                 CssToken::Accent | CssToken::AccentSubtle | CssToken::AccentHover => {
                     palette.primary.clone()
                 }
+                CssToken::Accent2 => palette.accent_2().to_owned(),
 
                 CssToken::Red
                 | CssToken::StatusError
@@ -39,17 +44,17 @@ pub(super) fn resolve_rgba(color: &ColorValue, config: &ConfigService) -> Rgba {
                 | CssToken::StatusErrorHover
                 | CssToken::BorderError => palette.red.clone(),
 
-                CssToken::Yellow | CssToken::StatusWarning | CssToken::StatusWarningSubtle => {
-                    palette.yellow.clone()
+                CssToken::Yellow => palette.yellow.clone(),
+                CssToken::StatusWarning | CssToken::StatusWarningSubtle => {
+                    palette.warning().to_owned()
                 }
 
                 CssToken::Green | CssToken::StatusSuccess | CssToken::StatusSuccessSubtle => {
                     palette.green.clone()
                 }
 
-                CssToken::Blue | CssToken::StatusInfo | CssToken::StatusInfoSubtle => {
-                    palette.blue.clone()
-                }
+                CssToken::Blue => palette.blue.clone(),
+                CssToken::StatusInfo | CssToken::StatusInfoSubtle => palette.info().to_owned(),
 
                 CssToken::BorderSubtle
                 | CssToken::BorderDefault
@@ -67,7 +72,7 @@ pub(super) fn resolve_rgba(color: &ColorValue, config: &ConfigService) -> Rgba {
             };
         }
         ColorValue::Auto => {
-            let raw_palette = config.config().styling.palette();
+            let raw_palette = select_palette(&config.config().styling, color_scheme);
             let palette = resolve_palette(&raw_palette, &config.config().styling);
             palette.primary.clone()
         }
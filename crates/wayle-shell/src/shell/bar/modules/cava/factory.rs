@@ -24,6 +24,7 @@ impl ModuleFactory for Factory {
         let init = CavaInit {
             settings: settings.clone(),
             config: services.config.clone(),
+            color_scheme: services.color_scheme.clone(),
             wallpaper: services.wallpaper.clone(),
             dropdowns: dropdowns.clone(),
         };
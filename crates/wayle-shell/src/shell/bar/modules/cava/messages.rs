@@ -1,7 +1,8 @@
 use std::{rc::Rc, sync::Arc};
 
 use wayle_cava::CavaService;
-use wayle_config::ConfigService;
+use wayle_config::{ConfigService, schemas::styling::ColorScheme};
+use wayle_core::Property;
 use wayle_wallpaper::WallpaperService;
 use wayle_widgets::prelude::BarSettings;
 
@@ -10,6 +11,7 @@ use crate::shell::bar::dropdowns::DropdownRegistry;
 pub(crate) struct CavaInit {
     pub settings: BarSettings,
     pub config: Arc<ConfigService>,
+    pub color_scheme: Property<ColorScheme>,
     pub wallpaper: Option<Arc<WallpaperService>>,
     pub dropdowns: Rc<DropdownRegistry>,
 }
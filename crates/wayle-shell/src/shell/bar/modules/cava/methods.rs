@@ -2,7 +2,10 @@ use std::{cell::Cell, rc::Rc, sync::Arc};
 
 use gtk::{glib::Propagation, prelude::*};
 use relm4::prelude::*;
-use wayle_config::{ConfigService, schemas::modules::CavaStyle};
+use wayle_config::{
+    ConfigService,
+    schemas::{modules::CavaStyle, styling::ColorScheme},
+};
 
 use super::{CavaModule, color, helpers, messages::CavaMsg, rendering};
 
@@ -55,6 +58,7 @@ impl CavaModule {
         frame_data: &Rc<Cell<Vec<f64>>>,
         is_vertical: bool,
         config: &Arc<ConfigService>,
+        color_scheme: ColorScheme,
     ) {
         let frame_values = frame_data.clone();
         let full_config = config.config();
@@ -65,7 +69,7 @@ impl CavaModule {
         let bar_width = cava_config.bar_width.get() as f64;
         let bar_spacing = cava_config.bar_gap.get() as f64;
         let bar_scale = full_config.bar.scale.get().value();
-        let fill_color = color::resolve_rgba(&cava_config.color.get(), config);
+        let fill_color = color::resolve_rgba(&cava_config.color.get(), config, color_scheme);
         let padding_rem = cava_config.internal_padding.get().value();
         let horizontal_padding = helpers::rem_to_px(padding_rem, bar_scale);
 
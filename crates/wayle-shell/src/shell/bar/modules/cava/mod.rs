@@ -12,7 +12,8 @@ use gtk::prelude::*;
 use relm4::prelude::*;
 use tracing::{error, info};
 use wayle_cava::CavaService;
-use wayle_config::{ConfigProperty, ConfigService};
+use wayle_config::{ConfigProperty, ConfigService, schemas::styling::ColorScheme};
+use wayle_core::Property;
 use wayle_widgets::{
     WatcherToken,
     prelude::{BarContainer, BarContainerBehavior, BarContainerColors, BarContainerInit},
@@ -34,6 +35,7 @@ pub(crate) struct CavaModule {
     is_vertical: bool,
     cava: Option<Arc<CavaService>>,
     config: Arc<ConfigService>,
+    color_scheme: Property<ColorScheme>,
     dropdowns: Rc<DropdownRegistry>,
     container_widget: gtk::Box,
 }
@@ -110,7 +112,13 @@ impl Component for CavaModule {
 
         let frame_data: Rc<Cell<Vec<f64>>> = Rc::new(Cell::new(vec![0.0; bars as usize]));
 
-        Self::setup_draw_func(&drawing_area, &frame_data, is_vertical, config);
+        Self::setup_draw_func(
+            &drawing_area,
+            &frame_data,
+            is_vertical,
+            config,
+            init.color_scheme.get(),
+        );
 
         let config_clone = init.config.clone();
         sender.oneshot_command(async move {
@@ -127,6 +135,7 @@ impl Component for CavaModule {
             &sender,
             init.settings.is_vertical,
             &init.config,
+            &init.color_scheme,
             &init.wallpaper,
         );
 
@@ -142,6 +151,7 @@ impl Component for CavaModule {
             is_vertical,
             cava: None,
             config: init.config.clone(),
+            color_scheme: init.color_scheme,
             dropdowns: init.dropdowns,
             container_widget: root.clone(),
         };
@@ -183,6 +193,7 @@ impl Component for CavaModule {
                     &self.frame_data,
                     self.is_vertical,
                     &self.config,
+                    self.color_scheme.get(),
                 );
 
                 let config_clone = self.config.clone();
@@ -207,6 +218,7 @@ impl Component for CavaModule {
                     &self.frame_data,
                     self.is_vertical,
                     &self.config,
+                    self.color_scheme.get(),
                 );
                 self.drawing_area.queue_draw();
             }
@@ -218,6 +230,7 @@ impl Component for CavaModule {
                     &self.frame_data,
                     self.is_vertical,
                     &self.config,
+                    self.color_scheme.get(),
                 );
                 self.drawing_area.queue_draw();
             }
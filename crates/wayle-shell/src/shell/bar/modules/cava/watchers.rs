@@ -7,7 +7,8 @@ use futures::{
 use relm4::ComponentSender;
 use tokio_util::sync::CancellationToken;
 use wayle_cava::CavaService;
-use wayle_config::{ConfigProperty, ConfigService};
+use wayle_config::{ConfigProperty, ConfigService, schemas::styling::ColorScheme};
+use wayle_core::Property;
 use wayle_wallpaper::WallpaperService;
 use wayle_widgets::{watch, watch_cancellable, watchers::changes_stream};
 
@@ -28,6 +29,7 @@ pub(super) fn spawn_config_watchers(
     sender: &ComponentSender<CavaModule>,
     is_vertical: ConfigProperty<bool>,
     config: &Arc<ConfigService>,
+    color_scheme: &Property<ColorScheme>,
     wallpaper: &Option<Arc<WallpaperService>>,
 ) {
     let full_config = config.config();
@@ -47,6 +49,8 @@ pub(super) fn spawn_config_watchers(
     let scale = bar_config.scale.clone();
     let theme = styling.theme_provider.clone();
     let palette = styling.palette.clone();
+    let theme_mode = styling.theme_mode.clone();
+    let light_theme = styling.light_theme.clone();
 
     let extraction_stream: BoxStream<'static, ()> = match wallpaper {
         Some(ws) => ws.watch_extraction().boxed(),
@@ -68,6 +72,9 @@ pub(super) fn spawn_config_watchers(
             changes_stream(&scale),
             changes_stream(&theme),
             changes_stream(&palette),
+            changes_stream(&theme_mode),
+            changes_stream(&light_theme),
+            color_scheme.watch(),
             extraction_stream
         ],
         |out| {
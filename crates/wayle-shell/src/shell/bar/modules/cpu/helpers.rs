@@ -42,6 +42,7 @@ mod tests {
     ) -> CpuData {
         CpuData {
             usage_percent: usage,
+            raw_usage_percent: usage,
             avg_frequency_mhz: avg_mhz,
             max_frequency_mhz: max_mhz,
             busiest_core_freq_mhz: busiest_mhz,
@@ -380,6 +380,7 @@ mod tests {
             format: "{{ output }}".to_string(),
             tooltip_format: None,
             hide_if_empty: false,
+            markup: false,
             icon_name: String::new(),
             icon_names: None,
             icon_map: None,
@@ -72,6 +72,8 @@ impl CustomModule {
         self.icon_bg_color.set(definition.icon_bg_color.clone());
         self.button_bg_color.set(definition.button_bg_color.clone());
         self.border_color.set(definition.border_color.clone());
+        self.bar_button
+            .emit(BarButtonInput::SetUseMarkup(definition.markup));
     }
 
     fn stop_execution_watchers(&mut self) {
@@ -17,7 +17,10 @@ use wayle_config::{
 };
 use wayle_widgets::{
     WatcherToken,
-    prelude::{BarButton, BarButtonBehavior, BarButtonColors, BarButtonInit, BarButtonOutput},
+    prelude::{
+        BarButton, BarButtonBehavior, BarButtonColors, BarButtonInit, BarButtonInput,
+        BarButtonOutput,
+    },
     utils::force_window_resize,
 };
 
@@ -115,6 +118,8 @@ impl Component for CustomModule {
                 BarButtonOutput::ScrollDown => CustomMsg::ScrollDown,
             });
 
+        bar_button.emit(BarButtonInput::SetUseMarkup(definition.markup));
+
         let custom_modules = &init.config.config().modules.custom;
         let mut poller_token = WatcherToken::new();
         let mut watcher_token = WatcherToken::new();
@@ -9,7 +9,7 @@ use wayle_hyprland::{Address, Client, WorkspaceId};
 
 use crate::shell::bar::modules::hyprland_workspaces::helpers::{
     IconContext, WorkspaceState, compute_static_css_classes, determine_workspace_state,
-    resolve_workspace_icons, workspace_id_css_class,
+    resolve_workspace_icons, uses_workspace_name, workspace_id_css_class,
 };
 
 const WORKSPACE_LABEL_CSS: &str = "workspace-label";
@@ -294,7 +294,7 @@ pub(crate) fn build_button_init(
 
         display_mode: config.display_mode.get(),
         active_indicator: config.active_indicator.get(),
-        label_use_name: config.label_use_name.get(),
+        label_use_name: uses_workspace_name(config.label_use_name.get(), config.numbering.get()),
         mapped_icon,
         divider: config.divider.get(),
 
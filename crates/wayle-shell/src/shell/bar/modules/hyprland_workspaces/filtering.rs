@@ -231,18 +231,11 @@ pub(crate) fn relative_workspace_number(
         .unwrap_or(id)
 }
 
-pub(crate) fn calculate_navigation_index(
-    current_idx: usize,
-    direction: i64,
-    total: usize,
-) -> usize {
-    if direction > 0 {
-        (current_idx + 1) % total
-    } else if current_idx == 0 {
-        total - 1
-    } else {
-        current_idx - 1
-    }
+/// Index `steps` positions from `current_idx`, wrapping around both ends.
+/// Negative steps move backward.
+pub(crate) fn calculate_navigation_index(current_idx: usize, steps: i64, total: usize) -> usize {
+    let total = total as i64;
+    (current_idx as i64 + steps).rem_euclid(total) as usize
 }
 
 #[cfg(test)]
@@ -664,6 +657,12 @@ mod tests {
         fn moves_backward() {
             assert_eq!(calculate_navigation_index(2, -1, 5), 1);
         }
+
+        #[test]
+        fn moves_several_steps_and_wraps() {
+            assert_eq!(calculate_navigation_index(2, 4, 5), 1);
+            assert_eq!(calculate_navigation_index(1, -3, 5), 3);
+        }
     }
 
     mod monitor_workspaces_sorted {
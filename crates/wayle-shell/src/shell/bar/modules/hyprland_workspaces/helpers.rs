@@ -222,7 +222,7 @@ pub(crate) fn compute_display_id(
     monitor_workspaces: &[WorkspaceId],
 ) -> WorkspaceId {
     match numbering {
-        Numbering::Absolute => id,
+        Numbering::Absolute | Numbering::Name => id,
         Numbering::Relative => {
             if id <= 0 || bar_monitor.is_none() {
                 return id;
@@ -232,6 +232,10 @@ pub(crate) fn compute_display_id(
     }
 }
 
+pub(crate) fn uses_workspace_name(label_use_name: bool, numbering: Numbering) -> bool {
+    label_use_name || numbering == Numbering::Name
+}
+
 pub(crate) fn should_update_for_monitor(
     monitor_specific: bool,
     bar_monitor: Option<&str>,
@@ -632,6 +636,33 @@ mod tests {
                 10
             );
         }
+
+        #[test]
+        fn name_returns_id() {
+            assert_eq!(
+                compute_display_id(5, Numbering::Name, Some("DP-1"), &[4, 5, 6]),
+                5
+            );
+        }
+    }
+
+    mod uses_workspace_name {
+        use super::*;
+
+        #[test]
+        fn name_numbering_uses_name() {
+            assert!(uses_workspace_name(false, Numbering::Name));
+        }
+
+        #[test]
+        fn label_use_name_uses_name() {
+            assert!(uses_workspace_name(true, Numbering::Relative));
+        }
+
+        #[test]
+        fn numeric_numbering_uses_id() {
+            assert!(!uses_workspace_name(false, Numbering::Absolute));
+        }
     }
 
     mod should_update_for_monitor {
@@ -32,5 +32,6 @@ pub(crate) enum WorkspacesCmd {
     UrgentWindow(Address),
     WindowFocused(Address),
     BlinkTick,
+    ScrollSettled,
     WorkspaceRulesLoaded(HashMap<WorkspaceId, String>),
 }
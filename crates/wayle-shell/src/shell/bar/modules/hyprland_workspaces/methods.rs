@@ -14,7 +14,7 @@ use wayle_hyprland::{Address, HyprlandService, WorkspaceId};
 use wayle_widgets::prelude::BarSettings;
 
 use super::{
-    BLINK_INTERVAL, HyprlandWorkspaces,
+    BLINK_INTERVAL, HyprlandWorkspaces, SCROLL_COALESCE_WINDOW,
     button::{ButtonBuildContext, WorkspaceButtonInput, build_button_init},
     filtering::{
         FilterContext, FilteredWorkspace, WorkspaceData, calculate_navigation_index,
@@ -341,7 +341,26 @@ impl HyprlandWorkspaces {
         });
     }
 
-    pub(super) fn navigate_workspace(&self, direction: i64) {
+    /// Adds a scroll step, opening a coalesce window if none is open. The
+    /// summed steps are applied once when it closes.
+    pub(super) fn queue_scroll(&mut self, direction: i64, sender: &ComponentSender<Self>) {
+        if let Some(steps) = &mut self.pending_scroll {
+            *steps += direction;
+            return;
+        }
+
+        self.pending_scroll = Some(direction);
+        sender.oneshot_command(async {
+            tokio::time::sleep(SCROLL_COALESCE_WINDOW).await;
+            WorkspacesCmd::ScrollSettled
+        });
+    }
+
+    pub(super) fn navigate_workspace(&mut self, steps: i64) {
+        if steps == 0 {
+            return;
+        }
+
         let Some(hyprland) = &self.hyprland else {
             return;
         };
@@ -354,15 +373,18 @@ impl HyprlandWorkspaces {
             return;
         }
 
+        let current_id = self.scroll_target.unwrap_or(self.active_workspace_id);
         let current_idx = workspaces
             .iter()
-            .position(|ws| ws.id == self.active_workspace_id)
+            .position(|ws| ws.id == current_id)
             .unwrap_or(0);
 
-        let new_idx = calculate_navigation_index(current_idx, direction, workspaces.len());
+        let new_idx = calculate_navigation_index(current_idx, steps, workspaces.len());
 
         if let Some(ws) = workspaces.get(new_idx) {
-            self.switch_to_workspace(ws.id);
+            let id = ws.id;
+            self.switch_to_workspace(id);
+            self.scroll_target = Some(id);
         }
     }
 
@@ -44,6 +44,10 @@ pub(crate) use self::{
 
 const BLINK_INTERVAL: Duration = Duration::from_millis(500);
 
+/// Scrolls arriving within this window of the first one are summed into a
+/// single workspace switch.
+const SCROLL_COALESCE_WINDOW: Duration = Duration::from_millis(50);
+
 pub(crate) struct HyprlandWorkspaces {
     hyprland: Option<Arc<HyprlandService>>,
     config: Arc<ConfigService>,
@@ -54,6 +58,12 @@ pub(crate) struct HyprlandWorkspaces {
     urgent_windows: HashSet<Address>,
     blink_on: bool,
     blink_token: Option<CancellationToken>,
+    /// Scroll steps waiting for the coalesce window to close. `None` when
+    /// no window is open.
+    pending_scroll: Option<i64>,
+    /// Workspace the last scroll switched to, until Hyprland reports an
+    /// active workspace change. Further scrolls count from here.
+    scroll_target: Option<WorkspaceId>,
     css_provider: gtk::CssProvider,
     buttons: FactoryVecDeque<WorkspaceButton>,
 }
@@ -129,6 +139,8 @@ impl Component for HyprlandWorkspaces {
             urgent_windows: HashSet::new(),
             blink_on: false,
             blink_token: None,
+            pending_scroll: None,
+            scroll_target: None,
             css_provider,
             buttons,
         };
@@ -140,16 +152,16 @@ impl Component for HyprlandWorkspaces {
         ComponentParts { model, widgets }
     }
 
-    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, _root: &Self::Root) {
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, _root: &Self::Root) {
         match msg {
             WorkspacesMsg::WorkspaceClicked(id) => {
                 self.switch_to_workspace(id);
             }
             WorkspacesMsg::ScrollUp => {
-                self.navigate_workspace(-1);
+                self.queue_scroll(-1, &sender);
             }
             WorkspacesMsg::ScrollDown => {
-                self.navigate_workspace(1);
+                self.queue_scroll(1, &sender);
             }
         }
     }
@@ -191,6 +203,7 @@ impl Component for HyprlandWorkspaces {
                 self.clear_urgent_windows_for_workspace(id);
                 self.stop_blink_if_no_urgent();
                 self.active_workspace_id = id;
+                self.scroll_target = None;
                 self.sync_after_active_workspace_change(has_min_workspace_count);
             }
 
@@ -213,6 +226,7 @@ impl Component for HyprlandWorkspaces {
                     self.clear_urgent_windows_for_workspace(workspace_id);
                     self.stop_blink_if_no_urgent();
                     self.active_workspace_id = workspace_id;
+                    self.scroll_target = None;
                     self.sync_after_active_workspace_change(has_min_workspace_count);
                 }
             }
@@ -247,6 +261,11 @@ impl Component for HyprlandWorkspaces {
                 self.blink_on = !self.blink_on;
                 self.update_active_state();
             }
+            WorkspacesCmd::ScrollSettled => {
+                if let Some(steps) = self.pending_scroll.take() {
+                    self.navigate_workspace(steps);
+                }
+            }
             WorkspacesCmd::WorkspaceRulesLoaded(rules) => {
                 self.workspace_monitor_rules = rules;
                 self.rebuild_buttons();
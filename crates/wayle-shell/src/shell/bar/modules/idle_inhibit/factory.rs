@@ -1,6 +1,8 @@
 use std::rc::Rc;
 
 use relm4::prelude::*;
+use tracing::warn;
+use wayle_idle_inhibit::InhibitSupport;
 use wayle_widgets::prelude::BarSettings;
 
 use super::{IdleInhibitInit, IdleInhibitModule};
@@ -21,9 +23,20 @@ impl ModuleFactory for Factory {
         dropdowns: &Rc<DropdownRegistry>,
         class: Option<String>,
     ) -> Option<ModuleInstance> {
+        let support = wayle_idle_inhibit::support_status();
+        if support != InhibitSupport::Supported {
+            warn!(
+                module = "idle-inhibit",
+                ?support,
+                "idle inhibition unsupported, skipping module"
+            );
+            return None;
+        }
+
         let init = IdleInhibitInit {
             settings: settings.clone(),
             idle_inhibit: services.idle_inhibit.clone(),
+            registry: services.idle_inhibit_registry.clone(),
             config: services.config.clone(),
             dropdowns: dropdowns.clone(),
         };
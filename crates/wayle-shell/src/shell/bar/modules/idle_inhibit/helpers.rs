@@ -46,6 +46,14 @@ pub(super) fn build_label(format: &str, ctx: &LabelContext) -> String {
     crate::template::render(format, template_ctx).unwrap_or_default()
 }
 
+/// Lists the reasons keeping the display awake, or `None` when there are none.
+pub(super) fn build_tooltip(reasons: &[String]) -> Option<String> {
+    if reasons.is_empty() {
+        return None;
+    }
+    Some(t!("bar-idle-inhibit-tooltip", reasons = reasons.join(", ")))
+}
+
 /// Selects icon based on active state.
 pub(super) fn select_icon(active: bool, icon_inactive: &str, icon_active: &str) -> String {
     if active {
@@ -133,6 +141,18 @@ mod tests {
         assert_eq!(build_label("{{ remaining }}", &ctx(true, 0, None)), "∞");
     }
 
+    #[test]
+    fn build_tooltip_none_without_reasons() {
+        assert_eq!(build_tooltip(&[]), None);
+    }
+
+    #[test]
+    fn build_tooltip_lists_reasons() {
+        let reasons = [String::from("manual"), String::from("media")];
+        let tooltip = build_tooltip(&reasons).unwrap_or_default();
+        assert!(tooltip.contains("manual, media"));
+    }
+
     #[test]
     fn select_icon_inactive() {
         assert_eq!(select_icon(false, "off", "on"), "off");
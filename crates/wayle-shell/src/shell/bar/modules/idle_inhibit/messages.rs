@@ -1,6 +1,7 @@
 use std::{rc::Rc, sync::Arc};
 
 use wayle_config::ConfigService;
+use wayle_idle_inhibit::IdleInhibitRegistry;
 use wayle_widgets::prelude::BarSettings;
 
 use crate::{services::idle_inhibit::IdleInhibitService, shell::bar::dropdowns::DropdownRegistry};
@@ -8,6 +9,7 @@ use crate::{services::idle_inhibit::IdleInhibitService, shell::bar::dropdowns::D
 pub(crate) struct IdleInhibitInit {
     pub settings: BarSettings,
     pub idle_inhibit: Arc<IdleInhibitService>,
+    pub registry: IdleInhibitRegistry,
     pub config: Arc<ConfigService>,
     pub dropdowns: Rc<DropdownRegistry>,
 }
@@ -1,6 +1,7 @@
-use relm4::{ComponentController, gtk::prelude::*};
-use tracing::warn;
-use wayle_idle_inhibit::IdleInhibitor;
+use std::{cell::RefCell, rc::Rc};
+
+use relm4::{ComponentController, gtk, gtk::prelude::*};
+use wayle_idle_inhibit::IdleInhibitRegistry;
 use wayle_widgets::prelude::BarButtonInput;
 
 use super::{
@@ -8,36 +9,21 @@ use super::{
     helpers::{self, LabelContext},
 };
 
+/// Registry reason for the inhibition this module's toggle controls.
+const MANUAL_REASON: &str = "manual";
+
 impl IdleInhibitModule {
     pub(super) fn sync_inhibitor(&mut self) {
         let should_be_active = self.state.active.get();
-        let is_active = self.inhibitor.is_some();
+        let is_active = self.inhibit_guard.is_some();
 
         if should_be_active && !is_active {
-            self.create_inhibitor();
+            self.inhibit_guard = Some(self.registry.acquire(MANUAL_REASON));
         } else if !should_be_active && is_active {
-            self.inhibitor.take();
+            self.inhibit_guard.take();
         }
     }
 
-    fn create_inhibitor(&mut self) {
-        let widget = self.bar_button.widget();
-        let Some(native) = widget.native() else {
-            warn!("widget has no native surface");
-            return;
-        };
-        let Some(gdk_surface) = native.surface() else {
-            warn!("native has no surface");
-            return;
-        };
-        let Some(inhibitor) = IdleInhibitor::new(&gdk_surface) else {
-            warn!("failed to create idle inhibitor");
-            return;
-        };
-
-        self.inhibitor = Some(inhibitor);
-    }
-
     pub(super) fn update_display(
         &self,
         config: &wayle_config::schemas::modules::IdleInhibitConfig,
@@ -60,5 +46,35 @@ impl IdleInhibitModule {
             },
         );
         self.bar_button.emit(BarButtonInput::SetLabel(label));
+
+        let tooltip = helpers::build_tooltip(&self.registry.active_reasons());
+        self.bar_button.emit(BarButtonInput::SetTooltip(tooltip));
     }
 }
+
+/// Offers the bar's surface to the registry while `widget` is realized.
+///
+/// The bar window gets a new native surface each time it's realized, so the
+/// surface is re-attached on every realize rather than looked up once.
+pub(super) fn track_surface(widget: &gtk::MenuButton, registry: &IdleInhibitRegistry) {
+    let attached: Rc<RefCell<Option<gtk::gdk::Surface>>> = Rc::default();
+
+    {
+        let registry = registry.clone();
+        let attached = attached.clone();
+        widget.connect_realize(move |widget| {
+            let Some(surface) = widget.native().and_then(|native| native.surface()) else {
+                return;
+            };
+            registry.attach_surface(&surface);
+            attached.replace(Some(surface));
+        });
+    }
+
+    let registry = registry.clone();
+    widget.connect_unrealize(move |_| {
+        if let Some(surface) = attached.take() {
+            registry.detach_surface(&surface);
+        }
+    });
+}
@@ -8,7 +8,7 @@ use std::{rc::Rc, sync::Arc};
 
 use relm4::{gtk::prelude::*, prelude::*};
 use wayle_config::{ConfigProperty, ConfigService, schemas::styling::CssToken};
-use wayle_idle_inhibit::IdleInhibitor;
+use wayle_idle_inhibit::{IdleInhibitRegistry, InhibitGuard};
 use wayle_widgets::prelude::{
     BarButton, BarButtonBehavior, BarButtonColors, BarButtonInit, BarButtonOutput,
 };
@@ -26,7 +26,8 @@ pub(crate) struct IdleInhibitModule {
     bar_button: Controller<BarButton>,
     config: Arc<ConfigService>,
     state: IdleInhibitState,
-    inhibitor: Option<IdleInhibitor>,
+    registry: IdleInhibitRegistry,
+    inhibit_guard: Option<InhibitGuard>,
     dropdowns: Rc<DropdownRegistry>,
 }
 
@@ -87,12 +88,14 @@ impl Component for IdleInhibitModule {
 
         watchers::spawn_config_watchers(&sender, config);
         watchers::spawn_state_watchers(&sender, &state);
+        methods::track_surface(bar_button.widget(), &init.registry);
 
         let model = Self {
             bar_button,
             config: init.config,
             state,
-            inhibitor: None,
+            registry: init.registry,
+            inhibit_guard: None,
             dropdowns: init.dropdowns,
         };
         let bar_button = model.bar_button.widget();
@@ -133,6 +136,6 @@ impl Component for IdleInhibitModule {
 
 impl Drop for IdleInhibitModule {
     fn drop(&mut self) {
-        self.inhibitor.take();
+        self.inhibit_guard.take();
     }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use serde_json::json;
+use wayle_config::ClickAction;
 use wayle_hyprland::DeviceInfo;
 
 pub(super) fn format_label(
@@ -24,10 +25,130 @@ pub(super) fn main_keyboard_layout(devices: &DeviceInfo) -> Option<&str> {
         .map(|kb| kb.active_keymap.as_str())
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum LayoutDirection {
+    Next,
+    Prev,
+}
+
+/// Maps the `:next-layout` and `:prev-layout` actions to a direction.
+pub(super) fn layout_action(action: &ClickAction) -> Option<LayoutDirection> {
+    match action {
+        ClickAction::Shell(cmd) if cmd == ":next-layout" => Some(LayoutDirection::Next),
+        ClickAction::Shell(cmd) if cmd == ":prev-layout" => Some(LayoutDirection::Prev),
+        _ => None,
+    }
+}
+
+/// Direction a click should cycle layouts in, if any.
+///
+/// An explicit `:next-layout` or `:prev-layout` action always cycles.
+/// `cycle-on-click` only supplies `click_direction` for clicks without a
+/// configured action, so it never overrides one.
+pub(super) fn click_layout_action(
+    action: &ClickAction,
+    cycle_on_click: bool,
+    click_direction: Option<LayoutDirection>,
+) -> Option<LayoutDirection> {
+    if let Some(direction) = layout_action(action) {
+        return Some(direction);
+    }
+
+    if cycle_on_click && *action == ClickAction::None {
+        return click_direction;
+    }
+
+    None
+}
+
+/// Device name and layout index to switch the main keyboard to.
+///
+/// `None` when there is no main keyboard or it has a single layout.
+pub(super) fn cycle_target(
+    devices: &DeviceInfo,
+    direction: LayoutDirection,
+) -> Option<(&str, usize)> {
+    let keyboard = devices.keyboards.iter().find(|kb| kb.main)?;
+    let count = keyboard.layout.split(',').count();
+    let index = cycle_index(keyboard.active_layout_index as usize, count, direction)?;
+
+    Some((keyboard.name.as_str(), index))
+}
+
+fn cycle_index(active: usize, count: usize, direction: LayoutDirection) -> Option<usize> {
+    if count < 2 {
+        return None;
+    }
+
+    let active = active.min(count - 1);
+    let index = match direction {
+        LayoutDirection::Next => (active + 1) % count,
+        LayoutDirection::Prev => (active + count - 1) % count,
+    };
+
+    Some(index)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn layout_actions_map_to_directions() {
+        let next = ClickAction::Shell(String::from(":next-layout"));
+        let prev = ClickAction::Shell(String::from(":prev-layout"));
+        let other = ClickAction::Shell(String::from("notify-send hi"));
+
+        assert_eq!(layout_action(&next), Some(LayoutDirection::Next));
+        assert_eq!(layout_action(&prev), Some(LayoutDirection::Prev));
+        assert_eq!(layout_action(&other), None);
+        assert_eq!(layout_action(&ClickAction::None), None);
+    }
+
+    #[test]
+    fn cycle_on_click_only_applies_without_an_action() {
+        let custom = ClickAction::Shell(String::from("notify-send hi"));
+        let next = Some(LayoutDirection::Next);
+
+        assert_eq!(
+            click_layout_action(&ClickAction::None, true, next),
+            Some(LayoutDirection::Next)
+        );
+        assert_eq!(click_layout_action(&custom, true, next), None);
+        assert_eq!(click_layout_action(&ClickAction::None, false, next), None);
+        assert_eq!(
+            click_layout_action(
+                &ClickAction::Shell(String::from(":prev-layout")),
+                false,
+                None
+            ),
+            Some(LayoutDirection::Prev)
+        );
+    }
+
+    #[test]
+    fn cycle_next_wraps_to_first() {
+        assert_eq!(cycle_index(0, 3, LayoutDirection::Next), Some(1));
+        assert_eq!(cycle_index(2, 3, LayoutDirection::Next), Some(0));
+    }
+
+    #[test]
+    fn cycle_prev_wraps_to_last() {
+        assert_eq!(cycle_index(1, 3, LayoutDirection::Prev), Some(0));
+        assert_eq!(cycle_index(0, 3, LayoutDirection::Prev), Some(2));
+    }
+
+    #[test]
+    fn cycle_single_layout_does_nothing() {
+        assert_eq!(cycle_index(0, 1, LayoutDirection::Next), None);
+        assert_eq!(cycle_index(0, 1, LayoutDirection::Prev), None);
+    }
+
+    #[test]
+    fn cycle_clamps_out_of_range_active_index() {
+        assert_eq!(cycle_index(7, 2, LayoutDirection::Next), Some(0));
+    }
+
     #[test]
     fn format_layout_only() {
         assert_eq!(format_label("us", "{{ layout }}", &HashMap::new()), "us");
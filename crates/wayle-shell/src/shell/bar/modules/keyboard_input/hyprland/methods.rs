@@ -1,12 +1,15 @@
 use std::sync::Arc;
 
-use relm4::{ComponentController, gtk};
+use relm4::{ComponentController, ComponentSender, gtk};
 use tokio::runtime::Handle;
 use tracing::warn;
 use wayle_hyprland::HyprlandService;
 use wayle_widgets::{prelude::BarButtonInput, utils::force_window_resize};
 
-use super::{HyprlandKeyboardInput, helpers};
+use super::{
+    HyprlandKeyboardInput,
+    helpers::{self, LayoutDirection},
+};
 
 impl HyprlandKeyboardInput {
     pub(super) fn update_label(&self, root: &gtk::Box) {
@@ -18,6 +21,33 @@ impl HyprlandKeyboardInput {
         self.bar_button.emit(BarButtonInput::SetLabel(label));
         force_window_resize(root);
     }
+
+    /// Switches the main keyboard to its next or previous layout.
+    ///
+    /// The label follows through the `activelayout` event, not here.
+    pub(super) fn cycle_layout(&self, sender: &ComponentSender<Self>, direction: LayoutDirection) {
+        let Some(hyprland) = self.hyprland.clone() else {
+            return;
+        };
+
+        sender.command(move |_out, _shutdown| async move {
+            let devices = match hyprland.devices().await {
+                Ok(devices) => devices,
+                Err(err) => {
+                    warn!(error = %err, "cannot get keyboard devices");
+                    return;
+                }
+            };
+
+            let Some((device, index)) = helpers::cycle_target(&devices, direction) else {
+                return;
+            };
+
+            if let Err(err) = hyprland.switch_xkb_layout(device, &index.to_string()).await {
+                warn!(error = %err, device, "cannot switch keyboard layout");
+            }
+        });
+    }
 }
 
 pub(super) fn initial_layout(hyprland: &Option<Arc<HyprlandService>>) -> String {
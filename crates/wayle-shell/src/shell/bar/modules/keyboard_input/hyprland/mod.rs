@@ -6,12 +6,13 @@ use std::{rc::Rc, sync::Arc};
 use gtk::prelude::*;
 use relm4::prelude::*;
 use wayle_config::{ConfigProperty, ConfigService, schemas::styling::CssToken};
+use wayle_hyprland::HyprlandService;
 use wayle_widgets::prelude::{
     BarButton, BarButtonBehavior, BarButtonColors, BarButtonInit, BarButtonInput, BarButtonOutput,
 };
 
 use super::{
-    helpers,
+    helpers::{self, LayoutDirection},
     messages::{KeyboardInputCmd, KeyboardInputInit, KeyboardInputMsg},
 };
 use crate::shell::bar::dropdowns::{self, DropdownRegistry};
@@ -21,6 +22,7 @@ pub(crate) struct HyprlandKeyboardInput {
     config: Arc<ConfigService>,
     current_layout: String,
     dropdowns: Rc<DropdownRegistry>,
+    hyprland: Option<Arc<HyprlandService>>,
 }
 
 #[relm4::component(pub(crate))]
@@ -91,6 +93,7 @@ impl Component for HyprlandKeyboardInput {
             config: init.config,
             current_layout: initial_layout,
             dropdowns: init.dropdowns,
+            hyprland: init.hyprland,
         };
         let bar_button = model.bar_button.widget();
         let widgets = view_output!();
@@ -98,17 +101,30 @@ impl Component for HyprlandKeyboardInput {
         ComponentParts { model, widgets }
     }
 
-    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, _root: &Self::Root) {
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, _root: &Self::Root) {
         let keyboard_input = &self.config.config().modules.keyboard_input;
+        let cycle_on_click = keyboard_input.cycle_on_click.get();
 
-        let action = match msg {
-            KeyboardInputMsg::LeftClick => keyboard_input.left_click.get(),
-            KeyboardInputMsg::RightClick => keyboard_input.right_click.get(),
-            KeyboardInputMsg::MiddleClick => keyboard_input.middle_click.get(),
-            KeyboardInputMsg::ScrollUp => keyboard_input.scroll_up.get(),
-            KeyboardInputMsg::ScrollDown => keyboard_input.scroll_down.get(),
+        let (action, click_direction) = match msg {
+            KeyboardInputMsg::LeftClick => {
+                (keyboard_input.left_click.get(), Some(LayoutDirection::Next))
+            }
+            KeyboardInputMsg::RightClick => (
+                keyboard_input.right_click.get(),
+                Some(LayoutDirection::Prev),
+            ),
+            KeyboardInputMsg::MiddleClick => (keyboard_input.middle_click.get(), None),
+            KeyboardInputMsg::ScrollUp => (keyboard_input.scroll_up.get(), None),
+            KeyboardInputMsg::ScrollDown => (keyboard_input.scroll_down.get(), None),
         };
 
+        if let Some(direction) =
+            helpers::click_layout_action(&action, cycle_on_click, click_direction)
+        {
+            self.cycle_layout(&sender, direction);
+            return;
+        }
+
         dropdowns::dispatch_click(&action, &self.dropdowns, &self.bar_button);
     }
 
@@ -7,7 +7,10 @@ use super::{MediaInit, MediaModule};
 use crate::shell::{
     bar::{
         dropdowns::DropdownRegistry,
-        modules::registry::{ModuleFactory, ModuleInstance, dynamic_controller, require_service},
+        modules::{
+            HideConditions, hide_when,
+            registry::{ModuleFactory, ModuleInstance, dynamic_controller, require_service},
+        },
     },
     services::ShellServices,
 };
@@ -23,6 +26,9 @@ impl ModuleFactory for Factory {
     ) -> Option<ModuleInstance> {
         let media = require_service("media", "media", services.media.clone())?;
 
+        let empty = media.active_player.map(Option::is_none);
+        let conditions = HideConditions::empty(empty).with_battery(services.battery.as_ref());
+
         let init = MediaInit {
             settings: settings.clone(),
             media,
@@ -30,6 +36,11 @@ impl ModuleFactory for Factory {
             dropdowns: dropdowns.clone(),
         };
         let controller = dynamic_controller(MediaModule::builder().launch(init).detach());
+        let controller = hide_when(
+            controller,
+            &services.config.config().modules.media.hide_when,
+            conditions,
+        );
         Some(ModuleInstance { controller, class })
     }
 }
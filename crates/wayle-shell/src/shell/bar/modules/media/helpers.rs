@@ -102,10 +102,9 @@ pub(super) fn build_icon(config: &MediaConfig, player: &Player) -> String {
     let desktop_entry = player.desktop_entry.get();
 
     if icon_type == MediaIconType::Application {
-        if let Some(icon) = desktop_entry_icon(desktop_entry.as_deref()) {
-            return icon;
-        }
-        return icon_name;
+        return desktop_entry_icon(desktop_entry.as_deref())
+            .or_else(|| player_icon(player))
+            .unwrap_or(icon_name);
     }
 
     let spinning_disc_icon = config.spinning_disc_icon.get();
@@ -124,7 +123,8 @@ pub(super) fn build_icon(config: &MediaConfig, player: &Player) -> String {
     }
 
     if icon_type == MediaIconType::ApplicationMapped
-        && let Some(icon) = desktop_entry_icon(desktop_entry.as_deref())
+        && let Some(icon) =
+            desktop_entry_icon(desktop_entry.as_deref()).or_else(|| player_icon(player))
     {
         return icon;
     }
@@ -132,6 +132,12 @@ pub(super) fn build_icon(config: &MediaConfig, player: &Player) -> String {
     icon_name
 }
 
+/// The icon `wayle-media` resolved from the player's desktop entry or
+/// identity, if the theme has it.
+fn player_icon(player: &Player) -> Option<String> {
+    player.icon_name().get().filter(|icon| icon_exists(icon))
+}
+
 pub(super) fn desktop_entry_icon(desktop_entry: Option<&str>) -> Option<String> {
     let entry = desktop_entry?;
     let app_info = lookup_desktop_entry(entry)?;
@@ -1,5 +1,6 @@
 mod battery;
 mod bluetooth;
+mod brightness;
 mod cava;
 mod clock;
 mod compositor;
@@ -22,6 +23,7 @@ mod registry;
 mod separator;
 mod storage;
 mod systray;
+mod visibility;
 mod volume;
 pub(crate) mod weather;
 mod window_title;
@@ -33,7 +35,10 @@ use tracing::warn;
 use wayle_config::schemas::bar::{BarModule, ModuleRef};
 use wayle_widgets::prelude::BarSettings;
 
-pub(crate) use self::registry::{ModuleFactory, ModuleInstance};
+pub(crate) use self::{
+    registry::{ModuleFactory, ModuleInstance},
+    visibility::{HideConditions, hide_when},
+};
 use crate::shell::{bar::dropdowns::DropdownRegistry, services::ShellServices};
 
 macro_rules! register_modules {
@@ -59,6 +64,7 @@ macro_rules! register_modules {
 register_modules! {
     Battery => battery::Factory,
     Bluetooth => bluetooth::Factory,
+    Brightness => brightness::Factory,
     Cava => cava::Factory,
     Clock => clock::Factory,
     Cpu => cpu::Factory,
@@ -1,13 +1,17 @@
 use std::rc::Rc;
 
 use relm4::prelude::*;
+use wayle_core::Property;
 use wayle_widgets::prelude::BarSettings;
 
 use super::{SystrayInit, SystrayModule};
 use crate::shell::{
     bar::{
         dropdowns::DropdownRegistry,
-        modules::registry::{ModuleFactory, ModuleInstance, dynamic_controller, require_service},
+        modules::{
+            HideConditions, hide_when,
+            registry::{ModuleFactory, ModuleInstance, dynamic_controller, require_service},
+        },
     },
     services::ShellServices,
 };
@@ -23,12 +27,22 @@ impl ModuleFactory for Factory {
     ) -> Option<ModuleInstance> {
         let systray = require_service("systray", "systray", services.systray.clone())?;
 
+        let empty = Property::new(true);
+        let conditions =
+            HideConditions::empty(empty.clone()).with_battery(services.battery.as_ref());
+
         let init = SystrayInit {
             is_vertical: settings.is_vertical.clone(),
             systray,
             config: services.config.clone(),
+            empty,
         };
         let controller = dynamic_controller(SystrayModule::builder().launch(init).detach());
+        let controller = hide_when(
+            controller,
+            &services.config.config().modules.systray.hide_when,
+            conditions,
+        );
         Some(ModuleInstance { controller, class })
     }
 }
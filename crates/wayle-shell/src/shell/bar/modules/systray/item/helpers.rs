@@ -1,9 +1,19 @@
 use gtk4::{gdk, glib, prelude::Cast};
-use wayle_systray::types::item::IconPixmap;
+use wayle_systray::types::item::{IconPixmap, Status};
 
 const TARGET_ICON_SIZE: i32 = 24;
 const ICON_EXTENSIONS: [&str; 3] = ["png", "svg", "xpm"];
 
+pub(super) const STATUS_CLASSES: [&str; 3] = ["tray-passive", "tray-active", "tray-attention"];
+
+pub(super) fn status_class(status: Status) -> &'static str {
+    match status {
+        Status::Passive => STATUS_CLASSES[0],
+        Status::Active => STATUS_CLASSES[1],
+        Status::NeedsAttention => STATUS_CLASSES[2],
+    }
+}
+
 pub(super) fn select_best_pixmap(pixmaps: &[IconPixmap]) -> Option<&IconPixmap> {
     pixmaps
         .iter()
@@ -56,6 +66,13 @@ fn argb_to_rgba(argb: &[u8]) -> Vec<u8> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn status_classes_are_distinct_per_status() {
+        assert_eq!(status_class(Status::Passive), "tray-passive");
+        assert_eq!(status_class(Status::Active), "tray-active");
+        assert_eq!(status_class(Status::NeedsAttention), "tray-attention");
+    }
+
     #[test]
     fn argb_to_rgba_single_pixel() {
         let argb = [0xFF, 0x11, 0x22, 0x33];
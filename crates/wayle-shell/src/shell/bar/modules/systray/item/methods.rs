@@ -8,12 +8,15 @@ use relm4::{
 use tracing::debug;
 use wayle_systray::{
     adapters::gtk4::{Adapter, TrayMenuModel},
-    types::Coordinates,
+    types::{Coordinates, item::Status, markup},
 };
 
 use super::{
     SystrayItem, SystrayItemMsg,
-    helpers::{create_texture_from_pixmap, load_icon_from_theme_path, select_best_pixmap},
+    helpers::{
+        STATUS_CLASSES, create_texture_from_pixmap, load_icon_from_theme_path, select_best_pixmap,
+        status_class,
+    },
 };
 use crate::shell::bar::modules::systray::helpers::find_override;
 
@@ -161,11 +164,16 @@ impl SystrayItem {
         let overrides = self.config.config().modules.systray.overrides.get();
         let override_match = find_override(&self.item, &overrides);
 
-        let icon_name = override_match
-            .and_then(|entry| entry.icon.clone())
-            .or_else(|| self.item.icon_name.get());
+        let override_icon = override_match.and_then(|entry| entry.icon.clone());
+        let attention = override_icon.is_none() && self.wants_attention_icon();
 
-        self.apply_icon(image, icon_name.as_deref());
+        let icon_name = if attention {
+            self.item.attention_icon_name.get()
+        } else {
+            override_icon.or_else(|| self.item.icon_name.get())
+        };
+
+        self.apply_icon(image, icon_name.as_deref(), attention);
 
         if let Some(color) = override_match.and_then(|entry| entry.color.clone()) {
             self.apply_icon_color(image, &color.to_css());
@@ -195,7 +203,28 @@ impl SystrayItem {
         }
     }
 
-    fn apply_icon(&self, image: &gtk::Image, icon_name: Option<&str>) {
+    pub(super) fn update_status(&self) {
+        let Some(button) = &self.button else {
+            return;
+        };
+
+        let class = status_class(self.item.status.get());
+        for other in STATUS_CLASSES {
+            if other != class {
+                button.remove_css_class(other);
+            }
+        }
+        button.add_css_class(class);
+    }
+
+    /// Whether the item needs attention and ships a dedicated icon for it.
+    fn wants_attention_icon(&self) -> bool {
+        self.item.status.get() == Status::NeedsAttention
+            && (self.item.attention_icon_name.get().is_some()
+                || !self.item.attention_icon_pixmap.get().is_empty())
+    }
+
+    fn apply_icon(&self, image: &gtk::Image, icon_name: Option<&str>, attention: bool) {
         if let Some(name) = icon_name {
             let theme_path = self.item.icon_theme_path.get();
             if let Some(texture) = theme_path
@@ -215,7 +244,11 @@ impl SystrayItem {
             return;
         }
 
-        let pixmaps = self.item.icon_pixmap.get();
+        let pixmaps = if attention {
+            self.item.attention_icon_pixmap.get()
+        } else {
+            self.item.icon_pixmap.get()
+        };
         if let Some(texture) = select_best_pixmap(&pixmaps).and_then(create_texture_from_pixmap) {
             image.set_paintable(Some(&texture));
             return;
@@ -224,6 +257,38 @@ impl SystrayItem {
         image.set_icon_name(Some("application-x-executable-symbolic"));
     }
 
+    /// Builds the tooltip on hover from the item's SNI tooltip, falling back
+    /// to its title, so it always reflects the latest values.
+    pub(super) fn setup_tooltip(&self, button: &gtk::Button) {
+        let item = self.item.clone();
+
+        button.set_has_tooltip(true);
+        button.connect_query_tooltip(move |_, _, _, _, tooltip| {
+            let data = item.tooltip.get();
+            let markup = data.to_pango_markup().or_else(|| {
+                let title = item.title.get();
+                let title = title.trim();
+                (!title.is_empty()).then(|| markup::escape(title))
+            });
+
+            let Some(markup) = markup else {
+                return false;
+            };
+
+            tooltip.set_markup(Some(&markup));
+
+            if let Some(texture) =
+                select_best_pixmap(&data.icon_pixmap).and_then(create_texture_from_pixmap)
+            {
+                tooltip.set_icon(Some(&texture));
+            } else if !data.icon_name.is_empty() {
+                tooltip.set_icon_from_icon_name(Some(&data.icon_name));
+            }
+
+            true
+        });
+    }
+
     pub(super) fn rebuild_menu_if_visible(&mut self) {
         let Some(popover) = self.popover.clone() else {
             return;
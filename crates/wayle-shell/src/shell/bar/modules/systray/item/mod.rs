@@ -40,6 +40,7 @@ pub(super) enum SystrayItemMsg {
     ShowMenu,
     MenuUpdated,
     IconUpdated,
+    StatusUpdated,
 }
 
 #[derive(Debug)]
@@ -123,14 +124,17 @@ impl FactoryComponent for SystrayItem {
 
         root.add_controller(right_click);
         root.add_controller(middle_click);
+        self.setup_tooltip(&root);
 
         watchers::spawn_menu_watcher(&sender, &self.item, self.cancel_token.clone());
         watchers::spawn_icon_watcher(&sender, &self.item, self.cancel_token.clone());
+        watchers::spawn_status_watcher(&sender, &self.item, self.cancel_token.clone());
 
         let widgets = view_output!();
 
         self.icon = Some(widgets.icon.clone());
         self.update_icon(&widgets.icon);
+        self.update_status();
 
         widgets
     }
@@ -184,6 +188,12 @@ impl FactoryComponent for SystrayItem {
                     self.update_icon(&icon);
                 }
             }
+            SystrayItemMsg::StatusUpdated => {
+                self.update_status();
+                if let Some(icon) = self.icon.clone() {
+                    self.update_icon(&icon);
+                }
+            }
         }
     }
 }
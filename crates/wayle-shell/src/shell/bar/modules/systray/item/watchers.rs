@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use futures::{StreamExt, stream::select};
+use futures::{StreamExt, stream::select_all};
 use relm4::prelude::FactorySender;
 use tokio_util::sync::CancellationToken;
 use wayle_systray::core::item::TrayItem;
@@ -39,7 +39,14 @@ pub(super) fn spawn_icon_watcher(
 ) {
     let icon_name = item.icon_name.watch().skip(1).map(|_| ());
     let icon_pixmap = item.icon_pixmap.watch().skip(1).map(|_| ());
-    let stream = select(icon_name, icon_pixmap);
+    let attention_icon_name = item.attention_icon_name.watch().skip(1).map(|_| ());
+    let attention_icon_pixmap = item.attention_icon_pixmap.watch().skip(1).map(|_| ());
+    let stream = select_all([
+        icon_name.boxed(),
+        icon_pixmap.boxed(),
+        attention_icon_name.boxed(),
+        attention_icon_pixmap.boxed(),
+    ]);
     let sender = sender.clone();
 
     relm4::spawn_local(async move {
@@ -58,3 +65,28 @@ pub(super) fn spawn_icon_watcher(
         }
     });
 }
+
+pub(super) fn spawn_status_watcher(
+    sender: &FactorySender<SystrayItem>,
+    item: &Arc<TrayItem>,
+    cancel_token: CancellationToken,
+) {
+    let stream = item.status.watch().skip(1);
+    let sender = sender.clone();
+
+    relm4::spawn_local(async move {
+        futures::pin_mut!(stream);
+
+        loop {
+            tokio::select! {
+                () = cancel_token.cancelled() => break,
+                result = stream.next() => {
+                    if result.is_none() {
+                        break;
+                    }
+                    sender.input(SystrayItemMsg::StatusUpdated);
+                }
+            }
+        }
+    });
+}
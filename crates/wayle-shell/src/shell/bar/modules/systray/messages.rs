@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
 use wayle_config::{ConfigProperty, ConfigService};
+use wayle_core::Property;
 use wayle_systray::{SystemTrayService, core::item::TrayItem};
 
 pub(crate) struct SystrayInit {
     pub is_vertical: ConfigProperty<bool>,
     pub systray: Arc<SystemTrayService>,
     pub config: Arc<ConfigService>,
+    pub empty: Property<bool>,
 }
 
 #[derive(Debug)]
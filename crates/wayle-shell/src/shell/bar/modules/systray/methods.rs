@@ -21,6 +21,6 @@ impl SystrayModule {
             });
         }
 
-        self.visible.set(!guard.is_empty());
+        self.empty.set(guard.is_empty());
     }
 }
@@ -12,6 +12,7 @@ use gtk4::prelude::{OrientableExt, WidgetExt};
 use item::SystrayItem;
 use relm4::{ComponentParts, ComponentSender, factory::FactoryVecDeque, gtk, prelude::*};
 use wayle_config::{ConfigProperty, ConfigService};
+use wayle_core::Property;
 use wayle_widgets::prelude::{
     BarContainer, BarContainerBehavior, BarContainerColors, BarContainerInit, force_window_resize,
 };
@@ -25,7 +26,7 @@ pub(crate) struct SystrayModule {
     container: Controller<BarContainer>,
     items: FactoryVecDeque<SystrayItem>,
     css_provider: gtk::CssProvider,
-    visible: ConfigProperty<bool>,
+    empty: Property<bool>,
     config: Arc<ConfigService>,
 }
 
@@ -58,8 +59,6 @@ impl Component for SystrayModule {
         let styling_config = &full_config.styling;
         let bar_config = &full_config.bar;
 
-        let visible = ConfigProperty::new(false);
-
         let container = BarContainer::builder()
             .launch(BarContainerInit {
                 colors: BarContainerColors {
@@ -68,7 +67,7 @@ impl Component for SystrayModule {
                 },
                 behavior: BarContainerBehavior {
                     show_border: config.border_show.clone(),
-                    visible: visible.clone(),
+                    visible: ConfigProperty::new(true),
                 },
                 is_vertical: init.is_vertical.clone(),
                 theme_provider: styling_config.theme_provider.clone(),
@@ -94,7 +93,7 @@ impl Component for SystrayModule {
             container,
             items,
             css_provider,
-            visible,
+            empty: init.empty,
             config: init.config,
         };
         let container = model.container.widget();
@@ -113,9 +112,6 @@ impl Component for SystrayModule {
         match msg {
             SystrayCmd::ItemsChanged(items) => {
                 self.update_items(items);
-                if let Some(parent) = root.parent() {
-                    parent.set_visible(self.visible.get());
-                }
                 force_window_resize(root);
             }
             SystrayCmd::StylingChanged => {
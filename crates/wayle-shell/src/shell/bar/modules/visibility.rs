@@ -0,0 +1,150 @@
+//! Shared `hide-when` handling for bar modules.
+
+use std::sync::Arc;
+
+use futures::{
+    StreamExt,
+    stream::{BoxStream, select_all},
+};
+use gtk::prelude::*;
+use relm4::gtk::{self, glib};
+use wayle_battery::{BatteryService, types::DeviceState};
+use wayle_config::{ConfigProperty, schemas::modules::HideWhen};
+use wayle_core::ComputedProperty;
+
+use super::registry::ModuleController;
+
+/// Module state that [`HideWhen`] conditions are checked against.
+///
+/// Conditions left as `None` never hide the module.
+#[derive(Default)]
+pub(crate) struct HideConditions {
+    /// True while the module has nothing to show.
+    pub empty: Option<ComputedProperty<bool>>,
+    /// True while the system runs on battery power.
+    pub on_battery: Option<ComputedProperty<bool>>,
+}
+
+impl HideConditions {
+    pub(crate) fn empty(empty: impl Into<ComputedProperty<bool>>) -> Self {
+        Self {
+            empty: Some(empty.into()),
+            on_battery: None,
+        }
+    }
+
+    pub(crate) fn with_battery(mut self, battery: Option<&Arc<BatteryService>>) -> Self {
+        self.on_battery = battery.map(|battery| {
+            battery.device.state.map(|state| {
+                matches!(
+                    state,
+                    DeviceState::Discharging | DeviceState::PendingDischarge
+                )
+            })
+        });
+        self
+    }
+
+    fn is_hidden(&self, mode: HideWhen) -> bool {
+        let active = |condition: &Option<ComputedProperty<bool>>| {
+            condition.as_ref().is_some_and(ComputedProperty::get)
+        };
+
+        match mode {
+            HideWhen::Never => false,
+            HideWhen::Empty => active(&self.empty),
+            HideWhen::OnBattery => active(&self.on_battery),
+        }
+    }
+
+    fn changes(&self) -> Vec<BoxStream<'static, ()>> {
+        [&self.empty, &self.on_battery]
+            .into_iter()
+            .flatten()
+            .map(|condition| condition.watch().map(|_| ()).boxed())
+            .collect()
+    }
+}
+
+struct HideWhenHandle {
+    inner: Box<dyn ModuleController>,
+    watcher: glib::JoinHandle<()>,
+}
+
+impl ModuleController for HideWhenHandle {
+    fn widget(&self) -> &gtk::Box {
+        self.inner.widget()
+    }
+}
+
+impl Drop for HideWhenHandle {
+    fn drop(&mut self) {
+        self.watcher.abort();
+    }
+}
+
+/// Shows or hides `controller`'s widget as `mode` and `conditions` change.
+///
+/// The bar item hides itself once none of its modules are visible.
+pub(crate) fn hide_when(
+    controller: Box<dyn ModuleController>,
+    mode: &ConfigProperty<HideWhen>,
+    conditions: HideConditions,
+) -> Box<dyn ModuleController> {
+    let widget = controller.widget().clone();
+    let mode = mode.clone();
+
+    let mut streams = conditions.changes();
+    streams.push(mode.watch().map(|_| ()).boxed());
+
+    let watcher = glib::spawn_future_local(async move {
+        let mut changes = select_all(streams);
+        while changes.next().await.is_some() {
+            widget.set_visible(!conditions.is_hidden(mode.get()));
+        }
+    });
+
+    Box::new(HideWhenHandle {
+        inner: controller,
+        watcher,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use wayle_core::Property;
+
+    use super::*;
+
+    #[test]
+    fn never_ignores_conditions() {
+        let conditions = HideConditions::empty(Property::new(true));
+        assert!(!conditions.is_hidden(HideWhen::Never));
+    }
+
+    #[test]
+    fn empty_follows_empty_condition() {
+        let empty = Property::new(true);
+        let conditions = HideConditions::empty(empty.clone());
+        assert!(conditions.is_hidden(HideWhen::Empty));
+
+        empty.set(false);
+        assert!(!conditions.is_hidden(HideWhen::Empty));
+    }
+
+    #[test]
+    fn missing_condition_keeps_module_visible() {
+        let conditions = HideConditions::default();
+        assert!(!conditions.is_hidden(HideWhen::Empty));
+        assert!(!conditions.is_hidden(HideWhen::OnBattery));
+    }
+
+    #[test]
+    fn on_battery_ignores_empty_condition() {
+        let conditions = HideConditions {
+            empty: Some(Property::new(true).into()),
+            on_battery: Some(Property::new(false).into()),
+        };
+        assert!(!conditions.is_hidden(HideWhen::OnBattery));
+    }
+}
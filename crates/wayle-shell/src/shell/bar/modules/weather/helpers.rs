@@ -194,7 +194,10 @@ mod tests {
             astronomy: Astronomy {
                 sunrise: NaiveTime::from_hms_opt(6, 30, 0).unwrap(),
                 sunset: NaiveTime::from_hms_opt(18, 45, 0).unwrap(),
+                moon_phase: None,
             },
+            alerts: Vec::new(),
+            air_quality: None,
             updated_at: Utc::now(),
         }
     }
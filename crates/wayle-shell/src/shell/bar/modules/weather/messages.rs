@@ -26,4 +26,5 @@ pub(crate) enum WeatherMsg {
 pub(crate) enum WeatherCmd {
     UpdateLabel(String),
     UpdateIcon(String),
+    SevereAlert(bool),
 }
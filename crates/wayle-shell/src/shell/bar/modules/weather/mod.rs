@@ -118,6 +118,8 @@ impl Component for WeatherModule {
             WeatherCmd::UpdateIcon(icon) => {
                 self.bar_button.emit(BarButtonInput::SetIcon(icon));
             }
+            WeatherCmd::SevereAlert(true) => root.add_css_class("severe-alert"),
+            WeatherCmd::SevereAlert(false) => root.remove_css_class("severe-alert"),
         }
     }
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use relm4::ComponentSender;
 use wayle_config::schemas::modules::WeatherConfig;
-use wayle_weather::WeatherService;
+use wayle_weather::{AlertSeverity, WeatherService};
 use wayle_widgets::watch;
 
 use super::{
@@ -17,6 +17,7 @@ pub(super) fn spawn_watchers(
     weather: &Arc<WeatherService>,
 ) {
     spawn_weather_watcher(sender, config, weather);
+    spawn_alert_watcher(sender, weather);
 }
 
 fn spawn_weather_watcher(
@@ -53,3 +54,12 @@ fn spawn_weather_watcher(
         }
     );
 }
+
+fn spawn_alert_watcher(sender: &ComponentSender<WeatherModule>, weather: &Arc<WeatherService>) {
+    let severity = weather.alert_severity();
+
+    watch!(sender, [severity.watch()], |out| {
+        let severe = severity.get().is_some_and(AlertSeverity::is_severe);
+        let _ = out.send(WeatherCmd::SevereAlert(severe));
+    });
+}
@@ -28,6 +28,7 @@ pub(crate) fn spawn(
         .connector()
         .map(|s| s.to_string())
         .unwrap_or_else(|| "unknown".to_string());
+    let description = monitor.description().map(|s| s.to_string());
 
     let mut layout_stream = config.bar.layout.watch();
     let mut hidden_stream = ipc.hidden_bars.watch();
@@ -41,13 +42,15 @@ pub(crate) fn spawn(
                 () = &mut shutdown_fut => break,
 
                 Some(_) = layout_stream.next() => {
-                    if let Some(layout) = build_layout(&config, &ipc, &connector) {
+                    let description = description.as_deref();
+                    if let Some(layout) = build_layout(&config, &ipc, &connector, description) {
                         let _ = out.send(BarCmd::LayoutLoaded(layout));
                     }
                 }
 
                 Some(_) = hidden_stream.next() => {
-                    if let Some(layout) = build_layout(&config, &ipc, &connector) {
+                    let description = description.as_deref();
+                    if let Some(layout) = build_layout(&config, &ipc, &connector, description) {
                         let _ = out.send(BarCmd::LayoutLoaded(layout));
                     }
                 }
@@ -56,11 +59,16 @@ pub(crate) fn spawn(
     });
 }
 
-fn build_layout(config: &Config, ipc: &ShellIpcState, connector: &str) -> Option<BarLayout> {
+fn build_layout(
+    config: &Config,
+    ipc: &ShellIpcState,
+    connector: &str,
+    description: Option<&str>,
+) -> Option<BarLayout> {
     let layouts = config.bar.layout.get();
     debug!(connector = %connector, layout_count = layouts.len(), "Loaded bar layouts");
 
-    let mut layout = find_layout(&layouts, connector)?;
+    let mut layout = find_layout(&layouts, connector, description)?;
 
     if ipc.hidden_bars.get().contains(connector) {
         layout.show = false;
@@ -69,25 +77,40 @@ fn build_layout(config: &Config, ipc: &ShellIpcState, connector: &str) -> Option
     Some(layout)
 }
 
-/// Finds the layout matching `connector` (exact match first, then `"*"` wildcard)
-/// and resolves any `extends` chain into a single flattened layout.
-pub(crate) fn find_layout(layouts: &[BarLayout], connector: &str) -> Option<BarLayout> {
+/// Finds the layout for a monitor and resolves any `extends` chain into a
+/// single flattened layout.
+///
+/// An exact `connector` match wins, then a `"desc:"` entry whose text starts
+/// the monitor's `description`, then the `"*"` wildcard.
+pub(crate) fn find_layout(
+    layouts: &[BarLayout],
+    connector: &str,
+    description: Option<&str>,
+) -> Option<BarLayout> {
     let mut visited = HashSet::new();
 
-    if let Some(layout) = layouts
-        .iter()
-        .find(|candidate| candidate.monitor == connector)
-    {
-        return Some(merge_parent(layout, layouts, &mut visited));
-    }
-
-    if let Some(layout) = layouts.iter().find(|candidate| candidate.monitor == "*") {
-        return Some(merge_parent(layout, layouts, &mut visited));
-    }
+    let by_connector = || {
+        layouts
+            .iter()
+            .find(|candidate| candidate.monitor == connector)
+    };
+    let by_description = || {
+        let description = description?;
+        layouts.iter().find(|candidate| {
+            candidate
+                .monitor
+                .strip_prefix(DESCRIPTION_PREFIX)
+                .is_some_and(|wanted| description.starts_with(wanted.trim()))
+        })
+    };
+    let wildcard = || layouts.iter().find(|candidate| candidate.monitor == "*");
 
-    None
+    let layout = by_connector().or_else(by_description).or_else(wildcard)?;
+    Some(merge_parent(layout, layouts, &mut visited))
 }
 
+const DESCRIPTION_PREFIX: &str = "desc:";
+
 fn merge_parent(
     layout: &BarLayout,
     all_layouts: &[BarLayout],
@@ -129,3 +152,45 @@ fn merge_parent(
 
     resolved
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(monitor: &str) -> BarLayout {
+        BarLayout {
+            monitor: monitor.to_string(),
+            ..BarLayout::default()
+        }
+    }
+
+    #[test]
+    fn connector_wins_over_description_and_wildcard() {
+        let layouts = [layout("*"), layout("desc:Dell Inc."), layout("DP-1")];
+
+        let found = find_layout(&layouts, "DP-1", Some("Dell Inc. DELL U2720Q"));
+
+        assert_eq!(found.map(|layout| layout.monitor).as_deref(), Some("DP-1"));
+    }
+
+    #[test]
+    fn description_prefix_selects_layout() {
+        let layouts = [layout("*"), layout("desc:Dell Inc. DELL U2720Q")];
+
+        let found = find_layout(&layouts, "DP-2", Some("Dell Inc. DELL U2720Q 8K2Q (DP-2)"));
+
+        assert_eq!(
+            found.map(|layout| layout.monitor).as_deref(),
+            Some("desc:Dell Inc. DELL U2720Q")
+        );
+    }
+
+    #[test]
+    fn unmatched_monitor_falls_back_to_wildcard() {
+        let layouts = [layout("*"), layout("eDP-1"), layout("desc:Dell Inc.")];
+
+        let found = find_layout(&layouts, "HDMI-A-1", Some("LG Electronics 27GL850"));
+
+        assert_eq!(found.map(|layout| layout.monitor).as_deref(), Some("*"));
+    }
+}
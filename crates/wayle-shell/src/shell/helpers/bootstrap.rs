@@ -1,7 +1,5 @@
 //! Shell startup: CSS, icons, and actions.
 
-use std::sync::Arc;
-
 use gdk4::Display;
 use gtk4::{
     CssProvider, STYLE_PROVIDER_PRIORITY_USER, Window, glib, prelude::ApplicationExt,
@@ -12,28 +10,43 @@ use relm4::{
     main_application,
 };
 use tracing::{info, warn};
-use wayle_config::ConfigService;
 use wayle_icons::IconRegistry;
-use wayle_styling::{STATIC_CSS, theme_css};
+use wayle_styling::{STATIC_CSS, select_palette, theme_css};
+use wayle_widgets::icons::set_missing_icon_handler;
+
+use crate::shell::ShellServices;
 
 relm4::new_action_group!(AppActionGroup, "app");
 relm4::new_stateless_action!(QuitAction, AppActionGroup, "quit");
 relm4::new_stateless_action!(InspectorAction, AppActionGroup, "inspector");
 
 pub(crate) fn init_icons() {
-    if let Err(err) = IconRegistry::new().and_then(|r| r.init()) {
+    let registry = match IconRegistry::new() {
+        Ok(registry) => registry,
+        Err(err) => {
+            warn!(error = %err, "Icon registry init failed");
+            return;
+        }
+    };
+
+    if let Err(err) = registry.init() {
         warn!(error = %err, "Icon registry init failed");
     }
+
+    set_missing_icon_handler(move |name| match registry.install_bundled(name) {
+        Ok(installed) => installed,
+        Err(err) => {
+            warn!(error = %err, icon = name, "cannot install bundled icon");
+            false
+        }
+    });
 }
 
-pub(crate) fn init_css_provider(
-    display: &Display,
-    config_service: &Arc<ConfigService>,
-) -> CssProvider {
+pub(crate) fn init_css_provider(display: &Display, services: &ShellServices) -> CssProvider {
     let provider = CssProvider::new();
 
-    let config = config_service.config();
-    let palette = config.styling.palette();
+    let config = services.config.config();
+    let palette = select_palette(&config.styling, services.color_scheme.get());
     let theme = theme_css(&palette, &config.general, &config.bar, &config.styling);
     let css = format!("{STATIC_CSS}\n{theme}");
 
@@ -80,7 +80,7 @@ impl Component for Shell {
         helpers::register_app_actions();
         watchers::init(&sender, &init.services);
 
-        let css_provider = helpers::init_css_provider(&display, &init.services.config);
+        let css_provider = helpers::init_css_provider(&display, &init.services);
         let bars = helpers::monitors::create_bars(&init.services);
         helpers::monitors::schedule_deferred_sync_if_needed(bars.len(), &sender);
 
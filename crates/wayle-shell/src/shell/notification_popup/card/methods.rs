@@ -26,6 +26,8 @@ impl NotificationPopupCard {
     }
 
     pub(super) fn apply_icon(&self, icon: &gtk::Image, icon_container: &gtk::Box) {
+        icon_container.remove_css_class("file-icon");
+
         match &self.resolved_icon {
             ResolvedIcon::Named(name) => {
                 icon.set_icon_name(Some(name));
@@ -160,9 +162,11 @@ impl NotificationPopupCard {
 
         hover.connect_enter(move |_, _, _| {
             service_enter.inhibit_popup(notif_id);
+            service_enter.pause_expiry(notif_id);
         });
         hover.connect_leave(move |_| {
             service_leave.release_popup(notif_id);
+            service_leave.resume_expiry(notif_id);
         });
         root.add_controller(hover);
     }
@@ -13,8 +13,7 @@ use wayle_notification::{NotificationService, core::notification::Notification};
 
 use super::{
     helpers::{
-        ResolvedIcon, relative_time, resolve_icon, sanitize_markup, urgency_bar_visible,
-        urgency_css_class,
+        ResolvedIcon, notification_icon, relative_time, urgency_bar_visible, urgency_css_class,
     },
     templates::NotificationContentTemplate,
 };
@@ -32,13 +31,15 @@ pub(crate) struct CardInit {
     pub(crate) shadow: bool,
 }
 
-/// Configuration change commands for popup cards.
+/// Configuration and content change commands for popup cards.
 #[derive(Debug)]
 pub(crate) enum CardCmd {
     ConfigChanged {
         shadow: bool,
         urgency_bar: UrgencyBarThreshold,
     },
+    /// The notification was replaced in place with new content.
+    ContentChanged,
 }
 
 /// A single notification popup card.
@@ -47,6 +48,7 @@ pub(crate) struct NotificationPopupCard {
     service: Arc<NotificationService>,
     hover_pause: bool,
     close_behavior: PopupCloseBehavior,
+    icon_source: IconSource,
     resolved_icon: ResolvedIcon,
     app_label: String,
     time_label: String,
@@ -96,16 +98,14 @@ impl Component for NotificationPopupCard {
                     },
                     #[template_child]
                     title {
+                        #[watch]
                         set_label: &model.notification.summary.get(),
                     },
                     #[template_child]
                     body {
-                        set_label: &model
-                            .notification
-                            .body
-                            .get()
-                            .as_deref()
-                            .map_or_else(String::new, sanitize_markup),
+                        #[watch]
+                        set_label: &model.notification.body_markup.get().unwrap_or_default(),
+                        #[watch]
                         set_visible: model.notification.body.get().is_some(),
                     },
                 },
@@ -144,13 +144,7 @@ impl Component for NotificationPopupCard {
     ) -> ComponentParts<Self> {
         let notif = &init.notification;
 
-        let resolved_icon = resolve_icon(
-            init.icon_source,
-            &notif.app_name.get(),
-            &notif.app_icon.get(),
-            &notif.image_path.get(),
-            &notif.desktop_entry.get(),
-        );
+        let resolved_icon = notification_icon(init.icon_source, notif);
 
         let app_label = notif
             .app_name
@@ -164,6 +158,7 @@ impl Component for NotificationPopupCard {
             service: init.service,
             hover_pause: init.hover_pause,
             close_behavior: init.close_behavior,
+            icon_source: init.icon_source,
             resolved_icon,
             app_label,
             time_label,
@@ -178,11 +173,18 @@ impl Component for NotificationPopupCard {
         model.setup_hover_controller(&root);
 
         watchers::spawn(&sender, &init.config);
+        watchers::spawn_content_watcher(&sender, &model.notification);
 
         ComponentParts { model, widgets }
     }
 
-    fn update_cmd(&mut self, msg: CardCmd, _sender: ComponentSender<Self>, root: &Self::Root) {
+    fn update_cmd_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: CardCmd,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
         match msg {
             CardCmd::ConfigChanged {
                 shadow,
@@ -200,6 +202,12 @@ impl Component for NotificationPopupCard {
                     root.remove_css_class("urgency-bar");
                 }
             }
+            CardCmd::ContentChanged => {
+                self.resolved_icon = notification_icon(self.icon_source, &self.notification);
+                self.apply_icon(&widgets.icon, &widgets.icon_container);
+            }
         }
+
+        self.update_view(widgets, sender);
     }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use relm4::ComponentSender;
 use wayle_config::ConfigService;
+use wayle_notification::core::notification::Notification;
 use wayle_widgets::watch;
 
 use super::{CardCmd, NotificationPopupCard};
@@ -18,3 +19,26 @@ pub(super) fn spawn(sender: &ComponentSender<NotificationPopupCard>, config: &Ar
         });
     });
 }
+
+pub(super) fn spawn_content_watcher(
+    sender: &ComponentSender<NotificationPopupCard>,
+    notification: &Arc<Notification>,
+) {
+    let summary = notification.summary.clone();
+    let body_markup = notification.body_markup.clone();
+    let app_icon = notification.app_icon.clone();
+    let image_path = notification.image_path.clone();
+
+    watch!(
+        sender,
+        [
+            summary.watch(),
+            body_markup.watch(),
+            app_icon.watch(),
+            image_path.watch()
+        ],
+        |out| {
+            let _ = out.send(CardCmd::ContentChanged);
+        }
+    );
+}
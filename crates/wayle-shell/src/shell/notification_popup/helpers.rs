@@ -1,25 +1,12 @@
 use chrono::{DateTime, Utc};
-use relm4::gtk::{glib, pango};
 use wayle_config::schemas::modules::notification::{IconSource, UrgencyBarThreshold};
-use wayle_notification::types::Urgency;
+use wayle_notification::{core::notification::Notification, types::Urgency};
 
 use crate::shell::bar::icons::lookup_app_icon;
 
 const FALLBACK_ICON: &str = "ld-bell-symbolic";
 const MINUTES_PER_HOUR: i64 = 60;
 
-/// Some apps send notification bodies with bare `&` or other broken XML
-/// that Pango chokes on. If the text parses cleanly we leave it alone,
-/// otherwise we escape the whole thing so the label at least shows
-/// something instead of blowing up.
-pub(crate) fn sanitize_markup(text: &str) -> String {
-    if pango::parse_markup(text, '\0').is_ok() {
-        return text.to_owned();
-    }
-
-    glib::markup_escape_text(text).into()
-}
-
 /// Resolved notification icon.
 #[derive(Debug, Clone)]
 pub(crate) enum ResolvedIcon {
@@ -109,6 +96,20 @@ pub(crate) fn resolve_icon(
     }
 }
 
+/// Resolves the current icon of `notification` (see [`resolve_icon`]).
+pub(crate) fn notification_icon(
+    icon_source: IconSource,
+    notification: &Notification,
+) -> ResolvedIcon {
+    resolve_icon(
+        icon_source,
+        &notification.app_name.get(),
+        &notification.app_icon.get(),
+        &notification.image_path.get(),
+        &notification.desktop_entry.get(),
+    )
+}
+
 /// Classifies a non-empty icon string as either a file path or theme icon name.
 fn try_icon_string(value: &Option<String>) -> Option<ResolvedIcon> {
     let icon = value.as_deref().filter(|raw| !raw.is_empty())?;
@@ -259,28 +260,4 @@ mod tests {
         let result = try_icon_string(&Some("firefox".into()));
         assert!(matches!(result, Some(ResolvedIcon::Named(name)) if name == "firefox"));
     }
-
-    #[test]
-    fn sanitize_markup_preserves_valid_markup() {
-        let valid = "<b>bold</b> and <i>italic</i>";
-        assert_eq!(sanitize_markup(valid), valid);
-    }
-
-    #[test]
-    fn sanitize_markup_escapes_bare_ampersand() {
-        let raw = "NixOS Package & Module";
-        assert_eq!(sanitize_markup(raw), "NixOS Package &amp; Module");
-    }
-
-    #[test]
-    fn sanitize_markup_passes_plain_text() {
-        let plain = "Hello world";
-        assert_eq!(sanitize_markup(plain), plain);
-    }
-
-    #[test]
-    fn sanitize_markup_escapes_mixed_invalid() {
-        let raw = "<b>bold</b> & more";
-        assert_eq!(sanitize_markup(raw), "&lt;b&gt;bold&lt;/b&gt; &amp; more");
-    }
 }
@@ -4,13 +4,15 @@ use wayle_audio::AudioService;
 use wayle_battery::BatteryService;
 use wayle_bluetooth::BluetoothService;
 use wayle_brightness::BrightnessService;
-use wayle_config::ConfigService;
-use wayle_core::DeferredService;
+use wayle_config::{ConfigService, schemas::styling::ColorScheme};
+use wayle_core::{DeferredService, Property};
 use wayle_hyprland::HyprlandService;
+use wayle_idle_inhibit::IdleInhibitRegistry;
 use wayle_media::MediaService;
 use wayle_network::NetworkService;
 use wayle_notification::NotificationService;
 use wayle_power_profiles::PowerProfilesService;
+use wayle_session::SessionService;
 use wayle_sysinfo::SysinfoService;
 use wayle_systray::SystemTrayService;
 use wayle_wallpaper::WallpaperService;
@@ -28,13 +30,16 @@ pub(crate) struct ShellServices {
     pub battery: Option<Arc<BatteryService>>,
     pub bluetooth: DeferredService<BluetoothService>,
     pub brightness: Option<Arc<BrightnessService>>,
+    pub color_scheme: Property<ColorScheme>,
     pub config: Arc<ConfigService>,
     pub hyprland: Option<Arc<HyprlandService>>,
     pub idle_inhibit: Arc<IdleInhibitService>,
+    pub idle_inhibit_registry: IdleInhibitRegistry,
     pub media: Option<Arc<MediaService>>,
     pub network: Option<Arc<NetworkService>>,
     pub notification: Option<Arc<NotificationService>>,
     pub power_profiles: DeferredService<PowerProfilesService>,
+    pub session: Option<Arc<SessionService>>,
     pub sysinfo: Arc<SysinfoService>,
     pub systray: Option<Arc<SystemTrayService>>,
     pub wallpaper: Option<Arc<WallpaperService>>,
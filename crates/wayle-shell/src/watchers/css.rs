@@ -5,20 +5,23 @@ use futures::{
     stream::{self, BoxStream},
 };
 use relm4::ComponentSender;
-use wayle_config::schemas::styling::ThemeProvider;
-use wayle_styling::{STATIC_CSS, theme_css};
+use tracing::warn;
+use wayle_config::schemas::styling::{ColorScheme, ThemeProvider};
+use wayle_core::Property;
+use wayle_styling::{STATIC_CSS, select_palette, theme_css, validate_theme};
 use wayle_widgets::{watch, watchers::changes_stream};
 
 use crate::shell::{Shell, ShellCmd, ShellInput, ShellServices};
 
 /// Spawns the CSS hot-reload watcher.
 ///
-/// Watches styling config properties and color extraction events. Regenerates
-/// theme CSS when config changes or after color extraction completes.
+/// Watches styling config properties, the system color scheme and color
+/// extraction events. Regenerates theme CSS when any of them change.
 pub fn spawn(sender: &ComponentSender<Shell>, services: &ShellServices) {
     let config = services.config.config().clone();
+    let color_scheme = services.color_scheme.clone();
 
-    let css = build_css(&config);
+    let css = build_css(&config, &color_scheme);
     sender.input_sender().send(ShellInput::ReloadCss(css)).ok();
 
     let palette_stream = changes_stream(&config.styling.palette);
@@ -26,6 +29,10 @@ pub fn spawn(sender: &ComponentSender<Shell>, services: &ShellServices) {
     let bar_stream = changes_stream(&config.bar);
     let global_scale_stream = config.styling.scale.watch();
     let global_rounding_stream = config.styling.rounding.watch();
+    let theme_mode_stream = config.styling.theme_mode.watch();
+    let light_theme_stream = config.styling.light_theme.watch();
+    let available_themes_stream = config.styling.available.watch();
+    let color_scheme_stream = color_scheme.watch();
 
     let theme_provider_stream = config
         .styling
@@ -46,15 +53,23 @@ pub fn spawn(sender: &ComponentSender<Shell>, services: &ShellServices) {
             bar_stream,
             global_scale_stream,
             global_rounding_stream,
+            theme_mode_stream,
+            light_theme_stream,
+            available_themes_stream,
+            color_scheme_stream,
             theme_provider_stream,
             extraction_stream,
         ],
-        move || Ok::<_, std::convert::Infallible>(build_css(&config_clone)) => ShellCmd::CssRecompiled
+        move || Ok::<_, std::convert::Infallible>(build_css(&config_clone, &color_scheme)) => ShellCmd::CssRecompiled
     );
 }
 
-fn build_css(config: &wayle_config::Config) -> String {
-    let palette = config.styling.palette();
+fn build_css(config: &wayle_config::Config, color_scheme: &Property<ColorScheme>) -> String {
+    let palette = select_palette(&config.styling, color_scheme.get());
+    if let Err(err) = validate_theme(&palette, &config.general, &config.styling) {
+        warn!(error = %err, "theme contains invalid values");
+    }
+
     let theme = theme_css(&palette, &config.general, &config.bar, &config.styling);
 
     format!("{STATIC_CSS}\n{theme}")
@@ -6,6 +6,7 @@ mod notification;
 mod osd;
 mod scss_dev;
 mod sysinfo;
+mod systray;
 mod wallpaper;
 mod weather;
 
@@ -13,6 +14,7 @@ use std::env;
 
 pub(crate) use color_extractor::build_extractor_config;
 use relm4::ComponentSender;
+pub(crate) use systray::sort_policy as systray_sort_policy;
 
 use crate::shell::{Shell, ShellServices};
 
@@ -24,6 +26,7 @@ pub(crate) fn init(sender: &ComponentSender<Shell>, services: &ShellServices) {
     color_extractor::spawn(services);
     notification::spawn(services);
     sysinfo::spawn(services);
+    systray::spawn(services);
     wallpaper::spawn(services);
     weather::spawn(services);
 
@@ -1,4 +1,4 @@
-//! Notification blocklist hot-reload watcher.
+//! Notification config hot-reload watchers.
 
 use std::sync::Arc;
 
@@ -7,7 +7,8 @@ use wayle_notification::NotificationService;
 
 use crate::shell::ShellServices;
 
-/// Syncs the notification blocklist from config to the service on change.
+/// Syncs the notification blocklist and coalescing from config to the
+/// service on change.
 pub fn spawn(services: &ShellServices) {
     let Some(notification) = &services.notification else {
         return;
@@ -15,6 +16,7 @@ pub fn spawn(services: &ShellServices) {
 
     let config = services.config.config();
     spawn_blocklist_watcher(&config.modules.notification, notification);
+    spawn_coalesce_watcher(&config.modules.notification, notification);
 }
 
 fn spawn_blocklist_watcher(
@@ -32,3 +34,19 @@ fn spawn_blocklist_watcher(
         }
     });
 }
+
+fn spawn_coalesce_watcher(
+    config: &wayle_config::schemas::modules::notification::NotificationConfig,
+    service: &Arc<NotificationService>,
+) {
+    let mut stream = config.coalesce.watch();
+    let service = service.clone();
+
+    tokio::spawn(async move {
+        stream.next().await;
+
+        while let Some(coalesce) = stream.next().await {
+            service.set_coalesce(coalesce);
+        }
+    });
+}
@@ -9,8 +9,9 @@ use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher, event::EventKind
 use relm4::ComponentSender;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info};
-use wayle_config::ConfigService;
-use wayle_styling::{compile_dev, scss_dir, theme_css};
+use wayle_config::{ConfigService, schemas::styling::ColorScheme};
+use wayle_core::Property;
+use wayle_styling::{compile_dev, scss_dir, select_palette, theme_css};
 
 use crate::shell::{Shell, ShellCmd, ShellServices};
 
@@ -44,12 +45,14 @@ pub fn spawn(sender: &ComponentSender<Shell>, services: &ShellServices) {
     let watcher = Arc::new(watcher);
     let cmd_sender = sender.command_sender().clone();
     let config_service = services.config.clone();
+    let color_scheme = services.color_scheme.clone();
 
     tokio::spawn(run_debounced_event_loop(
         watcher,
         rx,
         cmd_sender,
         config_service,
+        color_scheme,
     ));
 }
 
@@ -71,6 +74,7 @@ async fn run_debounced_event_loop(
     mut rx: mpsc::UnboundedReceiver<Event>,
     cmd_sender: relm4::Sender<ShellCmd>,
     config_service: Arc<ConfigService>,
+    color_scheme: Property<ColorScheme>,
 ) {
     use tokio::time::{Instant, sleep_until};
 
@@ -92,7 +96,7 @@ async fn run_debounced_event_loop(
             }
             Some(_) => {}
             None if deadline.is_some() => {
-                recompile_css(&cmd_sender, &config_service);
+                recompile_css(&cmd_sender, &config_service, color_scheme.get());
                 deadline = None;
             }
             None => break,
@@ -100,9 +104,13 @@ async fn run_debounced_event_loop(
     }
 }
 
-fn recompile_css(cmd_sender: &relm4::Sender<ShellCmd>, config_service: &ConfigService) {
+fn recompile_css(
+    cmd_sender: &relm4::Sender<ShellCmd>,
+    config_service: &ConfigService,
+    color_scheme: ColorScheme,
+) {
     let config = config_service.config();
-    let palette = config.styling.palette();
+    let palette = select_palette(&config.styling, color_scheme);
 
     match compile_dev() {
         Ok(static_css) => {
@@ -0,0 +1,36 @@
+//! Systray config hot-reload watcher.
+
+use futures::{StreamExt, stream};
+use wayle_config::schemas::modules::{SystrayConfig, TraySort};
+use wayle_systray::types::TraySortPolicy;
+
+use crate::shell::ShellServices;
+
+/// Maps the module's `sort` and `order` settings to the service policy.
+pub(crate) fn sort_policy(config: &SystrayConfig) -> TraySortPolicy {
+    match config.sort.get() {
+        TraySort::Registration => TraySortPolicy::RegistrationOrder,
+        TraySort::Alphabetical => TraySortPolicy::Alphabetical,
+        TraySort::Category => TraySortPolicy::CategoryThenAlpha,
+        TraySort::Manual => TraySortPolicy::Manual(config.order.get()),
+    }
+}
+
+/// Syncs the tray item sort policy from config to the service on change.
+pub fn spawn(services: &ShellServices) {
+    let Some(systray) = services.systray.clone() else {
+        return;
+    };
+
+    let config = services.config.config().modules.systray.clone();
+    let mut changes = stream::select(
+        config.sort.watch().map(|_| ()),
+        config.order.watch().map(|_| ()),
+    );
+
+    tokio::spawn(async move {
+        while changes.next().await.is_some() {
+            systray.sort_policy.set(sort_policy(&config));
+        }
+    });
+}
@@ -8,8 +8,21 @@ use wayle_config::schemas::styling::ThemeProvider;
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// SCSS compilation failed.
-    #[error("scss compilation failed")]
-    Compilation(#[from] Box<grass::Error>),
+    ///
+    /// `line` is 1-based and `snippet` is the source line grass rejected.
+    #[error("scss compilation failed at line {line}: {cause}\n  {snippet}")]
+    Compilation {
+        /// Line number of the offending source line.
+        line: usize,
+        /// The offending source line, trimmed.
+        snippet: String,
+        /// Likely user-facing cause of the failure.
+        cause: String,
+    },
+
+    /// SCSS compilation failed without pointing at a source line.
+    #[error("scss compilation failed: {0}")]
+    CompilationFailed(String),
 
     /// File I/O error when writing variables.
     #[error("cannot write scss variables")]
@@ -27,3 +40,57 @@ pub enum Error {
     #[error("theme provider '{0}' is not yet implemented")]
     ProviderNotImplemented(ThemeProvider),
 }
+
+impl Error {
+    /// Maps a grass failure to [`Error::Compilation`] with the offending line.
+    ///
+    /// Failures without a location become [`Error::CompilationFailed`].
+    ///
+    /// Lines declaring a generated theme variable (`$palette-primary: ...`)
+    /// get a cause naming that variable, since grass only reports the
+    /// expression it choked on.
+    pub(crate) fn from_grass(err: grass::Error) -> Self {
+        match err.kind() {
+            grass::ErrorKind::ParseError { message, loc, .. } => {
+                let snippet = loc.file.source_line(loc.begin.line).trim().to_owned();
+                let cause = match theme_variable(&snippet) {
+                    Some(variable) => format!("{} for {variable}", variable_cause(variable)),
+                    None => message,
+                };
+
+                Self::Compilation {
+                    line: loc.begin.line + 1,
+                    snippet,
+                    cause,
+                }
+            }
+            grass::ErrorKind::IoError(source) => {
+                Self::Io(std::io::Error::new(source.kind(), source.to_string()))
+            }
+            grass::ErrorKind::FromUtf8Error(message) => {
+                Self::CompilationFailed(format!("invalid UTF-8: {message}"))
+            }
+            other => Self::CompilationFailed(format!("{other:?}")),
+        }
+    }
+}
+
+fn theme_variable(line: &str) -> Option<&str> {
+    let (name, _) = line.split_once(':')?;
+    let name = name.trim();
+    let is_theme_variable = ["$palette-", "$cfg-font-", "$scale-"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix));
+
+    is_theme_variable.then_some(name)
+}
+
+fn variable_cause(variable: &str) -> &'static str {
+    if variable.starts_with("$palette-") {
+        "invalid color value"
+    } else if variable.starts_with("$cfg-font-") {
+        "invalid font value"
+    } else {
+        "invalid scale value"
+    }
+}
@@ -4,12 +4,16 @@
 //! Runtime theming is done via CSS custom properties.
 
 mod errors;
+mod mode;
 mod palette_provider;
+mod validate;
 
 use std::path::PathBuf;
 
 pub use errors::Error;
+pub use mode::select_palette;
 use tracing::error;
+pub use validate::validate_theme;
 use wayle_config::{
     infrastructure::themes::Palette,
     schemas::{
@@ -61,6 +65,9 @@ pub fn theme_css(
     --palette-yellow: {yellow};
     --palette-green: {green};
     --palette-blue: {blue};
+    --palette-warning: {warning};
+    --palette-info: {info};
+    --palette-accent-2: {accent_2};
 
     --cfg-font-sans: "{font_sans}";
     --cfg-font-mono: "{font_mono}";
@@ -90,6 +97,9 @@ pub fn theme_css(
         yellow = resolved.yellow,
         green = resolved.green,
         blue = resolved.blue,
+        warning = resolved.warning(),
+        info = resolved.info(),
+        accent_2 = resolved.accent_2(),
         font_sans = general.font_sans.get(),
         font_mono = general.font_mono.get(),
         global_scale = styling.scale.get(),
@@ -123,7 +133,7 @@ pub fn compile_dev() -> Result<String, Error> {
     let main_content = fs::read_to_string(&main_path).map_err(Error::Io)?;
     let options = grass::Options::default().load_path(&scss_path);
 
-    grass::from_string(&main_content, &options).map_err(Error::Compilation)
+    grass::from_string(&main_content, &options).map_err(|err| Error::from_grass(*err))
 }
 
 /// Resolves the active palette based on the current theme provider.
@@ -134,7 +144,8 @@ pub fn compile_dev() -> Result<String, Error> {
 ///
 /// # Errors
 ///
-/// Never errors -- falls back to the built-in palette if provider loading fails.
+/// Never errors -- falls back to the built-in palette if provider loading fails
+/// or the provider produced a malformed color.
 pub fn resolve_palette(fallback: &Palette, styling: &StylingConfig) -> Palette {
     use palette_provider::{matugen, pywal, wallust};
 
@@ -142,28 +153,39 @@ pub fn resolve_palette(fallback: &Palette, styling: &StylingConfig) -> Palette {
         ThemeProvider::Wayle => fallback.clone(),
         ThemeProvider::Matugen => {
             let is_light = styling.matugen_light.get();
-            matugen::MatugenProvider::load(is_light).unwrap_or_else(|err| {
-                error!(error = %err, "matugen palette load failed");
-                fallback.clone()
-            })
+            matugen::MatugenProvider::load(is_light)
+                .and_then(validated)
+                .unwrap_or_else(|err| {
+                    error!(error = %err, "matugen palette load failed");
+                    fallback.clone()
+                })
         }
         ThemeProvider::Wallust => {
             let is_light = styling.wallust_palette.get().is_light();
-            wallust::WallustProvider::load(is_light).unwrap_or_else(|err| {
-                error!(error = %err, "wallust palette load failed");
-                fallback.clone()
-            })
+            wallust::WallustProvider::load(is_light)
+                .and_then(validated)
+                .unwrap_or_else(|err| {
+                    error!(error = %err, "wallust palette load failed");
+                    fallback.clone()
+                })
         }
         ThemeProvider::Pywal => {
             let is_light = styling.pywal_light.get();
-            pywal::PywalProvider::load(is_light).unwrap_or_else(|err| {
-                error!(error = %err, "pywal palette load failed");
-                fallback.clone()
-            })
+            pywal::PywalProvider::load(is_light)
+                .and_then(validated)
+                .unwrap_or_else(|err| {
+                    error!(error = %err, "pywal palette load failed");
+                    fallback.clone()
+                })
         }
     }
 }
 
+fn validated(palette: Palette) -> Result<Palette, Error> {
+    validate::validate_palette(&palette)?;
+    Ok(palette)
+}
+
 #[cfg(test)]
 mod tests {
     use wayle_config::{
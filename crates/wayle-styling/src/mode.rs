@@ -0,0 +1,114 @@
+//! Light/dark palette variant selection.
+//!
+//! The dark variant is `styling.palette`. The light variant is the palette
+//! of the theme named by `styling.light-theme`, looked up among the
+//! discovered themes.
+
+use tracing::warn;
+use wayle_config::{
+    infrastructure::themes::Palette,
+    schemas::styling::{ColorScheme, StylingConfig},
+};
+
+/// Picks the palette for the configured theme mode.
+///
+/// `system` is the portal's color-scheme preference, used when the mode is
+/// [`ThemeMode::Auto`](wayle_config::schemas::styling::ThemeMode::Auto).
+/// Returns `styling.palette` when no light theme is configured or the named
+/// theme doesn't exist.
+pub fn select_palette(styling: &StylingConfig, system: ColorScheme) -> Palette {
+    let dark = styling.palette();
+
+    let light_theme = styling.light_theme.get();
+    if light_theme.is_empty() {
+        return dark;
+    }
+
+    let Some(light) = styling
+        .available
+        .get()
+        .into_iter()
+        .find(|theme| theme.name == light_theme)
+        .map(|theme| theme.palette)
+    else {
+        warn!(theme = %light_theme, "light theme not found, using the dark palette");
+        return dark;
+    };
+
+    styling
+        .theme_mode
+        .get()
+        .select(&light, &dark, system)
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use wayle_config::{
+        infrastructure::themes::palettes,
+        schemas::styling::{ThemeEntry, ThemeMode},
+    };
+
+    use super::*;
+
+    fn styling_with_light_theme() -> (StylingConfig, Palette) {
+        let styling = StylingConfig::default();
+        let light = Palette {
+            bg: String::from("#ffffff"),
+            ..styling.palette()
+        };
+
+        let mut available = palettes::builtins();
+        available.push(ThemeEntry {
+            name: String::from("paper"),
+            palette: light.clone(),
+            builtin: false,
+        });
+        styling.available.set(available);
+        styling.light_theme.set(String::from("paper"));
+
+        (styling, light)
+    }
+
+    #[test]
+    fn auto_mode_follows_the_system_preference() {
+        let (styling, light) = styling_with_light_theme();
+
+        assert_eq!(select_palette(&styling, ColorScheme::PreferLight), light);
+        assert_eq!(
+            select_palette(&styling, ColorScheme::PreferDark),
+            styling.palette()
+        );
+    }
+
+    #[test]
+    fn explicit_mode_wins_over_the_system_preference() {
+        let (styling, light) = styling_with_light_theme();
+
+        styling.theme_mode.set(ThemeMode::Light);
+        assert_eq!(select_palette(&styling, ColorScheme::PreferDark), light);
+
+        styling.theme_mode.set(ThemeMode::Dark);
+        assert_eq!(
+            select_palette(&styling, ColorScheme::PreferLight),
+            styling.palette()
+        );
+    }
+
+    #[test]
+    fn missing_light_theme_keeps_the_palette() {
+        let styling = StylingConfig::default();
+        styling.theme_mode.set(ThemeMode::Light);
+
+        assert_eq!(
+            select_palette(&styling, ColorScheme::PreferLight),
+            styling.palette()
+        );
+
+        styling.light_theme.set(String::from("no-such-theme"));
+        assert_eq!(
+            select_palette(&styling, ColorScheme::PreferLight),
+            styling.palette()
+        );
+    }
+}
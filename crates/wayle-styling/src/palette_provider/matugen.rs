@@ -85,6 +85,9 @@ impl MatugenOutput {
             yellow: colors.tertiary.pick(is_light),
             green: colors.secondary.pick(is_light),
             blue: primary,
+            warning: None,
+            info: None,
+            accent_2: None,
         }
     }
 }
@@ -66,6 +66,9 @@ impl PywalOutput {
             yellow: self.colors.color3,
             green: self.colors.color2,
             blue: self.colors.color4,
+            warning: None,
+            info: None,
+            accent_2: None,
         }
     }
 }
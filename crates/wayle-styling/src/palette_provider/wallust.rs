@@ -56,6 +56,9 @@ impl WallustOutput {
             yellow: self.color5,
             green: self.color4,
             blue: self.color6,
+            warning: None,
+            info: None,
+            accent_2: None,
         }
     }
 }
@@ -0,0 +1,117 @@
+//! Theme value validation through grass.
+//!
+//! Runtime theme values are injected as CSS custom properties, which GTK
+//! silently drops when malformed. Compiling them as SCSS variables first
+//! surfaces bad values with the variable they came from.
+
+use std::fmt::Write;
+
+use wayle_config::{
+    infrastructure::themes::Palette,
+    schemas::{general::GeneralConfig, styling::StylingConfig},
+};
+
+use crate::Error;
+
+/// Checks palette colors, fonts, and scale against what grass accepts.
+///
+/// # Errors
+///
+/// Returns [`Error::Compilation`] naming the first invalid variable, e.g.
+/// "invalid color value for $palette-primary".
+pub fn validate_theme(
+    palette: &Palette,
+    general: &GeneralConfig,
+    styling: &StylingConfig,
+) -> Result<(), Error> {
+    let mut source = palette_scss(palette);
+    let _ = writeln!(source, "$cfg-font-sans: \"{}\";", general.font_sans.get());
+    let _ = writeln!(source, "$cfg-font-mono: \"{}\";", general.font_mono.get());
+    let _ = writeln!(source, "$scale-global: {} * 1;", styling.scale.get());
+
+    compile(&source)
+}
+
+/// Checks palette colors only. Used for provider-generated palettes.
+pub(crate) fn validate_palette(palette: &Palette) -> Result<(), Error> {
+    compile(&palette_scss(palette))
+}
+
+fn palette_scss(palette: &Palette) -> String {
+    let slots = [
+        ("bg", palette.bg.as_str()),
+        ("surface", palette.surface.as_str()),
+        ("elevated", palette.elevated.as_str()),
+        ("fg", palette.fg.as_str()),
+        ("fg-muted", palette.fg_muted.as_str()),
+        ("primary", palette.primary.as_str()),
+        ("red", palette.red.as_str()),
+        ("yellow", palette.yellow.as_str()),
+        ("green", palette.green.as_str()),
+        ("blue", palette.blue.as_str()),
+        ("warning", palette.warning()),
+        ("info", palette.info()),
+        ("accent-2", palette.accent_2()),
+    ];
+
+    let mut source = String::new();
+    for (name, value) in slots {
+        let _ = writeln!(source, "$palette-{name}: rgba({value}, 1);");
+    }
+    source
+}
+
+fn compile(source: &str) -> Result<(), Error> {
+    grass::from_string(source, &grass::Options::default())
+        .map(|_| ())
+        .map_err(|err| Error::from_grass(*err))
+}
+
+#[cfg(test)]
+mod tests {
+    use wayle_config::infrastructure::themes::palettes;
+
+    use super::*;
+
+    #[test]
+    fn builtin_palette_is_valid() {
+        assert!(validate_palette(&palettes::wayle()).is_ok());
+    }
+
+    #[test]
+    fn malformed_color_names_the_palette_variable() {
+        let palette = Palette {
+            primary: String::from("#12"),
+            ..palettes::wayle()
+        };
+
+        let err = validate_palette(&palette).unwrap_err();
+        let Error::Compilation {
+            line,
+            snippet,
+            cause,
+        } = &err
+        else {
+            panic!("expected compilation error, got {err:?}");
+        };
+
+        assert_eq!(*line, 6);
+        assert_eq!(snippet, "$palette-primary: rgba(#12, 1);");
+        assert_eq!(cause, "invalid color value for $palette-primary");
+        assert!(
+            err.to_string()
+                .contains("invalid color value for $palette-primary")
+        );
+    }
+
+    #[test]
+    fn default_fonts_and_scale_are_valid() {
+        let result = validate_theme(
+            &palettes::wayle(),
+            &GeneralConfig::default(),
+            &StylingConfig::default(),
+        );
+
+        assert!(result.is_ok());
+    }
+}
@@ -7,13 +7,14 @@ use wayle_core::Property;
 use crate::{
     polling,
     service::SysinfoService,
-    types::{CpuData, DiskData, MemoryData, NetworkData},
+    types::{CpuData, DiskData, GpuData, MemoryData, NetworkData},
 };
 
 const DEFAULT_CPU_INTERVAL: Duration = Duration::from_secs(2);
 const DEFAULT_MEMORY_INTERVAL: Duration = Duration::from_secs(5);
 const DEFAULT_DISK_INTERVAL: Duration = Duration::from_secs(30);
 const DEFAULT_NETWORK_INTERVAL: Duration = Duration::from_secs(2);
+const DEFAULT_GPU_INTERVAL: Duration = Duration::from_secs(2);
 
 /// Builder for configuring a [`SysinfoService`].
 pub struct SysinfoServiceBuilder {
@@ -21,7 +22,10 @@ pub struct SysinfoServiceBuilder {
     memory_interval: Duration,
     disk_interval: Duration,
     network_interval: Duration,
+    gpu_interval: Duration,
+    gpu_enabled: bool,
     cpu_temp_sensor: String,
+    cpu_smoothing: f32,
 }
 
 impl SysinfoServiceBuilder {
@@ -32,7 +36,10 @@ impl SysinfoServiceBuilder {
             memory_interval: DEFAULT_MEMORY_INTERVAL,
             disk_interval: DEFAULT_DISK_INTERVAL,
             network_interval: DEFAULT_NETWORK_INTERVAL,
+            gpu_interval: DEFAULT_GPU_INTERVAL,
+            gpu_enabled: false,
             cpu_temp_sensor: String::from("auto"),
+            cpu_smoothing: 0.0,
         }
     }
 
@@ -60,6 +67,20 @@ impl SysinfoServiceBuilder {
         self
     }
 
+    /// Enables GPU polling, which is off by default.
+    ///
+    /// Without this, [`SysinfoService::gpus`] stays empty.
+    pub fn with_gpu(mut self) -> Self {
+        self.gpu_enabled = true;
+        self
+    }
+
+    /// Sets the GPU polling interval. Only used with [`with_gpu`](Self::with_gpu).
+    pub fn gpu_interval(mut self, interval: Duration) -> Self {
+        self.gpu_interval = interval;
+        self
+    }
+
     /// Sets the CPU temperature sensor label.
     ///
     /// Use `"auto"` for automatic detection, or specify a sensor label
@@ -69,6 +90,18 @@ impl SysinfoServiceBuilder {
         self
     }
 
+    /// Smooths [`CpuData::usage_percent`] with an exponential moving average.
+    ///
+    /// `factor` is the weight kept from the previous value on each poll:
+    /// `0.0` (the default) turns smoothing off, and higher values give a
+    /// calmer reading that reacts more slowly. Clamped to `0.0..=0.95`, and
+    /// a non-finite factor turns smoothing off.
+    /// The unsmoothed value stays in [`CpuData::raw_usage_percent`].
+    pub fn cpu_smoothing(mut self, factor: f32) -> Self {
+        self.cpu_smoothing = polling::cpu::smoothing_factor(factor);
+        self
+    }
+
     /// Builds the service and starts background polling tasks.
     #[instrument(skip_all, name = "SysinfoService::build")]
     pub fn build(self) -> SysinfoService {
@@ -78,6 +111,9 @@ impl SysinfoServiceBuilder {
         let memory = Property::new(MemoryData::default());
         let disks = Property::new(Vec::<DiskData>::new());
         let network = Property::new(Vec::<NetworkData>::new());
+        let gpus = Property::new(Vec::<GpuData>::new());
+        let uptime = Property::new(Duration::ZERO);
+        let load_average = Property::new((0.0, 0.0, 0.0));
 
         let tokens = polling::spawn_polling_tasks(
             &cancellation_token,
@@ -85,25 +121,40 @@ impl SysinfoServiceBuilder {
             &memory,
             &disks,
             &network,
+            &uptime,
+            &load_average,
             self.cpu_interval,
             self.memory_interval,
             self.disk_interval,
             self.network_interval,
             self.cpu_temp_sensor.clone(),
+            self.cpu_smoothing,
         );
 
+        let gpu_token = self.gpu_enabled.then(|| {
+            let token = cancellation_token.child_token();
+            polling::gpu::spawn(token.clone(), gpus.clone(), self.gpu_interval);
+            token
+        });
+
         SysinfoService {
             cancellation_token,
             cpu_token: RwLock::new(tokens.cpu),
             memory_token: RwLock::new(tokens.memory),
             disk_token: RwLock::new(tokens.disk),
             network_token: RwLock::new(tokens.network),
+            system_token: RwLock::new(tokens.system),
+            gpu_token: RwLock::new(gpu_token),
             cpu_interval: RwLock::new(self.cpu_interval),
             cpu_temp_sensor: RwLock::new(self.cpu_temp_sensor),
+            cpu_smoothing: RwLock::new(self.cpu_smoothing),
             cpu,
             memory,
             disks,
             network,
+            gpus,
+            uptime,
+            load_average,
         }
     }
 }
@@ -1,7 +1,7 @@
 //! System information monitoring service.
 //!
-//! Provides reactive access to CPU, memory, disk, and network metrics
-//! via polling-based background tasks.
+//! Provides reactive access to CPU, memory, disk, network, and GPU metrics,
+//! plus uptime and load average, via polling-based background tasks.
 //!
 //! # Quick Start
 //!
@@ -16,6 +16,9 @@
 //!
 //! let memory = service.memory.get();
 //! println!("Memory: {:.1}%", memory.usage_percent);
+//!
+//! let (one, five, fifteen) = service.load_average.get();
+//! println!("Up {}s, load {one:.2} {five:.2} {fifteen:.2}", service.uptime.get().as_secs());
 //! ```
 //!
 //! # Reactive Streams
@@ -35,6 +38,32 @@
 //! }
 //! # }
 //! ```
+//!
+//! # GPU Metrics
+//!
+//! GPU polling is opt-in:
+//!
+//! ```rust,no_run
+//! use wayle_sysinfo::SysinfoService;
+//!
+//! let service = SysinfoService::builder().with_gpu().build();
+//!
+//! for gpu in service.gpus.get() {
+//!     println!("{}: {:?}%", gpu.name, gpu.utilization_percent);
+//! }
+//! ```
+//!
+//! Metrics come from the DRM sysfs tree (`/sys/class/drm/cardN/device`):
+//!
+//! | Driver | Utilization | VRAM | Temperature |
+//! |--------|-------------|------|-------------|
+//! | `amdgpu` | Yes | Yes | Yes (hwmon) |
+//! | `i915`, `xe` | No | No | If the driver exposes hwmon |
+//!
+//! Intel busy time is only available through perf counters, and integrated
+//! GPUs share system memory, so those fields are `None`. NVIDIA cards are
+//! not listed: the proprietary driver reports nothing useful through sysfs,
+//! and reading it needs NVML, which is out of scope for now.
 
 mod builder;
 mod error;
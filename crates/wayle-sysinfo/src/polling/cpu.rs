@@ -8,6 +8,9 @@ use wayle_core::Property;
 
 use crate::types::{CoreData, CpuData};
 
+/// Highest CPU smoothing factor. At 1.0 the usage would never move.
+const MAX_CPU_SMOOTHING: f32 = 0.95;
+
 const CPU_TEMP_PATTERNS: &[&str] = &[
     "tctl",       // AMD Ryzen primary temp
     "tdie",       // AMD die temp
@@ -38,16 +41,38 @@ fn find_cpu_temperature(components: &Components, sensor: &str) -> Option<f32> {
     None
 }
 
+/// Limits a smoothing factor to `0.0..=0.95`. NaN and infinities turn
+/// smoothing off, since `clamp` would pass NaN through.
+pub(crate) fn smoothing_factor(factor: f32) -> f32 {
+    if factor.is_finite() {
+        factor.clamp(0.0, MAX_CPU_SMOOTHING)
+    } else {
+        0.0
+    }
+}
+
+/// Blends `raw` into the previous smoothed usage. `factor` is the weight of
+/// the previous value, so 0 passes `raw` through unchanged.
+fn smooth_usage(previous: Option<f32>, raw: f32, factor: f32) -> f32 {
+    let factor = smoothing_factor(factor);
+    match previous {
+        Some(previous) => factor * previous + (1.0 - factor) * raw,
+        None => raw,
+    }
+}
+
 pub(crate) fn spawn(
     token: CancellationToken,
     cpu: Property<CpuData>,
     poll_interval: Duration,
     temp_sensor: String,
+    smoothing: f32,
 ) {
     tokio::spawn(async move {
         let mut system = System::new();
         let mut components = Components::new_with_refreshed_list();
         let mut ticker = interval(poll_interval);
+        let mut smoothed_usage = None;
 
         loop {
             if !cpu.has_subscribers() {
@@ -59,6 +84,7 @@ pub(crate) fn spawn(
                     _ = cpu.wait_for_subscribers() => {}
                 }
                 ticker.reset();
+                smoothed_usage = None;
             }
 
             if !cpu.has_subscribers() {
@@ -97,8 +123,13 @@ pub(crate) fn spawn(
 
             let temperature = find_cpu_temperature(&components, &temp_sensor);
 
+            let raw_usage = system.global_cpu_usage();
+            let usage = smooth_usage(smoothed_usage, raw_usage, smoothing);
+            smoothed_usage = Some(usage);
+
             cpu.set(CpuData {
-                usage_percent: system.global_cpu_usage(),
+                usage_percent: usage,
+                raw_usage_percent: raw_usage,
                 avg_frequency_mhz: avg_freq,
                 max_frequency_mhz: max_freq,
                 busiest_core_freq_mhz: busiest_freq,
@@ -116,3 +147,23 @@ pub(crate) fn spawn(
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_finite_smoothing_passes_raw_usage_through() {
+        for factor in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(smoothing_factor(factor), 0.0);
+            assert_eq!(smooth_usage(Some(80.0), 20.0, factor), 20.0);
+        }
+    }
+
+    #[test]
+    fn smoothing_factor_is_clamped() {
+        assert_eq!(smoothing_factor(-1.0), 0.0);
+        assert_eq!(smoothing_factor(0.5), 0.5);
+        assert_eq!(smoothing_factor(1.0), MAX_CPU_SMOOTHING);
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use tokio::time::interval;
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+use wayle_core::Property;
+
+use crate::types::GpuData;
+
+const DRM_ROOT: &str = "/sys/class/drm";
+
+const AMD_VENDOR: &str = "0x1002";
+const INTEL_VENDOR: &str = "0x8086";
+
+const AMD_DRIVERS: &[&str] = &["amdgpu"];
+const INTEL_DRIVERS: &[&str] = &["i915", "xe"];
+
+pub(crate) fn spawn(
+    token: CancellationToken,
+    gpus: Property<Vec<GpuData>>,
+    poll_interval: Duration,
+) {
+    tokio::spawn(async move {
+        let mut ticker = interval(poll_interval);
+
+        loop {
+            if !gpus.has_subscribers() {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        debug!("GPU polling cancelled");
+                        return;
+                    }
+                    _ = gpus.wait_for_subscribers() => {}
+                }
+                ticker.reset();
+            }
+
+            if !gpus.has_subscribers() {
+                continue;
+            }
+
+            gpus.set(read_gpus(Path::new(DRM_ROOT)));
+
+            tokio::select! {
+                _ = token.cancelled() => {
+                    debug!("GPU polling cancelled");
+                    return;
+                }
+                _ = ticker.tick() => {}
+            }
+        }
+    });
+}
+
+/// Reads every AMD and Intel card under `drm_root`. Cards bound to other
+/// drivers (including NVIDIA's) are skipped.
+fn read_gpus(drm_root: &Path) -> Vec<GpuData> {
+    let Ok(entries) = fs::read_dir(drm_root) else {
+        return Vec::new();
+    };
+
+    let mut cards: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let card = entry.file_name().into_string().ok()?;
+            is_card(&card).then(|| (card, entry.path().join("device")))
+        })
+        .collect();
+    cards.sort();
+
+    cards
+        .into_iter()
+        .filter_map(|(card, device)| read_gpu(card, &device))
+        .collect()
+}
+
+/// `card0` is a GPU; `card0-DP-1` is one of its connectors.
+fn is_card(name: &str) -> bool {
+    name.strip_prefix("card")
+        .is_some_and(|index| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn read_gpu(card: String, device: &Path) -> Option<GpuData> {
+    let driver = fs::read_link(device.join("driver"))
+        .ok()?
+        .file_name()?
+        .to_string_lossy()
+        .into_owned();
+
+    let is_amd = AMD_DRIVERS.contains(&driver.as_str());
+    if !is_amd && !INTEL_DRIVERS.contains(&driver.as_str()) {
+        return None;
+    }
+
+    let name = read_trimmed(&device.join("product_name"))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| format!("{} {card}", vendor_label(device)));
+
+    let (utilization_percent, memory_used_bytes, memory_total_bytes) = if is_amd {
+        (
+            read_number::<f32>(&device.join("gpu_busy_percent")),
+            read_number(&device.join("mem_info_vram_used")),
+            read_number(&device.join("mem_info_vram_total")),
+        )
+    } else {
+        (None, None, None)
+    };
+
+    Some(GpuData {
+        card,
+        name,
+        driver,
+        utilization_percent,
+        memory_used_bytes,
+        memory_total_bytes,
+        temperature_celsius: read_temperature(device),
+    })
+}
+
+fn vendor_label(device: &Path) -> &'static str {
+    match read_trimmed(&device.join("vendor")).as_deref() {
+        Some(AMD_VENDOR) => "AMD",
+        Some(INTEL_VENDOR) => "Intel",
+        _ => "GPU",
+    }
+}
+
+/// First `temp1_input` under the device's hwmon directory, in millidegrees.
+fn read_temperature(device: &Path) -> Option<f32> {
+    fs::read_dir(device.join("hwmon"))
+        .ok()?
+        .flatten()
+        .find_map(|hwmon| read_number::<f32>(&hwmon.path().join("temp1_input")))
+        .map(|millidegrees| millidegrees / 1000.0)
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|contents| contents.trim().to_owned())
+}
+
+fn read_number<T: std::str::FromStr>(path: &Path) -> Option<T> {
+    read_trimmed(path)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io, os::unix::fs::symlink};
+
+    use super::*;
+
+    /// Creates `<root>/<card>/device` bound to `driver`, with `files`
+    /// written relative to the device directory.
+    fn add_card(root: &Path, card: &str, driver: &str, files: &[(&str, &str)]) -> io::Result<()> {
+        let device = root.join(card).join("device");
+        let driver_dir = root.join("drivers").join(driver);
+        fs::create_dir_all(&device)?;
+        fs::create_dir_all(&driver_dir)?;
+        symlink(&driver_dir, device.join("driver"))?;
+
+        for (name, contents) in files {
+            let path = device.join(name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, contents)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn reads_amd_card_metrics() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        add_card(
+            root.path(),
+            "card0",
+            "amdgpu",
+            &[
+                ("product_name", "Radeon RX 7800 XT\n"),
+                ("gpu_busy_percent", "42\n"),
+                ("mem_info_vram_used", "1073741824\n"),
+                ("mem_info_vram_total", "17179869184\n"),
+                ("hwmon/hwmon3/temp1_input", "55000\n"),
+            ],
+        )?;
+        fs::create_dir_all(root.path().join("card0-DP-1"))?;
+
+        assert_eq!(
+            read_gpus(root.path()),
+            vec![GpuData {
+                card: String::from("card0"),
+                name: String::from("Radeon RX 7800 XT"),
+                driver: String::from("amdgpu"),
+                utilization_percent: Some(42.0),
+                memory_used_bytes: Some(1_073_741_824),
+                memory_total_bytes: Some(17_179_869_184),
+                temperature_celsius: Some(55.0),
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn skips_unsupported_drivers_and_names_unlabelled_cards() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        add_card(root.path(), "card0", "nvidia", &[])?;
+        add_card(root.path(), "card1", "i915", &[("vendor", "0x8086\n")])?;
+
+        let gpus = read_gpus(root.path());
+
+        assert_eq!(gpus.len(), 1);
+        assert_eq!(gpus[0].name, "Intel card1");
+        assert_eq!(gpus[0].utilization_percent, None);
+        assert_eq!(gpus[0].memory_total_bytes, None);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_drm_root_reports_no_gpus() {
+        assert!(read_gpus(Path::new("/nonexistent/drm")).is_empty());
+    }
+}
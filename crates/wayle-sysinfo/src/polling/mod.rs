@@ -1,7 +1,9 @@
 pub(crate) mod cpu;
 pub(crate) mod disk;
+pub(crate) mod gpu;
 pub(crate) mod memory;
 pub(crate) mod network;
+pub(crate) mod system;
 
 use std::time::Duration;
 
@@ -16,6 +18,7 @@ pub(crate) struct PollingTokens {
     pub(crate) memory: CancellationToken,
     pub(crate) disk: CancellationToken,
     pub(crate) network: CancellationToken,
+    pub(crate) system: CancellationToken,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -25,31 +28,43 @@ pub(crate) fn spawn_polling_tasks(
     memory: &Property<MemoryData>,
     disks: &Property<Vec<DiskData>>,
     network: &Property<Vec<NetworkData>>,
+    uptime: &Property<Duration>,
+    load_average: &Property<(f64, f64, f64)>,
     cpu_interval: Duration,
     memory_interval: Duration,
     disk_interval: Duration,
     network_interval: Duration,
     cpu_temp_sensor: String,
+    cpu_smoothing: f32,
 ) -> PollingTokens {
     let cpu_token = cancellation_token.child_token();
     let memory_token = cancellation_token.child_token();
     let disk_token = cancellation_token.child_token();
     let network_token = cancellation_token.child_token();
+    let system_token = cancellation_token.child_token();
 
     cpu::spawn(
         cpu_token.clone(),
         cpu.clone(),
         cpu_interval,
         cpu_temp_sensor,
+        cpu_smoothing,
     );
     memory::spawn(memory_token.clone(), memory.clone(), memory_interval);
     disk::spawn(disk_token.clone(), disks.clone(), disk_interval);
     network::spawn(network_token.clone(), network.clone(), network_interval);
+    system::spawn(
+        system_token.clone(),
+        uptime.clone(),
+        load_average.clone(),
+        cpu_interval,
+    );
 
     PollingTokens {
         cpu: cpu_token,
         memory: memory_token,
         disk: disk_token,
         network: network_token,
+        system: system_token,
     }
 }
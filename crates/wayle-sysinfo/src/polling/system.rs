@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use sysinfo::System;
+use tokio::time::interval;
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+use wayle_core::Property;
+
+pub(crate) fn spawn(
+    token: CancellationToken,
+    uptime: Property<Duration>,
+    load_average: Property<(f64, f64, f64)>,
+    poll_interval: Duration,
+) {
+    tokio::spawn(async move {
+        let mut ticker = interval(poll_interval);
+
+        loop {
+            let has_subscribers = || uptime.has_subscribers() || load_average.has_subscribers();
+
+            if !has_subscribers() {
+                tokio::select! {
+                    _ = token.cancelled() => {
+                        debug!("System polling cancelled");
+                        return;
+                    }
+                    _ = uptime.wait_for_subscribers() => {}
+                    _ = load_average.wait_for_subscribers() => {}
+                }
+                ticker.reset();
+            }
+
+            if !has_subscribers() {
+                continue;
+            }
+
+            uptime.set(Duration::from_secs(System::uptime()));
+
+            // sysinfo reads the platform's loadavg source (/proc/loadavg on
+            // Linux) and reports zeros where there is none.
+            let load = System::load_average();
+            load_average.set((load.one, load.five, load.fifteen));
+
+            tokio::select! {
+                _ = token.cancelled() => {
+                    debug!("System polling cancelled");
+                    return;
+                }
+                _ = ticker.tick() => {}
+            }
+        }
+    });
+}
@@ -7,10 +7,11 @@ use wayle_core::Property;
 use crate::{
     builder::SysinfoServiceBuilder,
     polling,
-    types::{CpuData, DiskData, MemoryData, NetworkData},
+    types::{CpuData, DiskData, GpuData, MemoryData, NetworkData},
 };
 
-/// System information service for monitoring CPU, memory, disk, and network.
+/// System information service for monitoring CPU, memory, disk, network,
+/// uptime, and load average.
 ///
 /// Provides reactive properties that update at configurable intervals.
 /// All metrics are polled in the background and exposed via `Property<T>`
@@ -24,8 +25,11 @@ pub struct SysinfoService {
     pub(crate) memory_token: RwLock<CancellationToken>,
     pub(crate) disk_token: RwLock<CancellationToken>,
     pub(crate) network_token: RwLock<CancellationToken>,
+    pub(crate) system_token: RwLock<CancellationToken>,
+    pub(crate) gpu_token: RwLock<Option<CancellationToken>>,
     pub(crate) cpu_interval: RwLock<Duration>,
     pub(crate) cpu_temp_sensor: RwLock<String>,
+    pub(crate) cpu_smoothing: RwLock<f32>,
 
     /// CPU metrics including usage, frequency, and temperature.
     pub cpu: Property<CpuData>,
@@ -38,6 +42,17 @@ pub struct SysinfoService {
 
     /// Network metrics for all interfaces.
     pub network: Property<Vec<NetworkData>>,
+
+    /// Metrics for AMD and Intel GPUs. Empty unless enabled with
+    /// [`SysinfoServiceBuilder::with_gpu`].
+    pub gpus: Property<Vec<GpuData>>,
+
+    /// Time since boot. Refreshed on the CPU interval.
+    pub uptime: Property<Duration>,
+
+    /// 1, 5 and 15 minute load averages. Refreshed on the CPU interval;
+    /// all zeros on platforms without a load average.
+    pub load_average: Property<(f64, f64, f64)>,
 }
 
 impl SysinfoService {
@@ -47,12 +62,15 @@ impl SysinfoService {
     }
 
     /// Updates the CPU polling interval.
+    ///
+    /// Uptime and load average follow the same interval.
     pub fn set_cpu_interval(&self, interval: Duration) {
         debug!(?interval, "Updating CPU polling interval");
         if let Ok(mut guard) = self.cpu_interval.write() {
             *guard = interval;
         }
         self.restart_cpu_polling();
+        self.restart_system_polling(interval);
     }
 
     /// Updates the CPU temperature sensor label.
@@ -64,6 +82,18 @@ impl SysinfoService {
         self.restart_cpu_polling();
     }
 
+    /// Updates the CPU usage smoothing factor.
+    ///
+    /// See [`SysinfoServiceBuilder::cpu_smoothing`]. The moving average
+    /// starts over from the next poll.
+    pub fn set_cpu_smoothing(&self, factor: f32) {
+        debug!(factor, "Updating CPU usage smoothing");
+        if let Ok(mut guard) = self.cpu_smoothing.write() {
+            *guard = polling::cpu::smoothing_factor(factor);
+        }
+        self.restart_cpu_polling();
+    }
+
     fn restart_cpu_polling(&self) {
         let interval = self.cpu_interval.read().map(|g| *g).unwrap_or_default();
         let sensor = self
@@ -71,11 +101,32 @@ impl SysinfoService {
             .read()
             .map(|g| g.clone())
             .unwrap_or_default();
+        let smoothing = self.cpu_smoothing.read().map(|g| *g).unwrap_or_default();
 
         let new_token = self.cancellation_token.child_token();
         if let Ok(mut guard) = self.cpu_token.write() {
             guard.cancel();
-            polling::cpu::spawn(new_token.clone(), self.cpu.clone(), interval, sensor);
+            polling::cpu::spawn(
+                new_token.clone(),
+                self.cpu.clone(),
+                interval,
+                sensor,
+                smoothing,
+            );
+            *guard = new_token;
+        }
+    }
+
+    fn restart_system_polling(&self, interval: Duration) {
+        let new_token = self.cancellation_token.child_token();
+        if let Ok(mut guard) = self.system_token.write() {
+            guard.cancel();
+            polling::system::spawn(
+                new_token.clone(),
+                self.uptime.clone(),
+                self.load_average.clone(),
+                interval,
+            );
             *guard = new_token;
         }
     }
@@ -118,6 +169,22 @@ impl SysinfoService {
             *guard = new_token;
         }
     }
+
+    /// Updates the GPU polling interval.
+    ///
+    /// Restarts the GPU polling task with the new interval. Does nothing if
+    /// GPU polling was not enabled on the builder.
+    pub fn set_gpu_interval(&self, interval: Duration) {
+        debug!(?interval, "Updating GPU polling interval");
+        let new_token = self.cancellation_token.child_token();
+        if let Ok(mut guard) = self.gpu_token.write()
+            && let Some(token) = guard.as_ref()
+        {
+            token.cancel();
+            polling::gpu::spawn(new_token.clone(), self.gpus.clone(), interval);
+            *guard = Some(new_token);
+        }
+    }
 }
 
 impl Drop for SysinfoService {
@@ -4,8 +4,14 @@ use derive_more::Debug;
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct CpuData {
     /// Total CPU usage across all cores (0.0 - 100.0).
+    ///
+    /// Smoothed when [`cpu_smoothing`](crate::SysinfoServiceBuilder::cpu_smoothing)
+    /// is set, otherwise the same as [`raw_usage_percent`](Self::raw_usage_percent).
     pub usage_percent: f32,
 
+    /// Total CPU usage from the latest poll, before smoothing (0.0 - 100.0).
+    pub raw_usage_percent: f32,
+
     /// Average frequency across all cores in MHz.
     pub avg_frequency_mhz: u64,
 
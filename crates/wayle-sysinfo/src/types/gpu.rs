@@ -0,0 +1,27 @@
+/// GPU metrics snapshot.
+///
+/// Fields the driver doesn't expose are `None`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuData {
+    /// DRM card name (e.g., "card0").
+    pub card: String,
+
+    /// Display name: the driver-reported product name when available,
+    /// otherwise vendor and card (e.g., "AMD card1").
+    pub name: String,
+
+    /// Kernel driver bound to the device (e.g., "amdgpu", "i915", "xe").
+    pub driver: String,
+
+    /// Busy percentage (0.0 - 100.0).
+    pub utilization_percent: Option<f32>,
+
+    /// Used dedicated video memory in bytes.
+    pub memory_used_bytes: Option<u64>,
+
+    /// Total dedicated video memory in bytes.
+    pub memory_total_bytes: Option<u64>,
+
+    /// GPU temperature in Celsius.
+    pub temperature_celsius: Option<f32>,
+}
@@ -1,9 +1,11 @@
 mod cpu;
 mod disk;
+mod gpu;
 mod memory;
 mod network;
 
 pub use cpu::{CoreData, CpuData};
 pub use disk::DiskData;
+pub use gpu::GpuData;
 pub use memory::MemoryData;
 pub use network::NetworkData;
@@ -12,9 +12,10 @@ use crate::{
     discovery::SystemTrayServiceDiscovery,
     error::Error,
     events::TrayEvent,
+    monitoring::sorted_items,
     proxy::status_notifier_watcher::StatusNotifierWatcherProxy,
     service::SystemTrayService,
-    types::{TrayMode, WATCHER_BUS_NAME, WATCHER_OBJECT_PATH},
+    types::{TrayMode, TraySortPolicy, WATCHER_BUS_NAME, WATCHER_OBJECT_PATH},
     watcher::{StatusNotifierWatcher, discovery},
 };
 
@@ -24,6 +25,7 @@ const EVENT_CHANNEL_CAPACITY: usize = 256;
 pub struct SystemTrayServiceBuilder {
     mode: TrayMode,
     register_daemon: bool,
+    sort_policy: TraySortPolicy,
 }
 
 impl SystemTrayServiceBuilder {
@@ -32,6 +34,7 @@ impl SystemTrayServiceBuilder {
         Self {
             mode: TrayMode::Auto,
             register_daemon: false,
+            sort_policy: TraySortPolicy::RegistrationOrder,
         }
     }
 
@@ -54,6 +57,14 @@ impl SystemTrayServiceBuilder {
         self
     }
 
+    /// Sets how `items` is ordered. Defaults to registration order.
+    ///
+    /// Can be changed later through `SystemTrayService::sort_policy`.
+    pub fn sort_policy(mut self, policy: TraySortPolicy) -> Self {
+        self.sort_policy = policy;
+        self
+    }
+
     /// Builds the SystemTrayService.
     ///
     /// # Errors
@@ -97,7 +108,9 @@ impl SystemTrayServiceBuilder {
             event_rx: Mutex::new(Some(event_rx)),
             connection,
             is_watcher,
+            registered: Property::new(Vec::new()),
             items: Property::new(Vec::new()),
+            sort_policy: Property::new(self.sort_policy),
         });
 
         if !is_watcher {
@@ -112,7 +125,10 @@ impl SystemTrayServiceBuilder {
                 &service.cancellation_token,
             )
             .await?;
-            service.items.set(items);
+            service
+                .items
+                .set(sorted_items(&items, &service.sort_policy.get()));
+            service.registered.set(items);
         }
 
         if self.register_daemon {
@@ -21,7 +21,7 @@ use crate::{
     error::Error,
     proxy::{dbusmenu::DBusMenuProxy, status_notifier_item::StatusNotifierItemProxy},
     types::{
-        Coordinates,
+        Coordinates, TraySortKey,
         item::{Category, IconPixmap, Status, Tooltip},
         menu::{MenuEvent, MenuItem, RawMenuItemsPropsList},
     },
@@ -621,6 +621,22 @@ impl TrayItem {
         Ok(stream.filter_map(|_signal| async move { Some(()) }))
     }
 
+    pub(crate) fn sort_key(&self) -> TraySortKey {
+        TraySortKey {
+            id: self.id.get(),
+            title: self.title.get(),
+            category: self.category.get(),
+        }
+    }
+
+    /// Whether the bus name `name` owns the item registered as `service`.
+    ///
+    /// Items register either under a name of their own or as
+    /// `<unique name><object path>`, so both forms match.
+    pub(crate) fn is_owned_by(service: &str, name: &str) -> bool {
+        Self::parse_service_identifier(service).service == name
+    }
+
     /// Parse a service identifier into service name and object path.
     ///
     /// Handles two formats:
@@ -790,4 +806,18 @@ mod tests {
         assert_eq!(id.service, ":1.234");
         assert_eq!(id.path, "/some/nested/path");
     }
+
+    #[test]
+    fn is_owned_by_matches_bus_name_part_only() {
+        assert!(TrayItem::is_owned_by(":1.42/StatusNotifierItem", ":1.42"));
+        assert!(TrayItem::is_owned_by(
+            "org.kde.StatusNotifierItem-4077-1",
+            "org.kde.StatusNotifierItem-4077-1"
+        ));
+        assert!(!TrayItem::is_owned_by(":1.420/StatusNotifierItem", ":1.42"));
+        assert!(!TrayItem::is_owned_by(
+            "org.kde.StatusNotifierItem-4077-1",
+            ":1.42"
+        ));
+    }
 }
@@ -18,6 +18,22 @@ pub trait SystemTrayWayle {
     /// Activates a tray item by ID (simulates left-click).
     async fn activate(&self, id: String) -> Result<()>;
 
+    /// Lists all current system tray items, keyed by bus name.
+    ///
+    /// Returns array of (bus_name, id, title, icon_name, status).
+    async fn list_items(&self) -> Result<Vec<(String, String, String, String, String)>>;
+
+    /// Lists the clickable menu entries of the item at `bus_name`.
+    ///
+    /// Returns array of (menu_id, label path), with submenu labels joined by ` > `.
+    async fn list_menu(&self, bus_name: String) -> Result<Vec<(i32, String)>>;
+
+    /// Activates the item at `bus_name` with `x`/`y` as the position hint.
+    async fn activate_at(&self, bus_name: String, x: i32, y: i32) -> Result<()>;
+
+    /// Clicks the menu entry `menu_id` of the item at `bus_name`.
+    async fn activate_menu_item(&self, bus_name: String, menu_id: i32) -> Result<()>;
+
     /// Number of current tray items.
     #[zbus(property)]
     fn count(&self) -> Result<u32>;
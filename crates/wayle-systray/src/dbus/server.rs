@@ -3,7 +3,14 @@ use std::sync::Arc;
 use tracing::instrument;
 use zbus::{fdo, interface};
 
-use crate::{service::SystemTrayService, types::Coordinates};
+use crate::{
+    core::item::TrayItem,
+    service::SystemTrayService,
+    types::{
+        Coordinates,
+        menu::{MenuEvent, MenuItem},
+    },
+};
 
 #[derive(Debug)]
 pub(crate) struct SystemTrayDaemon {
@@ -42,6 +49,54 @@ impl SystemTrayDaemon {
             .map_err(|e| fdo::Error::Failed(e.to_string()))
     }
 
+    #[instrument(skip(self))]
+    pub async fn list_items(&self) -> Vec<(String, String, String, String, String)> {
+        self.service
+            .items
+            .get()
+            .iter()
+            .map(|item| {
+                (
+                    item.bus_name.get(),
+                    item.id.get(),
+                    item.title.get(),
+                    item.icon_name.get().unwrap_or_default(),
+                    item.status.get().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[instrument(skip(self), fields(bus_name = %bus_name))]
+    pub async fn list_menu(&self, bus_name: String) -> fdo::Result<Vec<(i32, String)>> {
+        let item = self.find_by_bus_name(&bus_name)?;
+
+        let mut entries = Vec::new();
+        if let Some(menu) = item.menu.get() {
+            collect_menu_entries(&menu.children, "", &mut entries);
+        }
+
+        Ok(entries)
+    }
+
+    #[instrument(skip(self), fields(bus_name = %bus_name, x, y))]
+    pub async fn activate_at(&self, bus_name: String, x: i32, y: i32) -> fdo::Result<()> {
+        let item = self.find_by_bus_name(&bus_name)?;
+
+        item.activate(Coordinates::new(x, y))
+            .await
+            .map_err(|e| fdo::Error::Failed(e.to_string()))
+    }
+
+    #[instrument(skip(self), fields(bus_name = %bus_name, menu_id))]
+    pub async fn activate_menu_item(&self, bus_name: String, menu_id: i32) -> fdo::Result<()> {
+        let item = self.find_by_bus_name(&bus_name)?;
+
+        item.menu_event(menu_id, MenuEvent::Clicked, 0)
+            .await
+            .map_err(|e| fdo::Error::Failed(e.to_string()))
+    }
+
     #[zbus(property)]
     pub async fn count(&self) -> u32 {
         self.service.items.get().len() as u32
@@ -52,3 +107,37 @@ impl SystemTrayDaemon {
         self.service.is_watcher
     }
 }
+
+impl SystemTrayDaemon {
+    fn find_by_bus_name(&self, bus_name: &str) -> fdo::Result<Arc<TrayItem>> {
+        self.service
+            .items
+            .get()
+            .iter()
+            .find(|item| item.bus_name.get() == bus_name)
+            .cloned()
+            .ok_or_else(|| fdo::Error::InvalidArgs(format!("Tray item not found: {bus_name}")))
+    }
+}
+
+/// Flattens visible, labelled menu entries into `(id, "Parent > Child")`
+/// pairs so scripts can pick a `menu_id` for `ActivateMenuItem`.
+fn collect_menu_entries(items: &[MenuItem], prefix: &str, entries: &mut Vec<(i32, String)>) {
+    for item in items
+        .iter()
+        .filter(|item| item.visible && !item.is_separator())
+    {
+        let label = item.label.clone().unwrap_or_default();
+        let path = if prefix.is_empty() {
+            label
+        } else {
+            format!("{prefix} > {label}")
+        };
+
+        if item.has_children() {
+            collect_menu_entries(&item.children, &path, entries);
+        } else {
+            entries.push((item.id, path));
+        }
+    }
+}
@@ -2,5 +2,4 @@
 pub(crate) enum TrayEvent {
     ItemRegistered(String),
     ItemUnregistered(String),
-    ServiceDisconnected(String),
 }
@@ -52,6 +52,7 @@
 //! |--------|--------|
 //! | `with_daemon()` | Interact with tray items from scripts or other processes |
 //! | `mode(TrayMode)` | Set operating mode: `Watcher`, `Host`, or `Auto` (default) |
+//! | `sort_policy(TraySortPolicy)` | Order of `items`: registration order (default), alphabetical, by category, or a manual ID list |
 //!
 //! ```rust,no_run
 //! use wayle_systray::{SystemTrayService, types::TrayMode};
@@ -81,7 +82,8 @@
 //! | Field | Type | Description |
 //! |-------|------|-------------|
 //! | `is_watcher` | `bool` | Whether operating as the watcher registry |
-//! | `items` | `Property<Vec<Arc<TrayItem>>>` | Currently registered tray items |
+//! | `items` | `Property<Vec<Arc<TrayItem>>>` | Currently registered tray items, in sort order |
+//! | `sort_policy` | `Property<TraySortPolicy>` | How `items` is ordered |
 
 /// UI framework adapters (GTK4) for native systray menu rendering.
 pub mod adapters;
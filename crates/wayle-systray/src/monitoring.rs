@@ -13,6 +13,7 @@ use super::{
     events::TrayEvent,
     proxy::status_notifier_watcher::StatusNotifierWatcherProxy,
     service::SystemTrayService,
+    types::TraySortPolicy,
 };
 
 impl ServiceMonitoring for SystemTrayService {
@@ -20,6 +21,8 @@ impl ServiceMonitoring for SystemTrayService {
 
     #[instrument(skip_all, err)]
     async fn start_monitoring(&self) -> Result<(), Self::Error> {
+        spawn_item_sorter(self);
+
         if self.is_watcher {
             handle_watcher_mode(self).await?;
         } else {
@@ -29,6 +32,42 @@ impl ServiceMonitoring for SystemTrayService {
     }
 }
 
+/// Keeps `items` in sync with the registered items, ordered by the current
+/// sort policy.
+fn spawn_item_sorter(service: &SystemTrayService) {
+    let sorted = service
+        .registered
+        .combine(&service.sort_policy, |registered, policy| {
+            sorted_items(registered, policy)
+        });
+    let items = service.items.clone();
+    let cancellation_token = service.cancellation_token.clone();
+
+    tokio::spawn(async move {
+        let mut changes = sorted.watch();
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    return;
+                }
+                Some(list) = changes.next() => {
+                    items.set(list);
+                }
+            }
+        }
+    });
+}
+
+/// `registered`, which is in registration order, ordered by `policy`.
+pub(crate) fn sorted_items(
+    registered: &[Arc<TrayItem>],
+    policy: &TraySortPolicy,
+) -> Vec<Arc<TrayItem>> {
+    let mut list = registered.to_vec();
+    policy.sort(&mut list, |item| item.sort_key());
+    list
+}
+
 #[instrument(skip_all)]
 async fn handle_watcher_mode(service: &SystemTrayService) -> Result<(), Error> {
     let mut event_receiver = service
@@ -37,7 +76,7 @@ async fn handle_watcher_mode(service: &SystemTrayService) -> Result<(), Error> {
         .ok()
         .and_then(|mut guard| guard.take())
         .unwrap_or_else(|| service.event_tx.subscribe());
-    let items = service.items.clone();
+    let items = service.registered.clone();
     let cancellation_token = service.cancellation_token.clone();
     let connection = service.connection.clone();
 
@@ -65,9 +104,6 @@ async fn handle_watcher_mode(service: &SystemTrayService) -> Result<(), Error> {
                         TrayEvent::ItemUnregistered(bus_name) => {
                             handle_item_unregistered(&bus_name, &items);
                         }
-                        TrayEvent::ServiceDisconnected(bus_name) => {
-                            handle_item_unregistered(&bus_name, &items);
-                        }
                     }
                 }
             }
@@ -85,7 +121,7 @@ async fn handle_host_mode(service: &SystemTrayService) -> Result<(), Error> {
     let mut registered = watcher.receive_status_notifier_item_registered().await?;
     let mut unregistered = watcher.receive_status_notifier_item_unregistered().await?;
 
-    let items = service.items.clone();
+    let items = service.registered.clone();
     let connection = service.connection.clone();
     let cancellation_token = service.cancellation_token.clone();
 
@@ -118,6 +154,7 @@ async fn handle_host_mode(service: &SystemTrayService) -> Result<(), Error> {
         }
     });
 
+    monitor_name_owner_changes(service).await?;
     Ok(())
 }
 
@@ -150,9 +187,27 @@ pub(crate) async fn handle_item_registered(
 
 #[instrument(skip(items), fields(bus_name = %bus_name))]
 fn handle_item_unregistered(bus_name: &str, items: &Property<Vec<Arc<TrayItem>>>) {
+    remove_items(items, |service| service == bus_name);
+
+    debug!("Item unregistered: {}", bus_name);
+}
+
+/// Drops items whose owner left the bus without unregistering them, e.g.
+/// because the app crashed.
+#[instrument(skip(items), fields(name = %name))]
+fn handle_owner_lost(name: &str, items: &Property<Vec<Arc<TrayItem>>>) {
+    let removed = remove_items(items, |service| TrayItem::is_owned_by(service, name));
+
+    if removed > 0 {
+        debug!(count = removed, "Pruned items whose owner left the bus");
+    }
+}
+
+fn remove_items(items: &Property<Vec<Arc<TrayItem>>>, matches: impl Fn(&str) -> bool) -> usize {
     let mut list = items.get();
+    let before = list.len();
     list.retain(|item| {
-        if item.bus_name.get() != bus_name {
+        if !matches(&item.bus_name.get()) {
             return true;
         }
         if let Some(token) = item.cancellation_token.as_ref() {
@@ -160,9 +215,12 @@ fn handle_item_unregistered(bus_name: &str, items: &Property<Vec<Arc<TrayItem>>>
         }
         false
     });
-    items.set(list);
 
-    debug!("Item unregistered: {}", bus_name);
+    let removed = before - list.len();
+    if removed > 0 {
+        items.set(list);
+    }
+    removed
 }
 
 #[instrument(skip(service), err)]
@@ -183,8 +241,7 @@ async fn monitor_name_owner_changes(service: &SystemTrayService) -> Result<(), E
         }
     };
 
-    let event_tx = service.event_tx.clone();
-    let items = service.items.clone();
+    let items = service.registered.clone();
     let cancellation_token = service.cancellation_token.clone();
 
     tokio::spawn(async move {
@@ -202,12 +259,7 @@ async fn monitor_name_owner_changes(service: &SystemTrayService) -> Result<(), E
                         continue;
                     }
 
-                    let list = items.get();
-                    if !list.iter().any(|item| item.bus_name.get() == args.name.to_string()) {
-                        continue;
-                    }
-
-                    let _ = event_tx.send(TrayEvent::ServiceDisconnected(args.name.to_string()));
+                    handle_owner_lost(args.name.as_str(), &items);
                 }
             }
         }
@@ -13,7 +13,7 @@ use super::{
     error::Error,
     events::TrayEvent,
     proxy::status_notifier_item::StatusNotifierItemProxy,
-    types::{Coordinates, ScrollDelta},
+    types::{Coordinates, ScrollDelta, TraySortPolicy},
 };
 use crate::{
     builder::SystemTrayServiceBuilder, proxy::status_notifier_watcher::StatusNotifierWatcherProxy,
@@ -30,12 +30,17 @@ pub struct SystemTrayService {
     pub(crate) event_rx: Mutex<Option<broadcast::Receiver<TrayEvent>>>,
     #[debug(skip)]
     pub(crate) connection: Connection,
+    #[debug(skip)]
+    pub(crate) registered: Property<Vec<Arc<TrayItem>>>,
 
     /// `true` if acting as the watcher registry, `false` if consuming from existing watcher.
     pub is_watcher: bool,
 
-    /// Currently registered tray items.
+    /// Currently registered tray items, ordered by [`Self::sort_policy`].
     pub items: Property<Vec<Arc<TrayItem>>>,
+
+    /// How [`Self::items`] is ordered. Setting it reorders the list.
+    pub sort_policy: Property<TraySortPolicy>,
 }
 
 impl SystemTrayService {
@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter, Result};
 
+use super::markup;
+
 pub(crate) type RawPixmap = (i32, i32, Vec<u8>);
 pub(crate) type RawPixmaps = Vec<RawPixmap>;
 pub(crate) type RawTooltip = (String, RawPixmaps, String, String);
@@ -125,6 +127,29 @@ impl From<(String, Vec<(i32, i32, Vec<u8>)>, String, String)> for Tooltip {
     }
 }
 
+impl Tooltip {
+    /// Whether the item provided neither a title nor a description.
+    pub fn is_empty(&self) -> bool {
+        self.title.trim().is_empty() && self.description.trim().is_empty()
+    }
+
+    /// Pango markup for the tooltip text: the title in bold, then the
+    /// description with its SNI markup converted (see [`markup::to_pango`]).
+    ///
+    /// Returns `None` when the tooltip has no text.
+    pub fn to_pango_markup(&self) -> Option<String> {
+        let title = self.title.trim();
+        let description = markup::to_pango(&self.description);
+
+        match (title.is_empty(), description.is_empty()) {
+            (true, true) => None,
+            (false, true) => Some(format!("<b>{}</b>", markup::escape(title))),
+            (true, false) => Some(description),
+            (false, false) => Some(format!("<b>{}</b>\n{description}", markup::escape(title))),
+        }
+    }
+}
+
 /// Scroll orientation for StatusNotifierItem scroll events.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScrollOrientation {
@@ -285,4 +310,30 @@ mod tests {
         assert_eq!(tooltip.icon_pixmap[1].width, 32);
         assert_eq!(tooltip.icon_pixmap[1].height, 32);
     }
+
+    #[test]
+    fn tooltip_markup_bolds_title_above_description() {
+        let tooltip = Tooltip {
+            title: String::from("Mail & Chat"),
+            description: String::from("<b>3</b> unread<br>1 draft"),
+            ..Tooltip::default()
+        };
+
+        assert_eq!(
+            tooltip.to_pango_markup().as_deref(),
+            Some("<b>Mail &amp; Chat</b>\n<b>3</b> unread\n1 draft")
+        );
+    }
+
+    #[test]
+    fn tooltip_markup_is_none_without_text() {
+        let tooltip = Tooltip {
+            icon_name: String::from("mail"),
+            title: String::from("  "),
+            ..Tooltip::default()
+        };
+
+        assert!(tooltip.is_empty());
+        assert_eq!(tooltip.to_pango_markup(), None);
+    }
 }
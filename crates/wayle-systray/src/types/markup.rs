@@ -0,0 +1,244 @@
+/// Tags Pango understands that SNI tooltips commonly use, mapped from their
+/// HTML spelling.
+const INLINE_TAGS: [(&str, &str); 10] = [
+    ("b", "b"),
+    ("strong", "b"),
+    ("i", "i"),
+    ("em", "i"),
+    ("u", "u"),
+    ("s", "s"),
+    ("tt", "tt"),
+    ("code", "tt"),
+    ("sub", "sub"),
+    ("sup", "sup"),
+];
+
+/// Tags that end a line of text.
+const BLOCK_TAGS: [&str; 5] = ["br", "p", "div", "li", "tr"];
+
+const ENTITIES: [&str; 5] = ["amp", "lt", "gt", "quot", "apos"];
+
+/// Converts SNI tooltip markup into Pango markup.
+///
+/// The SNI spec allows a small HTML subset in tooltip descriptions. Text
+/// styling (`<b>`, `<i>`, `<u>`, ...) is kept, line and paragraph breaks
+/// become newlines, and anything Pango can't render (links, images, fonts)
+/// is dropped while keeping its text. Unbalanced tags are closed and stray
+/// `<` or `&` are escaped, so the result always parses. Plain text passes
+/// through escaped.
+pub fn to_pango(markup: &str) -> String {
+    let mut output = String::with_capacity(markup.len());
+    let mut open: Vec<&'static str> = Vec::new();
+    let mut rest = markup;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '<' => match rest.find('>').filter(|_| starts_tag(rest)) {
+                Some(end) => {
+                    push_tag(&mut output, &mut open, &rest[1..end]);
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+                None => output.push_str("&lt;"),
+            },
+            '&' => match entity(rest) {
+                Some((text, len)) => {
+                    output.push_str(text);
+                    rest = &rest[len..];
+                    continue;
+                }
+                None => output.push_str("&amp;"),
+            },
+            '>' => output.push_str("&gt;"),
+            c => output.push(c),
+        }
+
+        rest = &rest[c.len_utf8()..];
+    }
+
+    for tag in open.into_iter().rev() {
+        close(&mut output, tag);
+    }
+
+    output.trim_end_matches(|c: char| c.is_ascii_whitespace()).to_owned()
+}
+
+/// Escapes plain text for use inside Pango markup.
+pub fn escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+fn starts_tag(text: &str) -> bool {
+    text[1..]
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '/')
+}
+
+fn push_tag(output: &mut String, open: &mut Vec<&'static str>, tag: &str) {
+    let tag = tag.trim();
+    let (closing, tag) = match tag.strip_prefix('/') {
+        Some(tag) => (true, tag),
+        None => (false, tag),
+    };
+    let name = tag
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    if BLOCK_TAGS.contains(&name.as_str()) {
+        if (name == "br" || closing) && !output.is_empty() && !output.ends_with('\n') {
+            output.push('\n');
+        }
+        return;
+    }
+
+    let Some(&(_, pango)) = INLINE_TAGS.iter().find(|(html, _)| *html == name) else {
+        return;
+    };
+
+    if !closing {
+        output.push('<');
+        output.push_str(pango);
+        output.push('>');
+        open.push(pango);
+        return;
+    }
+
+    let Some(index) = open.iter().rposition(|tag| *tag == pango) else {
+        return;
+    };
+
+    for tag in open.drain(index..).rev() {
+        close(output, tag);
+    }
+}
+
+fn close(output: &mut String, tag: &str) {
+    output.push_str("</");
+    output.push_str(tag);
+    output.push('>');
+}
+
+/// Returns the Pango-safe text for the entity at the start of `text` and
+/// the length it spans, if `text` starts with one.
+fn entity(text: &str) -> Option<(&str, usize)> {
+    let end = text.find(';')?;
+    let name = &text[1..end];
+    let len = end + 1;
+
+    if name == "nbsp" {
+        return Some(("\u{a0}", len));
+    }
+
+    let numeric = name
+        .strip_prefix('#')
+        .and_then(character_reference)
+        .is_some();
+
+    if numeric || ENTITIES.contains(&name) {
+        return Some((&text[..len], len));
+    }
+
+    None
+}
+
+/// Decodes the text after `&#`: decimal digits, or hex digits after `x`.
+///
+/// NUL and values that aren't Unicode scalar values are rejected, since
+/// Pango refuses them.
+fn character_reference(digits: &str) -> Option<char> {
+    let code = match digits.strip_prefix(['x', 'X']) {
+        Some(hex) if hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+            u32::from_str_radix(hex, 16).ok()?
+        }
+        None if digits.chars().all(|c| c.is_ascii_digit()) => digits.parse().ok()?,
+        _ => return None,
+    };
+    char::from_u32(code).filter(|c| *c != '\0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_text_styling() {
+        assert_eq!(
+            to_pango("<b>3</b> new <em>messages</em>"),
+            "<b>3</b> new <i>messages</i>"
+        );
+    }
+
+    #[test]
+    fn breaks_become_newlines() {
+        assert_eq!(
+            to_pango("Line one<br/>Line two<BR>Three"),
+            "Line one\nLine two\nThree"
+        );
+        assert_eq!(to_pango("<p>First</p><p>Second</p>"), "First\nSecond");
+    }
+
+    #[test]
+    fn drops_unsupported_tags_but_keeps_text() {
+        assert_eq!(
+            to_pango("<a href=\"https://example.com\">link</a> <img src=\"x.png\"/>"),
+            "link"
+        );
+    }
+
+    #[test]
+    fn closes_unbalanced_tags() {
+        assert_eq!(
+            to_pango("<b>bold <i>both</b> plain"),
+            "<b>bold <i>both</i></b> plain"
+        );
+        assert_eq!(to_pango("<u>open"), "<u>open</u>");
+        assert_eq!(to_pango("stray</i>"), "stray");
+    }
+
+    #[test]
+    fn escapes_stray_markup_characters() {
+        assert_eq!(to_pango("a < b & c > d"), "a &lt; b &amp; c &gt; d");
+        assert_eq!(
+            to_pango("Tom &amp; Jerry &#38; &nbsp;"),
+            "Tom &amp; Jerry &#38; \u{a0}"
+        );
+    }
+
+    #[test]
+    fn hex_digits_need_hex_prefix() {
+        assert_eq!(to_pango("&#x1a; &#X1A;"), "&#x1a; &#X1A;");
+        assert_eq!(to_pango("&#1a;"), "&amp;#1a;");
+    }
+
+    #[test]
+    fn escapes_invalid_character_references() {
+        assert_eq!(
+            to_pango("&#0; &#xD800; &#x110000;"),
+            "&amp;#0; &amp;#xD800; &amp;#x110000;"
+        );
+    }
+
+    #[test]
+    fn escape_handles_all_special_characters() {
+        assert_eq!(
+            escape("<\"a\" & 'b'>"),
+            "&lt;&quot;a&quot; &amp; &apos;b&apos;&gt;"
+        );
+    }
+}
@@ -1,9 +1,11 @@
 /// StatusNotifierItem type definitions.
 pub mod item;
+/// SNI tooltip markup conversion.
+pub mod markup;
 /// DBusMenu type definitions.
 pub mod menu;
 
-use item::ScrollOrientation;
+use item::{Category, ScrollOrientation};
 
 /// Coordinates for mouse events.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -59,6 +61,77 @@ pub enum TrayMode {
     Auto,
 }
 
+/// Order in which [`SystemTrayService::items`](crate::SystemTrayService::items)
+/// lists tray items.
+///
+/// Items that compare equal keep their registration order, so every policy
+/// gives the same order for the same set of items.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TraySortPolicy {
+    /// Order in which items registered with the watcher (default).
+    #[default]
+    RegistrationOrder,
+    /// Case-insensitive by title, falling back to the item ID for items
+    /// without a title.
+    Alphabetical,
+    /// Grouped by [`Category`] in spec order (application status,
+    /// communications, system services, hardware), alphabetical within
+    /// each group.
+    CategoryThenAlpha,
+    /// Items whose ID is listed come first, in list order. Unlisted items
+    /// follow in registration order.
+    Manual(Vec<String>),
+}
+
+/// Item fields a [`TraySortPolicy`] orders by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TraySortKey {
+    pub id: String,
+    pub title: String,
+    pub category: Category,
+}
+
+impl TraySortKey {
+    fn label(&self) -> String {
+        let label = if self.title.is_empty() {
+            &self.id
+        } else {
+            &self.title
+        };
+        label.to_lowercase()
+    }
+}
+
+impl TraySortPolicy {
+    /// Sorts `items`, which must be in registration order, by this policy.
+    pub(crate) fn sort<T>(&self, items: &mut [T], key: impl Fn(&T) -> TraySortKey) {
+        match self {
+            Self::RegistrationOrder => {}
+            Self::Alphabetical => items.sort_by_cached_key(|item| key(item).label()),
+            Self::CategoryThenAlpha => items.sort_by_cached_key(|item| {
+                let key = key(item);
+                (category_rank(key.category), key.label())
+            }),
+            Self::Manual(order) => items.sort_by_cached_key(|item| {
+                let id = key(item).id;
+                order
+                    .iter()
+                    .position(|listed| *listed == id)
+                    .unwrap_or(order.len())
+            }),
+        }
+    }
+}
+
+fn category_rank(category: Category) -> u8 {
+    match category {
+        Category::ApplicationStatus => 0,
+        Category::Communications => 1,
+        Category::SystemServices => 2,
+        Category::Hardware => 3,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +143,67 @@ mod tests {
         assert_eq!(coords.x, 100);
         assert_eq!(coords.y, 200);
     }
+
+    fn key(id: &str, title: &str, category: Category) -> TraySortKey {
+        TraySortKey {
+            id: String::from(id),
+            title: String::from(title),
+            category,
+        }
+    }
+
+    fn sorted(policy: TraySortPolicy, mut items: Vec<TraySortKey>) -> Vec<String> {
+        policy.sort(&mut items, Clone::clone);
+        items.into_iter().map(|item| item.id).collect()
+    }
+
+    fn sample() -> Vec<TraySortKey> {
+        vec![
+            key("steam", "Steam", Category::ApplicationStatus),
+            key("nm-applet", "", Category::SystemServices),
+            key("discord", "discord", Category::Communications),
+            key("blueman", "Bluetooth", Category::Hardware),
+        ]
+    }
+
+    #[test]
+    fn registration_order_keeps_items_as_registered() {
+        assert_eq!(
+            sorted(TraySortPolicy::RegistrationOrder, sample()),
+            ["steam", "nm-applet", "discord", "blueman"]
+        );
+    }
+
+    #[test]
+    fn alphabetical_ignores_case_and_falls_back_to_id() {
+        assert_eq!(
+            sorted(TraySortPolicy::Alphabetical, sample()),
+            ["blueman", "discord", "nm-applet", "steam"]
+        );
+    }
+
+    #[test]
+    fn category_then_alpha_groups_by_category() {
+        let mut items = sample();
+        items.push(key("slack", "Slack", Category::Communications));
+
+        assert_eq!(
+            sorted(TraySortPolicy::CategoryThenAlpha, items),
+            ["steam", "discord", "slack", "nm-applet", "blueman"]
+        );
+    }
+
+    #[test]
+    fn manual_puts_listed_ids_first_and_appends_the_rest() {
+        let policy = TraySortPolicy::Manual(vec![
+            String::from("discord"),
+            String::from("missing"),
+            String::from("steam"),
+        ]);
+
+        assert_eq!(
+            sorted(policy, sample()),
+            ["discord", "steam", "nm-applet", "blueman"]
+        );
+    }
 }
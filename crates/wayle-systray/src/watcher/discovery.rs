@@ -6,7 +6,9 @@ use tracing::{debug, info, warn};
 use zbus::{Connection, fdo::DBusProxy, names::OwnedBusName};
 
 use super::register_item;
-use crate::{events::TrayEvent, proxy::status_notifier_item::StatusNotifierItemProxy};
+use crate::{
+    core::item::TrayItem, events::TrayEvent, proxy::status_notifier_item::StatusNotifierItemProxy,
+};
 
 const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
 
@@ -104,12 +106,11 @@ async fn list_candidate_names(
 }
 
 async fn is_registered(items: &Arc<RwLock<Vec<String>>>, bus_name: &str) -> bool {
-    let items = items.read().await;
-    let prefix = format!("{bus_name}/");
-
     items
+        .read()
+        .await
         .iter()
-        .any(|registered| registered == bus_name || registered.starts_with(&prefix))
+        .any(|registered| TrayItem::is_owned_by(registered, bus_name))
 }
 
 async fn probe_sni(connection: &Connection, bus_name: &str) -> bool {
@@ -8,6 +8,7 @@ use zbus::{Connection, fdo::DBusProxy};
 
 use super::StatusNotifierWatcher;
 use crate::{
+    core::item::TrayItem,
     error::Error,
     events::TrayEvent,
     types::{WATCHER_INTERFACE, WATCHER_OBJECT_PATH},
@@ -84,21 +85,7 @@ pub(crate) async fn unregister_item(
     event_tx: &broadcast::Sender<TrayEvent>,
     connection: &Connection,
 ) -> Result<(), Error> {
-    let removed_items = {
-        let mut items = registered_items.write().await;
-        let prefix = format!("{bus_name}/");
-
-        let mut removed = Vec::new();
-        items.retain(|s| {
-            if s == bus_name || s.starts_with(&prefix) {
-                removed.push(s.clone());
-                false
-            } else {
-                true
-            }
-        });
-        removed
-    };
+    let removed_items = take_owned_by(&mut *registered_items.write().await, bus_name);
 
     for item in removed_items {
         let _ = event_tx.send(TrayEvent::ItemUnregistered(item.clone()));
@@ -120,6 +107,20 @@ pub(crate) async fn unregister_item(
     Ok(())
 }
 
+/// Removes and returns the items owned by `bus_name`.
+fn take_owned_by(items: &mut Vec<String>, bus_name: &str) -> Vec<String> {
+    let mut removed = Vec::new();
+    items.retain(|service| {
+        if TrayItem::is_owned_by(service, bus_name) {
+            removed.push(service.clone());
+            false
+        } else {
+            true
+        }
+    });
+    removed
+}
+
 pub(crate) async fn unregister_host(
     host: &str,
     registered_hosts: &Arc<RwLock<Vec<String>>>,
@@ -155,3 +156,28 @@ pub(crate) async fn unregister_host(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_loss_prunes_items_owned_by_that_name() {
+        let mut items = vec![
+            String::from(":1.42/StatusNotifierItem"),
+            String::from(":1.420/StatusNotifierItem"),
+            String::from("org.kde.StatusNotifierItem-4077-1"),
+        ];
+
+        let removed = take_owned_by(&mut items, ":1.42");
+
+        assert_eq!(removed, vec![String::from(":1.42/StatusNotifierItem")]);
+        assert_eq!(
+            items,
+            vec![
+                String::from(":1.420/StatusNotifierItem"),
+                String::from("org.kde.StatusNotifierItem-4077-1"),
+            ]
+        );
+    }
+}
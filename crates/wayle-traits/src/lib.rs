@@ -34,6 +34,23 @@ pub trait ModelMonitoring {
     async fn start_monitoring(self: Arc<Self>) -> Result<(), Self::Error>;
 }
 
+/// Models that can re-read their backing state on demand.
+///
+/// Complements [`Reactive`]: instead of building a new instance, the
+/// existing model's properties are updated in place so current watchers
+/// see the fresh values.
+pub trait Refresh {
+    /// Error type for refresh operations.
+    type Error;
+
+    /// Re-reads all backing properties and updates them in place.
+    ///
+    /// # Errors
+    /// Returns error if the backing state cannot be read.
+    #[allow(async_fn_in_trait)]
+    async fn refresh(&self) -> Result<(), Self::Error>;
+}
+
 /// Static models - fetch once, no monitoring.
 pub trait Static {
     /// Error type for static operations.
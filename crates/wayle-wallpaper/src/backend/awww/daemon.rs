@@ -9,51 +9,70 @@ use std::{
 
 use tracing::{debug, info, warn};
 
+use crate::types::EngineStatus;
+
 const READY_POLL_INTERVAL: Duration = Duration::from_millis(50);
 const READY_TIMEOUT: Duration = Duration::from_secs(5);
 
-/// Spawns the wallpaper daemon in the background if not already running.
+/// Probes the wallpaper daemon, starting it if `auto_start` is set and it
+/// isn't running.
 ///
 /// Prefers `awww-daemon`, falling back to `swww-daemon` if awww is not
-/// installed. Polls until the daemon is accepting commands, then signals
-/// readiness via [`super::DAEMON_READY`].
-pub fn spawn_daemon_if_needed() {
-    thread::spawn(ensure_daemon_ready);
+/// installed. After starting, polls until the daemon is accepting commands.
+/// Signals readiness via [`super::DAEMON_READY`] either way. Blocks, so call
+/// it from a blocking task.
+pub(crate) fn ensure_daemon(auto_start: bool) -> EngineStatus {
+    let status = probe_or_start(auto_start);
+    super::DAEMON_READY.notify_one();
+    status
 }
 
-fn ensure_daemon_ready() {
-    let daemon = super::daemon_binary();
-
-    if should_wait_for_ready(daemon) {
-        wait_until_ready(daemon);
+#[allow(clippy::cognitive_complexity)]
+fn probe_or_start(auto_start: bool) -> EngineStatus {
+    if !super::binary_in_path(super::client_binary()) {
+        warn!("neither awww nor swww found in PATH");
+        return EngineStatus::NotInstalled;
     }
 
-    super::DAEMON_READY.notify_one();
-}
+    let daemon = super::daemon_binary();
 
-#[allow(clippy::cognitive_complexity)]
-fn should_wait_for_ready(daemon: &str) -> bool {
     match is_daemon_running() {
         Ok(true) => {
             debug!("{daemon} already running");
-            false
+            return EngineStatus::Running;
         }
-        Ok(false) => {
-            info!("Starting {daemon}");
-            if let Err(error) = start_daemon() {
-                warn!(error = %error, "cannot start {daemon}");
-                return false;
-            }
-            true
+        Ok(false) if !auto_start => {
+            info!("{daemon} is not running");
+            return EngineStatus::NotStarted;
         }
+        Ok(false) => {}
         Err(error) => {
             warn!(error = %error, "cannot check {daemon} status");
-            false
+            return EngineStatus::NotStarted;
+        }
+    }
+
+    info!("Starting {daemon}");
+    match start_daemon() {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            warn!("{daemon} not found in PATH");
+            return EngineStatus::NotInstalled;
         }
+        Err(error) => {
+            warn!(error = %error, "cannot start {daemon}");
+            return EngineStatus::NotStarted;
+        }
+    }
+
+    if wait_until_ready(daemon) {
+        EngineStatus::Running
+    } else {
+        EngineStatus::NotStarted
     }
 }
 
-fn wait_until_ready(daemon: &str) {
+fn wait_until_ready(daemon: &str) -> bool {
     let start = Instant::now();
 
     while start.elapsed() < READY_TIMEOUT {
@@ -61,11 +80,12 @@ fn wait_until_ready(daemon: &str) {
 
         if let Ok(true) = is_daemon_running() {
             debug!(elapsed_ms = start.elapsed().as_millis(), "{daemon} ready");
-            return;
+            return true;
         }
     }
 
     warn!("{daemon} not ready after {}s", READY_TIMEOUT.as_secs());
+    false
 }
 
 fn is_daemon_running() -> Result<bool, io::Error> {
@@ -3,7 +3,7 @@ mod transition;
 
 use std::{env, io::ErrorKind, path::Path, process::Stdio, sync::OnceLock, time::Duration};
 
-pub(crate) use daemon::spawn_daemon_if_needed;
+pub(crate) use daemon::ensure_daemon;
 use tokio::{process::Command, sync::Notify};
 use tracing::instrument;
 pub use transition::{
@@ -74,28 +74,10 @@ impl AwwwBackend {
         monitor: Option<&str>,
         transition: &TransitionConfig,
     ) -> Result<(), Error> {
-        let resize_mode = match fit_mode {
-            FitMode::Fill => "crop",
-            FitMode::Fit => "fit",
-            FitMode::Center => "no",
-            FitMode::Stretch => "stretch",
-        };
-
-        let path_str = path
-            .to_str()
-            .ok_or_else(|| Error::InvalidImagePath(path.to_path_buf()))?;
+        let args = Self::command(path, fit_mode, monitor, transition)?;
 
         let mut cmd = Command::new(client_binary());
-        cmd.arg("img");
-        cmd.arg(path_str);
-        cmd.args([Self::RESIZE_FLAG, resize_mode]);
-
-        apply_transition_args(&mut cmd, transition);
-
-        if let Some(monitor) = monitor {
-            cmd.args([Self::OUTPUTS_FLAG, monitor]);
-        }
-
+        cmd.args(&args[1..]);
         cmd.stdout(Stdio::null());
         cmd.stderr(Stdio::piped());
 
@@ -119,18 +101,97 @@ impl AwwwBackend {
 
         Ok(())
     }
+
+    /// Builds the full awww/swww command line `apply` runs, binary first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidImagePath` if `path` is not valid UTF-8.
+    pub(crate) fn command(
+        path: &Path,
+        fit_mode: FitMode,
+        monitor: Option<&str>,
+        transition: &TransitionConfig,
+    ) -> Result<Vec<String>, Error> {
+        let resize_mode = match fit_mode {
+            FitMode::Fill => "crop",
+            FitMode::Fit => "fit",
+            FitMode::Center => "no",
+            FitMode::Stretch => "stretch",
+        };
+
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| Error::InvalidImagePath(path.to_path_buf()))?;
+
+        let mut args = vec![
+            client_binary().to_owned(),
+            String::from("img"),
+            path_str.to_owned(),
+            String::from(Self::RESIZE_FLAG),
+            String::from(resize_mode),
+        ];
+
+        args.extend(transition_args(transition));
+
+        if let Some(monitor) = monitor {
+            args.extend([String::from(Self::OUTPUTS_FLAG), monitor.to_owned()]);
+        }
+
+        Ok(args)
+    }
 }
 
-fn apply_transition_args(cmd: &mut Command, config: &TransitionConfig) {
-    cmd.args([TransitionType::FLAG, config.transition_type.type_name()]);
-    cmd.args([TransitionDuration::FLAG, &config.duration.to_string()]);
-    cmd.args([TransitionFps::FLAG, &config.fps.to_string()]);
+fn transition_args(config: &TransitionConfig) -> Vec<String> {
+    let mut args = vec![
+        String::from(TransitionType::FLAG),
+        String::from(config.transition_type.type_name()),
+        String::from(TransitionDuration::FLAG),
+        config.duration.to_string(),
+        String::from(TransitionFps::FLAG),
+        config.fps.to_string(),
+    ];
 
     if let Some(step) = config.step {
-        cmd.args([TransitionStep::FLAG, &step.to_string()]);
+        args.extend([String::from(TransitionStep::FLAG), step.to_string()]);
     }
 
     for (flag, value) in config.transition_type.cli_args() {
-        cmd.args([flag, &value]);
+        args.extend([String::from(flag), value]);
+    }
+
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn command_includes_transition_and_output() -> Result<(), Error> {
+        let transition = TransitionConfig {
+            transition_type: TransitionType::Wipe {
+                angle: TransitionAngle::default(),
+            },
+            ..Default::default()
+        };
+
+        let args = AwwwBackend::command(
+            &PathBuf::from("/walls/forest.png"),
+            FitMode::Fit,
+            Some("DP-1"),
+            &transition,
+        )?;
+
+        assert_eq!(args[1..5], ["img", "/walls/forest.png", "--resize", "fit"]);
+        assert!(
+            args.windows(2)
+                .any(|pair| pair == ["--transition-type", "wipe"])
+        );
+        assert!(args.windows(2).any(|pair| pair[0] == TransitionAngle::FLAG));
+        assert_eq!(args[args.len() - 2..], ["--outputs", "DP-1"]);
+        Ok(())
     }
 }
@@ -1,6 +1,6 @@
 mod awww;
 
-pub(crate) use awww::{AwwwBackend, spawn_daemon_if_needed, wait_for_daemon};
+pub(crate) use awww::{AwwwBackend, ensure_daemon, wait_for_daemon};
 pub use awww::{
     BezierCurve, Position, TransitionAngle, TransitionConfig, TransitionDuration, TransitionFps,
     TransitionStep, TransitionType, WaveDimensions,
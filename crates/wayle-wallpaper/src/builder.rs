@@ -1,6 +1,9 @@
 use std::{collections::HashMap, sync::Arc, time::Instant};
 
-use tokio::sync::broadcast;
+use tokio::{
+    sync::broadcast,
+    task::{self, JoinHandle},
+};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info};
 use wayle_core::Property;
@@ -8,12 +11,15 @@ use wayle_traits::ServiceMonitoring;
 use zbus::Connection;
 
 use crate::{
-    backend::{TransitionConfig, spawn_daemon_if_needed},
+    backend::{TransitionConfig, ensure_daemon},
     dbus::{SERVICE_NAME, SERVICE_PATH, WallpaperDaemon},
     error::Error,
+    persistence::WallpaperStore,
     service::WallpaperService,
-    tasks::{spawn_color_extractor, spawn_output_watcher},
-    types::ColorExtractorConfig,
+    tasks::{
+        spawn_color_extractor, spawn_engine_watcher, spawn_output_watcher, spawn_state_persister,
+    },
+    types::{ColorExtractorConfig, EngineStatus},
 };
 
 /// Builder for configuring a WallpaperService.
@@ -24,6 +30,8 @@ pub struct WallpaperServiceBuilder {
     theming_monitor: Option<String>,
     shared_cycle: bool,
     engine_active: bool,
+    auto_start_daemon: bool,
+    restore_on_start: bool,
 }
 
 impl Default for WallpaperServiceBuilder {
@@ -34,6 +42,8 @@ impl Default for WallpaperServiceBuilder {
             theming_monitor: None,
             shared_cycle: false,
             engine_active: true,
+            auto_start_daemon: true,
+            restore_on_start: true,
         }
     }
 }
@@ -49,14 +59,17 @@ impl WallpaperServiceBuilder {
     /// # Errors
     ///
     /// Returns error if D-Bus connection fails or service registration fails.
+    /// A missing or stopped wallpaper daemon is not an error; it is reported
+    /// by [`WallpaperService::engine_status`].
     #[allow(clippy::cognitive_complexity)]
     pub async fn build(self) -> Result<Arc<WallpaperService>, Error> {
         let start = Instant::now();
-        self.spawn_daemon_if_enabled();
+        let engine_probe = self.spawn_engine_probe();
 
         let connection = Self::connect_session_bus().await?;
         debug!(elapsed_ms = start.elapsed().as_millis(), "D-Bus connected");
 
+        let restore_on_start = self.restore_on_start;
         let service = self.create_service(&connection);
         Self::register_dbus(&connection, Arc::clone(&service)).await?;
         debug!(elapsed_ms = start.elapsed().as_millis(), "D-Bus registered");
@@ -67,6 +80,16 @@ impl WallpaperServiceBuilder {
             "Monitoring started"
         );
 
+        let engine_status = engine_probe.await.unwrap_or_default();
+        service.engine_status.set(engine_status);
+        debug!(
+            elapsed_ms = start.elapsed().as_millis(),
+            %engine_status,
+            "Wallpaper engine probed"
+        );
+
+        Self::restore_state(&service, restore_on_start);
+
         info!("Wallpaper service registered at {SERVICE_NAME}");
 
         Ok(service)
@@ -102,10 +125,30 @@ impl WallpaperServiceBuilder {
         self
     }
 
-    fn spawn_daemon_if_enabled(&self) {
-        if self.engine_active {
-            spawn_daemon_if_needed();
-        }
+    /// Starts the awww daemon when it isn't running and waits until it
+    /// accepts commands. Enabled by default.
+    ///
+    /// Only applies while the engine is active. When disabled, a stopped
+    /// daemon is reported as [`EngineStatus::NotStarted`] and rendering
+    /// fails with [`Error::AwwwDaemonNotRunning`].
+    pub fn auto_start_daemon(mut self, auto_start: bool) -> Self {
+        self.auto_start_daemon = auto_start;
+        self
+    }
+
+    /// Restores the previous session's wallpapers and cycling on startup.
+    ///
+    /// Enabled by default. When disabled, state isn't saved either, since
+    /// nothing would read it back.
+    pub fn restore_on_start(mut self, restore: bool) -> Self {
+        self.restore_on_start = restore;
+        self
+    }
+
+    /// Probes the daemon in the background so it overlaps with D-Bus setup.
+    fn spawn_engine_probe(&self) -> JoinHandle<EngineStatus> {
+        let auto_start = self.auto_start_daemon && self.engine_active;
+        task::spawn_blocking(move || ensure_daemon(auto_start))
     }
 
     async fn connect_session_bus() -> Result<Connection, Error> {
@@ -130,6 +173,8 @@ impl WallpaperServiceBuilder {
             transition: Property::new(self.transition),
             shared_cycle: Property::new(self.shared_cycle),
             engine_active: Property::new(self.engine_active),
+            engine_status: Property::new(EngineStatus::default()),
+            auto_start_daemon: self.auto_start_daemon,
         })
     }
 
@@ -161,10 +206,28 @@ impl WallpaperServiceBuilder {
         Ok(())
     }
 
+    fn restore_state(service: &Arc<WallpaperService>, restore: bool) {
+        if !restore {
+            return;
+        }
+
+        let Some(store) = WallpaperStore::new() else {
+            return;
+        };
+        let saved = store.load();
+
+        if service.restore(&saved) {
+            service.render_all_background();
+        }
+
+        spawn_state_persister(Arc::clone(service), store, saved);
+    }
+
     async fn start_background_tasks(service: &Arc<WallpaperService>) -> Result<(), Error> {
         service.start_monitoring().await?;
         spawn_output_watcher(Arc::clone(service));
         spawn_color_extractor(Arc::clone(service));
+        spawn_engine_watcher(Arc::clone(service));
         Ok(())
     }
 }
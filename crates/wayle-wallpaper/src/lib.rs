@@ -53,6 +53,8 @@
 //! | `theming_monitor(Option<String>)` | Which monitor drives color extraction |
 //! | `shared_cycle(bool)` | Sync cycling across monitors in shuffle mode |
 //! | `engine_active(bool)` | Toggle awww rendering (state tracking continues) |
+//! | `auto_start_daemon(bool)` | Start awww-daemon when it isn't running (default `true`) |
+//! | `restore_on_start(bool)` | Restore last session's wallpapers and cycling (default `true`) |
 //!
 //! ```rust,no_run
 //! use wayle_wallpaper::{WallpaperService, TransitionConfig, TransitionType};
@@ -69,6 +71,14 @@
 //! # }
 //! ```
 //!
+//! # Session Restore
+//!
+//! Per-monitor wallpapers, fit modes and the active cycling directory are
+//! saved to `$XDG_STATE_HOME/wayle/wallpaper.toml` as they change. On
+//! startup they are applied to the connected monitors again. Saved
+//! wallpapers that no longer exist are skipped. Turning off
+//! `restore_on_start` turns off saving as well.
+//!
 //! # Reactive Properties
 //!
 //! All fields are [`Property<T>`](wayle_core::Property):
@@ -90,6 +100,8 @@
 //! - `advance_cycle()` / `rewind_cycle()` - Manual navigation
 //! - `set_fit_mode()` - Change scaling mode per monitor or globally
 //! - `set_transition()` - Configure animations
+//! - `preview_transition()` / `transition_command()` - Try transition settings without keeping them
+//! - `engine_status()` - Whether the awww daemon is running, stopped or missing
 //!
 //! # D-Bus Interface
 //!
@@ -105,6 +117,7 @@ mod backend;
 mod builder;
 mod dbus;
 pub mod error;
+mod persistence;
 mod service;
 mod tasks;
 pub mod types;
@@ -119,5 +132,6 @@ pub use dbus::{SERVICE_NAME, SERVICE_PATH, WallpaperProxy};
 pub use error::Error;
 pub use service::WallpaperService;
 pub use types::{
-    ColorExtractor, ColorExtractorConfig, CyclingConfig, CyclingMode, FitMode, MonitorState,
+    ColorExtractor, ColorExtractorConfig, CyclingConfig, CyclingMode, EngineStatus, FitMode,
+    MonitorState,
 };
@@ -0,0 +1,241 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{self, ErrorKind, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use wayle_config::ConfigPaths;
+
+use crate::types::{CyclingConfig, CyclingMode, FitMode, MonitorState};
+
+const STATE_FILE: &str = "wallpaper.toml";
+
+/// Wallpaper state saved between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SavedState {
+    #[serde(default)]
+    pub monitors: BTreeMap<String, SavedMonitor>,
+    pub cycling: Option<SavedCycling>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SavedMonitor {
+    pub wallpaper: Option<PathBuf>,
+    #[serde(default)]
+    pub fit_mode: FitMode,
+    #[serde(default)]
+    pub cycle_index: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SavedCycling {
+    pub directory: PathBuf,
+    pub mode: CyclingMode,
+    pub interval_secs: u64,
+}
+
+impl SavedState {
+    /// Records the current state. Monitors without a wallpaper, including
+    /// ones that aren't connected right now, keep their saved entry so it
+    /// can be restored later.
+    pub fn update(
+        &mut self,
+        monitors: &HashMap<String, MonitorState>,
+        cycling: Option<&CyclingConfig>,
+    ) {
+        for (name, state) in monitors {
+            if state.wallpaper.is_none() {
+                continue;
+            }
+
+            self.monitors.insert(
+                name.clone(),
+                SavedMonitor {
+                    wallpaper: state.wallpaper.clone(),
+                    fit_mode: state.fit_mode,
+                    cycle_index: state.cycle_index,
+                },
+            );
+        }
+
+        self.cycling = cycling.map(|config| SavedCycling {
+            directory: config.directory.clone(),
+            mode: config.mode,
+            interval_secs: config.interval.as_secs(),
+        });
+    }
+}
+
+impl SavedCycling {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs)
+    }
+}
+
+/// Reads and writes [`SavedState`] in `$XDG_STATE_HOME/wayle/wallpaper.toml`.
+#[derive(Debug, Clone)]
+pub(crate) struct WallpaperStore {
+    path: PathBuf,
+}
+
+impl WallpaperStore {
+    pub fn new() -> Option<Self> {
+        match ConfigPaths::state_dir() {
+            Ok(dir) => Some(Self {
+                path: dir.join(STATE_FILE),
+            }),
+            Err(e) => {
+                warn!(error = %e, "cannot resolve state directory, wallpapers will not persist");
+                None
+            }
+        }
+    }
+
+    /// Returns the saved state, or an empty one if there is none or it
+    /// can't be read.
+    pub fn load(&self) -> SavedState {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => return SavedState::default(),
+            Err(e) => {
+                warn!(error = %e, path = %self.path.display(), "cannot read wallpaper state");
+                return SavedState::default();
+            }
+        };
+
+        toml::from_str(&content).unwrap_or_else(|e| {
+            warn!(error = %e, path = %self.path.display(), "cannot parse wallpaper state");
+            SavedState::default()
+        })
+    }
+
+    pub fn save(&self, state: &SavedState) {
+        let content = match toml::to_string(state) {
+            Ok(content) => content,
+            Err(e) => {
+                warn!(error = %e, "cannot serialize wallpaper state");
+                return;
+            }
+        };
+
+        match self.write_atomic(&content) {
+            Ok(()) => debug!(path = %self.path.display(), "wallpaper state saved"),
+            Err(e) => {
+                warn!(error = %e, path = %self.path.display(), "cannot write wallpaper state")
+            }
+        }
+    }
+
+    /// Writes to a temp file and renames it over the state file, so a crash
+    /// mid-write never leaves a truncated file behind.
+    fn write_atomic(&self, content: &str) -> io::Result<()> {
+        let temp_path = self.path.with_extension("toml.tmp");
+
+        let mut file = File::create(&temp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+
+        fs::rename(&temp_path, &self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_keeps_entries_for_monitors_without_wallpaper() {
+        let mut saved = SavedState::default();
+        saved.monitors.insert(
+            String::from("HDMI-A-1"),
+            SavedMonitor {
+                wallpaper: Some(PathBuf::from("/walls/old.png")),
+                fit_mode: FitMode::Fit,
+                cycle_index: 0,
+            },
+        );
+
+        let monitors = HashMap::from([
+            (
+                String::from("DP-1"),
+                MonitorState {
+                    wallpaper: Some(PathBuf::from("/walls/new.png")),
+                    fit_mode: FitMode::Fill,
+                    cycle_index: 3,
+                },
+            ),
+            (String::from("HDMI-A-1"), MonitorState::new()),
+        ]);
+        saved.update(&monitors, None);
+
+        assert_eq!(saved.monitors.len(), 2);
+        assert_eq!(
+            saved.monitors["DP-1"].wallpaper,
+            Some(PathBuf::from("/walls/new.png"))
+        );
+        assert_eq!(saved.monitors["DP-1"].cycle_index, 3);
+        assert_eq!(saved.monitors["HDMI-A-1"].fit_mode, FitMode::Fit);
+    }
+
+    #[test]
+    fn round_trips_through_toml() -> Result<(), Box<dyn std::error::Error>> {
+        let mut saved = SavedState::default();
+        saved.monitors.insert(
+            String::from("DP-1"),
+            SavedMonitor {
+                wallpaper: Some(PathBuf::from("/walls/a.png")),
+                fit_mode: FitMode::Center,
+                cycle_index: 2,
+            },
+        );
+        saved.cycling = Some(SavedCycling {
+            directory: PathBuf::from("/walls"),
+            mode: CyclingMode::Shuffle,
+            interval_secs: 300,
+        });
+
+        let content = toml::to_string(&saved)?;
+        let parsed: SavedState = toml::from_str(&content)?;
+
+        assert_eq!(parsed, saved);
+        Ok(())
+    }
+
+    #[test]
+    fn save_round_trips_without_leaving_temp_file() -> std::io::Result<()> {
+        let dir = std::env::temp_dir().join(format!("wayle-wallpaper-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let store = WallpaperStore {
+            path: dir.join(STATE_FILE),
+        };
+        let saved = SavedState {
+            cycling: Some(SavedCycling {
+                directory: PathBuf::from("/walls"),
+                mode: CyclingMode::Sequential,
+                interval_secs: 60,
+            }),
+            ..SavedState::default()
+        };
+
+        store.save(&SavedState::default());
+        store.save(&saved);
+
+        assert_eq!(store.load(), saved);
+        assert!(!store.path.with_extension("toml.tmp").exists());
+
+        fs::remove_dir_all(dir)
+    }
+
+    #[test]
+    fn missing_sections_default() -> Result<(), toml::de::Error> {
+        let parsed: SavedState = toml::from_str("")?;
+
+        assert_eq!(parsed, SavedState::default());
+        Ok(())
+    }
+}
@@ -1,4 +1,9 @@
-use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use derive_more::Debug;
 use futures::{
@@ -13,10 +18,13 @@ use wayle_core::Property;
 use zbus::Connection;
 
 use crate::{
-    backend::{AwwwBackend, TransitionConfig, wait_for_daemon},
+    backend::{AwwwBackend, TransitionConfig, ensure_daemon, wait_for_daemon},
     builder::WallpaperServiceBuilder,
     error::Error,
-    types::{ColorExtractorConfig, CyclingConfig, CyclingMode, FitMode, MonitorState},
+    persistence::SavedState,
+    types::{
+        ColorExtractorConfig, CyclingConfig, CyclingMode, EngineStatus, FitMode, MonitorState,
+    },
 };
 
 /// Desktop wallpaper manager. See [crate-level docs](crate) for usage.
@@ -30,6 +38,8 @@ pub struct WallpaperService {
     pub(crate) last_extracted_wallpaper: Property<Option<PathBuf>>,
     #[debug(skip)]
     pub(crate) extraction_complete: broadcast::Sender<()>,
+    pub(crate) engine_status: Property<EngineStatus>,
+    pub(crate) auto_start_daemon: bool,
 
     /// Monitor used for color extraction, or first available if `None`.
     pub theming_monitor: Property<Option<String>>,
@@ -79,6 +89,14 @@ impl WallpaperService {
             .and_then(|state| state.wallpaper.clone())
     }
 
+    /// Returns whether the awww daemon is available.
+    ///
+    /// Probed when the service is built and updated whenever rendering
+    /// finds the daemon gone or starts it again.
+    pub fn engine_status(&self) -> EngineStatus {
+        self.engine_status.get()
+    }
+
     /// Returns the cycling configuration, if cycling is active.
     pub fn cycling_config(&self) -> Option<CyclingConfig> {
         self.cycling.get()
@@ -111,7 +129,10 @@ impl WallpaperService {
                         .map(|s| s.fit_mode)
                         .unwrap_or_default();
                     let transition = self.transition.get();
-                    AwwwBackend::apply(&path, fit_mode, Some(name), &transition).await?;
+                    self.ensure_engine().await?;
+                    self.track_engine(
+                        AwwwBackend::apply(&path, fit_mode, Some(name), &transition).await,
+                    )?;
                 }
             }
             None => {
@@ -154,7 +175,10 @@ impl WallpaperService {
                     && let Some(path) = path
                 {
                     let transition = self.transition.get();
-                    AwwwBackend::apply(&path, mode, Some(name), &transition).await?;
+                    self.ensure_engine().await?;
+                    self.track_engine(
+                        AwwwBackend::apply(&path, mode, Some(name), &transition).await,
+                    )?;
                 }
             }
             None => {
@@ -361,6 +385,121 @@ impl WallpaperService {
         self.transition.set(transition);
     }
 
+    /// Shows `image` on `monitor` with `config`, then switches back after
+    /// `hold`.
+    ///
+    /// For tuning transition parameters without a lasting change: the
+    /// monitor's stored wallpaper and the service's `transition` are left
+    /// alone, and the monitor's current wallpaper is restored with the same
+    /// `config` so the transition plays twice. A monitor without a
+    /// wallpaper keeps showing `image`. Does nothing when `engine_active`
+    /// is off.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the image file does not exist, awww is not installed,
+    /// or the awww daemon is not running.
+    #[instrument(skip(self, config), fields(image = %image.display(), monitor))]
+    pub async fn preview_transition(
+        &self,
+        monitor: &str,
+        image: PathBuf,
+        config: &TransitionConfig,
+        hold: Duration,
+    ) -> Result<(), Error> {
+        if !image.exists() {
+            return Err(Error::ImageNotFound(image));
+        }
+
+        if !self.engine_active.get() {
+            return Ok(());
+        }
+
+        let fit_mode = self.fit_mode(monitor).unwrap_or_default();
+        self.ensure_engine().await?;
+        self.track_engine(AwwwBackend::apply(&image, fit_mode, Some(monitor), config).await)?;
+
+        tokio::time::sleep(hold).await;
+
+        let Some(previous) = self.wallpaper(monitor) else {
+            return Ok(());
+        };
+
+        let fit_mode = self.fit_mode(monitor).unwrap_or_default();
+        self.track_engine(AwwwBackend::apply(&previous, fit_mode, Some(monitor), config).await)
+    }
+
+    /// Returns the awww (or swww) command that would show `image` on
+    /// `monitor` with `config`, binary first.
+    ///
+    /// Nothing is run. Uses the monitor's fit mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the image path is not valid UTF-8.
+    pub fn transition_command(
+        &self,
+        monitor: &str,
+        image: &Path,
+        config: &TransitionConfig,
+    ) -> Result<Vec<String>, Error> {
+        let fit_mode = self.fit_mode(monitor).unwrap_or_default();
+        AwwwBackend::command(image, fit_mode, Some(monitor), config)
+    }
+
+    /// Applies a previous session's wallpapers and cycling to the registered
+    /// monitors. Saved wallpapers that no longer exist are skipped.
+    ///
+    /// Returns whether monitor wallpapers were restored that still need
+    /// rendering. A restored cycle is rendered by the cycling task instead.
+    pub(crate) fn restore(&self, saved: &SavedState) -> bool {
+        let mut monitors = self.monitors.get();
+        let mut restored = false;
+
+        for (name, state) in monitors.iter_mut() {
+            let Some(saved_monitor) = saved.monitors.get(name) else {
+                continue;
+            };
+
+            state.fit_mode = saved_monitor.fit_mode;
+            state.cycle_index = saved_monitor.cycle_index;
+
+            let Some(path) = &saved_monitor.wallpaper else {
+                continue;
+            };
+
+            if !path.exists() {
+                warn!(
+                    monitor = %name,
+                    path = %path.display(),
+                    "saved wallpaper no longer exists, skipping"
+                );
+                continue;
+            }
+
+            state.wallpaper = Some(path.clone());
+            restored = true;
+        }
+
+        self.monitors.set(monitors);
+
+        let Some(cycling) = &saved.cycling else {
+            return restored;
+        };
+
+        match CyclingConfig::new(cycling.directory.clone(), cycling.mode, cycling.interval()) {
+            Ok(config) => {
+                info!(directory = %cycling.directory.display(), "Restored wallpaper cycling");
+                self.cycling.set(Some(config));
+                false
+            }
+            Err(e) => {
+                warn!(error = %e, "cannot restore wallpaper cycling");
+                restored
+            }
+        }
+    }
+
     /// Renders the current cycle wallpaper to each monitor.
     async fn render_cycle(&self) -> Result<(), Error> {
         let Some(config) = self.cycling.get() else {
@@ -381,11 +520,12 @@ impl WallpaperService {
         self.monitors.set(monitors);
 
         if self.engine_active.get() {
+            self.ensure_engine().await?;
             let transition = self.transition.get();
             let futures = to_apply.iter().map(|(name, path, fit_mode)| {
                 AwwwBackend::apply(path, *fit_mode, Some(name.as_str()), &transition)
             });
-            try_join_all(futures).await?;
+            self.track_engine(try_join_all(futures).await)?;
         }
 
         Ok(())
@@ -407,12 +547,17 @@ impl WallpaperService {
     }
 
     /// Re-renders all monitors with their current wallpaper.
-    async fn rerender_all(&self) -> Result<(), Error> {
+    pub(crate) async fn rerender_all(&self) -> Result<(), Error> {
         if !self.engine_active.get() {
             return Ok(());
         }
 
         let monitors = self.monitors.get();
+        if monitors.values().all(|state| state.wallpaper.is_none()) {
+            return Ok(());
+        }
+
+        self.ensure_engine().await?;
         let transition = self.transition.get();
 
         let futures = monitors.iter().filter_map(|(name, state)| {
@@ -420,11 +565,43 @@ impl WallpaperService {
                 AwwwBackend::apply(path, state.fit_mode, Some(name.as_str()), &transition)
             })
         });
-        try_join_all(futures).await?;
+        self.track_engine(try_join_all(futures).await)?;
 
         Ok(())
     }
 
+    /// Makes sure the daemon can take commands before rendering.
+    ///
+    /// Re-probes when it wasn't running last time, starting it if
+    /// `auto_start_daemon` is set, so a daemon started after the service
+    /// is picked up.
+    async fn ensure_engine(&self) -> Result<(), Error> {
+        if self.engine_status.get() != EngineStatus::Running {
+            let auto_start = self.auto_start_daemon;
+            let status = tokio::task::spawn_blocking(move || ensure_daemon(auto_start))
+                .await
+                .unwrap_or_default();
+            self.engine_status.set(status);
+        }
+
+        match self.engine_status.get() {
+            EngineStatus::Running => Ok(()),
+            EngineStatus::NotInstalled => Err(Error::AwwwNotInstalled),
+            EngineStatus::NotStarted => Err(Error::AwwwDaemonNotRunning),
+        }
+    }
+
+    /// Records a daemon that disappeared or was uninstalled while running.
+    fn track_engine<T>(&self, result: Result<T, Error>) -> Result<T, Error> {
+        match &result {
+            Err(Error::AwwwDaemonNotRunning) => self.engine_status.set(EngineStatus::NotStarted),
+            Err(Error::AwwwNotInstalled) => self.engine_status.set(EngineStatus::NotInstalled),
+            _ => {}
+        }
+
+        result
+    }
+
     fn store_wallpaper(&self, monitor: &str, path: PathBuf) {
         let mut monitors = self.monitors.get();
         if let Some(state) = monitors.get_mut(monitor) {
@@ -12,6 +12,7 @@ use wayle_traits::ServiceMonitoring;
 
 use crate::{
     error::Error,
+    persistence::{SavedState, WallpaperStore},
     service::WallpaperService,
     wayland::{OutputEvent, OutputWatcher},
 };
@@ -137,3 +138,64 @@ pub(crate) fn spawn_color_extractor(service: Arc<WallpaperService>) {
         }
     });
 }
+
+/// Saves wallpaper and cycling state whenever either changes, so the next
+/// session can restore it.
+pub(crate) fn spawn_state_persister(
+    service: Arc<WallpaperService>,
+    store: WallpaperStore,
+    mut saved: SavedState,
+) {
+    let cancellation = service.cancellation_token.clone();
+
+    tokio::spawn(async move {
+        let mut monitor_watch = service.monitors.watch();
+        let mut cycling_watch = service.cycling.watch();
+
+        monitor_watch.next().await;
+        cycling_watch.next().await;
+
+        loop {
+            tokio::select! {
+                _ = cancellation.cancelled() => {
+                    return;
+                }
+
+                Some(_) = monitor_watch.next() => {}
+                Some(_) = cycling_watch.next() => {}
+            }
+
+            saved.update(&service.monitors.get(), service.cycling.get().as_ref());
+            store.save(&saved);
+        }
+    });
+}
+
+/// Re-renders wallpapers when the engine is switched back on, starting the
+/// daemon first if needed.
+pub(crate) fn spawn_engine_watcher(service: Arc<WallpaperService>) {
+    let cancellation = service.cancellation_token.clone();
+
+    tokio::spawn(async move {
+        let mut engine_watch = service.engine_active.watch();
+        engine_watch.next().await;
+
+        loop {
+            tokio::select! {
+                _ = cancellation.cancelled() => {
+                    return;
+                }
+
+                Some(active) = engine_watch.next() => {
+                    if !active {
+                        continue;
+                    }
+
+                    if let Err(e) = service.rerender_all().await {
+                        warn!(error = %e, "cannot render wallpapers after enabling engine");
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,24 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Availability of the awww (or swww) wallpaper daemon.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EngineStatus {
+    /// The daemon is accepting commands.
+    Running,
+    /// Neither awww nor swww is installed.
+    NotInstalled,
+    /// The client is installed but the daemon isn't running.
+    #[default]
+    NotStarted,
+}
+
+impl Display for EngineStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let s = match self {
+            Self::Running => "running",
+            Self::NotInstalled => "not installed",
+            Self::NotStarted => "not started",
+        };
+        write!(f, "{s}")
+    }
+}
@@ -2,10 +2,12 @@
 
 mod color_extractor;
 mod cycling;
+mod engine_status;
 mod fit_mode;
 mod monitor_state;
 
 pub use color_extractor::{ColorExtractor, ColorExtractorConfig};
 pub use cycling::{CyclingConfig, CyclingMode};
+pub use engine_status::EngineStatus;
 pub use fit_mode::FitMode;
 pub use monitor_state::MonitorState;
@@ -5,8 +5,10 @@ use tracing::instrument;
 use wayle_core::Property;
 
 use crate::{
+    error::Result,
     model::{LocationQuery, TemperatureUnit, WeatherProviderKind},
     polling::{self, PollingConfig},
+    provider::{BaseUrls, ForecastRange, parse_base_url},
     service::{WeatherService, WeatherStatus},
 };
 
@@ -20,6 +22,9 @@ pub struct WeatherServiceBuilder {
     units: TemperatureUnit,
     visual_crossing_key: Option<String>,
     weatherapi_key: Option<String>,
+    include_air_quality: bool,
+    forecast_range: ForecastRange,
+    base_urls: BaseUrls,
 }
 
 impl WeatherServiceBuilder {
@@ -34,6 +39,9 @@ impl WeatherServiceBuilder {
             units: TemperatureUnit::default(),
             visual_crossing_key: None,
             weatherapi_key: None,
+            include_air_quality: false,
+            forecast_range: ForecastRange::default(),
+            base_urls: BaseUrls::default(),
         }
     }
 
@@ -73,6 +81,44 @@ impl WeatherServiceBuilder {
         self
     }
 
+    /// Fetches air quality along with the weather, for providers that
+    /// support it. Off by default since it costs an extra request per poll.
+    pub fn include_air_quality(mut self, enabled: bool) -> Self {
+        self.include_air_quality = enabled;
+        self
+    }
+
+    /// Number of hourly forecast entries, starting at the current hour.
+    /// Defaults to 24.
+    ///
+    /// Providers that support it are asked for only this many hours, which
+    /// keeps responses small on metered connections.
+    pub fn hourly_hours(mut self, hours: u16) -> Self {
+        self.forecast_range.hourly_hours = hours;
+        self
+    }
+
+    /// Number of daily forecast entries, starting today. Defaults to 7.
+    ///
+    /// At least 1, since today's entry also supplies sunrise and sunset.
+    pub fn daily_days(mut self, days: u8) -> Self {
+        self.forecast_range.daily_days = days.max(1);
+        self
+    }
+
+    /// Sends `kind`'s requests to `url` instead of its public API host,
+    /// e.g. a self-hosted Open-Meteo or a proxy. Endpoint paths such as
+    /// `/v1/forecast` are appended to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidBaseUrl` if `url` is not an absolute http(s)
+    /// URL without a query or fragment.
+    pub fn provider_base_url(mut self, kind: WeatherProviderKind, url: &str) -> Result<Self> {
+        self.base_urls.set(kind, Some(parse_base_url(url)?));
+        Ok(self)
+    }
+
     /// Builds the service and starts the background polling task.
     ///
     /// If the selected provider requires an API key but none was provided,
@@ -89,6 +135,9 @@ impl WeatherServiceBuilder {
             kind: self.provider_kind,
             visual_crossing_key: self.visual_crossing_key.clone(),
             weatherapi_key: self.weatherapi_key.clone(),
+            include_air_quality: self.include_air_quality,
+            forecast_range: self.forecast_range,
+            base_urls: self.base_urls.clone(),
             location: self.location.clone(),
             poll_interval: self.poll_interval,
         };
@@ -110,6 +159,9 @@ impl WeatherServiceBuilder {
             units: RwLock::new(self.units),
             visual_crossing_key: RwLock::new(self.visual_crossing_key),
             weatherapi_key: RwLock::new(self.weatherapi_key),
+            include_air_quality: RwLock::new(self.include_air_quality),
+            forecast_range: self.forecast_range,
+            base_urls: RwLock::new(self.base_urls),
             weather,
             status,
         }
@@ -121,3 +173,29 @@ impl Default for WeatherServiceBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn provider_base_url_is_validated_when_set() {
+        let result = WeatherServiceBuilder::new()
+            .provider_base_url(WeatherProviderKind::OpenMeteo, "ftp://example.com");
+
+        assert!(matches!(result, Err(Error::InvalidBaseUrl { .. })));
+    }
+
+    #[test]
+    fn provider_base_url_is_stored_without_trailing_slash() -> Result<()> {
+        let builder = WeatherServiceBuilder::new()
+            .provider_base_url(WeatherProviderKind::OpenMeteo, "http://localhost:8080/")?;
+
+        assert_eq!(
+            builder.base_urls.get(WeatherProviderKind::OpenMeteo),
+            Some("http://localhost:8080")
+        );
+        Ok(())
+    }
+}
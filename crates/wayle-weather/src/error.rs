@@ -65,6 +65,15 @@ pub enum Error {
         provider: &'static str,
     },
 
+    /// Custom provider base URL cannot be used.
+    #[error("invalid provider base URL '{url}': {reason}")]
+    InvalidBaseUrl {
+        /// The configured base URL.
+        url: String,
+        /// Why the URL was rejected.
+        reason: String,
+    },
+
     /// API rate limit exceeded.
     #[error("{provider} rate limit exceeded")]
     RateLimited {
@@ -104,6 +113,7 @@ impl Error {
             | Self::LocationNotFound { .. }
             | Self::InvalidLocation { .. }
             | Self::ApiKeyMissing { .. }
+            | Self::InvalidBaseUrl { .. }
             | Self::NotAvailable => false,
         }
     }
@@ -41,6 +41,9 @@
 //! | `units(TemperatureUnit)` | Celsius or Fahrenheit display |
 //! | `visual_crossing_key(key)` | API key for Visual Crossing |
 //! | `weatherapi_key(key)` | API key for WeatherAPI.com |
+//! | `include_air_quality(bool)` | Also fetch air quality (extra request) |
+//! | `hourly_hours(u16)` / `daily_days(u8)` | Forecast length, 24 hours and 7 days by default |
+//! | `provider_base_url(kind, url)` | Self-hosted or proxied API host for a provider |
 //!
 //! ```rust,no_run
 //! use wayle_weather::{WeatherService, WeatherProviderKind, LocationQuery, TemperatureUnit};
@@ -54,13 +57,17 @@
 //!     .build();
 //! ```
 //!
+//! A custom base URL only replaces the forecast host. City names are still
+//! resolved through the public Open-Meteo geocoding API, so use
+//! [`LocationQuery::coords`] to keep every request on your own host.
+//!
 //! # Providers
 //!
-//! | Provider | API Key |
-//! |----------|---------|
-//! | [`OpenMeteo`](WeatherProviderKind::OpenMeteo) | No |
-//! | [`VisualCrossing`](WeatherProviderKind::VisualCrossing) | Yes |
-//! | [`WeatherApi`](WeatherProviderKind::WeatherApi) | Yes |
+//! | Provider | API Key | Alerts | Air Quality |
+//! |----------|---------|--------|-------------|
+//! | [`OpenMeteo`](WeatherProviderKind::OpenMeteo) | No | No | Yes |
+//! | [`VisualCrossing`](WeatherProviderKind::VisualCrossing) | Yes | No | No |
+//! | [`WeatherApi`](WeatherProviderKind::WeatherApi) | Yes | Yes | No |
 //!
 //! # Reactive Properties
 //!
@@ -74,6 +81,10 @@
 //! |-------|------|-------------|
 //! | `weather` | `Option<Arc<Weather>>` | Latest weather data, `None` until first fetch |
 //!
+//! [`alert_severity()`](WeatherService::alert_severity) derives the highest
+//! active alert severity from `weather`, for badging a widget during severe
+//! weather.
+//!
 //! # Runtime Updates
 //!
 //! All settings can change after creation:
@@ -82,6 +93,8 @@
 //! - [`set_location()`](WeatherService::set_location) - Weather location
 //! - [`set_units()`](WeatherService::set_units) - Temperature display
 //! - [`set_provider()`](WeatherService::set_provider) - Weather source
+//! - [`set_include_air_quality()`](WeatherService::set_include_air_quality) - Air quality fetching
+//! - [`set_provider_base_url()`](WeatherService::set_provider_base_url) - Custom API host
 //!
 //! # Weather Data
 //!
@@ -91,6 +104,8 @@
 //! - `daily` - Next 7+ days ([`DailyForecast`])
 //! - `location` - Resolved coordinates
 //! - `astronomy` - Sunrise/sunset times
+//! - `alerts` - Active weather alerts ([`WeatherAlert`]), where the provider reports them
+//! - `air_quality` - Current [`AirQuality`], when enabled and supported
 
 mod builder;
 pub(crate) mod geocoding;
@@ -114,10 +129,11 @@ pub mod types;
 pub use builder::WeatherServiceBuilder;
 pub use error::{Error, Result};
 pub use model::{
-    Astronomy, CurrentWeather, DailyForecast, HourlyForecast, Location, LocationQuery,
-    TemperatureUnit, Weather, WeatherCondition, WeatherProviderKind,
+    AirQuality, AlertSeverity, AqiCategory, Astronomy, CurrentWeather, DailyForecast,
+    HourlyForecast, Location, LocationQuery, LunarPhase, MoonPhase, TemperatureUnit, Weather,
+    WeatherAlert, WeatherCondition, WeatherProviderKind,
 };
-pub use provider::{ProviderConfig, WeatherProvider, create_provider};
+pub use provider::{ForecastRange, ProviderConfig, WeatherProvider, create_provider};
 pub use service::{WeatherErrorKind, WeatherService, WeatherStatus};
 pub use types::{
     Distance, Percentage, Precipitation, Pressure, Speed, Temperature, UvIndex, WindDirection,
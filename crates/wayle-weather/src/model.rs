@@ -1,3 +1,5 @@
+use std::f64::consts::TAU;
+
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -16,12 +18,28 @@ pub struct Weather {
     pub daily: Vec<DailyForecast>,
     /// Where this weather data applies.
     pub location: Location,
-    /// Sun rise/set times.
+    /// Sun rise/set times and moon phase.
     pub astronomy: Astronomy,
+    /// Active alerts and warnings. Empty for providers without alert data.
+    #[serde(default)]
+    pub alerts: Vec<WeatherAlert>,
+    /// Current air quality. `None` unless requested with
+    /// [`include_air_quality`](crate::WeatherServiceBuilder::include_air_quality)
+    /// and reported by the provider.
+    #[serde(default)]
+    pub air_quality: Option<AirQuality>,
     /// When this data was fetched from the provider.
     pub updated_at: DateTime<Utc>,
 }
 
+impl Weather {
+    /// Highest severity among the active alerts, `None` when there are none.
+    #[must_use]
+    pub fn max_alert_severity(&self) -> Option<AlertSeverity> {
+        self.alerts.iter().map(|alert| alert.severity).max()
+    }
+}
+
 /// Current weather conditions at the location.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CurrentWeather {
@@ -143,6 +161,217 @@ pub struct Astronomy {
     pub sunrise: NaiveTime,
     /// Sunset time (local).
     pub sunset: NaiveTime,
+    /// Moon phase for the day. Taken from the provider when it reports one,
+    /// otherwise computed from the date. `None` only for data cached before
+    /// moon phases were tracked.
+    #[serde(default)]
+    pub moon_phase: Option<MoonPhase>,
+}
+
+/// Mean length of a lunar cycle, in days.
+const SYNODIC_MONTH_DAYS: f64 = 29.530_588_853;
+
+/// Phase of the moon on a given day.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MoonPhase {
+    /// Named phase.
+    pub phase: LunarPhase,
+    /// Lit fraction of the visible disc, from 0.0 (new) to 1.0 (full).
+    pub illumination: f32,
+}
+
+impl MoonPhase {
+    /// Phase at `position` through the lunar cycle, where 0.0 is a new
+    /// moon, 0.5 a full moon and 1.0 the next new moon.
+    #[must_use]
+    pub fn from_cycle_position(position: f64) -> Self {
+        let position = position.rem_euclid(1.0);
+
+        Self {
+            phase: LunarPhase::from_cycle_position(position),
+            illumination: ((1.0 - (TAU * position).cos()) / 2.0) as f32,
+        }
+    }
+
+    /// Phase at midday UTC on `date`, from the mean lunar cycle. Can be
+    /// about a day off from the true phase.
+    #[must_use]
+    pub fn on(date: NaiveDate) -> Self {
+        let reference_new_moon = NaiveDate::from_ymd_opt(2000, 1, 6)
+            .and_then(|day| day.and_hms_opt(18, 14, 0))
+            .unwrap_or_default();
+        let midday = date.and_hms_opt(12, 0, 0).unwrap_or_default();
+        let elapsed_days = (midday - reference_new_moon).num_seconds() as f64 / 86_400.0;
+
+        Self::from_cycle_position(elapsed_days / SYNODIC_MONTH_DAYS)
+    }
+}
+
+/// The eight named phases of the moon, in cycle order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LunarPhase {
+    /// Moon not visible.
+    New,
+    /// Less than half lit, growing.
+    WaxingCrescent,
+    /// Right half lit (northern hemisphere).
+    FirstQuarter,
+    /// More than half lit, growing.
+    WaxingGibbous,
+    /// Fully lit.
+    Full,
+    /// More than half lit, shrinking.
+    WaningGibbous,
+    /// Left half lit (northern hemisphere).
+    LastQuarter,
+    /// Less than half lit, shrinking.
+    WaningCrescent,
+}
+
+impl LunarPhase {
+    const CYCLE: [Self; 8] = [
+        Self::New,
+        Self::WaxingCrescent,
+        Self::FirstQuarter,
+        Self::WaxingGibbous,
+        Self::Full,
+        Self::WaningGibbous,
+        Self::LastQuarter,
+        Self::WaningCrescent,
+    ];
+
+    /// Nearest named phase to `position` through the lunar cycle (see
+    /// [`MoonPhase::from_cycle_position`]).
+    #[must_use]
+    pub fn from_cycle_position(position: f64) -> Self {
+        let eighths = (position.rem_euclid(1.0) * 8.0).round() as usize;
+        Self::CYCLE[eighths % Self::CYCLE.len()]
+    }
+
+    /// Maps a phase name such as `"Waxing Crescent"`, case-insensitively.
+    /// Returns `None` for unrecognized names.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let phase = match name.trim().to_ascii_lowercase().as_str() {
+            "new moon" | "new" => Self::New,
+            "waxing crescent" => Self::WaxingCrescent,
+            "first quarter" => Self::FirstQuarter,
+            "waxing gibbous" => Self::WaxingGibbous,
+            "full moon" | "full" => Self::Full,
+            "waning gibbous" => Self::WaningGibbous,
+            "last quarter" | "third quarter" => Self::LastQuarter,
+            "waning crescent" => Self::WaningCrescent,
+            _ => return None,
+        };
+        Some(phase)
+    }
+}
+
+/// Weather alert or warning issued for the location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeatherAlert {
+    /// Short summary (e.g. "Tornado Warning issued for Travis County").
+    pub headline: String,
+    /// Provider-agnostic severity.
+    pub severity: AlertSeverity,
+    /// Full alert text.
+    pub description: String,
+    /// When the alert takes effect, if reported.
+    pub effective: Option<DateTime<Utc>>,
+    /// When the alert expires, if reported.
+    pub expires: Option<DateTime<Utc>>,
+}
+
+/// Alert severity, ordered from least to most severe.
+///
+/// Follows the CAP (Common Alerting Protocol) severity levels that most
+/// national weather services publish.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertSeverity {
+    /// Severity not reported by the provider.
+    #[default]
+    Unknown,
+    /// Minimal threat to life or property.
+    Minor,
+    /// Possible threat to life or property.
+    Moderate,
+    /// Significant threat to life or property.
+    Severe,
+    /// Extraordinary threat to life or property.
+    Extreme,
+}
+
+impl AlertSeverity {
+    /// Maps a CAP severity string, case-insensitively. Unrecognized values
+    /// map to `Unknown`.
+    #[must_use]
+    pub fn from_cap(severity: &str) -> Self {
+        match severity.trim().to_ascii_lowercase().as_str() {
+            "minor" => Self::Minor,
+            "moderate" => Self::Moderate,
+            "severe" => Self::Severe,
+            "extreme" => Self::Extreme,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Whether the alert warrants drawing attention (`Severe` or `Extreme`).
+    #[must_use]
+    pub fn is_severe(self) -> bool {
+        self >= Self::Severe
+    }
+}
+
+/// Current air quality at the location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AirQuality {
+    /// Air quality index on the US EPA scale (0-500).
+    pub aqi: u16,
+    /// Fine particulate matter concentration in µg/m³.
+    pub pm2_5: f32,
+    /// Coarse particulate matter concentration in µg/m³.
+    pub pm10: f32,
+    /// Ground-level ozone concentration in µg/m³.
+    pub ozone: f32,
+    /// Health category for `aqi`.
+    pub category: AqiCategory,
+}
+
+/// Air quality health category, ordered from cleanest to most polluted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AqiCategory {
+    /// Little or no risk.
+    Good,
+    /// Acceptable, with some risk for unusually sensitive people.
+    Moderate,
+    /// Sensitive groups may experience health effects.
+    UnhealthyForSensitiveGroups,
+    /// Everyone may begin to experience health effects.
+    Unhealthy,
+    /// Health alert, everyone may experience more serious effects.
+    VeryUnhealthy,
+    /// Emergency conditions.
+    Hazardous,
+}
+
+impl AqiCategory {
+    /// Maps an index on the US EPA scale to its category.
+    #[must_use]
+    pub fn from_us_aqi(aqi: u16) -> Self {
+        match aqi {
+            0..=50 => Self::Good,
+            51..=100 => Self::Moderate,
+            101..=150 => Self::UnhealthyForSensitiveGroups,
+            151..=200 => Self::Unhealthy,
+            201..=300 => Self::VeryUnhealthy,
+            _ => Self::Hazardous,
+        }
+    }
 }
 
 /// Weather condition categories mapped from provider-specific codes.
@@ -287,6 +516,92 @@ pub enum WeatherProviderKind {
 mod tests {
     use super::*;
 
+    #[test]
+    fn alert_severity_from_cap() {
+        assert_eq!(AlertSeverity::from_cap("Severe"), AlertSeverity::Severe);
+        assert_eq!(AlertSeverity::from_cap("extreme"), AlertSeverity::Extreme);
+        assert_eq!(AlertSeverity::from_cap(" Minor "), AlertSeverity::Minor);
+        assert_eq!(AlertSeverity::from_cap(""), AlertSeverity::Unknown);
+        assert_eq!(AlertSeverity::from_cap("Bogus"), AlertSeverity::Unknown);
+    }
+
+    #[test]
+    fn alert_severity_ordering() {
+        assert!(AlertSeverity::Extreme > AlertSeverity::Severe);
+        assert!(AlertSeverity::Moderate > AlertSeverity::Unknown);
+        assert!(AlertSeverity::Severe.is_severe());
+        assert!(!AlertSeverity::Moderate.is_severe());
+    }
+
+    #[test]
+    fn aqi_category_from_us_aqi() {
+        assert_eq!(AqiCategory::from_us_aqi(0), AqiCategory::Good);
+        assert_eq!(AqiCategory::from_us_aqi(50), AqiCategory::Good);
+        assert_eq!(AqiCategory::from_us_aqi(51), AqiCategory::Moderate);
+        assert_eq!(
+            AqiCategory::from_us_aqi(150),
+            AqiCategory::UnhealthyForSensitiveGroups
+        );
+        assert_eq!(AqiCategory::from_us_aqi(200), AqiCategory::Unhealthy);
+        assert_eq!(AqiCategory::from_us_aqi(300), AqiCategory::VeryUnhealthy);
+        assert_eq!(AqiCategory::from_us_aqi(450), AqiCategory::Hazardous);
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap_or_default()
+    }
+
+    #[test]
+    fn moon_phase_on_known_dates() {
+        let new_moon = MoonPhase::on(date(2024, 4, 8));
+        assert_eq!(new_moon.phase, LunarPhase::New);
+        assert!(new_moon.illumination < 0.01);
+
+        assert_eq!(
+            MoonPhase::on(date(2024, 4, 15)).phase,
+            LunarPhase::FirstQuarter
+        );
+
+        let full_moon = MoonPhase::on(date(2024, 4, 23));
+        assert_eq!(full_moon.phase, LunarPhase::Full);
+        assert!(full_moon.illumination > 0.99);
+
+        assert_eq!(
+            MoonPhase::on(date(2024, 5, 1)).phase,
+            LunarPhase::LastQuarter
+        );
+        assert_eq!(MoonPhase::on(date(1999, 12, 22)).phase, LunarPhase::Full);
+    }
+
+    #[test]
+    fn lunar_phase_from_cycle_position_wraps() {
+        assert_eq!(LunarPhase::from_cycle_position(0.0), LunarPhase::New);
+        assert_eq!(LunarPhase::from_cycle_position(0.97), LunarPhase::New);
+        assert_eq!(
+            LunarPhase::from_cycle_position(0.1),
+            LunarPhase::WaxingCrescent
+        );
+        assert_eq!(LunarPhase::from_cycle_position(0.5), LunarPhase::Full);
+        assert_eq!(
+            LunarPhase::from_cycle_position(-0.1),
+            LunarPhase::WaningCrescent
+        );
+    }
+
+    #[test]
+    fn lunar_phase_from_name() {
+        assert_eq!(
+            LunarPhase::from_name("Waxing Crescent"),
+            Some(LunarPhase::WaxingCrescent)
+        );
+        assert_eq!(LunarPhase::from_name("FULL MOON"), Some(LunarPhase::Full));
+        assert_eq!(
+            LunarPhase::from_name(" Third Quarter "),
+            Some(LunarPhase::LastQuarter)
+        );
+        assert_eq!(LunarPhase::from_name("Blue Moon"), None);
+    }
+
     #[test]
     fn wmo_code_0_is_clear() {
         assert_eq!(WeatherCondition::from_wmo_code(0), WeatherCondition::Clear);
@@ -9,7 +9,7 @@ use crate::{
     error::{Error, error_chain},
     geocoding,
     model::{LocationQuery, Weather, WeatherProviderKind},
-    provider::{ProviderConfig, create_provider},
+    provider::{BaseUrls, ForecastRange, ProviderConfig, create_provider},
     service::{WeatherErrorKind, WeatherStatus},
 };
 
@@ -21,6 +21,9 @@ pub(crate) struct PollingConfig {
     pub kind: WeatherProviderKind,
     pub visual_crossing_key: Option<String>,
     pub weatherapi_key: Option<String>,
+    pub include_air_quality: bool,
+    pub forecast_range: ForecastRange,
+    pub base_urls: BaseUrls,
     pub location: LocationQuery,
     pub poll_interval: Duration,
 }
@@ -75,6 +78,9 @@ async fn fetch_with_retry(
         kind: config.kind,
         visual_crossing_key: config.visual_crossing_key.as_deref(),
         weatherapi_key: config.weatherapi_key.as_deref(),
+        include_air_quality: config.include_air_quality,
+        forecast_range: config.forecast_range,
+        base_url: config.base_urls.get(config.kind),
     })
     .map_err(|err| {
         warn!(error = %error_chain(&err), "cannot create weather provider");
@@ -3,7 +3,7 @@ mod visual_crossing;
 mod weatherapi;
 
 use async_trait::async_trait;
-use chrono::{NaiveTime, Utc};
+use chrono::{Local, NaiveTime, Utc};
 pub use open_meteo::OpenMeteo;
 pub use visual_crossing::VisualCrossing;
 pub use weatherapi::WeatherApi;
@@ -11,8 +11,8 @@ pub use weatherapi::WeatherApi;
 use crate::{
     error::{Error, Result},
     model::{
-        Astronomy, CurrentWeather, DailyForecast, HourlyForecast, Location, LocationQuery, Weather,
-        WeatherProviderKind,
+        Astronomy, CurrentWeather, DailyForecast, HourlyForecast, Location, LocationQuery,
+        MoonPhase, Weather, WeatherAlert, WeatherProviderKind,
     },
 };
 
@@ -41,26 +41,127 @@ pub struct ProviderConfig<'a> {
     pub visual_crossing_key: Option<&'a str>,
     /// API key for WeatherAPI.com (required if `kind` is `WeatherApi`).
     pub weatherapi_key: Option<&'a str>,
+    /// Also fetch air quality where the provider supports it.
+    pub include_air_quality: bool,
+    /// How many hourly and daily entries to fetch.
+    pub forecast_range: ForecastRange,
+    /// Replaces the provider's API host, e.g. a self-hosted Open-Meteo
+    /// instance or a corporate proxy. Endpoint paths are appended to it.
+    pub base_url: Option<&'a str>,
+}
+
+/// How far ahead forecasts reach.
+///
+/// Providers that accept a range ask only for these entries; the others
+/// are trimmed to it after parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForecastRange {
+    /// Hourly entries, starting at the current hour.
+    pub hourly_hours: u16,
+    /// Daily entries, starting today.
+    pub daily_days: u8,
+}
+
+impl Default for ForecastRange {
+    fn default() -> Self {
+        Self {
+            hourly_hours: 24,
+            daily_days: 7,
+        }
+    }
+}
+
+impl ForecastRange {
+    /// Whole days to request from providers that only fetch by day.
+    ///
+    /// Hourly entries start at the current hour, so they can reach one day
+    /// further than `hourly_hours / 24`.
+    pub(crate) fn days_to_fetch(&self) -> u16 {
+        (self.hourly_hours.div_ceil(24) + 1).max(u16::from(self.daily_days))
+    }
+}
+
+/// Custom base URLs, one per provider.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BaseUrls {
+    open_meteo: Option<String>,
+    visual_crossing: Option<String>,
+    weatherapi: Option<String>,
+}
+
+impl BaseUrls {
+    pub(crate) fn get(&self, kind: WeatherProviderKind) -> Option<&str> {
+        match kind {
+            WeatherProviderKind::OpenMeteo => self.open_meteo.as_deref(),
+            WeatherProviderKind::VisualCrossing => self.visual_crossing.as_deref(),
+            WeatherProviderKind::WeatherApi => self.weatherapi.as_deref(),
+        }
+    }
+
+    pub(crate) fn set(&mut self, kind: WeatherProviderKind, url: Option<String>) {
+        let slot = match kind {
+            WeatherProviderKind::OpenMeteo => &mut self.open_meteo,
+            WeatherProviderKind::VisualCrossing => &mut self.visual_crossing,
+            WeatherProviderKind::WeatherApi => &mut self.weatherapi,
+        };
+        *slot = url;
+    }
+}
+
+/// Checks that `url` is an absolute http(s) URL without query or fragment,
+/// and returns it without a trailing slash so endpoint paths can be appended.
+pub(crate) fn parse_base_url(url: &str) -> Result<String> {
+    let invalid = |reason: &str| Error::InvalidBaseUrl {
+        url: url.to_owned(),
+        reason: reason.to_owned(),
+    };
+
+    let parsed = reqwest::Url::parse(url).map_err(|err| invalid(&err.to_string()))?;
+
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid("scheme must be http or https"));
+    }
+
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        return Err(invalid("must not contain a query or fragment"));
+    }
+
+    Ok(parsed.as_str().trim_end_matches('/').to_owned())
+}
+
+/// Joins `path` onto the custom base URL, or onto `default_host` if unset.
+pub(crate) fn endpoint(base_url: Option<&str>, default_host: &str, path: &str) -> String {
+    format!("{}{path}", base_url.unwrap_or(default_host))
 }
 
 /// Assembles a `Weather` from parsed provider data.
 ///
 /// Extracts astronomy from the first daily forecast entry, falling back to
-/// 06:00 sunrise / 18:00 sunset if daily data is empty.
+/// 06:00 sunrise / 18:00 sunset if daily data is empty. Without a
+/// `moon_phase` from the provider, it is computed for that day's date.
 pub(crate) fn build_weather(
     current: CurrentWeather,
     hourly: Vec<HourlyForecast>,
     daily: Vec<DailyForecast>,
     location: Location,
+    alerts: Vec<WeatherAlert>,
+    moon_phase: Option<MoonPhase>,
 ) -> Weather {
+    let date = daily
+        .first()
+        .map_or_else(|| Local::now().date_naive(), |first_day| first_day.date);
+    let moon_phase = moon_phase.unwrap_or_else(|| MoonPhase::on(date));
+
     let astronomy = daily.first().map_or_else(
         || Astronomy {
             sunrise: NaiveTime::from_hms_opt(6, 0, 0).unwrap_or_default(),
             sunset: NaiveTime::from_hms_opt(18, 0, 0).unwrap_or_default(),
+            moon_phase: Some(moon_phase),
         },
         |first_day| Astronomy {
             sunrise: first_day.sunrise,
             sunset: first_day.sunset,
+            moon_phase: Some(moon_phase),
         },
     );
 
@@ -70,6 +171,8 @@ pub(crate) fn build_weather(
         daily,
         location,
         astronomy,
+        alerts,
+        air_quality: None,
         updated_at: Utc::now(),
     }
 }
@@ -79,20 +182,112 @@ pub(crate) fn build_weather(
 /// # Errors
 ///
 /// Returns `Error::ApiKeyMissing` if the provider requires an API key but none is provided.
+/// Returns `Error::InvalidBaseUrl` if `base_url` is not an absolute http(s) URL.
 pub fn create_provider(config: ProviderConfig<'_>) -> Result<Box<dyn WeatherProvider>> {
+    let base_url = config.base_url.map(parse_base_url).transpose()?;
+
     match config.kind {
-        WeatherProviderKind::OpenMeteo => Ok(Box::new(OpenMeteo::new())),
+        WeatherProviderKind::OpenMeteo => Ok(Box::new(
+            OpenMeteo::new()
+                .with_air_quality(config.include_air_quality)
+                .with_forecast_range(config.forecast_range)
+                .with_base_url(base_url),
+        )),
         WeatherProviderKind::VisualCrossing => {
             let key = config.visual_crossing_key.ok_or(Error::ApiKeyMissing {
                 provider: "visual-crossing",
             })?;
-            Ok(Box::new(VisualCrossing::new(key)))
+            Ok(Box::new(
+                VisualCrossing::new(key)
+                    .with_forecast_range(config.forecast_range)
+                    .with_base_url(base_url),
+            ))
         }
         WeatherProviderKind::WeatherApi => {
             let key = config.weatherapi_key.ok_or(Error::ApiKeyMissing {
                 provider: "weatherapi",
             })?;
-            Ok(Box::new(WeatherApi::new(key)))
+            Ok(Box::new(
+                WeatherApi::new(key)
+                    .with_forecast_range(config.forecast_range)
+                    .with_base_url(base_url),
+            ))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_base_url_strips_trailing_slash() -> Result<()> {
+        assert_eq!(
+            parse_base_url("http://localhost:8080/")?,
+            "http://localhost:8080"
+        );
+        assert_eq!(
+            parse_base_url("https://proxy.example.com/open-meteo/")?,
+            "https://proxy.example.com/open-meteo"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_base_url_rejects_unusable_urls() {
+        for url in [
+            "localhost:8080",
+            "ftp://example.com",
+            "https://example.com/?key=1",
+            "api.open-meteo.com",
+        ] {
+            assert!(
+                matches!(parse_base_url(url), Err(Error::InvalidBaseUrl { .. })),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn endpoint_prefers_custom_base_url() {
+        assert_eq!(
+            endpoint(None, "https://api.open-meteo.com", "/v1/forecast"),
+            "https://api.open-meteo.com/v1/forecast"
+        );
+        assert_eq!(
+            endpoint(
+                Some("http://localhost:8080"),
+                "https://api.open-meteo.com",
+                "/v1/forecast"
+            ),
+            "http://localhost:8080/v1/forecast"
+        );
+    }
+
+    #[test]
+    fn days_to_fetch_covers_both_ranges() {
+        let range = |hourly_hours, daily_days| ForecastRange {
+            hourly_hours,
+            daily_days,
+        };
+
+        assert_eq!(ForecastRange::default().days_to_fetch(), 7);
+        assert_eq!(range(12, 1).days_to_fetch(), 2);
+        assert_eq!(range(48, 3).days_to_fetch(), 3);
+        assert_eq!(range(72, 1).days_to_fetch(), 4);
+    }
+
+    #[test]
+    fn invalid_base_url_fails_provider_creation() {
+        let result = create_provider(ProviderConfig {
+            kind: WeatherProviderKind::OpenMeteo,
+            visual_crossing_key: None,
+            weatherapi_key: None,
+            include_air_quality: false,
+            forecast_range: ForecastRange::default(),
+            base_url: Some("not a url"),
+        });
+
+        assert!(matches!(result, Err(Error::InvalidBaseUrl { .. })));
+    }
+}
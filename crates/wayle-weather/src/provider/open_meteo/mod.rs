@@ -3,15 +3,24 @@ mod types;
 
 use async_trait::async_trait;
 use parse::PROVIDER;
-use types::{ApiResponse, ForecastRequest};
+use tracing::warn;
+use types::{AirQualityRequest, AirQualityResponse, ApiResponse, ForecastRequest};
 
-use super::{WeatherProvider, build_weather};
+use super::{ForecastRange, WeatherProvider, build_weather, endpoint};
 use crate::{
-    error::{Error, Result},
-    model::{Location, LocationQuery, Weather, WeatherProviderKind},
+    error::{Error, Result, error_chain},
+    model::{AirQuality, Location, LocationQuery, Weather, WeatherProviderKind},
 };
 
-const FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
+const FORECAST_HOST: &str = "https://api.open-meteo.com";
+const FORECAST_PATH: &str = "/v1/forecast";
+const AIR_QUALITY_HOST: &str = "https://air-quality-api.open-meteo.com";
+const AIR_QUALITY_PATH: &str = "/v1/air-quality";
+
+/// Longest daily forecast the API serves.
+const MAX_FORECAST_DAYS: u8 = 16;
+
+const AIR_QUALITY_PARAMS: &str = "us_aqi,pm2_5,pm10,ozone";
 
 const HOURLY_PARAMS: &str = "temperature_2m,relative_humidity_2m,apparent_temperature,\
     precipitation_probability,precipitation,weather_code,cloud_cover,pressure_msl,\
@@ -24,6 +33,9 @@ const DAILY_PARAMS: &str = "weather_code,temperature_2m_max,temperature_2m_min,\
 /// Open-Meteo weather provider (default, no API key required).
 pub struct OpenMeteo {
     client: reqwest::Client,
+    air_quality: bool,
+    forecast_range: ForecastRange,
+    base_url: Option<String>,
 }
 
 impl OpenMeteo {
@@ -32,7 +44,65 @@ impl OpenMeteo {
     pub fn new() -> Self {
         Self {
             client: reqwest::Client::new(),
+            air_quality: false,
+            forecast_range: ForecastRange::default(),
+            base_url: None,
+        }
+    }
+
+    /// Sends forecast and air quality requests to `base_url` instead of the
+    /// public Open-Meteo hosts, e.g. a self-hosted instance.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: Option<String>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Also fetches current air quality, at the cost of one extra request
+    /// per poll.
+    #[must_use]
+    pub fn with_air_quality(mut self, enabled: bool) -> Self {
+        self.air_quality = enabled;
+        self
+    }
+
+    /// Requests only `range` instead of the default 24 hours and 7 days.
+    #[must_use]
+    pub fn with_forecast_range(mut self, range: ForecastRange) -> Self {
+        self.forecast_range = range;
+        self
+    }
+
+    async fn fetch_air_quality(&self, resolved: &Location) -> Result<Option<AirQuality>> {
+        let request = AirQualityRequest {
+            latitude: resolved.lat,
+            longitude: resolved.lon,
+            current: AIR_QUALITY_PARAMS,
+            timezone: "auto",
+        };
+
+        let resp = self
+            .client
+            .get(endpoint(
+                self.base_url.as_deref(),
+                AIR_QUALITY_HOST,
+                AIR_QUALITY_PATH,
+            ))
+            .query(&request)
+            .send()
+            .await
+            .map_err(|err| Error::http(PROVIDER, err))?;
+
+        if !resp.status().is_success() {
+            return Err(Error::status(PROVIDER, resp.status()));
         }
+
+        let data: AirQualityResponse = resp
+            .json()
+            .await
+            .map_err(|err| Error::parse(PROVIDER, err.to_string()))?;
+
+        Ok(parse::build_air_quality(&data.current))
     }
 }
 
@@ -57,12 +127,19 @@ impl WeatherProvider for OpenMeteo {
             temperature_unit: "celsius",
             wind_speed_unit: "kmh",
             timezone: "auto",
-            forecast_days: 7,
+            forecast_days: self.forecast_range.daily_days.min(MAX_FORECAST_DAYS),
+            // Hourly data starts at the current hour; the extra entry lets
+            // the current hour be told apart from the last one available.
+            forecast_hours: self.forecast_range.hourly_hours.saturating_add(1),
         };
 
         let resp = self
             .client
-            .get(FORECAST_URL)
+            .get(endpoint(
+                self.base_url.as_deref(),
+                FORECAST_HOST,
+                FORECAST_PATH,
+            ))
             .query(&request)
             .send()
             .await
@@ -82,9 +159,22 @@ impl WeatherProvider for OpenMeteo {
             .map_err(|err| Error::parse(PROVIDER, err.to_string()))?;
 
         let current = parse::build_current(&data)?;
-        let hourly = parse::build_hourly(&data.hourly, 24)?;
-        let daily = parse::build_daily(&data, 7)?;
+        let hourly =
+            parse::build_hourly(&data.hourly, usize::from(self.forecast_range.hourly_hours))?;
+        let daily = parse::build_daily(&data, usize::from(self.forecast_range.daily_days))?;
+
+        let mut weather = build_weather(current, hourly, daily, resolved.clone(), Vec::new(), None);
+
+        if self.air_quality {
+            weather.air_quality = self
+                .fetch_air_quality(resolved)
+                .await
+                .unwrap_or_else(|err| {
+                    warn!(error = %error_chain(&err), "cannot fetch air quality");
+                    None
+                });
+        }
 
-        Ok(build_weather(current, hourly, daily, resolved.clone()))
+        Ok(weather)
     }
 }
@@ -1,10 +1,12 @@
 use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
 use tracing::warn;
 
-use super::types::{ApiResponse, HourlyData};
+use super::types::{AirQualityData, ApiResponse, HourlyData};
 use crate::{
     error::{Error, Result},
-    model::{CurrentWeather, DailyForecast, HourlyForecast, WeatherCondition},
+    model::{
+        AirQuality, AqiCategory, CurrentWeather, DailyForecast, HourlyForecast, WeatherCondition,
+    },
     types::{
         Distance, Percentage, Precipitation, Pressure, Speed, Temperature, UvIndex, WindDirection,
     },
@@ -98,6 +100,20 @@ pub fn build_daily(data: &ApiResponse, count: usize) -> Result<Vec<DailyForecast
     Ok(forecasts)
 }
 
+/// Returns `None` when the API has no reading for any of the values, which
+/// happens for locations outside the model's coverage.
+pub fn build_air_quality(data: &AirQualityData) -> Option<AirQuality> {
+    let aqi = data.us_aqi?.clamp(0.0, 500.0).round() as u16;
+
+    Some(AirQuality {
+        aqi,
+        pm2_5: data.pm2_5?.max(0.0) as f32,
+        pm10: data.pm10?.max(0.0) as f32,
+        ozone: data.ozone?.max(0.0) as f32,
+        category: AqiCategory::from_us_aqi(aqi),
+    })
+}
+
 pub fn find_current_hour_index(times: &[String]) -> usize {
     let now = Local::now().naive_local();
     for (hour_idx, time_str) in times.iter().enumerate() {
@@ -212,6 +228,37 @@ mod tests {
         assert_eq!(result.unwrap().to_string(), "14:30:00");
     }
 
+    #[test]
+    fn air_quality_maps_us_aqi_to_category() {
+        let data = AirQualityData {
+            us_aqi: Some(112.4),
+            pm2_5: Some(40.2),
+            pm10: Some(55.0),
+            ozone: Some(-1.0),
+        };
+
+        let air_quality = build_air_quality(&data).unwrap();
+
+        assert_eq!(air_quality.aqi, 112);
+        assert_eq!(
+            air_quality.category,
+            AqiCategory::UnhealthyForSensitiveGroups
+        );
+        assert_eq!(air_quality.ozone, 0.0);
+    }
+
+    #[test]
+    fn air_quality_is_none_without_readings() {
+        let data = AirQualityData {
+            us_aqi: None,
+            pm2_5: Some(4.0),
+            pm10: Some(8.0),
+            ozone: Some(60.0),
+        };
+
+        assert!(build_air_quality(&data).is_none());
+    }
+
     #[test]
     fn percentage_clamps_values() {
         let arr = vec![150.0, -10.0, 50.0];
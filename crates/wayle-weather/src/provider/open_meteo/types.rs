@@ -12,6 +12,28 @@ pub struct ForecastRequest {
     pub wind_speed_unit: &'static str,
     pub timezone: &'static str,
     pub forecast_days: u8,
+    pub forecast_hours: u16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AirQualityRequest {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub current: &'static str,
+    pub timezone: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AirQualityResponse {
+    pub current: AirQualityData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AirQualityData {
+    pub us_aqi: Option<f64>,
+    pub pm2_5: Option<f64>,
+    pub pm10: Option<f64>,
+    pub ozone: Option<f64>,
 }
 
 #[derive(Debug, Deserialize)]
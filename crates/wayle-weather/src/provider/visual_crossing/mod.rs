@@ -2,18 +2,19 @@ mod parse;
 mod types;
 
 use async_trait::async_trait;
+use chrono::{Days, Local};
 use parse::PROVIDER;
 use serde::Serialize;
 use types::ApiResponse;
 
-use super::{WeatherProvider, build_weather};
+use super::{ForecastRange, WeatherProvider, build_weather, endpoint};
 use crate::{
     error::{Error, Result},
     model::{Location, LocationQuery, Weather, WeatherProviderKind},
 };
 
-const BASE_URL: &str =
-    "https://weather.visualcrossing.com/VisualCrossingWebServices/rest/services/timeline";
+const DEFAULT_HOST: &str = "https://weather.visualcrossing.com";
+const TIMELINE_PATH: &str = "/VisualCrossingWebServices/rest/services/timeline";
 
 #[derive(Serialize)]
 struct TimelineRequest<'a> {
@@ -29,6 +30,8 @@ struct TimelineRequest<'a> {
 pub struct VisualCrossing {
     client: reqwest::Client,
     api_key: String,
+    forecast_range: ForecastRange,
+    base_url: Option<String>,
 }
 
 impl VisualCrossing {
@@ -38,9 +41,37 @@ impl VisualCrossing {
         Self {
             client: reqwest::Client::new(),
             api_key: api_key.into(),
+            forecast_range: ForecastRange::default(),
+            base_url: None,
         }
     }
 
+    /// Requests only the days `range` needs instead of the default 15.
+    #[must_use]
+    pub fn with_forecast_range(mut self, range: ForecastRange) -> Self {
+        self.forecast_range = range;
+        self
+    }
+
+    /// `today/<last day>` path segment covering the forecast range.
+    fn date_range(&self) -> String {
+        let today = Local::now().date_naive();
+        let extra_days = u64::from(self.forecast_range.days_to_fetch().saturating_sub(1));
+        let last_day = today
+            .checked_add_days(Days::new(extra_days))
+            .unwrap_or(today);
+
+        format!("{today}/{last_day}")
+    }
+
+    /// Sends requests to `base_url` instead of the public Visual Crossing
+    /// host, e.g. through a proxy.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: Option<String>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
     fn location_path(location: &LocationQuery) -> String {
         match location {
             LocationQuery::Coordinates { lat, lon } => format!("{lat},{lon}"),
@@ -63,7 +94,8 @@ impl WeatherProvider for VisualCrossing {
 
     async fn fetch(&self, location: &LocationQuery, resolved: &Location) -> Result<Weather> {
         let location_path = Self::location_path(location);
-        let url = format!("{BASE_URL}/{location_path}");
+        let timeline = endpoint(self.base_url.as_deref(), DEFAULT_HOST, TIMELINE_PATH);
+        let url = format!("{timeline}/{location_path}/{}", self.date_range());
 
         let request = TimelineRequest {
             key: &self.api_key,
@@ -100,9 +132,17 @@ impl WeatherProvider for VisualCrossing {
             .map_err(|err| Error::parse(PROVIDER, err.to_string()))?;
 
         let current = parse::build_current(&data)?;
-        let hourly = parse::build_hourly(&data, 24)?;
-        let daily = parse::build_daily(&data, 7)?;
-
-        Ok(build_weather(current, hourly, daily, resolved.clone()))
+        let hourly = parse::build_hourly(&data, usize::from(self.forecast_range.hourly_hours))?;
+        let daily = parse::build_daily(&data, usize::from(self.forecast_range.daily_days))?;
+        let moon_phase = parse::build_moon_phase(&data);
+
+        Ok(build_weather(
+            current,
+            hourly,
+            daily,
+            resolved.clone(),
+            Vec::new(),
+            moon_phase,
+        ))
     }
 }
@@ -3,7 +3,7 @@ use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
 use super::types::{ApiResponse, DayData, HourData};
 use crate::{
     error::{Error, Result},
-    model::{CurrentWeather, DailyForecast, HourlyForecast, WeatherCondition},
+    model::{CurrentWeather, DailyForecast, HourlyForecast, MoonPhase, WeatherCondition},
     types::{
         Distance, Percentage, Precipitation, Pressure, Speed, Temperature, UvIndex, WindDirection,
     },
@@ -105,6 +105,15 @@ pub fn build_daily(data: &ApiResponse, count: usize) -> Result<Vec<DailyForecast
     Ok(forecasts)
 }
 
+/// Today's moon phase. Visual Crossing reports it as the position through
+/// the lunar cycle.
+pub fn build_moon_phase(data: &ApiResponse) -> Option<MoonPhase> {
+    data.days
+        .first()?
+        .moonphase
+        .map(MoonPhase::from_cycle_position)
+}
+
 fn build_daily_forecast(day_data: &DayData) -> Result<DailyForecast> {
     let date = parse_date(&day_data.datetime)?;
     let sunrise = parse_time(&day_data.sunrise)?;
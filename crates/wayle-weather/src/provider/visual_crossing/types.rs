@@ -57,6 +57,8 @@ pub struct DayData {
     pub uvindex: f64,
     pub sunrise: String,
     pub sunset: String,
+    #[serde(default)]
+    pub moonphase: Option<f64>,
     pub conditions: String,
     pub icon: String,
     #[serde(default)]
@@ -6,13 +6,17 @@ use parse::PROVIDER;
 use serde::Serialize;
 use types::ApiResponse;
 
-use super::{WeatherProvider, build_weather};
+use super::{ForecastRange, WeatherProvider, build_weather, endpoint};
 use crate::{
     error::{Error, Result},
     model::{Location, LocationQuery, Weather, WeatherProviderKind},
 };
 
-const BASE_URL: &str = "https://api.weatherapi.com/v1/forecast.json";
+const DEFAULT_HOST: &str = "https://api.weatherapi.com";
+const FORECAST_PATH: &str = "/v1/forecast.json";
+
+/// Longest forecast the API serves.
+const MAX_FORECAST_DAYS: u16 = 14;
 
 #[derive(Serialize)]
 struct ForecastRequest<'a> {
@@ -27,6 +31,8 @@ struct ForecastRequest<'a> {
 pub struct WeatherApi {
     client: reqwest::Client,
     api_key: String,
+    forecast_range: ForecastRange,
+    base_url: Option<String>,
 }
 
 impl WeatherApi {
@@ -36,9 +42,26 @@ impl WeatherApi {
         Self {
             client: reqwest::Client::new(),
             api_key: api_key.into(),
+            forecast_range: ForecastRange::default(),
+            base_url: None,
         }
     }
 
+    /// Requests only the days `range` needs instead of the default 7.
+    #[must_use]
+    pub fn with_forecast_range(mut self, range: ForecastRange) -> Self {
+        self.forecast_range = range;
+        self
+    }
+
+    /// Sends requests to `base_url` instead of the public WeatherAPI.com
+    /// host, e.g. through a proxy.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: Option<String>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
     fn location_query(location: &LocationQuery) -> String {
         match location {
             LocationQuery::Coordinates { lat, lon } => format!("{lat},{lon}"),
@@ -63,14 +86,18 @@ impl WeatherProvider for WeatherApi {
         let request = ForecastRequest {
             key: &self.api_key,
             q: Self::location_query(location),
-            days: 7,
+            days: i32::from(self.forecast_range.days_to_fetch().min(MAX_FORECAST_DAYS)),
             aqi: "no",
-            alerts: "no",
+            alerts: "yes",
         };
 
         let resp = self
             .client
-            .get(BASE_URL)
+            .get(endpoint(
+                self.base_url.as_deref(),
+                DEFAULT_HOST,
+                FORECAST_PATH,
+            ))
             .query(&request)
             .send()
             .await
@@ -96,9 +123,18 @@ impl WeatherProvider for WeatherApi {
             .map_err(|err| Error::parse(PROVIDER, err.to_string()))?;
 
         let current = parse::build_current(&data)?;
-        let hourly = parse::build_hourly(&data, 24)?;
-        let daily = parse::build_daily(&data, 7)?;
-
-        Ok(build_weather(current, hourly, daily, resolved.clone()))
+        let hourly = parse::build_hourly(&data, usize::from(self.forecast_range.hourly_hours))?;
+        let daily = parse::build_daily(&data, usize::from(self.forecast_range.daily_days))?;
+        let alerts = parse::build_alerts(&data);
+        let moon_phase = parse::build_moon_phase(&data);
+
+        Ok(build_weather(
+            current,
+            hourly,
+            daily,
+            resolved.clone(),
+            alerts,
+            moon_phase,
+        ))
     }
 }
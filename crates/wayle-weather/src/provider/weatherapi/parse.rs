@@ -1,9 +1,12 @@
-use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
-use super::types::{ApiResponse, ForecastDay, HourData};
+use super::types::{AlertData, ApiResponse, AstroData, ForecastDay, HourData};
 use crate::{
     error::{Error, Result},
-    model::{CurrentWeather, DailyForecast, HourlyForecast, WeatherCondition},
+    model::{
+        AlertSeverity, CurrentWeather, DailyForecast, HourlyForecast, LunarPhase, MoonPhase,
+        WeatherAlert, WeatherCondition,
+    },
     types::{
         Distance, Percentage, Precipitation, Pressure, Speed, Temperature, UvIndex, WindDirection,
     },
@@ -113,6 +116,56 @@ fn build_daily_forecast(forecast_day: &ForecastDay) -> Result<DailyForecast> {
     })
 }
 
+/// Today's moon phase, or `None` if the response lacks the phase name or
+/// illumination.
+pub fn build_moon_phase(data: &ApiResponse) -> Option<MoonPhase> {
+    moon_phase(&data.forecast.forecastday.first()?.astro)
+}
+
+fn moon_phase(astro: &AstroData) -> Option<MoonPhase> {
+    let phase = LunarPhase::from_name(astro.moon_phase.as_deref()?)?;
+    let percent = match astro.moon_illumination.as_ref()? {
+        serde_json::Value::Number(number) => number.as_f64()?,
+        serde_json::Value::String(text) => text.trim().parse().ok()?,
+        _ => return None,
+    };
+
+    Some(MoonPhase {
+        phase,
+        illumination: (percent / 100.0).clamp(0.0, 1.0) as f32,
+    })
+}
+
+pub fn build_alerts(data: &ApiResponse) -> Vec<WeatherAlert> {
+    data.alerts
+        .iter()
+        .flat_map(|alerts| &alerts.alert)
+        .map(build_alert)
+        .collect()
+}
+
+fn build_alert(alert: &AlertData) -> WeatherAlert {
+    let headline = if alert.headline.is_empty() {
+        alert.event.clone()
+    } else {
+        alert.headline.clone()
+    };
+
+    WeatherAlert {
+        headline,
+        severity: AlertSeverity::from_cap(&alert.severity),
+        description: alert.desc.trim().to_owned(),
+        effective: alert.effective.as_deref().and_then(parse_rfc3339),
+        expires: alert.expires.as_deref().and_then(parse_rfc3339),
+    }
+}
+
+fn parse_rfc3339(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|datetime| datetime.with_timezone(&Utc))
+}
+
 fn parse_date(s: &str) -> Result<NaiveDate> {
     NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|err| Error::parse(PROVIDER, err.to_string()))
 }
@@ -189,6 +242,81 @@ mod tests {
 
     use super::*;
 
+    fn alert(headline: &str, severity: &str) -> AlertData {
+        AlertData {
+            headline: headline.to_owned(),
+            event: String::from("Tornado Warning"),
+            severity: severity.to_owned(),
+            desc: String::from("  Take shelter now.\n"),
+            effective: Some(String::from("2024-05-01T18:30:00-05:00")),
+            expires: Some(String::from("not a timestamp")),
+        }
+    }
+
+    fn astro(moon_phase: Option<&str>, moon_illumination: serde_json::Value) -> AstroData {
+        AstroData {
+            sunrise: String::from("06:12 AM"),
+            sunset: String::from("07:45 PM"),
+            moon_phase: moon_phase.map(String::from),
+            moon_illumination: Some(moon_illumination),
+        }
+    }
+
+    #[test]
+    fn moon_phase_reads_name_and_illumination() {
+        let expected = Some(MoonPhase {
+            phase: LunarPhase::WaxingGibbous,
+            illumination: 0.71,
+        });
+
+        assert_eq!(
+            moon_phase(&astro(Some("Waxing Gibbous"), serde_json::json!(71))),
+            expected
+        );
+        assert_eq!(
+            moon_phase(&astro(Some("Waxing Gibbous"), serde_json::json!("71"))),
+            expected
+        );
+    }
+
+    #[test]
+    fn moon_phase_needs_known_name_and_illumination() {
+        assert_eq!(moon_phase(&astro(None, serde_json::json!(71))), None);
+        assert_eq!(
+            moon_phase(&astro(Some("Blue Moon"), serde_json::json!(71))),
+            None
+        );
+        assert_eq!(
+            moon_phase(&astro(Some("Full Moon"), serde_json::Value::Null)),
+            None
+        );
+    }
+
+    #[test]
+    fn build_alert_maps_fields() {
+        let alert = build_alert(&alert(
+            "Tornado Warning issued for Travis County",
+            "Extreme",
+        ));
+
+        assert_eq!(alert.headline, "Tornado Warning issued for Travis County");
+        assert_eq!(alert.severity, AlertSeverity::Extreme);
+        assert_eq!(alert.description, "Take shelter now.");
+        assert_eq!(
+            alert.effective.map(|time| time.to_rfc3339()).as_deref(),
+            Some("2024-05-01T23:30:00+00:00")
+        );
+        assert_eq!(alert.expires, None);
+    }
+
+    #[test]
+    fn build_alert_falls_back_to_event_name() {
+        let alert = build_alert(&alert("", ""));
+
+        assert_eq!(alert.headline, "Tornado Warning");
+        assert_eq!(alert.severity, AlertSeverity::Unknown);
+    }
+
     #[test]
     fn parse_date_valid() {
         let result = parse_date("2024-01-15");
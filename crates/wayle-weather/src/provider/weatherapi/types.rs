@@ -7,6 +7,8 @@ pub struct ApiResponse {
     pub location: LocationData,
     pub current: CurrentData,
     pub forecast: ForecastWrapper,
+    #[serde(default)]
+    pub alerts: Option<AlertsWrapper>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -75,6 +77,12 @@ pub struct DayData {
 pub struct AstroData {
     pub sunrise: String,
     pub sunset: String,
+    #[serde(default)]
+    pub moon_phase: Option<String>,
+    /// Percentage, sent as a number or a numeric string depending on the
+    /// API version.
+    #[serde(default)]
+    pub moon_illumination: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -96,3 +104,23 @@ pub struct HourData {
     pub dewpoint_c: f64,
     pub chance_of_rain: f64,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct AlertsWrapper {
+    #[serde(default)]
+    pub alert: Vec<AlertData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlertData {
+    #[serde(default)]
+    pub headline: String,
+    #[serde(default)]
+    pub event: String,
+    #[serde(default)]
+    pub severity: String,
+    #[serde(default)]
+    pub desc: String,
+    pub effective: Option<String>,
+    pub expires: Option<String>,
+}
@@ -5,13 +5,14 @@ use std::{
 
 use tokio_util::sync::CancellationToken;
 use tracing::debug;
-use wayle_core::Property;
+use wayle_core::{ComputedProperty, Property};
 
 use crate::{
     builder::WeatherServiceBuilder,
-    error::Error,
-    model::{LocationQuery, TemperatureUnit, Weather, WeatherProviderKind},
+    error::{Error, Result},
+    model::{AlertSeverity, LocationQuery, TemperatureUnit, Weather, WeatherProviderKind},
     polling::{self, PollingConfig},
+    provider::{BaseUrls, ForecastRange, parse_base_url},
 };
 
 /// Categorized error for UI display without implementation details.
@@ -49,6 +50,7 @@ impl From<&Error> for WeatherErrorKind {
             Error::ProviderStatus { .. }
             | Error::Parse { .. }
             | Error::InvalidLocation { .. }
+            | Error::InvalidBaseUrl { .. }
             | Error::NotAvailable => Self::Other,
         }
     }
@@ -86,6 +88,9 @@ pub struct WeatherService {
     pub(crate) units: RwLock<TemperatureUnit>,
     pub(crate) visual_crossing_key: RwLock<Option<String>>,
     pub(crate) weatherapi_key: RwLock<Option<String>>,
+    pub(crate) include_air_quality: RwLock<bool>,
+    pub(crate) forecast_range: ForecastRange,
+    pub(crate) base_urls: RwLock<BaseUrls>,
 
     /// Current weather data. `None` until first successful fetch.
     pub weather: Property<Option<Arc<Weather>>>,
@@ -100,6 +105,19 @@ impl WeatherService {
         WeatherServiceBuilder::new()
     }
 
+    /// Highest severity among the current alerts, `None` when no alerts are
+    /// active or no data has been fetched yet.
+    ///
+    /// Follows [`weather`](Self::weather), so a bar module can watch it to
+    /// badge itself while a severe alert is in effect.
+    pub fn alert_severity(&self) -> ComputedProperty<Option<AlertSeverity>> {
+        self.weather.map(|weather| {
+            weather
+                .as_ref()
+                .and_then(|weather| weather.max_alert_severity())
+        })
+    }
+
     /// Updates the polling interval.
     pub fn set_poll_interval(&self, interval: Duration) {
         debug!(?interval, "Updating weather polling interval");
@@ -157,6 +175,37 @@ impl WeatherService {
         self.restart_polling();
     }
 
+    /// Enables or disables fetching air quality.
+    pub fn set_include_air_quality(&self, enabled: bool) {
+        debug!(enabled, "Updating air quality fetching");
+        if let Ok(mut guard) = self.include_air_quality.write() {
+            *guard = enabled;
+        }
+        self.restart_polling();
+    }
+
+    /// Updates the base URL used for `kind`, or restores its public host
+    /// with `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidBaseUrl` if `url` is not an absolute http(s)
+    /// URL without a query or fragment. The current URL is kept.
+    pub fn set_provider_base_url(
+        &self,
+        kind: WeatherProviderKind,
+        url: Option<&str>,
+    ) -> Result<()> {
+        let url = url.map(parse_base_url).transpose()?;
+
+        debug!(?kind, ?url, "Updating weather provider base URL");
+        if let Ok(mut guard) = self.base_urls.write() {
+            guard.set(kind, url);
+        }
+        self.restart_polling();
+        Ok(())
+    }
+
     fn restart_polling(&self) {
         self.status.set(WeatherStatus::Loading);
 
@@ -186,6 +235,17 @@ impl WeatherService {
                 .read()
                 .ok()
                 .and_then(|guard| guard.clone()),
+            include_air_quality: self
+                .include_air_quality
+                .read()
+                .map(|guard| *guard)
+                .unwrap_or_default(),
+            forecast_range: self.forecast_range,
+            base_urls: self
+                .base_urls
+                .read()
+                .map(|guard| guard.clone())
+                .unwrap_or_default(),
         };
 
         let new_token = self.cancellation_token.child_token();
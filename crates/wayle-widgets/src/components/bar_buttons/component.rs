@@ -17,7 +17,7 @@ use super::{
     },
     watchers::{spawn_icon_position_watcher, spawn_variant_watcher},
 };
-use crate::{styling::InlineStyling, utils::force_window_resize};
+use crate::{icons::ensure_icon, styling::InlineStyling, utils::force_window_resize};
 
 /// Initialization data for BarButton.
 #[derive(Debug, Clone)]
@@ -43,6 +43,8 @@ pub enum BarButtonInput {
     SetIcon(String),
     /// Update the label.
     SetLabel(String),
+    /// Parse the label as Pango markup instead of plain text.
+    SetUseMarkup(bool),
     /// Update the tooltip.
     SetTooltip(Option<String>),
     /// Lock label width to prevent resize while a popover is open.
@@ -66,6 +68,7 @@ pub enum BarButtonCmd {
 pub struct BarButton {
     icon: String,
     label: String,
+    use_markup: bool,
     tooltip: Option<String>,
     size_frozen: bool,
     pending_label: Option<String>,
@@ -150,6 +153,9 @@ impl Component for BarButton {
                         #[watch]
                         set_hexpand: model.settings.is_vertical.get(),
 
+                        #[watch]
+                        set_use_markup: model.use_markup,
+
                         #[watch]
                         set_label: &model.label,
 
@@ -171,10 +177,12 @@ impl Component for BarButton {
     ) -> ComponentParts<Self> {
         let css_provider = gtk::CssProvider::new();
         let scroll_sensitivity = init.settings.scroll_sensitivity;
+        ensure_icon(&init.icon);
 
         let model = BarButton {
             icon: init.icon,
             label: init.label,
+            use_markup: false,
             tooltip: init.tooltip,
             size_frozen: false,
             pending_label: None,
@@ -209,7 +217,10 @@ impl Component for BarButton {
 
     fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, _root: &Self::Root) {
         match msg {
-            BarButtonInput::SetIcon(icon) => self.icon = icon,
+            BarButtonInput::SetIcon(icon) => {
+                ensure_icon(&icon);
+                self.icon = icon;
+            }
             BarButtonInput::SetLabel(label) => {
                 if self.size_frozen {
                     self.pending_label = Some(label);
@@ -217,6 +228,7 @@ impl Component for BarButton {
                     self.label = label;
                 }
             }
+            BarButtonInput::SetUseMarkup(use_markup) => self.use_markup = use_markup,
             BarButtonInput::SetTooltip(tooltip) => self.tooltip = tooltip,
             BarButtonInput::FreezeSize => {
                 self.size_frozen = true;
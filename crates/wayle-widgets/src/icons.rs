@@ -3,9 +3,13 @@
 use std::cell::OnceCell;
 
 use gtk4::{IconTheme, gdk::Display};
+use tracing::debug;
+
+type MissingIconHandler = Box<dyn Fn(&str) -> bool>;
 
 thread_local! {
     static ICON_THEME: OnceCell<IconTheme> = const { OnceCell::new() };
+    static MISSING_ICON_HANDLER: OnceCell<MissingIconHandler> = const { OnceCell::new() };
 }
 
 #[allow(clippy::expect_used)]
@@ -19,7 +23,28 @@ fn with_icon_theme<R>(f: impl FnOnce(&IconTheme) -> R) -> R {
     })
 }
 
+/// Sets the handler [`icon_exists`] calls when the theme lacks an icon.
+///
+/// The handler returns `true` if it made the icon available, such as by
+/// installing a bundled copy. Only the first handler set is kept.
+pub fn set_missing_icon_handler(handler: impl Fn(&str) -> bool + 'static) {
+    MISSING_ICON_HANDLER.with(|cell| {
+        let _ = cell.set(Box::new(handler));
+    });
+}
+
 /// Checks if an icon exists in the current icon theme.
+///
+/// On a miss, gives the [missing icon handler](set_missing_icon_handler)
+/// a chance to provide it.
 pub fn icon_exists(name: &str) -> bool {
     with_icon_theme(|theme| theme.has_icon(name))
+        || MISSING_ICON_HANDLER.with(|cell| cell.get().is_some_and(|handler| handler(name)))
+}
+
+/// Looks up `name` before it's shown so a missing icon can be provided.
+pub fn ensure_icon(name: &str) {
+    if !icon_exists(name) {
+        debug!(icon = name, "icon not found in theme");
+    }
 }
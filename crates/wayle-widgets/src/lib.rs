@@ -24,7 +24,7 @@ pub mod styling;
 pub mod utils;
 pub mod watchers;
 
-pub use watchers::WatcherToken;
+pub use watchers::{WatcherGroup, WatcherToken};
 
 /// Convenient re-exports of all widget templates and class constants.
 pub mod prelude {
@@ -33,8 +33,8 @@ pub mod prelude {
         primitives::{
             alert::*, badge::*, buttons::*, card::*, checkbox::*, confirm_modal::*, dropdown::*,
             empty_state::*, password_input::*, popover::*, progress_bar::*, progress_ring::*,
-            radio_group::*, separator::*, slider::*, spinner::*, status_dot::*, switch::*,
-            text_input::*,
+            radio_group::*, reactive_label::*, rich_tooltip::*, separator::*, slider::*,
+            spinner::*, status_dot::*, switch::*, text_input::*,
         },
         styling::{InlineStyling, resolve_color},
         utils::force_window_resize,
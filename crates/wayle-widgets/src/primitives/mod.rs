@@ -13,6 +13,8 @@ pub mod popover;
 pub mod progress_bar;
 pub mod progress_ring;
 pub mod radio_group;
+pub mod reactive_label;
+pub mod rich_tooltip;
 pub mod separator;
 pub mod slider;
 pub mod spinner;
@@ -70,6 +70,8 @@ pub struct ProgressRingInit {
     pub size: Size,
     /// Color variant for semantic meaning.
     pub color: ColorVariant,
+    /// Icon shown in the center, above the label.
+    pub icon: Option<String>,
 }
 
 /// Messages for updating the progress ring state.
@@ -81,12 +83,16 @@ pub enum ProgressRingMsg {
     SetLabel(String),
     /// Updates the color variant.
     SetColor(ColorVariant),
+    /// Shows an icon in the center, or hides it with `None`.
+    SetIcon(Option<String>),
 }
 
-/// Circular progress ring with Cairo-drawn arcs and optional center label.
+/// Circular progress ring with Cairo-drawn arcs and optional center icon
+/// and label.
 pub struct ProgressRing {
     fraction: Rc<Cell<f64>>,
     label_text: String,
+    icon: Option<String>,
     current_color: ColorVariant,
     drawing_area: gtk::DrawingArea,
 }
@@ -109,12 +115,27 @@ impl SimpleComponent for ProgressRing {
                 set_vexpand: true,
             },
 
-            add_overlay = &gtk::Label {
+            add_overlay = &gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
                 set_halign: gtk::Align::Center,
                 set_valign: gtk::Align::Center,
-                add_css_class: "ring-text",
-                #[watch]
-                set_label: &model.label_text,
+                add_css_class: "ring-center",
+
+                gtk::Image {
+                    add_css_class: "ring-icon",
+                    #[watch]
+                    set_icon_name: model.icon.as_deref(),
+                    #[watch]
+                    set_visible: model.icon.is_some(),
+                },
+
+                gtk::Label {
+                    add_css_class: "ring-text",
+                    #[watch]
+                    set_label: &model.label_text,
+                    #[watch]
+                    set_visible: !model.label_text.is_empty(),
+                },
             },
         }
     }
@@ -137,6 +158,7 @@ impl SimpleComponent for ProgressRing {
         let model = ProgressRing {
             fraction,
             label_text: String::new(),
+            icon: init.icon,
             current_color: init.color,
             drawing_area: drawing_area.clone(),
         };
@@ -163,6 +185,9 @@ impl SimpleComponent for ProgressRing {
             ProgressRingMsg::SetLabel(text) => {
                 self.label_text = text;
             }
+            ProgressRingMsg::SetIcon(icon) => {
+                self.icon = icon;
+            }
             ProgressRingMsg::SetColor(new_color) => {
                 if new_color == self.current_color {
                     return;
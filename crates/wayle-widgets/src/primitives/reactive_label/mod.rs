@@ -0,0 +1,148 @@
+//! Label component whose text and tooltip follow reactive properties.
+#![allow(missing_docs)]
+
+use gtk::prelude::*;
+use relm4::prelude::*;
+use wayle_core::ComputedProperty;
+
+use crate::{WatcherToken, watch_cancellable};
+
+/// Sources for a [`ReactiveLabel`].
+///
+/// Accepts a `Property<String>` or a `ComputedProperty<String>` for both
+/// the text and the optional tooltip.
+#[derive(Debug, Clone)]
+pub struct ReactiveLabelInit {
+    /// Source for the label text.
+    pub label: ComputedProperty<String>,
+    /// Source for the tooltip text. No tooltip when `None`.
+    pub tooltip: Option<ComputedProperty<String>>,
+}
+
+impl ReactiveLabelInit {
+    /// Label bound to `label`, without a tooltip.
+    pub fn new(label: impl Into<ComputedProperty<String>>) -> Self {
+        Self {
+            label: label.into(),
+            tooltip: None,
+        }
+    }
+
+    /// Also binds the tooltip text to `tooltip`.
+    #[must_use]
+    pub fn with_tooltip(mut self, tooltip: impl Into<ComputedProperty<String>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+}
+
+/// Input messages for rebinding a [`ReactiveLabel`].
+#[derive(Debug)]
+pub enum ReactiveLabelMsg {
+    /// Follow a different text source, dropping the previous one.
+    BindLabel(ComputedProperty<String>),
+    /// Follow a different tooltip source, or clear the tooltip with `None`.
+    BindTooltip(Option<ComputedProperty<String>>),
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum ReactiveLabelCmd {
+    LabelChanged(String),
+    TooltipChanged(String),
+}
+
+/// Label that keeps its text (and optionally tooltip) in sync with a property.
+///
+/// Watchers stop when the controller is dropped or a source is rebound.
+pub struct ReactiveLabel {
+    label: String,
+    tooltip: Option<String>,
+    label_watcher: WatcherToken,
+    tooltip_watcher: WatcherToken,
+}
+
+#[relm4::component(pub)]
+impl Component for ReactiveLabel {
+    type Init = ReactiveLabelInit;
+    type Input = ReactiveLabelMsg;
+    type Output = ();
+    type CommandOutput = ReactiveLabelCmd;
+
+    view! {
+        gtk::Label {
+            #[watch]
+            set_label: &model.label,
+            #[watch]
+            set_tooltip_text: model.tooltip.as_deref(),
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let mut model = Self {
+            label: String::new(),
+            tooltip: None,
+            label_watcher: WatcherToken::new(),
+            tooltip_watcher: WatcherToken::new(),
+        };
+
+        model.bind_label(&sender, init.label);
+        model.bind_tooltip(&sender, init.tooltip);
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, _root: &Self::Root) {
+        match msg {
+            ReactiveLabelMsg::BindLabel(label) => self.bind_label(&sender, label),
+            ReactiveLabelMsg::BindTooltip(tooltip) => self.bind_tooltip(&sender, tooltip),
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        msg: Self::CommandOutput,
+        _sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        match msg {
+            ReactiveLabelCmd::LabelChanged(label) => self.label = label,
+            ReactiveLabelCmd::TooltipChanged(tooltip) => self.tooltip = Some(tooltip),
+        }
+    }
+}
+
+impl ReactiveLabel {
+    fn bind_label(&mut self, sender: &ComponentSender<Self>, label: ComputedProperty<String>) {
+        self.label = label.get();
+
+        let token = self.label_watcher.reset();
+        watch_cancellable!(sender, token, [label.watch()], |out| {
+            let _ = out.send(ReactiveLabelCmd::LabelChanged(label.get()));
+        });
+    }
+
+    fn bind_tooltip(
+        &mut self,
+        sender: &ComponentSender<Self>,
+        tooltip: Option<ComputedProperty<String>>,
+    ) {
+        let token = self.tooltip_watcher.reset();
+
+        let Some(tooltip) = tooltip else {
+            self.tooltip = None;
+            return;
+        };
+        self.tooltip = Some(tooltip.get());
+
+        watch_cancellable!(sender, token, [tooltip.watch()], |out| {
+            let _ = out.send(ReactiveLabelCmd::TooltipChanged(tooltip.get()));
+        });
+    }
+}
@@ -0,0 +1,173 @@
+//! Detail tooltip whose icon, title and rows follow a reactive source.
+
+use futures::StreamExt;
+use gtk4::prelude::*;
+use relm4::gtk::{self, glib};
+use wayle_core::ComputedProperty;
+
+/// What a [`RichTooltip`] shows: an optional icon and title, followed by
+/// label/value rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichTooltipContent {
+    /// Icon name shown before the title.
+    pub icon: Option<String>,
+    /// Heading line.
+    pub title: String,
+    /// Label/value pairs, laid out as two aligned columns.
+    pub rows: Vec<(String, String)>,
+}
+
+impl RichTooltipContent {
+    /// Content with just a title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Shows `icon` before the title.
+    #[must_use]
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Appends a `label: value` row.
+    #[must_use]
+    pub fn with_row(mut self, label: impl Into<String>, value: impl Into<String>) -> Self {
+        self.rows.push((label.into(), value.into()));
+        self
+    }
+
+    /// True when there is neither a title nor any row, in which case no
+    /// tooltip is shown.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_empty() && self.rows.is_empty()
+    }
+}
+
+/// Keeps a widget's tooltip in sync with a [`RichTooltipContent`] source.
+///
+/// GTK only builds a tooltip when the pointer enters the widget, so a plain
+/// `set_tooltip_*` call made while it is shown goes unnoticed. This builds
+/// the tooltip from the source's latest value on every query and asks GTK
+/// to query again whenever the source changes, so an open tooltip updates
+/// in place.
+///
+/// Dropping the binding stops following the source and removes the tooltip.
+#[derive(Debug)]
+#[must_use = "the tooltip is removed when the binding is dropped"]
+pub struct RichTooltip {
+    widget: glib::WeakRef<gtk::Widget>,
+    handler: Option<glib::SignalHandlerId>,
+    watcher: glib::JoinHandle<()>,
+}
+
+impl RichTooltip {
+    /// Attaches a tooltip built from `source` to `widget`.
+    ///
+    /// Accepts a `Property<RichTooltipContent>` or any
+    /// `ComputedProperty<RichTooltipContent>`, e.g. one derived with
+    /// `Property::map` or `Property::combine`.
+    pub fn bind(
+        widget: &impl IsA<gtk::Widget>,
+        source: impl Into<ComputedProperty<RichTooltipContent>>,
+    ) -> Self {
+        let widget = widget.as_ref();
+        let source = source.into();
+
+        widget.set_has_tooltip(true);
+
+        let handler = widget.connect_query_tooltip({
+            let source = source.clone();
+            move |_, _, _, _, tooltip| {
+                let content = source.get();
+                if content.is_empty() {
+                    return false;
+                }
+
+                tooltip.set_custom(Some(&build_content(&content)));
+                true
+            }
+        });
+
+        let weak_widget = widget.downgrade();
+        let watcher = glib::spawn_future_local({
+            let weak_widget = weak_widget.clone();
+            async move {
+                let mut changes = source.watch();
+                while changes.next().await.is_some() {
+                    let Some(widget) = weak_widget.upgrade() else {
+                        return;
+                    };
+                    widget.trigger_tooltip_query();
+                }
+            }
+        });
+
+        Self {
+            widget: weak_widget,
+            handler: Some(handler),
+            watcher,
+        }
+    }
+}
+
+impl Drop for RichTooltip {
+    fn drop(&mut self) {
+        self.watcher.abort();
+
+        if let (Some(widget), Some(handler)) = (self.widget.upgrade(), self.handler.take()) {
+            widget.disconnect(handler);
+        }
+    }
+}
+
+fn build_content(content: &RichTooltipContent) -> gtk::Box {
+    let container = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    container.add_css_class("rich-tooltip");
+
+    if content.icon.is_some() || !content.title.is_empty() {
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+        header.add_css_class("rich-tooltip-header");
+
+        if let Some(icon) = &content.icon {
+            let image = gtk::Image::from_icon_name(icon);
+            image.add_css_class("rich-tooltip-icon");
+            header.append(&image);
+        }
+
+        if !content.title.is_empty() {
+            let title = gtk::Label::new(Some(&content.title));
+            title.add_css_class("rich-tooltip-title");
+            title.set_xalign(0.0);
+            header.append(&title);
+        }
+
+        container.append(&header);
+    }
+
+    if !content.rows.is_empty() {
+        let grid = gtk::Grid::new();
+        grid.add_css_class("rich-tooltip-rows");
+
+        for (row, (label, value)) in (0..).zip(&content.rows) {
+            let label = gtk::Label::new(Some(label));
+            label.add_css_class("rich-tooltip-label");
+            label.set_xalign(0.0);
+
+            let value = gtk::Label::new(Some(value));
+            value.add_css_class("rich-tooltip-value");
+            value.set_xalign(1.0);
+            value.set_hexpand(true);
+
+            grid.attach(&label, 0, row, 1, 1);
+            grid.attach(&value, 1, row, 1, 1);
+        }
+
+        container.append(&grid);
+    }
+
+    container
+}
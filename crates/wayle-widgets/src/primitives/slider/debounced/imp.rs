@@ -10,6 +10,9 @@ use gtk4::{gdk, glib, prelude::*, subclass::prelude::*};
 const THROTTLE_INTERVAL: Duration = Duration::from_millis(100);
 const GRACE_PERIOD: Duration = Duration::from_millis(150);
 
+/// Page Up/Down moves this many steps; arrow keys move one.
+const PAGE_STEPS: f64 = 10.0;
+
 type LabelFormatter = Box<dyn Fn(f64) -> String>;
 
 #[derive(Properties)]
@@ -18,12 +21,18 @@ pub struct DebouncedSliderImp {
     #[property(get, set = Self::set_value_external, explicit_notify)]
     value: Cell<f64>,
 
-    #[property(get, set)]
+    #[property(get, set = Self::set_range_min)]
     range_min: Cell<f64>,
 
-    #[property(get, set)]
+    #[property(get, set = Self::set_range_max)]
     range_max: Cell<f64>,
 
+    #[property(get, set = Self::set_step)]
+    step: Cell<f64>,
+
+    #[property(get, set = Self::set_muted)]
+    muted: Cell<bool>,
+
     #[property(get, set = Self::set_show_label)]
     show_label: Cell<bool>,
 
@@ -45,6 +54,8 @@ impl Default for DebouncedSliderImp {
             value: Cell::new(0.0),
             range_min: Cell::new(0.0),
             range_max: Cell::new(100.0),
+            step: Cell::new(1.0),
+            muted: Cell::new(false),
             show_label: Cell::new(false),
             scale: OnceCell::new(),
             label: OnceCell::new(),
@@ -93,6 +104,50 @@ impl WidgetImpl for DebouncedSliderImp {}
 impl BoxImpl for DebouncedSliderImp {}
 
 impl DebouncedSliderImp {
+    fn set_range_min(&self, min: f64) {
+        self.range_min.set(min);
+        self.apply_range();
+    }
+
+    fn set_range_max(&self, max: f64) {
+        self.range_max.set(max);
+        self.apply_range();
+    }
+
+    fn apply_range(&self) {
+        if let Some(scale) = self.scale.get() {
+            self.setting_programmatically.set(true);
+            scale.set_range(self.range_min.get(), self.range_max.get());
+            self.value.set(scale.value());
+            self.update_label(scale.value());
+            self.setting_programmatically.set(false);
+        }
+        self.update_muted_class();
+    }
+
+    fn set_step(&self, step: f64) {
+        self.step.set(step);
+        if let Some(scale) = self.scale.get() {
+            scale.set_increments(step, step * PAGE_STEPS);
+        }
+    }
+
+    fn set_muted(&self, muted: bool) {
+        self.muted.set(muted);
+        self.update_muted_class();
+    }
+
+    /// Muted explicitly, or sitting at the bottom of the range.
+    fn update_muted_class(&self) {
+        let silent = self.muted.get() || self.value.get() <= self.range_min.get();
+        let obj = self.obj();
+        if silent {
+            obj.add_css_class("muted");
+        } else {
+            obj.remove_css_class("muted");
+        }
+    }
+
     fn set_show_label(&self, visible: bool) {
         self.show_label.set(visible);
         if let Some(label) = self.label.get() {
@@ -115,6 +170,7 @@ impl DebouncedSliderImp {
             scale.set_value(value);
         }
         self.update_label(value);
+        self.update_muted_class();
 
         self.setting_programmatically.set(false);
     }
@@ -155,6 +211,7 @@ impl DebouncedSliderImp {
             .build();
         scale.set_cursor_from_name(Some("pointer"));
         scale.set_range(min, max);
+        scale.set_increments(self.step.get(), self.step.get() * PAGE_STEPS);
         scale.set_value(val);
 
         let label = gtk4::Label::builder()
@@ -164,11 +221,13 @@ impl DebouncedSliderImp {
             .visible(self.show_label.get())
             .build();
 
+        self.obj().add_css_class("debounced-slider");
         self.obj().append(&scale);
         self.obj().append(&label);
 
         let _ = self.scale.set(scale);
         let _ = self.label.set(label);
+        self.update_muted_class();
     }
 
     fn connect_signals(&self) {
@@ -190,6 +249,7 @@ impl DebouncedSliderImp {
             let value = scale.value();
             imp.value.set(value);
             imp.update_label(value);
+            imp.update_muted_class();
             imp.throttle_commit(value);
         });
 
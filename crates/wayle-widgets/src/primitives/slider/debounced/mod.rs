@@ -4,7 +4,7 @@
 mod imp;
 
 use glib::subclass::types::ObjectSubclassIsExt;
-use gtk4::glib;
+use gtk4::{glib, prelude::*};
 
 glib::wrapper! {
     /// Slider combining a Scale and optional label that updates at full GTK frame rate.
@@ -12,6 +12,9 @@ glib::wrapper! {
     /// Emits `committed` signal after 100ms of inactivity for backend writes.
     /// Programmatic `set_value()` calls are ignored while the user is dragging
     /// or within a grace period after drag ends.
+    ///
+    /// Arrow keys move by `step`, Page Up/Down by ten steps. The widget gets
+    /// the `muted` CSS class while `muted` is set or the value is at `range-min`.
     pub struct DebouncedSlider(ObjectSubclass<imp::DebouncedSliderImp>)
         @extends gtk4::Box, gtk4::Widget,
         @implements gtk4::Accessible, gtk4::Buildable, gtk4::ConstraintTarget, gtk4::Orientable;
@@ -31,6 +34,23 @@ impl DebouncedSlider {
             .build()
     }
 
+    /// Sets the value bounds and the keyboard step in one call.
+    pub fn set_range(&self, min: f64, max: f64, step: f64) {
+        self.set_range_min(min);
+        self.set_range_max(max);
+        self.set_step(step);
+    }
+
+    /// Runs `f` with the value each time the `committed` signal fires.
+    pub fn connect_committed(&self, f: impl Fn(&Self, f64) + 'static) -> glib::SignalHandlerId {
+        self.connect_local("committed", false, move |values| {
+            let slider = values[0].get::<Self>().ok()?;
+            let value = values[1].get::<f64>().ok()?;
+            f(&slider, value);
+            None
+        })
+    }
+
     /// Sets a custom label formatter. Defaults to `"{value:.0}%"`.
     pub fn set_formatter(&self, formatter: impl Fn(f64) -> String + 'static) {
         if let Ok(mut guard) = self.imp().formatter.try_borrow_mut() {
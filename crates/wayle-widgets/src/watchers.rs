@@ -5,10 +5,14 @@
 //!
 //! For watchers that need to be cancelled before component shutdown (e.g., when
 //! a device changes), use [`watch_cancellable!`] with a [`CancellationToken`].
+//! [`WatcherToken`] and [`WatcherGroup`] manage those tokens.
 //!
 //! [`CancellationToken`]: tokio_util::sync::CancellationToken
 
-use std::pin::Pin;
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
 
 use futures::stream::Stream;
 use tokio::sync::mpsc;
@@ -34,12 +38,15 @@ use wayle_config::SubscribeChanges;
 /// Self::spawn_device_watchers(&sender, &device, token);
 /// ```
 #[derive(Debug, Default)]
-pub struct WatcherToken(Option<CancellationToken>);
+pub struct WatcherToken {
+    current: Option<CancellationToken>,
+    group: Option<Arc<Mutex<CancellationToken>>>,
+}
 
 impl WatcherToken {
     /// Creates an empty watcher token with no active watcher.
     pub fn new() -> Self {
-        Self(None)
+        Self::default()
     }
 
     /// Cancels any existing watcher and returns a fresh token.
@@ -47,23 +54,86 @@ impl WatcherToken {
     /// The returned token should be passed to `watch_cancellable!` or used
     /// directly with `token.cancelled()` in a `tokio::select!`.
     pub fn reset(&mut self) -> CancellationToken {
-        if let Some(token) = self.0.take() {
+        if let Some(token) = self.current.take() {
             token.cancel();
         }
-        let token = CancellationToken::new();
-        self.0 = Some(token.clone());
+        let token = match &self.group {
+            Some(group) => lock(group).child_token(),
+            None => CancellationToken::new(),
+        };
+        self.current = Some(token.clone());
         token
     }
 }
 
 impl Drop for WatcherToken {
     fn drop(&mut self) {
-        if let Some(token) = self.0.take() {
+        if let Some(token) = self.current.take() {
             token.cancel();
         }
     }
 }
 
+/// Cancels a set of [`WatcherToken`]s together.
+///
+/// Tokens handed out by [`child_token`](Self::child_token) still reset on
+/// their own, but [`cancel_all`](Self::cancel_all) or dropping the group
+/// stops every watcher they spawned. A component keeps one group instead of
+/// wiring each token into its cleanup separately.
+///
+/// # Example
+///
+/// ```ignore
+/// struct MyComponent {
+///     watchers: WatcherGroup,
+///     device_watcher: WatcherToken,
+///     streams_watcher: WatcherToken,
+/// }
+///
+/// let watchers = WatcherGroup::new();
+/// let device_watcher = watchers.child_token();
+/// let streams_watcher = watchers.child_token();
+///
+/// // When the service goes away:
+/// self.watchers.cancel_all();
+/// ```
+#[derive(Debug, Default)]
+pub struct WatcherGroup(Arc<Mutex<CancellationToken>>);
+
+impl WatcherGroup {
+    /// Creates a group with no watchers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an empty [`WatcherToken`] whose watchers belong to this group.
+    pub fn child_token(&self) -> WatcherToken {
+        WatcherToken {
+            current: None,
+            group: Some(Arc::clone(&self.0)),
+        }
+    }
+
+    /// Cancels every watcher spawned from this group's tokens.
+    ///
+    /// The group stays usable: tokens reset afterwards start new watchers.
+    pub fn cancel_all(&self) {
+        let mut group = lock(&self.0);
+        group.cancel();
+        *group = CancellationToken::new();
+    }
+}
+
+impl Drop for WatcherGroup {
+    fn drop(&mut self) {
+        lock(&self.0).cancel();
+    }
+}
+
+fn lock(group: &Mutex<CancellationToken>) -> MutexGuard<'_, CancellationToken> {
+    group.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 /// Converts a [`SubscribeChanges`] implementor into a stream.
 ///
 /// Bridges the channel-based `subscribe_changes` API with the stream-based
@@ -386,3 +456,55 @@ macro_rules! watch_async {
         });
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_cancels_previous_token() {
+        let mut watcher = WatcherToken::new();
+        let first = watcher.reset();
+        let second = watcher.reset();
+
+        assert!(first.is_cancelled());
+        assert!(!second.is_cancelled());
+    }
+
+    #[test]
+    fn cancel_all_cancels_every_child() {
+        let group = WatcherGroup::new();
+        let mut a = group.child_token();
+        let mut b = group.child_token();
+        let token_a = a.reset();
+        let token_b = b.reset();
+
+        group.cancel_all();
+
+        assert!(token_a.is_cancelled());
+        assert!(token_b.is_cancelled());
+    }
+
+    #[test]
+    fn children_reset_after_cancel_all_are_live() {
+        let group = WatcherGroup::new();
+        let mut watcher = group.child_token();
+        watcher.reset();
+
+        group.cancel_all();
+        let token = watcher.reset();
+
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn dropping_group_cancels_children() {
+        let group = WatcherGroup::new();
+        let mut watcher = group.child_token();
+        let token = watcher.reset();
+
+        drop(group);
+
+        assert!(token.is_cancelled());
+    }
+}
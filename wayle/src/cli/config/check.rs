@@ -0,0 +1,36 @@
+use wayle_config::{Config, ConfigPaths, Diagnostic};
+
+use crate::cli::CliAction;
+
+/// Checks `config.toml` and its imports for keys Wayle doesn't recognize.
+///
+/// Unknown keys are ignored when the config is applied, so a typo such as
+/// `[modules.clok]` otherwise goes unnoticed. Each one is printed with its
+/// dotted path.
+///
+/// # Errors
+///
+/// Returns error if the config cannot be loaded or has unknown keys.
+pub fn execute() -> CliAction {
+    let path = ConfigPaths::main_config();
+    let toml =
+        Config::load_toml_with_imports(&path).map_err(|e| format!("Failed to load config: {e}"))?;
+
+    let unknown = Config::default().unknown_keys(&toml);
+
+    for key in &unknown {
+        Diagnostic::warning("unknown config key")
+            .field("Key", key)
+            .field("File", path.display().to_string())
+            .hint("check the spelling against `wayle config default --stdout`")
+            .emit();
+    }
+
+    if !unknown.is_empty() {
+        return Err(format!("{} unknown key(s) in config", unknown.len()));
+    }
+
+    println!("No problems found in {}", path.display());
+
+    Ok(())
+}
@@ -32,4 +32,8 @@ pub enum ConfigCommands {
         #[arg(long)]
         stdout: bool,
     },
+    /// Print the effective configuration with the source of each value
+    Dump,
+    /// Report config keys that Wayle doesn't recognize
+    Check,
 }
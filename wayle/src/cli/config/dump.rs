@@ -0,0 +1,24 @@
+use crate::{cli::CliAction, config::ConfigService};
+
+/// Prints the effective configuration as TOML.
+///
+/// Shows the values Wayle actually uses after layering `config.toml`, its
+/// imports and `runtime.toml` over the defaults, with each non-default
+/// value commented with its source file.
+///
+/// # Errors
+///
+/// Returns error if config loading or serialization fails.
+pub async fn execute() -> CliAction {
+    let config_service = ConfigService::load()
+        .await
+        .map_err(|e| format!("Failed to load config: {e}"))?;
+
+    let toml = config_service
+        .export_effective()
+        .map_err(|e| format!("Failed to serialize config: {e}"))?;
+
+    print!("{toml}");
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
+/// Unknown key check command
+pub mod check;
 /// Configuration command definitions
 pub mod commands;
 /// Default configuration output command
 pub mod default;
+/// Effective configuration output command
+pub mod dump;
 /// Get configuration value command
 pub mod get;
 /// Reset configuration value command
@@ -26,5 +30,7 @@ pub async fn execute(command: ConfigCommands) -> CliAction {
         ConfigCommands::Reset { path } => reset::execute(path).await,
         ConfigCommands::Schema { stdout } => schema::execute(stdout),
         ConfigCommands::Default { stdout } => default::execute(stdout),
+        ConfigCommands::Dump => dump::execute().await,
+        ConfigCommands::Check => check::execute(),
     }
 }
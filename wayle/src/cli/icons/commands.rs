@@ -17,6 +17,10 @@ pub enum IconsCommands {
         /// Icon slugs to install (e.g., home settings bell)
         #[arg(required = true)]
         slugs: Vec<String>,
+        /// Keep the icons' own colors (brand colors for simple-icons)
+        /// instead of following the theme
+        #[arg(long)]
+        keep_colors: bool,
     },
     /// Import local SVG file(s) as icons
     #[command(after_long_help = IMPORT_HELP)]
@@ -50,6 +54,22 @@ pub enum IconsCommands {
         /// Destination directory for exported icons
         destination: PathBuf,
     },
+    /// Lint installed icons for markup that breaks GTK recoloring (heuristic)
+    #[command(after_long_help = LINT_HELP)]
+    Lint {
+        /// Icon names to lint (defaults to all installed icons)
+        names: Vec<String>,
+        /// Re-transform failing icons with strict normalization
+        #[arg(short, long)]
+        repair: bool,
+    },
+    /// Remove installed icons the current config no longer references
+    #[command(after_long_help = PRUNE_HELP)]
+    Prune {
+        /// List the icons that would be removed without deleting them
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 const INSTALL_HELP: &str = concat!(
@@ -61,6 +81,9 @@ const INSTALL_HELP: &str = concat!(
     "    wayle icons install simple-icons firefox spotify\n",
     "        -> si-firefox-symbolic, si-spotify-symbolic\n",
     "\n",
+    "    wayle icons install simple-icons spotify --keep-colors\n",
+    "        -> si-spotify (brand green, installs alongside si-spotify-symbolic)\n",
+    "\n",
     "Run 'wayle icons sources' to see all available icon sources.\n",
     "Icons are saved to ~/.local/share/wayle/icons/ as GTK symbolic icons.",
 );
@@ -76,3 +99,31 @@ const IMPORT_HELP: &str = concat!(
     "\n",
     "Icons without a known prefix (tb-, tbf-, si-, md-, ld-) get 'cm-' added.",
 );
+
+const LINT_HELP: &str = concat!(
+    styled_header!("Examples:"),
+    "\n",
+    "    wayle icons lint\n",
+    "        -> Lints every installed symbolic icon\n",
+    "\n",
+    "    wayle icons lint --repair tb-home-symbolic\n",
+    "        -> Re-transforms the icon if it fails\n",
+    "\n",
+    "Linting is a heuristic text check of the SVG markup, not a full\n",
+    "verification pass: nothing is rendered. It flags embedded styles, paths\n",
+    "without symbolic paint and filled full-canvas paths, but an icon that\n",
+    "passes can still render wrong.",
+);
+
+const PRUNE_HELP: &str = concat!(
+    styled_header!("Examples:"),
+    "\n",
+    "    wayle icons prune --dry-run\n",
+    "        -> Lists unused icons without deleting them\n",
+    "\n",
+    "    wayle icons prune\n",
+    "        -> Removes them and reports the space freed\n",
+    "\n",
+    "Only icons added with 'wayle icons install' or 'import' are considered.\n",
+    "Icons referenced anywhere in the config, and Wayle's bundled icons, are kept.",
+);
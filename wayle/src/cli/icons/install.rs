@@ -1,23 +1,28 @@
-use wayle_icons::{IconManager, sources};
+use wayle_icons::{IconManager, InstallOptions, sources};
 
 use crate::cli::CliAction;
 
 /// Installs icons from a CDN source.
 ///
+/// Icons are recolored to follow the theme unless `keep_colors` is set.
+///
 /// # Errors
 ///
 /// Returns error if:
 /// - Source name is invalid
 /// - Icon directory cannot be created
-pub async fn execute(source_name: String, slugs: Vec<String>) -> CliAction {
+pub async fn execute(source_name: String, slugs: Vec<String>, keep_colors: bool) -> CliAction {
     let source = sources::from_cli_name(&source_name).map_err(|err| err.to_string())?;
 
     let manager = IconManager::new().map_err(|err| err.to_string())?;
 
     let slug_refs: Vec<&str> = slugs.iter().map(String::as_str).collect();
+    let options = InstallOptions {
+        recolor: !keep_colors,
+    };
 
     let result = manager
-        .install(source.as_ref(), &slug_refs)
+        .install(source.as_ref(), &slug_refs, options)
         .await
         .map_err(|err| err.to_string())?;
 
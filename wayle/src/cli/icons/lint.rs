@@ -0,0 +1,66 @@
+use wayle_icons::IconManager;
+
+use crate::cli::CliAction;
+
+/// Lints installed icons for markup that stops GTK recoloring them.
+///
+/// Static checks only; icons aren't rendered, so a clean result is not a
+/// guarantee. Lints every installed symbolic icon when no names are given,
+/// skipping icons installed with their own colors. With `repair`, failing icons are
+/// re-transformed with strict normalization.
+///
+/// # Errors
+///
+/// Returns error if icon manager initialization fails or any icon still
+/// fails the lint.
+pub fn execute(names: Vec<String>, repair: bool) -> CliAction {
+    let manager = IconManager::new().map_err(|err| err.to_string())?;
+
+    let mut names = if names.is_empty() {
+        manager
+            .list()
+            .into_iter()
+            .filter(|name| name.ends_with("-symbolic"))
+            .collect()
+    } else {
+        names
+    };
+    names.sort();
+
+    let mut failed = 0;
+
+    for name in &names {
+        let Err(err) = manager.lint(name) else {
+            continue;
+        };
+
+        if !repair {
+            eprintln!("  {err}");
+            failed += 1;
+            continue;
+        }
+
+        match manager.repair(name) {
+            Ok(()) => println!("  Repaired: {name}"),
+            Err(err) => {
+                eprintln!("  {err}");
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        let hint = if repair {
+            ""
+        } else {
+            " (run with --repair to fix)"
+        };
+        return Err(format!(
+            "{failed} of {} icon(s) failed the lint{hint}",
+            names.len()
+        ));
+    }
+
+    println!("{} icon(s) passed the lint", names.len());
+    Ok(())
+}
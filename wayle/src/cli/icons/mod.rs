@@ -6,10 +6,14 @@ pub mod export;
 pub mod import;
 /// Install icons from CDN
 pub mod install;
+/// Lint installed icons
+pub mod lint;
 /// List installed icons
 pub mod list;
 /// Open icons directory
 pub mod open;
+/// Remove icons the config no longer uses
+pub mod prune;
 /// Remove installed icons
 pub mod remove;
 /// Install bundled icons
@@ -29,7 +33,11 @@ use super::CliAction;
 pub async fn execute(command: IconsCommands) -> CliAction {
     match command {
         IconsCommands::Setup => setup::execute(),
-        IconsCommands::Install { source, slugs } => install::execute(source, slugs).await,
+        IconsCommands::Install {
+            source,
+            slugs,
+            keep_colors,
+        } => install::execute(source, slugs, keep_colors).await,
         IconsCommands::Import { path, name } => import::execute(path, name),
         IconsCommands::Remove { names } => remove::execute(names),
         IconsCommands::Sources => sources::execute(),
@@ -39,5 +47,7 @@ pub async fn execute(command: IconsCommands) -> CliAction {
         } => list::execute(source, interactive),
         IconsCommands::Open => open::execute(),
         IconsCommands::Export { destination } => export::execute(destination),
+        IconsCommands::Lint { names, repair } => lint::execute(names, repair),
+        IconsCommands::Prune { dry_run } => prune::execute(dry_run),
     }
 }
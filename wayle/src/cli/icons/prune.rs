@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use toml::Value;
+use wayle_config::{Config, ConfigPaths};
+use wayle_icons::{IconManager, bundled};
+
+use crate::cli::CliAction;
+
+/// Removes installed icons the current config no longer references.
+///
+/// Every string in the effective config (defaults plus `config.toml` and its
+/// imports) counts as a reference, as do the icons bundled with Wayle. Only
+/// icons recorded in the install manifest are candidates, so icons copied
+/// in by hand are left alone.
+///
+/// # Errors
+///
+/// Returns error if the config cannot be loaded or an icon cannot be deleted.
+pub fn execute(dry_run: bool) -> CliAction {
+    let manager = IconManager::new().map_err(|err| err.to_string())?;
+    let keep = referenced_icons()?;
+
+    if dry_run {
+        let manifest = manager.manifest();
+        let stale: Vec<&str> = manifest
+            .iter()
+            .map(|(name, _)| name)
+            .filter(|name| !keep.contains(*name) && manager.is_installed(name))
+            .collect();
+
+        if stale.is_empty() {
+            println!("No unused icons");
+            return Ok(());
+        }
+
+        println!("\nWould remove {} icon(s):\n", stale.len());
+        for name in stale {
+            println!("  {name}");
+        }
+        println!();
+
+        return Ok(());
+    }
+
+    let result = manager.prune(&keep).map_err(|err| err.to_string())?;
+
+    if result.removed.is_empty() {
+        println!("No unused icons");
+        return Ok(());
+    }
+
+    for name in &result.removed {
+        println!("Removed: {name}");
+    }
+    println!(
+        "\n{} icon(s) removed, {} freed",
+        result.removed.len(),
+        format_bytes(result.freed_bytes)
+    );
+
+    Ok(())
+}
+
+fn referenced_icons() -> Result<HashSet<String>, String> {
+    let config = Config::load_with_imports(&ConfigPaths::main_config())
+        .map_err(|err| format!("Failed to load config: {err}"))?;
+    let value =
+        Value::try_from(&config).map_err(|err| format!("Failed to read config values: {err}"))?;
+
+    let mut keep: HashSet<String> = bundled::names().into_iter().collect();
+    collect_strings(&value, &mut keep);
+
+    Ok(keep)
+}
+
+/// Adds every string in `value` to `keep`, along with its `-symbolic`
+/// variant since configs may name either.
+fn collect_strings(value: &Value, keep: &mut HashSet<String>) {
+    match value {
+        Value::String(text) => {
+            keep.insert(format!("{text}-symbolic"));
+            keep.insert(text.clone());
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_strings(item, keep);
+            }
+        }
+        Value::Table(table) => {
+            for item in table.values() {
+                collect_strings(item, keep);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const KIB: f64 = 1024.0;
+
+    let bytes = bytes as f64;
+    if bytes < KIB {
+        format!("{bytes} B")
+    } else if bytes < KIB * KIB {
+        format!("{:.1} KiB", bytes / KIB)
+    } else {
+        format!("{:.1} MiB", bytes / (KIB * KIB))
+    }
+}
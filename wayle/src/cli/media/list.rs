@@ -20,7 +20,7 @@ pub async fn execute() -> CliAction {
 
     println!("Available media players:");
 
-    for (index, (id, identity, state)) in players.iter().enumerate() {
+    for (index, (id, identity, state, title, artist)) in players.iter().enumerate() {
         let status = match state.as_str() {
             "Playing" => "Playing",
             "Paused" => "Paused",
@@ -28,6 +28,12 @@ pub async fn execute() -> CliAction {
         };
 
         println!("  {}. {} ({}) [{}]", index + 1, identity, id, status);
+
+        match (title.is_empty(), artist.is_empty()) {
+            (false, false) => println!("     {artist} - {title}"),
+            (false, true) => println!("     {title}"),
+            _ => {}
+        }
     }
 
     Ok(())
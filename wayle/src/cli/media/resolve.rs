@@ -33,7 +33,7 @@ pub async fn resolve_player(
         if index == 0 {
             return Err("Player numbers start at 1".to_string());
         }
-        if let Some((id, _, _)) = players.get(index - 1) {
+        if let Some((id, ..)) = players.get(index - 1) {
             return Ok(id.clone());
         }
         return Err(format!(
@@ -44,7 +44,7 @@ pub async fn resolve_player(
 
     let input_lower = input.to_lowercase();
 
-    for (id, identity, _) in &players {
+    for (id, identity, ..) in &players {
         if id.to_lowercase().contains(&input_lower)
             || identity.to_lowercase().contains(&input_lower)
         {
@@ -55,7 +55,7 @@ pub async fn resolve_player(
     let available: Vec<_> = players
         .iter()
         .enumerate()
-        .map(|(i, (_, identity, _))| format!("{}. {}", i + 1, identity))
+        .map(|(i, (_, identity, ..))| format!("{}. {}", i + 1, identity))
         .collect();
 
     Err(format!(